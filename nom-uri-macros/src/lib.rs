@@ -0,0 +1,80 @@
+//! Compile-time validated URI literals for `nom-uri`.
+
+use nom_uri::{Host, Uri};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// Parses a string literal as a `URI` at compile time and expands to a `&'static nom_uri::Uri<'static>`.
+///
+/// The component offsets are computed by the macro, so nothing is parsed at runtime.
+///
+/// ```
+/// use nom_uri_macros::uri;
+///
+/// let api = uri!("https://api.example.com/v1");
+/// assert_eq!(api.host_str(), Some("api.example.com"));
+/// assert_eq!(api.path(), "/v1");
+/// ```
+///
+/// An invalid literal fails compilation with the offset at which it stopped matching:
+///
+/// ```compile_fail
+/// let api = nom_uri_macros::uri!("https://api.example.com/v 1");
+/// ```
+#[proc_macro]
+pub fn uri(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let lit = syn::parse_macro_input!(input as LitStr);
+    expand(&lit)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(lit: &LitStr) -> syn::Result<TokenStream> {
+    let s = lit.value();
+    let uri = Uri::parse(&s)
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid URI literal: {}", e)))?;
+
+    let range = |part: &str| {
+        let start = part.as_ptr() as usize - s.as_ptr() as usize;
+        let end = start + part.len();
+        quote! { (#start, #end) }
+    };
+    let option = |range: Option<TokenStream>| match range {
+        Some(r) => quote! { ::core::option::Option::Some(#r) },
+        None => quote! { ::core::option::Option::None },
+    };
+
+    let scheme = range(uri.scheme());
+    let authority = option(uri.authority().map(|authority| {
+        let span = range(authority);
+        let userinfo = option(uri.userinfo().map(range));
+        let host = range(uri.host_str().expect("authority with a host"));
+        let host_kind = match uri.host().expect("authority with a host") {
+            Host::Ipv6(_) | Host::IpvFuture(_) => quote! { IpLiteral },
+            Host::Ipv4(_) => quote! { Ipv4Address },
+            Host::RegName(_) => quote! { RegName },
+        };
+        let port = option(uri.port().map(range));
+        quote! {
+            (#span, #userinfo, #host, ::nom_uri::parser::host::Kind::#host_kind, #port)
+        }
+    }));
+    let path = range(uri.path());
+    let query = option(uri.query().map(range));
+    let fragment = option(uri.fragment().map(range));
+
+    Ok(quote! {
+        {
+            static URI: ::nom_uri::Uri<'static> = ::nom_uri::Uri::__from_static(
+                #lit,
+                #scheme,
+                #authority,
+                #path,
+                #query,
+                #fragment,
+            );
+            &URI
+        }
+    })
+}
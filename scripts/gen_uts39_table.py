@@ -0,0 +1,77 @@
+#!/usr/bin/env python3
+"""Generates src/deceptive/uts39_table.rs from the Unicode data files.
+
+Takes the paths of Scripts.txt (https://www.unicode.org/Public/16.0.0/ucd/Scripts.txt)
+and confusables.txt (https://www.unicode.org/Public/security/16.0.0/confusables.txt).
+Run from the repository root:
+
+    python3 scripts/gen_uts39_table.py Scripts.txt confusables.txt > src/deceptive/uts39_table.rs
+"""
+
+import sys
+
+# characters of these scripts are used with any script
+NEUTRAL = {"Common", "Inherited"}
+
+
+def records(path):
+    with open(path, encoding="utf-8-sig") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if line:
+                yield [field.strip() for field in line.split(";")]
+
+
+def scripts(path):
+    ranges = []
+    for fields in records(path):
+        if fields[1] in NEUTRAL:
+            continue
+        start, _, end = fields[0].partition("..")
+        ranges.append((int(start, 16), int(end or start, 16), fields[1]))
+    ranges.sort()
+    merged = []
+    for start, end, script in ranges:
+        if merged and merged[-1][2] == script and merged[-1][1] + 1 == start:
+            merged[-1] = (merged[-1][0], end, script)
+        else:
+            merged.append((start, end, script))
+    return merged
+
+
+def confusables(path):
+    return sorted(
+        (int(fields[0], 16), "".join(chr(int(cp, 16)) for cp in fields[1].split()))
+        for fields in records(path)
+    )
+
+
+def rust_str(s):
+    out = []
+    for c in s:
+        if c in '"\\':
+            out.append("\\" + c)
+        elif " " <= c <= "~":
+            out.append(c)
+        else:
+            out.append("\\u{%X}" % ord(c))
+    return '"%s"' % "".join(out)
+
+
+def main():
+    print("// Generated by scripts/gen_uts39_table.py from Unicode 16.0.0. Do not edit.")
+    print()
+    print("pub(super) static SCRIPTS: &[(u32, u32, &str)] = &[")
+    for start, end, script in scripts(sys.argv[1]):
+        print("    (0x%X, 0x%X, %s)," % (start, end, rust_str(script)))
+    print("];")
+    print()
+    print("#[rustfmt::skip]")
+    print("pub(super) static CONFUSABLES: &[(u32, &str)] = &[")
+    for cp, prototype in confusables(sys.argv[2]):
+        print("    (0x%X, %s)," % (cp, rust_str(prototype)))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
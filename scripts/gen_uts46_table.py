@@ -0,0 +1,166 @@
+#!/usr/bin/env python3
+"""Generates src/idna/uts46_table.rs from the Unicode data files.
+
+Takes the paths of IdnaMappingTable.txt (https://www.unicode.org/Public/idna/16.0.0/IdnaMappingTable.txt),
+UnicodeData.txt (https://www.unicode.org/Public/16.0.0/ucd/UnicodeData.txt) and CompositionExclusions.txt
+(https://www.unicode.org/Public/16.0.0/ucd/CompositionExclusions.txt). Run from the repository root:
+
+    python3 scripts/gen_uts46_table.py IdnaMappingTable.txt UnicodeData.txt CompositionExclusions.txt > src/idna/uts46_table.rs
+"""
+
+import sys
+
+# non-transitional processing keeps the deviation characters; the STD3 statuses of older tables are applied in code
+STATUS = {
+    "valid": "Valid",
+    "deviation": "Valid",
+    "disallowed_STD3_valid": "Valid",
+    "mapped": "Mapped",
+    "disallowed_STD3_mapped": "Mapped",
+    "ignored": "Ignored",
+    "disallowed": "Disallowed",
+}
+
+
+def records(path):
+    with open(path, encoding="utf-8-sig") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if line:
+                yield [field.strip() for field in line.split(";")]
+
+
+def code_points(s):
+    return "".join(chr(int(cp, 16)) for cp in s.split())
+
+
+def mapping(path):
+    entries = []
+    for fields in records(path):
+        start, _, end = fields[0].partition("..")
+        start, end = int(start, 16), int(end or start, 16)
+        status = STATUS[fields[1]]
+        mapped = code_points(fields[2]) if status == "Mapped" else None
+        if entries and status != "Mapped" and entries[-1][2] == status and entries[-1][1] + 1 == start:
+            entries[-1] = (entries[-1][0], end, status, None)
+        else:
+            entries.append((start, end, status, mapped))
+    return entries
+
+
+def unicode_data(path):
+    """Returns the general category, canonical combining class and decomposition mapping by code point."""
+    data = {}
+    first = None
+    for fields in records(path):
+        cp = int(fields[0], 16)
+        if fields[1].endswith(", First>"):
+            first = cp
+            continue
+        for x in range(first if fields[1].endswith(", Last>") else cp, cp + 1):
+            data[x] = (fields[2], int(fields[3]), fields[5])
+    return data
+
+
+def ranges(data, pred):
+    out = []
+    for cp in sorted(data):
+        value = pred(*data[cp])
+        if not value:
+            continue
+        if out and out[-1][1] + 1 == cp and out[-1][2] == value:
+            out[-1] = (out[-1][0], cp, value)
+        else:
+            out.append((cp, cp, value))
+    return out
+
+
+def canonical(data, cp):
+    d = data.get(cp, ("", 0, ""))[2]
+    if not d or d.startswith("<"):
+        return None
+    return [int(x, 16) for x in d.split()]
+
+
+def decompose(data, cp):
+    parts = canonical(data, cp)
+    if parts is None:
+        return [cp]
+    return [x for part in parts for x in decompose(data, part)]
+
+
+def compositions(data, exclusions):
+    pairs = []
+    for cp in data:
+        parts = canonical(data, cp)
+        # singletons, non-starter decompositions and exclusions are never composed
+        if parts is None or len(parts) != 2 or cp in exclusions:
+            continue
+        if data[cp][1] != 0 or data[parts[0]][1] != 0:
+            continue
+        pairs.append((parts[0], parts[1], cp))
+    return sorted(pairs)
+
+
+def rust_str(s):
+    out = []
+    for c in s:
+        if c in '"\\':
+            out.append("\\" + c)
+        elif " " <= c <= "~":
+            out.append(c)
+        else:
+            out.append("\\u{%X}" % ord(c))
+    return '"%s"' % "".join(out)
+
+
+def main():
+    data = unicode_data(sys.argv[2])
+    exclusions = {int(fields[0], 16) for fields in records(sys.argv[3])}
+
+    print("// Generated by scripts/gen_uts46_table.py from Unicode 16.0.0. Do not edit.")
+    print()
+    print("use super::uts46::Status;")
+    print()
+    print("pub(super) static MAPPING: &[(u32, u32, Status)] = &[")
+    for start, end, status, mapped in mapping(sys.argv[1]):
+        if status == "Mapped":
+            print("    (0x%X, 0x%X, Status::Mapped(%s))," % (start, end, rust_str(mapped)))
+        else:
+            print("    (0x%X, 0x%X, Status::%s)," % (start, end, status))
+    print("];")
+    print()
+    print("/// General_Category=Mark")
+    print("pub(super) static COMBINING_MARK: &[(u32, u32)] = &[")
+    for start, end, _ in ranges(data, lambda gc, ccc, d: gc.startswith("M")):
+        print("    (0x%X, 0x%X)," % (start, end))
+    print("];")
+    print()
+    print("/// Canonical_Combining_Class=Virama")
+    print("pub(super) static VIRAMA: &[(u32, u32)] = &[")
+    for start, end, _ in ranges(data, lambda gc, ccc, d: ccc == 9):
+        print("    (0x%X, 0x%X)," % (start, end))
+    print("];")
+    print()
+    print("/// Canonical_Combining_Class of the non-starters, sorted.")
+    print("pub(super) static COMBINING_CLASS: &[(u32, u32, u8)] = &[")
+    for start, end, ccc in ranges(data, lambda gc, ccc, d: ccc):
+        print("    (0x%X, 0x%X, %d)," % (start, end, ccc))
+    print("];")
+    print()
+    print("/// Full canonical decompositions, sorted. Hangul syllables are decomposed algorithmically.")
+    print("pub(super) static DECOMPOSITION: &[(u32, &str)] = &[")
+    for cp in sorted(data):
+        if canonical(data, cp) is not None:
+            print("    (0x%X, %s)," % (cp, rust_str("".join(map(chr, decompose(data, cp))))))
+    print("];")
+    print()
+    print("/// Canonical composition pairs, sorted. Hangul syllables are composed algorithmically.")
+    print("pub(super) static COMPOSITION: &[(u32, u32, u32)] = &[")
+    for a, b, c in compositions(data, exclusions):
+        print("    (0x%X, 0x%X, 0x%X)," % (a, b, c))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
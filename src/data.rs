@@ -0,0 +1,496 @@
+//! The `data` URI scheme
+//!
+//! ```text
+//! dataurl    := "data:" [ mediatype ] [ ";base64" ] "," data
+//! mediatype  := [ type "/" subtype ] *( ";" parameter )
+//! data       := *urlchar
+//! parameter  := attribute "=" value
+//! ```
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc2397#section-3>
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{ParseError, Uri};
+
+/// An error returned when a URI is not a `data` URI, or when its data cannot be decoded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataError {
+    /// The input is not a `URI`.
+    Syntax(ParseError),
+    /// The scheme is not `data`.
+    Scheme,
+    /// There is no `,` before the data, or the URI has an authority.
+    MissingComma,
+    /// The media type or a parameter is invalid.
+    MediaType,
+    /// The base64 data is invalid at the byte offset in the URI.
+    Base64(usize),
+}
+
+impl core::fmt::Display for DataError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DataError::Syntax(e) => write!(f, "{}", e),
+            DataError::Scheme => f.write_str("scheme is not data"),
+            DataError::MissingComma => f.write_str("missing ',' before the data"),
+            DataError::MediaType => f.write_str("invalid media type"),
+            DataError::Base64(offset) => write!(f, "invalid base64 at offset {}", offset),
+        }
+    }
+}
+
+impl core::error::Error for DataError {}
+
+/// A parsed `data` URI.
+///
+/// The data runs from the `,` to the fragment, so it includes a query. The media type and the parameters are
+/// returned as written, not percent-decoded.
+///
+/// ```
+/// use nom_uri::data::DataUri;
+///
+/// let uri = DataUri::parse("data:text/plain;charset=utf-8;base64,SGVsbG8sIOS4lueVjA==").unwrap();
+/// assert_eq!(uri.media_type(), "text/plain");
+/// assert_eq!(uri.charset(), Some("utf-8"));
+/// assert!(uri.is_base64());
+///
+/// let uri = DataUri::parse("data:,A%20brief%20note").unwrap();
+/// assert_eq!(uri.media_type(), "text/plain");
+/// assert_eq!(uri.charset(), Some("US-ASCII"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DataUri<'a> {
+    uri: Uri<'a>,
+    media_type: &'a str,
+    parameters: &'a str,
+    base64: bool,
+    /// The offset of the data in the URI.
+    offset: usize,
+}
+
+impl<'a> DataUri<'a> {
+    /// Parses `s` as a `data` URI.
+    pub fn parse(s: &'a str) -> Result<Self, DataError> {
+        Self::try_from(Uri::parse(s).map_err(DataError::Syntax)?)
+    }
+
+    pub fn as_uri(&self) -> Uri<'a> {
+        self.uri
+    }
+
+    /// Returns `type "/" subtype`, or `text/plain` if it is omitted.
+    pub fn media_type(&self) -> &'a str {
+        match self.media_type {
+            "" => "text/plain",
+            media_type => media_type,
+        }
+    }
+
+    /// Returns the parameters in order, without `;base64`.
+    pub fn parameters(&self) -> Parameters<'a> {
+        Parameters {
+            rest: self.parameters,
+        }
+    }
+
+    /// Returns the value of the first parameter named `name`, ignoring ASCII case.
+    pub fn parameter(&self, name: &str) -> Option<&'a str> {
+        self.parameters()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v)
+    }
+
+    /// Returns the `charset` parameter, or `US-ASCII` if the media type and the parameters are omitted.
+    pub fn charset(&self) -> Option<&'a str> {
+        match self.parameter("charset") {
+            None if self.media_type.is_empty() && self.parameters.is_empty() => Some("US-ASCII"),
+            charset => charset,
+        }
+    }
+
+    pub fn is_base64(&self) -> bool {
+        self.base64
+    }
+
+    /// Returns the data, not decoded.
+    pub fn data(&self) -> &'a str {
+        let end = match self.uri.fragment() {
+            Some(fragment) => self.uri.as_str().len() - fragment.len() - 1,
+            None => self.uri.as_str().len(),
+        };
+        &self.uri.as_str()[self.offset..end]
+    }
+
+    /// Returns a decoder of the data, percent-decoding it and then base64-decoding it if it is base64.
+    pub fn decoder(&self) -> Decoder<'a> {
+        Decoder {
+            data: self.data().as_bytes(),
+            offset: self.offset,
+            pos: 0,
+            base64: self.base64.then(Base64::default),
+            pending: Pending::default(),
+        }
+    }
+
+    /// Decodes the data.
+    ///
+    /// ```
+    /// use nom_uri::data::DataUri;
+    ///
+    /// let uri = DataUri::parse("data:text/plain;charset=utf-8;base64,SGVsbG8sIOS4lueVjA==").unwrap();
+    /// assert_eq!(uri.decode().unwrap(), "Hello, 世界".as_bytes());
+    /// let uri = DataUri::parse("data:,A%20brief%20note").unwrap();
+    /// assert_eq!(uri.decode().unwrap(), b"A brief note");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn decode(&self) -> Result<Vec<u8>, DataError> {
+        let mut decoder = self.decoder();
+        let mut decoded = Vec::with_capacity(self.data().len());
+        let mut buf = [0; 256];
+        loop {
+            match decoder.read(&mut buf)? {
+                0 => return Ok(decoded),
+                n => decoded.extend_from_slice(&buf[..n]),
+            }
+        }
+    }
+}
+
+impl<'a> TryFrom<Uri<'a>> for DataUri<'a> {
+    type Error = DataError;
+
+    fn try_from(uri: Uri<'a>) -> Result<Self, Self::Error> {
+        if !uri.scheme().eq_ignore_ascii_case("data") {
+            return Err(DataError::Scheme);
+        }
+        if uri.authority().is_some() {
+            return Err(DataError::MissingComma);
+        }
+        let path = uri.path();
+        let (header, _) = path.split_once(',').ok_or(DataError::MissingComma)?;
+        let offset = uri.scheme().len() + 1 + header.len() + 1;
+        let (header, base64) = match header.len().checked_sub(7) {
+            Some(i) if header[i..].eq_ignore_ascii_case(";base64") => (&header[..i], true),
+            _ => (header, false),
+        };
+        let (media_type, parameters) = header.split_at(header.find(';').unwrap_or(header.len()));
+        if !media_type.is_empty() && !is_media_type(media_type) {
+            return Err(DataError::MediaType);
+        }
+        let parameters = Parameters { rest: parameters };
+        if parameters
+            .clone()
+            .any(|(name, value)| name.is_empty() || value.is_empty())
+            || parameters.rest.split(';').skip(1).any(|p| !p.contains('='))
+        {
+            return Err(DataError::MediaType);
+        }
+        Ok(Self {
+            uri,
+            media_type,
+            parameters: parameters.rest,
+            base64,
+            offset,
+        })
+    }
+}
+
+impl core::fmt::Display for DataUri<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.uri)
+    }
+}
+
+fn is_media_type(s: &str) -> bool {
+    let is_token = |s: &str| {
+        !s.is_empty()
+            && s.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"!#$&-^_.+%".contains(&b))
+    };
+    s.split_once('/')
+        .is_some_and(|(t, subtype)| is_token(t) && is_token(subtype))
+}
+
+/// An iterator over the `attribute "=" value` parameters of a [`DataUri`].
+#[derive(Clone, Debug)]
+pub struct Parameters<'a> {
+    /// `*( ";" parameter )`
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Parameters<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.strip_prefix(';')?;
+        let (parameter, rest) = rest.split_at(rest.find(';').unwrap_or(rest.len()));
+        self.rest = rest;
+        Some(parameter.split_once('=').unwrap_or((parameter, "")))
+    }
+}
+
+/// A streaming decoder of the data of a [`DataUri`].
+///
+/// ```
+/// use nom_uri::data::DataUri;
+///
+/// let uri = DataUri::parse("data:;base64,AAECAwQFBgcICQ==").unwrap();
+/// let mut decoder = uri.decoder();
+/// let mut buf = [0; 4];
+/// assert_eq!(decoder.read(&mut buf), Ok(4));
+/// assert_eq!(buf, [0, 1, 2, 3]);
+/// assert_eq!(decoder.read(&mut buf), Ok(4));
+/// assert_eq!(decoder.read(&mut buf), Ok(2));
+/// assert_eq!(&buf[..2], [8, 9]);
+/// assert_eq!(decoder.read(&mut buf), Ok(0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Decoder<'a> {
+    data: &'a [u8],
+    /// The offset of the data in the URI.
+    offset: usize,
+    pos: usize,
+    base64: Option<Base64>,
+    pending: Pending,
+}
+
+/// The decoded bytes that are not yet read.
+#[derive(Clone, Copy, Debug, Default)]
+struct Pending {
+    bytes: [u8; 3],
+    len: usize,
+    pos: usize,
+}
+
+impl Pending {
+    /// Sets the bytes to the first `len` bytes of the 24 `bits`.
+    fn set(&mut self, bits: u32, len: usize) {
+        self.bytes = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+        self.len = len;
+        self.pos = 0;
+    }
+}
+
+/// The state of base64 decoding.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc4648#section-4>
+#[derive(Clone, Copy, Debug, Default)]
+struct Base64 {
+    bits: u32,
+    sextets: u8,
+    padding: u8,
+    finished: bool,
+}
+
+impl Decoder<'_> {
+    /// Decodes into `buf`, returning the number of bytes written. It returns 0 at the end of the data.
+    ///
+    /// Base64 data may omit its padding.
+    pub fn read(&mut self, buf: &mut [u8]) -> Result<usize, DataError> {
+        let mut written = 0;
+        while written < buf.len() {
+            if self.pending.pos < self.pending.len {
+                buf[written] = self.pending.bytes[self.pending.pos];
+                self.pending.pos += 1;
+                written += 1;
+                continue;
+            }
+            let offset = self.offset + self.pos;
+            let b = self.next_byte();
+            let Some(base64) = &mut self.base64 else {
+                match b {
+                    Some(b) => {
+                        buf[written] = b;
+                        written += 1;
+                        continue;
+                    }
+                    None => break,
+                }
+            };
+            if base64.finished {
+                break;
+            }
+            let error = DataError::Base64(offset);
+            let sextet = match b {
+                None => {
+                    base64.finished = true;
+                    if base64.sextets == 1 {
+                        return Err(error);
+                    }
+                    // the 6 or 12 bits that do not make a byte are dropped
+                    let bits = base64.bits << (6 * (4 - u32::from(base64.sextets)));
+                    self.pending
+                        .set(bits, base64.sextets.saturating_sub(1).into());
+                    continue;
+                }
+                Some(b'=') => {
+                    if base64.sextets < 2 || base64.sextets + base64.padding >= 4 {
+                        return Err(error);
+                    }
+                    base64.padding += 1;
+                    continue;
+                }
+                Some(_) if base64.padding > 0 => return Err(error),
+                Some(b) => match b {
+                    b'A'..=b'Z' => b - b'A',
+                    b'a'..=b'z' => b - b'a' + 26,
+                    b'0'..=b'9' => b - b'0' + 52,
+                    b'+' => 62,
+                    b'/' => 63,
+                    _ => return Err(error),
+                },
+            };
+            base64.bits = base64.bits << 6 | u32::from(sextet);
+            base64.sextets += 1;
+            if base64.sextets == 4 {
+                let bits = base64.bits;
+                *base64 = Base64::default();
+                self.pending.set(bits, 3);
+            }
+        }
+        Ok(written)
+    }
+
+    /// Returns the next percent-decoded byte.
+    fn next_byte(&mut self) -> Option<u8> {
+        let b = *self.data.get(self.pos)?;
+        if b == b'%' {
+            // a URI only has `%` in `pct-encoded` triplets
+            let pair = core::str::from_utf8(&self.data[self.pos + 1..self.pos + 3]).ok()?;
+            self.pos += 3;
+            u8::from_str_radix(pair, 16).ok()
+        } else {
+            self.pos += 1;
+            Some(b)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for Decoder<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Decoder::read(self, buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let f = |s| {
+            DataUri::parse(s).map(|u| {
+                (
+                    u.media_type(),
+                    u.parameters().collect::<Vec<_>>(),
+                    u.is_base64(),
+                    u.data(),
+                )
+            })
+        };
+        assert_eq!(f("data:,"), Ok(("text/plain", vec![], false, "")));
+        assert_eq!(
+            f("data:image/gif;base64,R0lGODdh"),
+            Ok(("image/gif", vec![], true, "R0lGODdh"))
+        );
+        assert_eq!(
+            f("DATA:text/plain;charset=iso-8859-7,%be%fg%be"),
+            Err(DataError::Syntax(ParseError::new(38)))
+        );
+        assert_eq!(
+            f("data:text/plain;charset=iso-8859-7,%be%d3%be"),
+            Ok((
+                "text/plain",
+                vec![("charset", "iso-8859-7")],
+                false,
+                "%be%d3%be"
+            ))
+        );
+        assert_eq!(
+            f("data:application/vnd-xxx-query,select_vcount,fcol_from_fieldtable/local"),
+            Ok((
+                "application/vnd-xxx-query",
+                vec![],
+                false,
+                "select_vcount,fcol_from_fieldtable/local"
+            ))
+        );
+        assert_eq!(
+            f("data:;a=1;B=2;BASE64,x?y#z"),
+            Ok(("text/plain", vec![("a", "1"), ("B", "2")], true, "x?y"))
+        );
+        assert_eq!(f("data:base64,"), Err(DataError::MediaType));
+        assert_eq!(f("data:text,a"), Err(DataError::MediaType));
+        assert_eq!(f("data:text/,a"), Err(DataError::MediaType));
+        assert_eq!(f("data:;a,b"), Err(DataError::MediaType));
+        assert_eq!(f("data:;=b,c"), Err(DataError::MediaType));
+        assert_eq!(f("data:text/plain"), Err(DataError::MissingComma));
+        assert_eq!(f("data://a/,b"), Err(DataError::MissingComma));
+        assert_eq!(f("http:,a"), Err(DataError::Scheme));
+
+        let uri = DataUri::parse("data:text/html;Charset=UTF-8,a").unwrap();
+        assert_eq!(uri.charset(), Some("UTF-8"));
+        assert_eq!(uri.parameter("CHARSET"), Some("UTF-8"));
+        assert_eq!(DataUri::parse("data:image/png,").unwrap().charset(), None);
+        assert_eq!(
+            DataUri::parse("data:;base64,").unwrap().charset(),
+            Some("US-ASCII")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_decode() {
+        let f = |s| DataUri::parse(s).unwrap().decode();
+        assert_eq!(f("data:,a%20b%2C"), Ok(b"a b,".to_vec()));
+        assert_eq!(f("data:,a?b"), Ok(b"a?b".to_vec()));
+        assert_eq!(f("data:;base64,"), Ok(vec![]));
+        assert_eq!(f("data:;base64,Zg=="), Ok(b"f".to_vec()));
+        assert_eq!(f("data:;base64,Zm8="), Ok(b"fo".to_vec()));
+        assert_eq!(f("data:;base64,Zm9v"), Ok(b"foo".to_vec()));
+        assert_eq!(f("data:;base64,Zm9vYg"), Ok(b"foob".to_vec()));
+        assert_eq!(f("data:;base64,Zm9vYmE"), Ok(b"fooba".to_vec()));
+        assert_eq!(f("data:;base64,Zm9vYmFy"), Ok(b"foobar".to_vec()));
+        assert_eq!(f("data:;base64,%5A%6d9v"), Ok(b"foo".to_vec()));
+        assert_eq!(f("data:;base64,+/+/"), Ok(vec![0xFB, 0xFF, 0xBF]));
+        assert_eq!(f("data:;base64,Z"), Err(DataError::Base64(14)));
+        assert_eq!(f("data:;base64,Zm9v!"), Err(DataError::Base64(17)));
+        assert_eq!(f("data:;base64,Z==="), Err(DataError::Base64(14)));
+        assert_eq!(f("data:;base64,Zg==="), Err(DataError::Base64(17)));
+        assert_eq!(f("data:;base64,Zg==Zg=="), Err(DataError::Base64(17)));
+        assert_eq!(f("data:;base64,Zm9v%20"), Err(DataError::Base64(17)));
+    }
+
+    #[test]
+    fn test_decoder() {
+        let uri = DataUri::parse("data:;base64,Zm9vYmFyYmF6").unwrap();
+        for size in 1..10 {
+            let mut decoder = uri.decoder();
+            let mut decoded = vec![];
+            let mut buf = vec![0; size];
+            loop {
+                match decoder.read(&mut buf).unwrap() {
+                    0 => break,
+                    n => decoded.extend_from_slice(&buf[..n]),
+                }
+            }
+            assert_eq!(decoded, b"foobarbaz", "buffer size {}", size);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decoder_io_read() {
+        let mut decoded = vec![];
+        std::io::Read::read_to_end(
+            &mut DataUri::parse("data:,a%00b").unwrap().decoder(),
+            &mut decoded,
+        )
+        .unwrap();
+        assert_eq!(decoded, b"a\0b");
+    }
+}
@@ -0,0 +1,7139 @@
+// Generated by scripts/gen_uts39_table.py from Unicode 16.0.0. Do not edit.
+
+pub(super) static SCRIPTS: &[(u32, u32, &str)] = &[
+    (0x41, 0x5A, "Latin"),
+    (0x61, 0x7A, "Latin"),
+    (0xAA, 0xAA, "Latin"),
+    (0xBA, 0xBA, "Latin"),
+    (0xC0, 0xD6, "Latin"),
+    (0xD8, 0xF6, "Latin"),
+    (0xF8, 0x2B8, "Latin"),
+    (0x2E0, 0x2E4, "Latin"),
+    (0x2EA, 0x2EB, "Bopomofo"),
+    (0x370, 0x373, "Greek"),
+    (0x375, 0x377, "Greek"),
+    (0x37A, 0x37D, "Greek"),
+    (0x37F, 0x37F, "Greek"),
+    (0x384, 0x384, "Greek"),
+    (0x386, 0x386, "Greek"),
+    (0x388, 0x38A, "Greek"),
+    (0x38C, 0x38C, "Greek"),
+    (0x38E, 0x3A1, "Greek"),
+    (0x3A3, 0x3E1, "Greek"),
+    (0x3E2, 0x3EF, "Coptic"),
+    (0x3F0, 0x3FF, "Greek"),
+    (0x400, 0x484, "Cyrillic"),
+    (0x487, 0x52F, "Cyrillic"),
+    (0x531, 0x556, "Armenian"),
+    (0x559, 0x58A, "Armenian"),
+    (0x58D, 0x58F, "Armenian"),
+    (0x591, 0x5C7, "Hebrew"),
+    (0x5D0, 0x5EA, "Hebrew"),
+    (0x5EF, 0x5F4, "Hebrew"),
+    (0x600, 0x604, "Arabic"),
+    (0x606, 0x60B, "Arabic"),
+    (0x60D, 0x61A, "Arabic"),
+    (0x61C, 0x61E, "Arabic"),
+    (0x620, 0x63F, "Arabic"),
+    (0x641, 0x64A, "Arabic"),
+    (0x656, 0x66F, "Arabic"),
+    (0x671, 0x6DC, "Arabic"),
+    (0x6DE, 0x6FF, "Arabic"),
+    (0x700, 0x70D, "Syriac"),
+    (0x70F, 0x74A, "Syriac"),
+    (0x74D, 0x74F, "Syriac"),
+    (0x750, 0x77F, "Arabic"),
+    (0x780, 0x7B1, "Thaana"),
+    (0x7C0, 0x7FA, "Nko"),
+    (0x7FD, 0x7FF, "Nko"),
+    (0x800, 0x82D, "Samaritan"),
+    (0x830, 0x83E, "Samaritan"),
+    (0x840, 0x85B, "Mandaic"),
+    (0x85E, 0x85E, "Mandaic"),
+    (0x860, 0x86A, "Syriac"),
+    (0x870, 0x88E, "Arabic"),
+    (0x890, 0x891, "Arabic"),
+    (0x897, 0x8E1, "Arabic"),
+    (0x8E3, 0x8FF, "Arabic"),
+    (0x900, 0x950, "Devanagari"),
+    (0x955, 0x963, "Devanagari"),
+    (0x966, 0x97F, "Devanagari"),
+    (0x980, 0x983, "Bengali"),
+    (0x985, 0x98C, "Bengali"),
+    (0x98F, 0x990, "Bengali"),
+    (0x993, 0x9A8, "Bengali"),
+    (0x9AA, 0x9B0, "Bengali"),
+    (0x9B2, 0x9B2, "Bengali"),
+    (0x9B6, 0x9B9, "Bengali"),
+    (0x9BC, 0x9C4, "Bengali"),
+    (0x9C7, 0x9C8, "Bengali"),
+    (0x9CB, 0x9CE, "Bengali"),
+    (0x9D7, 0x9D7, "Bengali"),
+    (0x9DC, 0x9DD, "Bengali"),
+    (0x9DF, 0x9E3, "Bengali"),
+    (0x9E6, 0x9FE, "Bengali"),
+    (0xA01, 0xA03, "Gurmukhi"),
+    (0xA05, 0xA0A, "Gurmukhi"),
+    (0xA0F, 0xA10, "Gurmukhi"),
+    (0xA13, 0xA28, "Gurmukhi"),
+    (0xA2A, 0xA30, "Gurmukhi"),
+    (0xA32, 0xA33, "Gurmukhi"),
+    (0xA35, 0xA36, "Gurmukhi"),
+    (0xA38, 0xA39, "Gurmukhi"),
+    (0xA3C, 0xA3C, "Gurmukhi"),
+    (0xA3E, 0xA42, "Gurmukhi"),
+    (0xA47, 0xA48, "Gurmukhi"),
+    (0xA4B, 0xA4D, "Gurmukhi"),
+    (0xA51, 0xA51, "Gurmukhi"),
+    (0xA59, 0xA5C, "Gurmukhi"),
+    (0xA5E, 0xA5E, "Gurmukhi"),
+    (0xA66, 0xA76, "Gurmukhi"),
+    (0xA81, 0xA83, "Gujarati"),
+    (0xA85, 0xA8D, "Gujarati"),
+    (0xA8F, 0xA91, "Gujarati"),
+    (0xA93, 0xAA8, "Gujarati"),
+    (0xAAA, 0xAB0, "Gujarati"),
+    (0xAB2, 0xAB3, "Gujarati"),
+    (0xAB5, 0xAB9, "Gujarati"),
+    (0xABC, 0xAC5, "Gujarati"),
+    (0xAC7, 0xAC9, "Gujarati"),
+    (0xACB, 0xACD, "Gujarati"),
+    (0xAD0, 0xAD0, "Gujarati"),
+    (0xAE0, 0xAE3, "Gujarati"),
+    (0xAE6, 0xAF1, "Gujarati"),
+    (0xAF9, 0xAFF, "Gujarati"),
+    (0xB01, 0xB03, "Oriya"),
+    (0xB05, 0xB0C, "Oriya"),
+    (0xB0F, 0xB10, "Oriya"),
+    (0xB13, 0xB28, "Oriya"),
+    (0xB2A, 0xB30, "Oriya"),
+    (0xB32, 0xB33, "Oriya"),
+    (0xB35, 0xB39, "Oriya"),
+    (0xB3C, 0xB44, "Oriya"),
+    (0xB47, 0xB48, "Oriya"),
+    (0xB4B, 0xB4D, "Oriya"),
+    (0xB55, 0xB57, "Oriya"),
+    (0xB5C, 0xB5D, "Oriya"),
+    (0xB5F, 0xB63, "Oriya"),
+    (0xB66, 0xB77, "Oriya"),
+    (0xB82, 0xB83, "Tamil"),
+    (0xB85, 0xB8A, "Tamil"),
+    (0xB8E, 0xB90, "Tamil"),
+    (0xB92, 0xB95, "Tamil"),
+    (0xB99, 0xB9A, "Tamil"),
+    (0xB9C, 0xB9C, "Tamil"),
+    (0xB9E, 0xB9F, "Tamil"),
+    (0xBA3, 0xBA4, "Tamil"),
+    (0xBA8, 0xBAA, "Tamil"),
+    (0xBAE, 0xBB9, "Tamil"),
+    (0xBBE, 0xBC2, "Tamil"),
+    (0xBC6, 0xBC8, "Tamil"),
+    (0xBCA, 0xBCD, "Tamil"),
+    (0xBD0, 0xBD0, "Tamil"),
+    (0xBD7, 0xBD7, "Tamil"),
+    (0xBE6, 0xBFA, "Tamil"),
+    (0xC00, 0xC0C, "Telugu"),
+    (0xC0E, 0xC10, "Telugu"),
+    (0xC12, 0xC28, "Telugu"),
+    (0xC2A, 0xC39, "Telugu"),
+    (0xC3C, 0xC44, "Telugu"),
+    (0xC46, 0xC48, "Telugu"),
+    (0xC4A, 0xC4D, "Telugu"),
+    (0xC55, 0xC56, "Telugu"),
+    (0xC58, 0xC5A, "Telugu"),
+    (0xC5D, 0xC5D, "Telugu"),
+    (0xC60, 0xC63, "Telugu"),
+    (0xC66, 0xC6F, "Telugu"),
+    (0xC77, 0xC7F, "Telugu"),
+    (0xC80, 0xC8C, "Kannada"),
+    (0xC8E, 0xC90, "Kannada"),
+    (0xC92, 0xCA8, "Kannada"),
+    (0xCAA, 0xCB3, "Kannada"),
+    (0xCB5, 0xCB9, "Kannada"),
+    (0xCBC, 0xCC4, "Kannada"),
+    (0xCC6, 0xCC8, "Kannada"),
+    (0xCCA, 0xCCD, "Kannada"),
+    (0xCD5, 0xCD6, "Kannada"),
+    (0xCDD, 0xCDE, "Kannada"),
+    (0xCE0, 0xCE3, "Kannada"),
+    (0xCE6, 0xCEF, "Kannada"),
+    (0xCF1, 0xCF3, "Kannada"),
+    (0xD00, 0xD0C, "Malayalam"),
+    (0xD0E, 0xD10, "Malayalam"),
+    (0xD12, 0xD44, "Malayalam"),
+    (0xD46, 0xD48, "Malayalam"),
+    (0xD4A, 0xD4F, "Malayalam"),
+    (0xD54, 0xD63, "Malayalam"),
+    (0xD66, 0xD7F, "Malayalam"),
+    (0xD81, 0xD83, "Sinhala"),
+    (0xD85, 0xD96, "Sinhala"),
+    (0xD9A, 0xDB1, "Sinhala"),
+    (0xDB3, 0xDBB, "Sinhala"),
+    (0xDBD, 0xDBD, "Sinhala"),
+    (0xDC0, 0xDC6, "Sinhala"),
+    (0xDCA, 0xDCA, "Sinhala"),
+    (0xDCF, 0xDD4, "Sinhala"),
+    (0xDD6, 0xDD6, "Sinhala"),
+    (0xDD8, 0xDDF, "Sinhala"),
+    (0xDE6, 0xDEF, "Sinhala"),
+    (0xDF2, 0xDF4, "Sinhala"),
+    (0xE01, 0xE3A, "Thai"),
+    (0xE40, 0xE5B, "Thai"),
+    (0xE81, 0xE82, "Lao"),
+    (0xE84, 0xE84, "Lao"),
+    (0xE86, 0xE8A, "Lao"),
+    (0xE8C, 0xEA3, "Lao"),
+    (0xEA5, 0xEA5, "Lao"),
+    (0xEA7, 0xEBD, "Lao"),
+    (0xEC0, 0xEC4, "Lao"),
+    (0xEC6, 0xEC6, "Lao"),
+    (0xEC8, 0xECE, "Lao"),
+    (0xED0, 0xED9, "Lao"),
+    (0xEDC, 0xEDF, "Lao"),
+    (0xF00, 0xF47, "Tibetan"),
+    (0xF49, 0xF6C, "Tibetan"),
+    (0xF71, 0xF97, "Tibetan"),
+    (0xF99, 0xFBC, "Tibetan"),
+    (0xFBE, 0xFCC, "Tibetan"),
+    (0xFCE, 0xFD4, "Tibetan"),
+    (0xFD9, 0xFDA, "Tibetan"),
+    (0x1000, 0x109F, "Myanmar"),
+    (0x10A0, 0x10C5, "Georgian"),
+    (0x10C7, 0x10C7, "Georgian"),
+    (0x10CD, 0x10CD, "Georgian"),
+    (0x10D0, 0x10FA, "Georgian"),
+    (0x10FC, 0x10FF, "Georgian"),
+    (0x1100, 0x11FF, "Hangul"),
+    (0x1200, 0x1248, "Ethiopic"),
+    (0x124A, 0x124D, "Ethiopic"),
+    (0x1250, 0x1256, "Ethiopic"),
+    (0x1258, 0x1258, "Ethiopic"),
+    (0x125A, 0x125D, "Ethiopic"),
+    (0x1260, 0x1288, "Ethiopic"),
+    (0x128A, 0x128D, "Ethiopic"),
+    (0x1290, 0x12B0, "Ethiopic"),
+    (0x12B2, 0x12B5, "Ethiopic"),
+    (0x12B8, 0x12BE, "Ethiopic"),
+    (0x12C0, 0x12C0, "Ethiopic"),
+    (0x12C2, 0x12C5, "Ethiopic"),
+    (0x12C8, 0x12D6, "Ethiopic"),
+    (0x12D8, 0x1310, "Ethiopic"),
+    (0x1312, 0x1315, "Ethiopic"),
+    (0x1318, 0x135A, "Ethiopic"),
+    (0x135D, 0x137C, "Ethiopic"),
+    (0x1380, 0x1399, "Ethiopic"),
+    (0x13A0, 0x13F5, "Cherokee"),
+    (0x13F8, 0x13FD, "Cherokee"),
+    (0x1400, 0x167F, "Canadian_Aboriginal"),
+    (0x1680, 0x169C, "Ogham"),
+    (0x16A0, 0x16EA, "Runic"),
+    (0x16EE, 0x16F8, "Runic"),
+    (0x1700, 0x1715, "Tagalog"),
+    (0x171F, 0x171F, "Tagalog"),
+    (0x1720, 0x1734, "Hanunoo"),
+    (0x1740, 0x1753, "Buhid"),
+    (0x1760, 0x176C, "Tagbanwa"),
+    (0x176E, 0x1770, "Tagbanwa"),
+    (0x1772, 0x1773, "Tagbanwa"),
+    (0x1780, 0x17DD, "Khmer"),
+    (0x17E0, 0x17E9, "Khmer"),
+    (0x17F0, 0x17F9, "Khmer"),
+    (0x1800, 0x1801, "Mongolian"),
+    (0x1804, 0x1804, "Mongolian"),
+    (0x1806, 0x1819, "Mongolian"),
+    (0x1820, 0x1878, "Mongolian"),
+    (0x1880, 0x18AA, "Mongolian"),
+    (0x18B0, 0x18F5, "Canadian_Aboriginal"),
+    (0x1900, 0x191E, "Limbu"),
+    (0x1920, 0x192B, "Limbu"),
+    (0x1930, 0x193B, "Limbu"),
+    (0x1940, 0x1940, "Limbu"),
+    (0x1944, 0x194F, "Limbu"),
+    (0x1950, 0x196D, "Tai_Le"),
+    (0x1970, 0x1974, "Tai_Le"),
+    (0x1980, 0x19AB, "New_Tai_Lue"),
+    (0x19B0, 0x19C9, "New_Tai_Lue"),
+    (0x19D0, 0x19DA, "New_Tai_Lue"),
+    (0x19DE, 0x19DF, "New_Tai_Lue"),
+    (0x19E0, 0x19FF, "Khmer"),
+    (0x1A00, 0x1A1B, "Buginese"),
+    (0x1A1E, 0x1A1F, "Buginese"),
+    (0x1A20, 0x1A5E, "Tai_Tham"),
+    (0x1A60, 0x1A7C, "Tai_Tham"),
+    (0x1A7F, 0x1A89, "Tai_Tham"),
+    (0x1A90, 0x1A99, "Tai_Tham"),
+    (0x1AA0, 0x1AAD, "Tai_Tham"),
+    (0x1B00, 0x1B4C, "Balinese"),
+    (0x1B4E, 0x1B7F, "Balinese"),
+    (0x1B80, 0x1BBF, "Sundanese"),
+    (0x1BC0, 0x1BF3, "Batak"),
+    (0x1BFC, 0x1BFF, "Batak"),
+    (0x1C00, 0x1C37, "Lepcha"),
+    (0x1C3B, 0x1C49, "Lepcha"),
+    (0x1C4D, 0x1C4F, "Lepcha"),
+    (0x1C50, 0x1C7F, "Ol_Chiki"),
+    (0x1C80, 0x1C8A, "Cyrillic"),
+    (0x1C90, 0x1CBA, "Georgian"),
+    (0x1CBD, 0x1CBF, "Georgian"),
+    (0x1CC0, 0x1CC7, "Sundanese"),
+    (0x1D00, 0x1D25, "Latin"),
+    (0x1D26, 0x1D2A, "Greek"),
+    (0x1D2B, 0x1D2B, "Cyrillic"),
+    (0x1D2C, 0x1D5C, "Latin"),
+    (0x1D5D, 0x1D61, "Greek"),
+    (0x1D62, 0x1D65, "Latin"),
+    (0x1D66, 0x1D6A, "Greek"),
+    (0x1D6B, 0x1D77, "Latin"),
+    (0x1D78, 0x1D78, "Cyrillic"),
+    (0x1D79, 0x1DBE, "Latin"),
+    (0x1DBF, 0x1DBF, "Greek"),
+    (0x1E00, 0x1EFF, "Latin"),
+    (0x1F00, 0x1F15, "Greek"),
+    (0x1F18, 0x1F1D, "Greek"),
+    (0x1F20, 0x1F45, "Greek"),
+    (0x1F48, 0x1F4D, "Greek"),
+    (0x1F50, 0x1F57, "Greek"),
+    (0x1F59, 0x1F59, "Greek"),
+    (0x1F5B, 0x1F5B, "Greek"),
+    (0x1F5D, 0x1F5D, "Greek"),
+    (0x1F5F, 0x1F7D, "Greek"),
+    (0x1F80, 0x1FB4, "Greek"),
+    (0x1FB6, 0x1FC4, "Greek"),
+    (0x1FC6, 0x1FD3, "Greek"),
+    (0x1FD6, 0x1FDB, "Greek"),
+    (0x1FDD, 0x1FEF, "Greek"),
+    (0x1FF2, 0x1FF4, "Greek"),
+    (0x1FF6, 0x1FFE, "Greek"),
+    (0x2071, 0x2071, "Latin"),
+    (0x207F, 0x207F, "Latin"),
+    (0x2090, 0x209C, "Latin"),
+    (0x2126, 0x2126, "Greek"),
+    (0x212A, 0x212B, "Latin"),
+    (0x2132, 0x2132, "Latin"),
+    (0x214E, 0x214E, "Latin"),
+    (0x2160, 0x2188, "Latin"),
+    (0x2800, 0x28FF, "Braille"),
+    (0x2C00, 0x2C5F, "Glagolitic"),
+    (0x2C60, 0x2C7F, "Latin"),
+    (0x2C80, 0x2CF3, "Coptic"),
+    (0x2CF9, 0x2CFF, "Coptic"),
+    (0x2D00, 0x2D25, "Georgian"),
+    (0x2D27, 0x2D27, "Georgian"),
+    (0x2D2D, 0x2D2D, "Georgian"),
+    (0x2D30, 0x2D67, "Tifinagh"),
+    (0x2D6F, 0x2D70, "Tifinagh"),
+    (0x2D7F, 0x2D7F, "Tifinagh"),
+    (0x2D80, 0x2D96, "Ethiopic"),
+    (0x2DA0, 0x2DA6, "Ethiopic"),
+    (0x2DA8, 0x2DAE, "Ethiopic"),
+    (0x2DB0, 0x2DB6, "Ethiopic"),
+    (0x2DB8, 0x2DBE, "Ethiopic"),
+    (0x2DC0, 0x2DC6, "Ethiopic"),
+    (0x2DC8, 0x2DCE, "Ethiopic"),
+    (0x2DD0, 0x2DD6, "Ethiopic"),
+    (0x2DD8, 0x2DDE, "Ethiopic"),
+    (0x2DE0, 0x2DFF, "Cyrillic"),
+    (0x2E80, 0x2E99, "Han"),
+    (0x2E9B, 0x2EF3, "Han"),
+    (0x2F00, 0x2FD5, "Han"),
+    (0x3005, 0x3005, "Han"),
+    (0x3007, 0x3007, "Han"),
+    (0x3021, 0x3029, "Han"),
+    (0x302E, 0x302F, "Hangul"),
+    (0x3038, 0x303B, "Han"),
+    (0x3041, 0x3096, "Hiragana"),
+    (0x309D, 0x309F, "Hiragana"),
+    (0x30A1, 0x30FA, "Katakana"),
+    (0x30FD, 0x30FF, "Katakana"),
+    (0x3105, 0x312F, "Bopomofo"),
+    (0x3131, 0x318E, "Hangul"),
+    (0x31A0, 0x31BF, "Bopomofo"),
+    (0x31F0, 0x31FF, "Katakana"),
+    (0x3200, 0x321E, "Hangul"),
+    (0x3260, 0x327E, "Hangul"),
+    (0x32D0, 0x32FE, "Katakana"),
+    (0x3300, 0x3357, "Katakana"),
+    (0x3400, 0x4DBF, "Han"),
+    (0x4E00, 0x9FFF, "Han"),
+    (0xA000, 0xA48C, "Yi"),
+    (0xA490, 0xA4C6, "Yi"),
+    (0xA4D0, 0xA4FF, "Lisu"),
+    (0xA500, 0xA62B, "Vai"),
+    (0xA640, 0xA69F, "Cyrillic"),
+    (0xA6A0, 0xA6F7, "Bamum"),
+    (0xA722, 0xA787, "Latin"),
+    (0xA78B, 0xA7CD, "Latin"),
+    (0xA7D0, 0xA7D1, "Latin"),
+    (0xA7D3, 0xA7D3, "Latin"),
+    (0xA7D5, 0xA7DC, "Latin"),
+    (0xA7F2, 0xA7FF, "Latin"),
+    (0xA800, 0xA82C, "Syloti_Nagri"),
+    (0xA840, 0xA877, "Phags_Pa"),
+    (0xA880, 0xA8C5, "Saurashtra"),
+    (0xA8CE, 0xA8D9, "Saurashtra"),
+    (0xA8E0, 0xA8FF, "Devanagari"),
+    (0xA900, 0xA92D, "Kayah_Li"),
+    (0xA92F, 0xA92F, "Kayah_Li"),
+    (0xA930, 0xA953, "Rejang"),
+    (0xA95F, 0xA95F, "Rejang"),
+    (0xA960, 0xA97C, "Hangul"),
+    (0xA980, 0xA9CD, "Javanese"),
+    (0xA9D0, 0xA9D9, "Javanese"),
+    (0xA9DE, 0xA9DF, "Javanese"),
+    (0xA9E0, 0xA9FE, "Myanmar"),
+    (0xAA00, 0xAA36, "Cham"),
+    (0xAA40, 0xAA4D, "Cham"),
+    (0xAA50, 0xAA59, "Cham"),
+    (0xAA5C, 0xAA5F, "Cham"),
+    (0xAA60, 0xAA7F, "Myanmar"),
+    (0xAA80, 0xAAC2, "Tai_Viet"),
+    (0xAADB, 0xAADF, "Tai_Viet"),
+    (0xAAE0, 0xAAF6, "Meetei_Mayek"),
+    (0xAB01, 0xAB06, "Ethiopic"),
+    (0xAB09, 0xAB0E, "Ethiopic"),
+    (0xAB11, 0xAB16, "Ethiopic"),
+    (0xAB20, 0xAB26, "Ethiopic"),
+    (0xAB28, 0xAB2E, "Ethiopic"),
+    (0xAB30, 0xAB5A, "Latin"),
+    (0xAB5C, 0xAB64, "Latin"),
+    (0xAB65, 0xAB65, "Greek"),
+    (0xAB66, 0xAB69, "Latin"),
+    (0xAB70, 0xABBF, "Cherokee"),
+    (0xABC0, 0xABED, "Meetei_Mayek"),
+    (0xABF0, 0xABF9, "Meetei_Mayek"),
+    (0xAC00, 0xD7A3, "Hangul"),
+    (0xD7B0, 0xD7C6, "Hangul"),
+    (0xD7CB, 0xD7FB, "Hangul"),
+    (0xF900, 0xFA6D, "Han"),
+    (0xFA70, 0xFAD9, "Han"),
+    (0xFB00, 0xFB06, "Latin"),
+    (0xFB13, 0xFB17, "Armenian"),
+    (0xFB1D, 0xFB36, "Hebrew"),
+    (0xFB38, 0xFB3C, "Hebrew"),
+    (0xFB3E, 0xFB3E, "Hebrew"),
+    (0xFB40, 0xFB41, "Hebrew"),
+    (0xFB43, 0xFB44, "Hebrew"),
+    (0xFB46, 0xFB4F, "Hebrew"),
+    (0xFB50, 0xFBC2, "Arabic"),
+    (0xFBD3, 0xFD3D, "Arabic"),
+    (0xFD40, 0xFD8F, "Arabic"),
+    (0xFD92, 0xFDC7, "Arabic"),
+    (0xFDCF, 0xFDCF, "Arabic"),
+    (0xFDF0, 0xFDFF, "Arabic"),
+    (0xFE2E, 0xFE2F, "Cyrillic"),
+    (0xFE70, 0xFE74, "Arabic"),
+    (0xFE76, 0xFEFC, "Arabic"),
+    (0xFF21, 0xFF3A, "Latin"),
+    (0xFF41, 0xFF5A, "Latin"),
+    (0xFF66, 0xFF6F, "Katakana"),
+    (0xFF71, 0xFF9D, "Katakana"),
+    (0xFFA0, 0xFFBE, "Hangul"),
+    (0xFFC2, 0xFFC7, "Hangul"),
+    (0xFFCA, 0xFFCF, "Hangul"),
+    (0xFFD2, 0xFFD7, "Hangul"),
+    (0xFFDA, 0xFFDC, "Hangul"),
+    (0x10000, 0x1000B, "Linear_B"),
+    (0x1000D, 0x10026, "Linear_B"),
+    (0x10028, 0x1003A, "Linear_B"),
+    (0x1003C, 0x1003D, "Linear_B"),
+    (0x1003F, 0x1004D, "Linear_B"),
+    (0x10050, 0x1005D, "Linear_B"),
+    (0x10080, 0x100FA, "Linear_B"),
+    (0x10140, 0x1018E, "Greek"),
+    (0x101A0, 0x101A0, "Greek"),
+    (0x10280, 0x1029C, "Lycian"),
+    (0x102A0, 0x102D0, "Carian"),
+    (0x10300, 0x10323, "Old_Italic"),
+    (0x1032D, 0x1032F, "Old_Italic"),
+    (0x10330, 0x1034A, "Gothic"),
+    (0x10350, 0x1037A, "Old_Permic"),
+    (0x10380, 0x1039D, "Ugaritic"),
+    (0x1039F, 0x1039F, "Ugaritic"),
+    (0x103A0, 0x103C3, "Old_Persian"),
+    (0x103C8, 0x103D5, "Old_Persian"),
+    (0x10400, 0x1044F, "Deseret"),
+    (0x10450, 0x1047F, "Shavian"),
+    (0x10480, 0x1049D, "Osmanya"),
+    (0x104A0, 0x104A9, "Osmanya"),
+    (0x104B0, 0x104D3, "Osage"),
+    (0x104D8, 0x104FB, "Osage"),
+    (0x10500, 0x10527, "Elbasan"),
+    (0x10530, 0x10563, "Caucasian_Albanian"),
+    (0x1056F, 0x1056F, "Caucasian_Albanian"),
+    (0x10570, 0x1057A, "Vithkuqi"),
+    (0x1057C, 0x1058A, "Vithkuqi"),
+    (0x1058C, 0x10592, "Vithkuqi"),
+    (0x10594, 0x10595, "Vithkuqi"),
+    (0x10597, 0x105A1, "Vithkuqi"),
+    (0x105A3, 0x105B1, "Vithkuqi"),
+    (0x105B3, 0x105B9, "Vithkuqi"),
+    (0x105BB, 0x105BC, "Vithkuqi"),
+    (0x105C0, 0x105F3, "Todhri"),
+    (0x10600, 0x10736, "Linear_A"),
+    (0x10740, 0x10755, "Linear_A"),
+    (0x10760, 0x10767, "Linear_A"),
+    (0x10780, 0x10785, "Latin"),
+    (0x10787, 0x107B0, "Latin"),
+    (0x107B2, 0x107BA, "Latin"),
+    (0x10800, 0x10805, "Cypriot"),
+    (0x10808, 0x10808, "Cypriot"),
+    (0x1080A, 0x10835, "Cypriot"),
+    (0x10837, 0x10838, "Cypriot"),
+    (0x1083C, 0x1083C, "Cypriot"),
+    (0x1083F, 0x1083F, "Cypriot"),
+    (0x10840, 0x10855, "Imperial_Aramaic"),
+    (0x10857, 0x1085F, "Imperial_Aramaic"),
+    (0x10860, 0x1087F, "Palmyrene"),
+    (0x10880, 0x1089E, "Nabataean"),
+    (0x108A7, 0x108AF, "Nabataean"),
+    (0x108E0, 0x108F2, "Hatran"),
+    (0x108F4, 0x108F5, "Hatran"),
+    (0x108FB, 0x108FF, "Hatran"),
+    (0x10900, 0x1091B, "Phoenician"),
+    (0x1091F, 0x1091F, "Phoenician"),
+    (0x10920, 0x10939, "Lydian"),
+    (0x1093F, 0x1093F, "Lydian"),
+    (0x10980, 0x1099F, "Meroitic_Hieroglyphs"),
+    (0x109A0, 0x109B7, "Meroitic_Cursive"),
+    (0x109BC, 0x109CF, "Meroitic_Cursive"),
+    (0x109D2, 0x109FF, "Meroitic_Cursive"),
+    (0x10A00, 0x10A03, "Kharoshthi"),
+    (0x10A05, 0x10A06, "Kharoshthi"),
+    (0x10A0C, 0x10A13, "Kharoshthi"),
+    (0x10A15, 0x10A17, "Kharoshthi"),
+    (0x10A19, 0x10A35, "Kharoshthi"),
+    (0x10A38, 0x10A3A, "Kharoshthi"),
+    (0x10A3F, 0x10A48, "Kharoshthi"),
+    (0x10A50, 0x10A58, "Kharoshthi"),
+    (0x10A60, 0x10A7F, "Old_South_Arabian"),
+    (0x10A80, 0x10A9F, "Old_North_Arabian"),
+    (0x10AC0, 0x10AE6, "Manichaean"),
+    (0x10AEB, 0x10AF6, "Manichaean"),
+    (0x10B00, 0x10B35, "Avestan"),
+    (0x10B39, 0x10B3F, "Avestan"),
+    (0x10B40, 0x10B55, "Inscriptional_Parthian"),
+    (0x10B58, 0x10B5F, "Inscriptional_Parthian"),
+    (0x10B60, 0x10B72, "Inscriptional_Pahlavi"),
+    (0x10B78, 0x10B7F, "Inscriptional_Pahlavi"),
+    (0x10B80, 0x10B91, "Psalter_Pahlavi"),
+    (0x10B99, 0x10B9C, "Psalter_Pahlavi"),
+    (0x10BA9, 0x10BAF, "Psalter_Pahlavi"),
+    (0x10C00, 0x10C48, "Old_Turkic"),
+    (0x10C80, 0x10CB2, "Old_Hungarian"),
+    (0x10CC0, 0x10CF2, "Old_Hungarian"),
+    (0x10CFA, 0x10CFF, "Old_Hungarian"),
+    (0x10D00, 0x10D27, "Hanifi_Rohingya"),
+    (0x10D30, 0x10D39, "Hanifi_Rohingya"),
+    (0x10D40, 0x10D65, "Garay"),
+    (0x10D69, 0x10D85, "Garay"),
+    (0x10D8E, 0x10D8F, "Garay"),
+    (0x10E60, 0x10E7E, "Arabic"),
+    (0x10E80, 0x10EA9, "Yezidi"),
+    (0x10EAB, 0x10EAD, "Yezidi"),
+    (0x10EB0, 0x10EB1, "Yezidi"),
+    (0x10EC2, 0x10EC4, "Arabic"),
+    (0x10EFC, 0x10EFF, "Arabic"),
+    (0x10F00, 0x10F27, "Old_Sogdian"),
+    (0x10F30, 0x10F59, "Sogdian"),
+    (0x10F70, 0x10F89, "Old_Uyghur"),
+    (0x10FB0, 0x10FCB, "Chorasmian"),
+    (0x10FE0, 0x10FF6, "Elymaic"),
+    (0x11000, 0x1104D, "Brahmi"),
+    (0x11052, 0x11075, "Brahmi"),
+    (0x1107F, 0x1107F, "Brahmi"),
+    (0x11080, 0x110C2, "Kaithi"),
+    (0x110CD, 0x110CD, "Kaithi"),
+    (0x110D0, 0x110E8, "Sora_Sompeng"),
+    (0x110F0, 0x110F9, "Sora_Sompeng"),
+    (0x11100, 0x11134, "Chakma"),
+    (0x11136, 0x11147, "Chakma"),
+    (0x11150, 0x11176, "Mahajani"),
+    (0x11180, 0x111DF, "Sharada"),
+    (0x111E1, 0x111F4, "Sinhala"),
+    (0x11200, 0x11211, "Khojki"),
+    (0x11213, 0x11241, "Khojki"),
+    (0x11280, 0x11286, "Multani"),
+    (0x11288, 0x11288, "Multani"),
+    (0x1128A, 0x1128D, "Multani"),
+    (0x1128F, 0x1129D, "Multani"),
+    (0x1129F, 0x112A9, "Multani"),
+    (0x112B0, 0x112EA, "Khudawadi"),
+    (0x112F0, 0x112F9, "Khudawadi"),
+    (0x11300, 0x11303, "Grantha"),
+    (0x11305, 0x1130C, "Grantha"),
+    (0x1130F, 0x11310, "Grantha"),
+    (0x11313, 0x11328, "Grantha"),
+    (0x1132A, 0x11330, "Grantha"),
+    (0x11332, 0x11333, "Grantha"),
+    (0x11335, 0x11339, "Grantha"),
+    (0x1133C, 0x11344, "Grantha"),
+    (0x11347, 0x11348, "Grantha"),
+    (0x1134B, 0x1134D, "Grantha"),
+    (0x11350, 0x11350, "Grantha"),
+    (0x11357, 0x11357, "Grantha"),
+    (0x1135D, 0x11363, "Grantha"),
+    (0x11366, 0x1136C, "Grantha"),
+    (0x11370, 0x11374, "Grantha"),
+    (0x11380, 0x11389, "Tulu_Tigalari"),
+    (0x1138B, 0x1138B, "Tulu_Tigalari"),
+    (0x1138E, 0x1138E, "Tulu_Tigalari"),
+    (0x11390, 0x113B5, "Tulu_Tigalari"),
+    (0x113B7, 0x113C0, "Tulu_Tigalari"),
+    (0x113C2, 0x113C2, "Tulu_Tigalari"),
+    (0x113C5, 0x113C5, "Tulu_Tigalari"),
+    (0x113C7, 0x113CA, "Tulu_Tigalari"),
+    (0x113CC, 0x113D5, "Tulu_Tigalari"),
+    (0x113D7, 0x113D8, "Tulu_Tigalari"),
+    (0x113E1, 0x113E2, "Tulu_Tigalari"),
+    (0x11400, 0x1145B, "Newa"),
+    (0x1145D, 0x11461, "Newa"),
+    (0x11480, 0x114C7, "Tirhuta"),
+    (0x114D0, 0x114D9, "Tirhuta"),
+    (0x11580, 0x115B5, "Siddham"),
+    (0x115B8, 0x115DD, "Siddham"),
+    (0x11600, 0x11644, "Modi"),
+    (0x11650, 0x11659, "Modi"),
+    (0x11660, 0x1166C, "Mongolian"),
+    (0x11680, 0x116B9, "Takri"),
+    (0x116C0, 0x116C9, "Takri"),
+    (0x116D0, 0x116E3, "Myanmar"),
+    (0x11700, 0x1171A, "Ahom"),
+    (0x1171D, 0x1172B, "Ahom"),
+    (0x11730, 0x11746, "Ahom"),
+    (0x11800, 0x1183B, "Dogra"),
+    (0x118A0, 0x118F2, "Warang_Citi"),
+    (0x118FF, 0x118FF, "Warang_Citi"),
+    (0x11900, 0x11906, "Dives_Akuru"),
+    (0x11909, 0x11909, "Dives_Akuru"),
+    (0x1190C, 0x11913, "Dives_Akuru"),
+    (0x11915, 0x11916, "Dives_Akuru"),
+    (0x11918, 0x11935, "Dives_Akuru"),
+    (0x11937, 0x11938, "Dives_Akuru"),
+    (0x1193B, 0x11946, "Dives_Akuru"),
+    (0x11950, 0x11959, "Dives_Akuru"),
+    (0x119A0, 0x119A7, "Nandinagari"),
+    (0x119AA, 0x119D7, "Nandinagari"),
+    (0x119DA, 0x119E4, "Nandinagari"),
+    (0x11A00, 0x11A47, "Zanabazar_Square"),
+    (0x11A50, 0x11AA2, "Soyombo"),
+    (0x11AB0, 0x11ABF, "Canadian_Aboriginal"),
+    (0x11AC0, 0x11AF8, "Pau_Cin_Hau"),
+    (0x11B00, 0x11B09, "Devanagari"),
+    (0x11BC0, 0x11BE1, "Sunuwar"),
+    (0x11BF0, 0x11BF9, "Sunuwar"),
+    (0x11C00, 0x11C08, "Bhaiksuki"),
+    (0x11C0A, 0x11C36, "Bhaiksuki"),
+    (0x11C38, 0x11C45, "Bhaiksuki"),
+    (0x11C50, 0x11C6C, "Bhaiksuki"),
+    (0x11C70, 0x11C8F, "Marchen"),
+    (0x11C92, 0x11CA7, "Marchen"),
+    (0x11CA9, 0x11CB6, "Marchen"),
+    (0x11D00, 0x11D06, "Masaram_Gondi"),
+    (0x11D08, 0x11D09, "Masaram_Gondi"),
+    (0x11D0B, 0x11D36, "Masaram_Gondi"),
+    (0x11D3A, 0x11D3A, "Masaram_Gondi"),
+    (0x11D3C, 0x11D3D, "Masaram_Gondi"),
+    (0x11D3F, 0x11D47, "Masaram_Gondi"),
+    (0x11D50, 0x11D59, "Masaram_Gondi"),
+    (0x11D60, 0x11D65, "Gunjala_Gondi"),
+    (0x11D67, 0x11D68, "Gunjala_Gondi"),
+    (0x11D6A, 0x11D8E, "Gunjala_Gondi"),
+    (0x11D90, 0x11D91, "Gunjala_Gondi"),
+    (0x11D93, 0x11D98, "Gunjala_Gondi"),
+    (0x11DA0, 0x11DA9, "Gunjala_Gondi"),
+    (0x11EE0, 0x11EF8, "Makasar"),
+    (0x11F00, 0x11F10, "Kawi"),
+    (0x11F12, 0x11F3A, "Kawi"),
+    (0x11F3E, 0x11F5A, "Kawi"),
+    (0x11FB0, 0x11FB0, "Lisu"),
+    (0x11FC0, 0x11FF1, "Tamil"),
+    (0x11FFF, 0x11FFF, "Tamil"),
+    (0x12000, 0x12399, "Cuneiform"),
+    (0x12400, 0x1246E, "Cuneiform"),
+    (0x12470, 0x12474, "Cuneiform"),
+    (0x12480, 0x12543, "Cuneiform"),
+    (0x12F90, 0x12FF2, "Cypro_Minoan"),
+    (0x13000, 0x13455, "Egyptian_Hieroglyphs"),
+    (0x13460, 0x143FA, "Egyptian_Hieroglyphs"),
+    (0x14400, 0x14646, "Anatolian_Hieroglyphs"),
+    (0x16100, 0x16139, "Gurung_Khema"),
+    (0x16800, 0x16A38, "Bamum"),
+    (0x16A40, 0x16A5E, "Mro"),
+    (0x16A60, 0x16A69, "Mro"),
+    (0x16A6E, 0x16A6F, "Mro"),
+    (0x16A70, 0x16ABE, "Tangsa"),
+    (0x16AC0, 0x16AC9, "Tangsa"),
+    (0x16AD0, 0x16AED, "Bassa_Vah"),
+    (0x16AF0, 0x16AF5, "Bassa_Vah"),
+    (0x16B00, 0x16B45, "Pahawh_Hmong"),
+    (0x16B50, 0x16B59, "Pahawh_Hmong"),
+    (0x16B5B, 0x16B61, "Pahawh_Hmong"),
+    (0x16B63, 0x16B77, "Pahawh_Hmong"),
+    (0x16B7D, 0x16B8F, "Pahawh_Hmong"),
+    (0x16D40, 0x16D79, "Kirat_Rai"),
+    (0x16E40, 0x16E9A, "Medefaidrin"),
+    (0x16F00, 0x16F4A, "Miao"),
+    (0x16F4F, 0x16F87, "Miao"),
+    (0x16F8F, 0x16F9F, "Miao"),
+    (0x16FE0, 0x16FE0, "Tangut"),
+    (0x16FE1, 0x16FE1, "Nushu"),
+    (0x16FE2, 0x16FE3, "Han"),
+    (0x16FE4, 0x16FE4, "Khitan_Small_Script"),
+    (0x16FF0, 0x16FF1, "Han"),
+    (0x17000, 0x187F7, "Tangut"),
+    (0x18800, 0x18AFF, "Tangut"),
+    (0x18B00, 0x18CD5, "Khitan_Small_Script"),
+    (0x18CFF, 0x18CFF, "Khitan_Small_Script"),
+    (0x18D00, 0x18D08, "Tangut"),
+    (0x1AFF0, 0x1AFF3, "Katakana"),
+    (0x1AFF5, 0x1AFFB, "Katakana"),
+    (0x1AFFD, 0x1AFFE, "Katakana"),
+    (0x1B000, 0x1B000, "Katakana"),
+    (0x1B001, 0x1B11F, "Hiragana"),
+    (0x1B120, 0x1B122, "Katakana"),
+    (0x1B132, 0x1B132, "Hiragana"),
+    (0x1B150, 0x1B152, "Hiragana"),
+    (0x1B155, 0x1B155, "Katakana"),
+    (0x1B164, 0x1B167, "Katakana"),
+    (0x1B170, 0x1B2FB, "Nushu"),
+    (0x1BC00, 0x1BC6A, "Duployan"),
+    (0x1BC70, 0x1BC7C, "Duployan"),
+    (0x1BC80, 0x1BC88, "Duployan"),
+    (0x1BC90, 0x1BC99, "Duployan"),
+    (0x1BC9C, 0x1BC9F, "Duployan"),
+    (0x1D200, 0x1D245, "Greek"),
+    (0x1D800, 0x1DA8B, "SignWriting"),
+    (0x1DA9B, 0x1DA9F, "SignWriting"),
+    (0x1DAA1, 0x1DAAF, "SignWriting"),
+    (0x1DF00, 0x1DF1E, "Latin"),
+    (0x1DF25, 0x1DF2A, "Latin"),
+    (0x1E000, 0x1E006, "Glagolitic"),
+    (0x1E008, 0x1E018, "Glagolitic"),
+    (0x1E01B, 0x1E021, "Glagolitic"),
+    (0x1E023, 0x1E024, "Glagolitic"),
+    (0x1E026, 0x1E02A, "Glagolitic"),
+    (0x1E030, 0x1E06D, "Cyrillic"),
+    (0x1E08F, 0x1E08F, "Cyrillic"),
+    (0x1E100, 0x1E12C, "Nyiakeng_Puachue_Hmong"),
+    (0x1E130, 0x1E13D, "Nyiakeng_Puachue_Hmong"),
+    (0x1E140, 0x1E149, "Nyiakeng_Puachue_Hmong"),
+    (0x1E14E, 0x1E14F, "Nyiakeng_Puachue_Hmong"),
+    (0x1E290, 0x1E2AE, "Toto"),
+    (0x1E2C0, 0x1E2F9, "Wancho"),
+    (0x1E2FF, 0x1E2FF, "Wancho"),
+    (0x1E4D0, 0x1E4F9, "Nag_Mundari"),
+    (0x1E5D0, 0x1E5FA, "Ol_Onal"),
+    (0x1E5FF, 0x1E5FF, "Ol_Onal"),
+    (0x1E7E0, 0x1E7E6, "Ethiopic"),
+    (0x1E7E8, 0x1E7EB, "Ethiopic"),
+    (0x1E7ED, 0x1E7EE, "Ethiopic"),
+    (0x1E7F0, 0x1E7FE, "Ethiopic"),
+    (0x1E800, 0x1E8C4, "Mende_Kikakui"),
+    (0x1E8C7, 0x1E8D6, "Mende_Kikakui"),
+    (0x1E900, 0x1E94B, "Adlam"),
+    (0x1E950, 0x1E959, "Adlam"),
+    (0x1E95E, 0x1E95F, "Adlam"),
+    (0x1EE00, 0x1EE03, "Arabic"),
+    (0x1EE05, 0x1EE1F, "Arabic"),
+    (0x1EE21, 0x1EE22, "Arabic"),
+    (0x1EE24, 0x1EE24, "Arabic"),
+    (0x1EE27, 0x1EE27, "Arabic"),
+    (0x1EE29, 0x1EE32, "Arabic"),
+    (0x1EE34, 0x1EE37, "Arabic"),
+    (0x1EE39, 0x1EE39, "Arabic"),
+    (0x1EE3B, 0x1EE3B, "Arabic"),
+    (0x1EE42, 0x1EE42, "Arabic"),
+    (0x1EE47, 0x1EE47, "Arabic"),
+    (0x1EE49, 0x1EE49, "Arabic"),
+    (0x1EE4B, 0x1EE4B, "Arabic"),
+    (0x1EE4D, 0x1EE4F, "Arabic"),
+    (0x1EE51, 0x1EE52, "Arabic"),
+    (0x1EE54, 0x1EE54, "Arabic"),
+    (0x1EE57, 0x1EE57, "Arabic"),
+    (0x1EE59, 0x1EE59, "Arabic"),
+    (0x1EE5B, 0x1EE5B, "Arabic"),
+    (0x1EE5D, 0x1EE5D, "Arabic"),
+    (0x1EE5F, 0x1EE5F, "Arabic"),
+    (0x1EE61, 0x1EE62, "Arabic"),
+    (0x1EE64, 0x1EE64, "Arabic"),
+    (0x1EE67, 0x1EE6A, "Arabic"),
+    (0x1EE6C, 0x1EE72, "Arabic"),
+    (0x1EE74, 0x1EE77, "Arabic"),
+    (0x1EE79, 0x1EE7C, "Arabic"),
+    (0x1EE7E, 0x1EE7E, "Arabic"),
+    (0x1EE80, 0x1EE89, "Arabic"),
+    (0x1EE8B, 0x1EE9B, "Arabic"),
+    (0x1EEA1, 0x1EEA3, "Arabic"),
+    (0x1EEA5, 0x1EEA9, "Arabic"),
+    (0x1EEAB, 0x1EEBB, "Arabic"),
+    (0x1EEF0, 0x1EEF1, "Arabic"),
+    (0x1F200, 0x1F200, "Hiragana"),
+    (0x20000, 0x2A6DF, "Han"),
+    (0x2A700, 0x2B739, "Han"),
+    (0x2B740, 0x2B81D, "Han"),
+    (0x2B820, 0x2CEA1, "Han"),
+    (0x2CEB0, 0x2EBE0, "Han"),
+    (0x2EBF0, 0x2EE5D, "Han"),
+    (0x2F800, 0x2FA1D, "Han"),
+    (0x30000, 0x3134A, "Han"),
+    (0x31350, 0x323AF, "Han"),
+];
+
+#[rustfmt::skip]
+pub(super) static CONFUSABLES: &[(u32, &str)] = &[
+    (0x22, "''"),
+    (0x25, "\u{BA}/\u{2080}"),
+    (0x30, "O"),
+    (0x31, "l"),
+    (0x49, "l"),
+    (0x60, "'"),
+    (0x6D, "rn"),
+    (0x7C, "l"),
+    (0xA0, " "),
+    (0xA2, "c\u{338}"),
+    (0xA5, "Y\u{335}"),
+    (0xAF, "\u{2C9}"),
+    (0xB4, "'"),
+    (0xB5, "\u{3BC}"),
+    (0xB8, ","),
+    (0xC6, "AE"),
+    (0xC7, "C\u{326}"),
+    (0xD0, "D\u{335}"),
+    (0xD7, "x"),
+    (0xD8, "O\u{338}"),
+    (0xE6, "ae"),
+    (0xE7, "c\u{326}"),
+    (0xF0, "\u{2202}\u{335}"),
+    (0xF6, "\u{629}"),
+    (0xF8, "o\u{338}"),
+    (0x110, "D\u{335}"),
+    (0x111, "d\u{335}"),
+    (0x11A, "\u{114}"),
+    (0x11B, "\u{115}"),
+    (0x126, "H\u{335}"),
+    (0x127, "h\u{335}"),
+    (0x131, "i"),
+    (0x132, "lJ"),
+    (0x133, "ij"),
+    (0x13F, "l\u{B7}"),
+    (0x140, "l\u{B7}"),
+    (0x141, "L\u{338}"),
+    (0x142, "l\u{338}"),
+    (0x146, "\u{272}"),
+    (0x149, "'n"),
+    (0x150, "\u{D6}"),
+    (0x152, "OE"),
+    (0x153, "oe"),
+    (0x163, "\u{1AB}"),
+    (0x166, "T\u{335}"),
+    (0x167, "t\u{335}"),
+    (0x17F, "f"),
+    (0x180, "b\u{335}"),
+    (0x181, "'B"),
+    (0x182, "b\u{304}"),
+    (0x183, "b\u{304}"),
+    (0x184, "b"),
+    (0x187, "C'"),
+    (0x189, "D\u{335}"),
+    (0x18A, "'D"),
+    (0x18C, "d\u{304}"),
+    (0x18D, "g"),
+    (0x191, "F\u{326}"),
+    (0x192, "f\u{326}"),
+    (0x193, "G'"),
+    (0x196, "l"),
+    (0x197, "l\u{335}"),
+    (0x198, "K'"),
+    (0x199, "k\u{314}"),
+    (0x19A, "l\u{335}"),
+    (0x19B, "\u{3BB}\u{338}"),
+    (0x19D, "N\u{326}"),
+    (0x19E, "n\u{329}"),
+    (0x19F, "O\u{335}"),
+    (0x1A0, "O'"),
+    (0x1A1, "o'"),
+    (0x1A4, "'P"),
+    (0x1A5, "p\u{314}"),
+    (0x1A6, "R"),
+    (0x1A7, "2"),
+    (0x1AC, "'T"),
+    (0x1AD, "t\u{314}"),
+    (0x1AE, "T\u{328}"),
+    (0x1B3, "'Y"),
+    (0x1B4, "y\u{314}"),
+    (0x1B5, "Z\u{335}"),
+    (0x1B6, "z\u{335}"),
+    (0x1B7, "3"),
+    (0x1BB, "2\u{335}"),
+    (0x1BC, "5"),
+    (0x1BD, "s"),
+    (0x1BF, "\u{FE}"),
+    (0x1C0, "l"),
+    (0x1C1, "ll"),
+    (0x1C3, "!"),
+    (0x1C4, "D\u{17D}"),
+    (0x1C5, "D\u{17E}"),
+    (0x1C6, "d\u{17E}"),
+    (0x1C7, "LJ"),
+    (0x1C8, "Lj"),
+    (0x1C9, "lj"),
+    (0x1CA, "NJ"),
+    (0x1CB, "Nj"),
+    (0x1CC, "nj"),
+    (0x1CD, "\u{102}"),
+    (0x1CE, "\u{103}"),
+    (0x1CF, "\u{12C}"),
+    (0x1D0, "\u{12D}"),
+    (0x1D1, "\u{14E}"),
+    (0x1D2, "\u{14F}"),
+    (0x1D3, "\u{16C}"),
+    (0x1D4, "\u{16D}"),
+    (0x1E4, "G\u{335}"),
+    (0x1E5, "g\u{335}"),
+    (0x1E6, "\u{11E}"),
+    (0x1E7, "\u{11F}"),
+    (0x1F1, "DZ"),
+    (0x1F2, "Dz"),
+    (0x1F3, "dz"),
+    (0x1F5, "\u{123}"),
+    (0x1FE, "O\u{338}\u{301}"),
+    (0x21A, "\u{162}"),
+    (0x21B, "\u{1AB}"),
+    (0x21C, "3"),
+    (0x222, "8"),
+    (0x223, "8"),
+    (0x224, "Z\u{326}"),
+    (0x225, "z\u{326}"),
+    (0x226, "\u{C5}"),
+    (0x227, "\u{E5}"),
+    (0x23C, "c\u{338}"),
+    (0x23E, "T\u{338}"),
+    (0x241, "?"),
+    (0x244, "U\u{335}"),
+    (0x246, "E\u{338}"),
+    (0x247, "e\u{338}"),
+    (0x248, "J\u{335}"),
+    (0x249, "j\u{335}"),
+    (0x24D, "r\u{335}"),
+    (0x24E, "Y\u{335}"),
+    (0x24F, "y\u{335}"),
+    (0x251, "a"),
+    (0x253, "b\u{314}"),
+    (0x256, "d\u{328}"),
+    (0x257, "d\u{314}"),
+    (0x259, "\u{1DD}"),
+    (0x25A, "\u{1DD}\u{2DE}"),
+    (0x25B, "\u{A793}"),
+    (0x260, "g\u{314}"),
+    (0x261, "g"),
+    (0x263, "y"),
+    (0x266, "h\u{314}"),
+    (0x268, "i\u{335}"),
+    (0x269, "i"),
+    (0x26A, "i"),
+    (0x26B, "l\u{334}"),
+    (0x26D, "l\u{328}"),
+    (0x26E, "l\u{21D}"),
+    (0x26F, "w"),
+    (0x271, "rn\u{326}"),
+    (0x273, "n\u{328}"),
+    (0x275, "o\u{335}"),
+    (0x276, "o\u{1D07}"),
+    (0x27C, "r\u{329}"),
+    (0x27D, "r\u{328}"),
+    (0x282, "s\u{328}"),
+    (0x28B, "u"),
+    (0x28F, "y"),
+    (0x290, "z\u{328}"),
+    (0x292, "\u{21D}"),
+    (0x294, "?"),
+    (0x2A0, "q\u{314}"),
+    (0x2A3, "dz"),
+    (0x2A4, "d\u{21D}"),
+    (0x2A5, "d\u{291}"),
+    (0x2A6, "ts"),
+    (0x2A7, "t\u{283}"),
+    (0x2A8, "t\u{255}"),
+    (0x2A9, "f\u{14B}"),
+    (0x2AA, "ls"),
+    (0x2AB, "lz"),
+    (0x2B3, "\u{18F4}"),
+    (0x2B9, "'"),
+    (0x2BA, "''"),
+    (0x2BB, "'"),
+    (0x2BC, "'"),
+    (0x2BD, "'"),
+    (0x2BE, "'"),
+    (0x2BF, "\u{559}"),
+    (0x2C2, "<"),
+    (0x2C3, ">"),
+    (0x2C4, "^"),
+    (0x2C6, "^"),
+    (0x2C8, "'"),
+    (0x2CA, "'"),
+    (0x2CB, "'"),
+    (0x2D0, ":"),
+    (0x2D3, "\u{559}"),
+    (0x2D7, "-"),
+    (0x2D8, "\u{2C7}"),
+    (0x2D9, "\u{971}"),
+    (0x2DA, "\u{B0}"),
+    (0x2DB, "i"),
+    (0x2DC, "~"),
+    (0x2DD, "''"),
+    (0x2E1, "\u{18F3}"),
+    (0x2E2, "\u{18F5}"),
+    (0x2E4, "\u{2C1}"),
+    (0x2EE, "''"),
+    (0x2F4, "'"),
+    (0x2F6, "''"),
+    (0x2F8, ":"),
+    (0x2FB, "\u{2EA}"),
+    (0x305, "\u{304}"),
+    (0x30C, "\u{306}"),
+    (0x30D, "\u{670}"),
+    (0x310, "\u{306}\u{307}"),
+    (0x311, "\u{302}"),
+    (0x315, "\u{313}"),
+    (0x317, "\u{650}"),
+    (0x320, "\u{331}"),
+    (0x321, "\u{326}"),
+    (0x322, "\u{328}"),
+    (0x327, "\u{326}"),
+    (0x336, "\u{335}"),
+    (0x337, "\u{338}"),
+    (0x339, "\u{326}"),
+    (0x340, "\u{300}"),
+    (0x341, "\u{301}"),
+    (0x342, "\u{303}"),
+    (0x343, "\u{313}"),
+    (0x345, "\u{328}"),
+    (0x347, "\u{333}"),
+    (0x357, "\u{350}"),
+    (0x358, "\u{307}"),
+    (0x366, "\u{30A}"),
+    (0x36E, "\u{306}"),
+    (0x370, "\u{2C75}"),
+    (0x374, "'"),
+    (0x375, "\u{2CF}"),
+    (0x376, "\u{418}"),
+    (0x377, "\u{1D0E}"),
+    (0x37A, "i"),
+    (0x37B, "\u{254}"),
+    (0x37D, "\u{A73F}"),
+    (0x37E, ";"),
+    (0x37F, "J"),
+    (0x384, "'"),
+    (0x387, "\u{B7}"),
+    (0x391, "A"),
+    (0x392, "B"),
+    (0x395, "E"),
+    (0x396, "Z"),
+    (0x397, "H"),
+    (0x398, "O\u{335}"),
+    (0x399, "l"),
+    (0x39A, "K"),
+    (0x39B, "\u{245}"),
+    (0x39C, "M"),
+    (0x39D, "N"),
+    (0x39F, "O"),
+    (0x3A1, "P"),
+    (0x3A3, "\u{1A9}"),
+    (0x3A4, "T"),
+    (0x3A5, "Y"),
+    (0x3A7, "X"),
+    (0x3B1, "a"),
+    (0x3B2, "\u{DF}"),
+    (0x3B3, "y"),
+    (0x3B4, "\u{1E9F}"),
+    (0x3B5, "\u{A793}"),
+    (0x3B7, "n\u{329}"),
+    (0x3B8, "O\u{335}"),
+    (0x3B9, "i"),
+    (0x3BA, "\u{138}"),
+    (0x3BD, "v"),
+    (0x3BF, "o"),
+    (0x3C1, "p"),
+    (0x3C3, "o"),
+    (0x3C4, "\u{1D1B}"),
+    (0x3C5, "u"),
+    (0x3C6, "\u{278}"),
+    (0x3D0, "\u{DF}"),
+    (0x3D1, "O\u{335}"),
+    (0x3D2, "Y"),
+    (0x3D5, "\u{278}"),
+    (0x3D6, "\u{3C0}"),
+    (0x3DB, "\u{3C2}"),
+    (0x3DC, "F"),
+    (0x3E8, "2"),
+    (0x3E9, "\u{1A8}"),
+    (0x3F0, "\u{138}"),
+    (0x3F1, "p"),
+    (0x3F2, "c"),
+    (0x3F3, "j"),
+    (0x3F4, "O\u{335}"),
+    (0x3F5, "\u{A793}"),
+    (0x3F7, "\u{DE}"),
+    (0x3F8, "\u{FE}"),
+    (0x3F9, "C"),
+    (0x3FA, "M"),
+    (0x3FD, "\u{186}"),
+    (0x3FF, "\u{A73E}"),
+    (0x404, "\u{A792}"),
+    (0x405, "S"),
+    (0x406, "l"),
+    (0x408, "J"),
+    (0x410, "A"),
+    (0x411, "b\u{304}"),
+    (0x412, "B"),
+    (0x413, "\u{393}"),
+    (0x415, "E"),
+    (0x417, "3"),
+    (0x419, "\u{40D}"),
+    (0x41A, "K"),
+    (0x41B, "\u{245}"),
+    (0x41C, "M"),
+    (0x41D, "H"),
+    (0x41E, "O"),
+    (0x41F, "\u{3A0}"),
+    (0x420, "P"),
+    (0x421, "C"),
+    (0x422, "T"),
+    (0x423, "Y"),
+    (0x424, "\u{3A6}"),
+    (0x425, "X"),
+    (0x42B, "bl"),
+    (0x42C, "b"),
+    (0x42E, "lO"),
+    (0x430, "a"),
+    (0x431, "6"),
+    (0x432, "\u{299}"),
+    (0x433, "r"),
+    (0x435, "e"),
+    (0x437, "\u{25C}"),
+    (0x438, "\u{1D0E}"),
+    (0x43A, "\u{138}"),
+    (0x43C, "\u{28D}"),
+    (0x43D, "\u{29C}"),
+    (0x43E, "o"),
+    (0x43F, "\u{3C0}"),
+    (0x440, "p"),
+    (0x441, "c"),
+    (0x442, "\u{1D1B}"),
+    (0x443, "y"),
+    (0x444, "\u{278}"),
+    (0x445, "x"),
+    (0x44A, "\u{2C9}b"),
+    (0x44B, "\u{185}i"),
+    (0x44C, "\u{185}"),
+    (0x44F, "\u{1D19}"),
+    (0x454, "\u{A793}"),
+    (0x455, "s"),
+    (0x456, "i"),
+    (0x458, "j"),
+    (0x45B, "h\u{335}"),
+    (0x45D, "\u{439}"),
+    (0x461, "w"),
+    (0x462, "b\u{335}"),
+    (0x463, "b\u{335}"),
+    (0x470, "\u{3A8}"),
+    (0x471, "\u{3C8}"),
+    (0x472, "O\u{335}"),
+    (0x473, "o\u{335}"),
+    (0x474, "V"),
+    (0x475, "v"),
+    (0x47C, "\u{460}\u{486}\u{487}"),
+    (0x47D, "w\u{486}\u{487}"),
+    (0x48A, "\u{40D}\u{326}"),
+    (0x48B, "\u{439}\u{326}"),
+    (0x48C, "b\u{335}"),
+    (0x48D, "b\u{335}"),
+    (0x490, "\u{393}'"),
+    (0x491, "r'"),
+    (0x492, "\u{393}\u{335}"),
+    (0x493, "r\u{335}"),
+    (0x496, "\u{416}\u{329}"),
+    (0x497, "\u{436}\u{329}"),
+    (0x498, "3\u{326}"),
+    (0x499, "\u{25C}\u{326}"),
+    (0x49A, "K\u{329}"),
+    (0x49B, "\u{138}\u{329}"),
+    (0x49E, "K\u{335}"),
+    (0x49F, "\u{138}\u{335}"),
+    (0x4A2, "H\u{329}"),
+    (0x4A3, "\u{29C}\u{329}"),
+    (0x4AA, "C\u{326}"),
+    (0x4AB, "c\u{326}"),
+    (0x4AC, "T\u{329}"),
+    (0x4AD, "\u{1D1B}\u{329}"),
+    (0x4AE, "Y"),
+    (0x4AF, "y"),
+    (0x4B0, "Y\u{335}"),
+    (0x4B1, "y\u{335}"),
+    (0x4B2, "X\u{329}"),
+    (0x4BB, "h"),
+    (0x4BD, "e"),
+    (0x4BE, "\u{4BC}\u{328}"),
+    (0x4BF, "e\u{328}"),
+    (0x4C0, "l"),
+    (0x4C5, "\u{245}\u{326}"),
+    (0x4C6, "\u{43B}\u{326}"),
+    (0x4C7, "H\u{326}"),
+    (0x4C8, "\u{29C}\u{326}"),
+    (0x4C9, "H\u{326}"),
+    (0x4CA, "\u{29C}\u{326}"),
+    (0x4CB, "\u{4B6}"),
+    (0x4CC, "\u{4B7}"),
+    (0x4CD, "M\u{326}"),
+    (0x4CE, "\u{28D}\u{326}"),
+    (0x4CF, "i"),
+    (0x4D4, "AE"),
+    (0x4D5, "ae"),
+    (0x4D8, "\u{18F}"),
+    (0x4D9, "\u{1DD}"),
+    (0x4E0, "3"),
+    (0x4E1, "\u{21D}"),
+    (0x4E8, "O\u{335}"),
+    (0x4E9, "o\u{335}"),
+    (0x501, "d"),
+    (0x50A, "\u{1F6}"),
+    (0x50C, "G"),
+    (0x50D, "\u{262}"),
+    (0x510, "\u{190}"),
+    (0x511, "\u{A793}"),
+    (0x51B, "q"),
+    (0x51C, "W"),
+    (0x51D, "w"),
+    (0x53B, "\u{12AE}"),
+    (0x544, "\u{1206}"),
+    (0x54A, "\u{1323}"),
+    (0x54C, "\u{1261}"),
+    (0x54D, "U"),
+    (0x54F, "S"),
+    (0x553, "\u{3A6}"),
+    (0x555, "O"),
+    (0x55A, "'"),
+    (0x55D, "'"),
+    (0x561, "w"),
+    (0x563, "q"),
+    (0x566, "q"),
+    (0x56E, "\u{1E9F}"),
+    (0x570, "h"),
+    (0x575, "\u{237}"),
+    (0x578, "n"),
+    (0x57A, "\u{270}"),
+    (0x57C, "n"),
+    (0x57D, "u"),
+    (0x581, "g"),
+    (0x584, "f"),
+    (0x585, "o"),
+    (0x587, "\u{565}\u{582}"),
+    (0x589, ":"),
+    (0x59C, "\u{301}"),
+    (0x59D, "\u{301}"),
+    (0x5A4, "\u{59A}"),
+    (0x5A8, "\u{599}"),
+    (0x5AD, "\u{596}"),
+    (0x5AE, "\u{598}"),
+    (0x5AF, "\u{30A}"),
+    (0x5B4, "\u{323}"),
+    (0x5B9, "\u{307}"),
+    (0x5BA, "\u{307}"),
+    (0x5C0, "l"),
+    (0x5C1, "\u{307}"),
+    (0x5C2, "\u{307}"),
+    (0x5C3, ":"),
+    (0x5C4, "\u{307}"),
+    (0x5C5, "\u{323}"),
+    (0x5D5, "l"),
+    (0x5D8, "v"),
+    (0x5D9, "'"),
+    (0x5DF, "l"),
+    (0x5E1, "o"),
+    (0x5F0, "ll"),
+    (0x5F1, "l'"),
+    (0x5F2, "''"),
+    (0x5F3, "'"),
+    (0x5F4, "''"),
+    (0x609, "\u{BA}/\u{2080}\u{2080}"),
+    (0x60A, "\u{BA}/\u{2080}\u{2080}\u{2080}"),
+    (0x60D, ","),
+    (0x60F, "\u{639}"),
+    (0x618, "\u{301}"),
+    (0x619, "\u{313}"),
+    (0x61A, "\u{650}"),
+    (0x623, "l\u{674}"),
+    (0x624, "\u{648}\u{674}"),
+    (0x625, "l\u{655}"),
+    (0x626, "\u{649}\u{674}"),
+    (0x627, "l"),
+    (0x62B, "\u{649}\u{6DB}"),
+    (0x634, "\u{633}\u{6DB}"),
+    (0x63D, "\u{649}\u{302}"),
+    (0x63F, "\u{649}\u{6DB}"),
+    (0x647, "o"),
+    (0x64A, "\u{649}"),
+    (0x64B, "\u{30B}"),
+    (0x64E, "\u{301}"),
+    (0x64F, "\u{313}"),
+    (0x652, "\u{30A}"),
+    (0x653, "\u{303}"),
+    (0x656, "\u{329}"),
+    (0x657, "\u{312}"),
+    (0x658, "\u{306}"),
+    (0x659, "\u{304}"),
+    (0x65A, "\u{306}"),
+    (0x65B, "\u{302}"),
+    (0x65C, "\u{323}"),
+    (0x65D, "\u{314}"),
+    (0x65F, "\u{655}"),
+    (0x660, "."),
+    (0x661, "l"),
+    (0x665, "o"),
+    (0x667, "V"),
+    (0x668, "\u{245}"),
+    (0x66A, "\u{BA}/\u{2080}"),
+    (0x66B, ","),
+    (0x66C, "\u{60C}"),
+    (0x66D, "*"),
+    (0x66E, "\u{649}"),
+    (0x66F, "\u{6A1}"),
+    (0x672, "l\u{674}"),
+    (0x673, "l\u{655}"),
+    (0x675, "l\u{674}"),
+    (0x676, "\u{648}\u{674}"),
+    (0x677, "\u{648}\u{313}\u{674}"),
+    (0x678, "\u{649}\u{674}"),
+    (0x679, "\u{649}\u{615}"),
+    (0x67E, "\u{649}\u{6DB}"),
+    (0x681, "\u{62D}\u{654}"),
+    (0x685, "\u{62D}\u{6DB}"),
+    (0x688, "\u{62F}\u{615}"),
+    (0x68B, "\u{68A}\u{615}"),
+    (0x68E, "\u{62F}\u{6DB}"),
+    (0x691, "\u{631}\u{615}"),
+    (0x692, "\u{631}\u{306}"),
+    (0x698, "\u{631}\u{6DB}"),
+    (0x69E, "\u{635}\u{6DB}"),
+    (0x69F, "\u{637}\u{6DB}"),
+    (0x6A4, "\u{6A1}\u{6DB}"),
+    (0x6A7, "\u{641}"),
+    (0x6A8, "\u{6A1}\u{6DB}"),
+    (0x6A9, "\u{643}"),
+    (0x6AA, "\u{643}"),
+    (0x6AD, "\u{643}\u{6DB}"),
+    (0x6B4, "\u{6AF}\u{6DB}"),
+    (0x6B5, "\u{644}\u{306}"),
+    (0x6B7, "\u{644}\u{6DB}"),
+    (0x6BA, "\u{649}"),
+    (0x6BB, "\u{649}\u{615}"),
+    (0x6BD, "\u{649}\u{6DB}"),
+    (0x6BE, "o"),
+    (0x6C1, "o"),
+    (0x6C2, "\u{6C0}"),
+    (0x6C3, "\u{629}"),
+    (0x6C6, "\u{648}\u{306}"),
+    (0x6C7, "\u{648}\u{313}"),
+    (0x6C8, "\u{648}\u{670}"),
+    (0x6C9, "\u{648}\u{302}"),
+    (0x6CB, "\u{648}\u{6DB}"),
+    (0x6CC, "\u{649}"),
+    (0x6CE, "\u{649}\u{306}"),
+    (0x6D0, "\u{67B}"),
+    (0x6D1, "\u{649}\u{6DB}"),
+    (0x6D2, "\u{649}"),
+    (0x6D4, "-"),
+    (0x6D5, "o"),
+    (0x6DF, "\u{30A}"),
+    (0x6E8, "\u{306}\u{307}"),
+    (0x6EC, "\u{307}"),
+    (0x6EE, "\u{62F}\u{302}"),
+    (0x6EF, "\u{631}\u{302}"),
+    (0x6F0, "."),
+    (0x6F1, "l"),
+    (0x6F2, "\u{662}"),
+    (0x6F3, "\u{663}"),
+    (0x6F4, "\u{664}"),
+    (0x6F5, "o"),
+    (0x6F6, "\u{666}"),
+    (0x6F7, "V"),
+    (0x6F8, "\u{245}"),
+    (0x6F9, "\u{669}"),
+    (0x6FD, "\u{621}\u{348}"),
+    (0x6FE, "\u{645}\u{348}"),
+    (0x6FF, "o\u{302}"),
+    (0x701, "."),
+    (0x702, "."),
+    (0x703, ":"),
+    (0x704, ":"),
+    (0x740, "\u{307}"),
+    (0x741, "\u{307}"),
+    (0x742, "\u{73C}"),
+    (0x747, "\u{301}"),
+    (0x751, "\u{628}\u{6DB}"),
+    (0x756, "\u{649}\u{306}"),
+    (0x762, "\u{6AC}"),
+    (0x763, "\u{643}\u{6DB}"),
+    (0x767, "\u{754}"),
+    (0x768, "\u{646}\u{615}"),
+    (0x769, "\u{646}\u{306}"),
+    (0x76C, "\u{631}\u{654}"),
+    (0x771, "\u{697}\u{615}"),
+    (0x772, "\u{62D}\u{654}"),
+    (0x77E, "\u{633}\u{302}"),
+    (0x7C0, "O"),
+    (0x7CA, "l"),
+    (0x7EB, "\u{304}"),
+    (0x7ED, "\u{307}"),
+    (0x7EE, "\u{302}"),
+    (0x7F3, "\u{308}"),
+    (0x7F4, "'"),
+    (0x7F5, "'"),
+    (0x7FA, "_"),
+    (0x8A1, "\u{628}\u{654}"),
+    (0x8A4, "\u{6A2}\u{6DB}"),
+    (0x8A7, "\u{645}\u{6DB}"),
+    (0x8A8, "\u{649}\u{654}"),
+    (0x8A9, "\u{754}"),
+    (0x8AE, "\u{62F}\u{324}\u{323}"),
+    (0x8AF, "\u{635}\u{324}\u{323}"),
+    (0x8B0, "\u{6AF}"),
+    (0x8B1, "\u{648}"),
+    (0x8B2, "\u{632}\u{302}"),
+    (0x8B6, "\u{628}\u{6E2}"),
+    (0x8B7, "\u{649}\u{6DB}\u{6E2}"),
+    (0x8B9, "\u{631}\u{306}\u{307}"),
+    (0x8BA, "\u{649}\u{306}\u{307}"),
+    (0x8BB, "\u{6A1}"),
+    (0x8BC, "\u{6A1}"),
+    (0x8BD, "\u{649}"),
+    (0x8E5, "\u{64C}"),
+    (0x8E8, "\u{64C}"),
+    (0x8EA, "\u{307}"),
+    (0x8EB, "\u{308}"),
+    (0x8ED, "\u{323}"),
+    (0x8EE, "\u{324}"),
+    (0x8F0, "\u{30B}"),
+    (0x8F1, "\u{64C}"),
+    (0x8F2, "\u{64D}"),
+    (0x8F3, "\u{313}"),
+    (0x8F8, "\u{350}"),
+    (0x8F9, "\u{354}"),
+    (0x8FA, "\u{355}"),
+    (0x8FF, "\u{350}"),
+    (0x900, "\u{352}"),
+    (0x901, "\u{306}\u{307}"),
+    (0x902, "\u{307}"),
+    (0x903, ":"),
+    (0x904, "\u{905}\u{946}"),
+    (0x906, "\u{905}\u{93E}"),
+    (0x908, "\u{930}\u{94D}\u{907}"),
+    (0x90D, "\u{90F}\u{945}"),
+    (0x90E, "\u{90F}\u{946}"),
+    (0x910, "\u{90F}\u{947}"),
+    (0x911, "\u{905}\u{949}"),
+    (0x912, "\u{905}\u{93E}\u{946}"),
+    (0x913, "\u{905}\u{93E}\u{947}"),
+    (0x914, "\u{905}\u{93E}\u{948}"),
+    (0x93C, "\u{323}"),
+    (0x952, "\u{331}"),
+    (0x953, "\u{300}"),
+    (0x954, "\u{301}"),
+    (0x965, "\u{964}\u{964}"),
+    (0x966, "o"),
+    (0x967, "\u{669}"),
+    (0x97D, "?"),
+    (0x981, "\u{306}\u{307}"),
+    (0x986, "\u{985}\u{9BE}"),
+    (0x9BC, "\u{323}"),
+    (0x9E0, "\u{98B}\u{9C3}"),
+    (0x9E1, "\u{98B}\u{9C3}"),
+    (0x9E6, "O"),
+    (0x9EA, "8"),
+    (0x9ED, "9"),
+    (0xA02, "\u{307}"),
+    (0xA03, "\u{983}"),
+    (0xA06, "\u{A05}\u{A3E}"),
+    (0xA07, "\u{A72}\u{A3F}"),
+    (0xA08, "\u{A72}\u{A40}"),
+    (0xA09, "\u{A73}\u{A41}"),
+    (0xA0A, "\u{A73}\u{A42}"),
+    (0xA0F, "\u{A72}\u{A47}"),
+    (0xA10, "\u{A05}\u{A48}"),
+    (0xA14, "\u{A05}\u{A4C}"),
+    (0xA3C, "\u{323}"),
+    (0xA4B, "\u{946}"),
+    (0xA4D, "\u{94D}"),
+    (0xA66, "o"),
+    (0xA67, "9"),
+    (0xA6A, "8"),
+    (0xA81, "\u{306}\u{307}"),
+    (0xA82, "\u{307}"),
+    (0xA83, ":"),
+    (0xA86, "\u{A85}\u{ABE}"),
+    (0xA8D, "\u{A85}\u{AC5}"),
+    (0xA8F, "\u{A85}\u{AC7}"),
+    (0xA90, "\u{A85}\u{AC8}"),
+    (0xA91, "\u{A85}\u{ABE}\u{AC5}"),
+    (0xA93, "\u{A85}\u{ABE}\u{AC7}"),
+    (0xA94, "\u{A85}\u{ABE}\u{AC8}"),
+    (0xABC, "\u{323}"),
+    (0xABD, "\u{93D}"),
+    (0xAC1, "\u{941}"),
+    (0xAC2, "\u{942}"),
+    (0xACD, "\u{94D}"),
+    (0xAE6, "o"),
+    (0xAE8, "\u{968}"),
+    (0xAE9, "\u{969}"),
+    (0xAEA, "\u{96A}"),
+    (0xAEE, "\u{96E}"),
+    (0xAF0, "\u{970}"),
+    (0xB01, "\u{306}\u{307}"),
+    (0xB03, "8"),
+    (0xB06, "\u{B05}\u{B3E}"),
+    (0xB20, "O"),
+    (0xB3C, "\u{323}"),
+    (0xB66, "O"),
+    (0xB68, "9"),
+    (0xB82, "\u{30A}"),
+    (0xB8A, "\u{B89}\u{BB3}"),
+    (0xB9C, "\u{B90}"),
+    (0xBB0, "\u{B88}"),
+    (0xBBE, "\u{B88}"),
+    (0xBC8, "\u{BA9}"),
+    (0xBCA, "\u{BC6}\u{B88}"),
+    (0xBCB, "\u{BC7}\u{B88}"),
+    (0xBCC, "\u{BC6}\u{BB3}"),
+    (0xBCD, "\u{307}"),
+    (0xBD7, "\u{BB3}"),
+    (0xBE6, "o"),
+    (0xBE7, "\u{B95}"),
+    (0xBE8, "\u{B89}"),
+    (0xBEA, "\u{B9A}"),
+    (0xBEB, "\u{B88}\u{BC1}"),
+    (0xBEC, "\u{B9A}\u{BC1}"),
+    (0xBED, "\u{B8E}"),
+    (0xBEE, "\u{B85}"),
+    (0xBF0, "\u{BAF}"),
+    (0xBF2, "\u{B9A}\u{BC2}"),
+    (0xBF4, "\u{BAE}\u{BC0}"),
+    (0xBF5, "\u{BF3}"),
+    (0xBF7, "\u{B8E}\u{BB5}"),
+    (0xBF8, "\u{BB7}"),
+    (0xBFA, "\u{BA8}\u{BC0}"),
+    (0xC00, "\u{306}\u{307}"),
+    (0xC02, "o"),
+    (0xC03, "\u{983}"),
+    (0xC13, "\u{C12}\u{C55}"),
+    (0xC14, "\u{C12}\u{C4C}"),
+    (0xC20, "\u{C30}\u{5BC}"),
+    (0xC22, "\u{C21}\u{323}"),
+    (0xC25, "\u{C27}\u{5BC}"),
+    (0xC2D, "\u{C2C}\u{323}"),
+    (0xC2E, "\u{C35}\u{C41}"),
+    (0xC37, "\u{C35}\u{323}"),
+    (0xC39, "\u{C35}\u{C3E}"),
+    (0xC42, "\u{C41}\u{C3E}"),
+    (0xC44, "\u{C43}\u{C3E}"),
+    (0xC60, "\u{C0B}\u{C3E}"),
+    (0xC61, "\u{C0C}\u{C3E}"),
+    (0xC66, "o"),
+    (0xC81, "\u{306}\u{307}"),
+    (0xC82, "o"),
+    (0xC83, "\u{983}"),
+    (0xC85, "\u{C05}"),
+    (0xC86, "\u{C06}"),
+    (0xC87, "\u{C07}"),
+    (0xC92, "\u{C12}"),
+    (0xC93, "\u{C12}\u{C55}"),
+    (0xC94, "\u{C12}\u{C4C}"),
+    (0xC9C, "\u{C1C}"),
+    (0xC9E, "\u{C1E}"),
+    (0xCA3, "\u{C23}"),
+    (0xCAF, "\u{C2F}"),
+    (0xCB1, "\u{C31}"),
+    (0xCB2, "\u{C32}"),
+    (0xCE1, "\u{C8C}\u{CBE}"),
+    (0xCE6, "o"),
+    (0xCE7, "\u{C67}"),
+    (0xCE8, "\u{C68}"),
+    (0xCEF, "\u{C6F}"),
+    (0xD01, "\u{306}\u{307}"),
+    (0xD02, "o"),
+    (0xD03, "\u{983}"),
+    (0xD08, "\u{D07}\u{D57}"),
+    (0xD09, "\u{B89}"),
+    (0xD0A, "\u{B89}\u{D57}"),
+    (0xD0C, "\u{D28}\u{D41}"),
+    (0xD10, "\u{D0E}\u{D46}"),
+    (0xD13, "\u{D12}\u{D3E}"),
+    (0xD14, "\u{D12}\u{D57}"),
+    (0xD19, "\u{D28}\u{D41}"),
+    (0xD1C, "\u{B90}"),
+    (0xD20, "o"),
+    (0xD23, "\u{BA3}"),
+    (0xD31, "\u{D30}"),
+    (0xD34, "\u{BB4}"),
+    (0xD36, "\u{BB6}"),
+    (0xD3A, "\u{B9F}\u{BBF}"),
+    (0xD3F, "\u{BBF}"),
+    (0xD40, "\u{BBF}"),
+    (0xD42, "\u{D41}"),
+    (0xD43, "\u{D41}"),
+    (0xD48, "\u{D46}\u{D46}"),
+    (0xD4E, "\u{971}"),
+    (0xD5A, "\u{D28}\u{D4D}\u{D2E}"),
+    (0xD5F, "o\u{D30}o"),
+    (0xD61, "\u{D1E}"),
+    (0xD66, "o"),
+    (0xD6A, "\u{D30}\u{D4D}"),
+    (0xD6B, "\u{D26}\u{D4D}\u{D30}"),
+    (0xD6C, "\u{D28}\u{D4D}\u{D28}"),
+    (0xD6D, "9"),
+    (0xD6E, "\u{D35}\u{D4D}\u{D30}"),
+    (0xD6F, "\u{D28}\u{D4D}"),
+    (0xD76, "\u{D39}\u{D4D}\u{D2E}"),
+    (0xD79, "\u{D28}\u{D41}"),
+    (0xD7B, "\u{D28}\u{D4D}"),
+    (0xD7C, "\u{D30}\u{D4D}"),
+    (0xD82, "o"),
+    (0xD83, "\u{983}"),
+    (0xDE9, "\u{DE8}\u{DCF}"),
+    (0xDEA, "\u{DA2}"),
+    (0xDEB, "\u{DAF}"),
+    (0xDEF, "\u{DE8}\u{DD3}"),
+    (0xE03, "\u{E02}"),
+    (0xE0B, "\u{E0A}"),
+    (0xE0F, "\u{E0E}"),
+    (0xE14, "\u{E04}"),
+    (0xE15, "\u{E04}"),
+    (0xE17, "\u{E11}"),
+    (0xE21, "\u{E06}"),
+    (0xE26, "\u{E20}"),
+    (0xE33, "\u{30A}\u{E32}"),
+    (0xE41, "\u{E40}\u{E40}"),
+    (0xE45, "\u{E32}"),
+    (0xE4D, "\u{30A}"),
+    (0xE50, "o"),
+    (0xE88, "\u{E08}"),
+    (0xE8D, "\u{E22}"),
+    (0xE9A, "\u{E1A}"),
+    (0xE9B, "\u{E1B}"),
+    (0xE9D, "\u{E1D}"),
+    (0xE9E, "\u{E1E}"),
+    (0xE9F, "\u{E1F}"),
+    (0xEB3, "\u{30A}\u{EB2}"),
+    (0xEB8, "\u{E38}"),
+    (0xEB9, "\u{E39}"),
+    (0xEC8, "\u{E48}"),
+    (0xEC9, "\u{E49}"),
+    (0xECA, "\u{E4A}"),
+    (0xECB, "\u{E4B}"),
+    (0xECD, "\u{30A}"),
+    (0xED0, "o"),
+    (0xEDC, "\u{EAB}\u{E99}"),
+    (0xEDD, "\u{EAB}\u{EA1}"),
+    (0xF00, "\u{F68}\u{F7C}\u{F7E}"),
+    (0xF02, "\u{F60}\u{F74}\u{F82}\u{F7F}"),
+    (0xF03, "\u{F60}\u{F74}\u{F82}\u{F14}"),
+    (0xF0C, "\u{F0B}"),
+    (0xF0E, "\u{F0D}\u{F0D}"),
+    (0xF1B, "\u{F1A}\u{F1A}"),
+    (0xF1E, "\u{F1D}\u{F1D}"),
+    (0xF1F, "\u{F1A}\u{F1D}"),
+    (0xF37, "\u{325}"),
+    (0xF6A, "\u{F62}"),
+    (0xF77, "\u{FB2}\u{F71}\u{F80}"),
+    (0xF79, "\u{FB3}\u{F71}\u{F80}"),
+    (0xFCE, "\u{F1D}\u{F1A}"),
+    (0xFD5, "\u{5350}"),
+    (0xFD6, "\u{534D}"),
+    (0x1000, "\u{1002}\u{102C}"),
+    (0x1010, "o\u{102C}"),
+    (0x101D, "o"),
+    (0x101F, "\u{1015}\u{102C}"),
+    (0x1029, "\u{101E}\u{103C}"),
+    (0x102A, "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}"),
+    (0x1036, "\u{30A}"),
+    (0x1038, "\u{983}"),
+    (0x1040, "o"),
+    (0x104B, "\u{104A}\u{104A}"),
+    (0x1065, "\u{1041}"),
+    (0x1066, "\u{1015}\u{103E}"),
+    (0x106F, "\u{1015}\u{102C}\u{103E}"),
+    (0x1070, "\u{1003}\u{103E}"),
+    (0x107E, "\u{107D}\u{103E}"),
+    (0x1081, "\u{1002}\u{103E}"),
+    (0x109E, "\u{1083}\u{30A}"),
+    (0x10A0, "\u{A786}"),
+    (0x10E7, "y"),
+    (0x10F3, "\u{21D}"),
+    (0x10FF, "o"),
+    (0x1101, "\u{1100}\u{1100}"),
+    (0x1104, "\u{1103}\u{1103}"),
+    (0x1108, "\u{1107}\u{1107}"),
+    (0x110A, "\u{1109}\u{1109}"),
+    (0x110D, "\u{110C}\u{110C}"),
+    (0x1113, "\u{1102}\u{1100}"),
+    (0x1114, "\u{1102}\u{1102}"),
+    (0x1115, "\u{1102}\u{1103}"),
+    (0x1116, "\u{1102}\u{1107}"),
+    (0x1117, "\u{1103}\u{1100}"),
+    (0x1118, "\u{1105}\u{1102}"),
+    (0x1119, "\u{1105}\u{1105}"),
+    (0x111A, "\u{1105}\u{1112}"),
+    (0x111B, "\u{1105}\u{110B}"),
+    (0x111C, "\u{1106}\u{1107}"),
+    (0x111D, "\u{1106}\u{110B}"),
+    (0x111E, "\u{1107}\u{1100}"),
+    (0x111F, "\u{1107}\u{1102}"),
+    (0x1120, "\u{1107}\u{1103}"),
+    (0x1121, "\u{1107}\u{1109}"),
+    (0x1122, "\u{1107}\u{1109}\u{1100}"),
+    (0x1123, "\u{1107}\u{1109}\u{1103}"),
+    (0x1124, "\u{1107}\u{1109}\u{1107}"),
+    (0x1125, "\u{1107}\u{1109}\u{1109}"),
+    (0x1126, "\u{1107}\u{1109}\u{110C}"),
+    (0x1127, "\u{1107}\u{110C}"),
+    (0x1128, "\u{1107}\u{110E}"),
+    (0x1129, "\u{1107}\u{1110}"),
+    (0x112A, "\u{1107}\u{1111}"),
+    (0x112B, "\u{1107}\u{110B}"),
+    (0x112C, "\u{1107}\u{1107}\u{110B}"),
+    (0x112D, "\u{1109}\u{1100}"),
+    (0x112E, "\u{1109}\u{1102}"),
+    (0x112F, "\u{1109}\u{1103}"),
+    (0x1130, "\u{1109}\u{1105}"),
+    (0x1131, "\u{1109}\u{1106}"),
+    (0x1132, "\u{1109}\u{1107}"),
+    (0x1133, "\u{1109}\u{1107}\u{1100}"),
+    (0x1134, "\u{1109}\u{1109}\u{1109}"),
+    (0x1135, "\u{1109}\u{110B}"),
+    (0x1136, "\u{1109}\u{110C}"),
+    (0x1137, "\u{1109}\u{110E}"),
+    (0x1138, "\u{1109}\u{110F}"),
+    (0x1139, "\u{1109}\u{1110}"),
+    (0x113A, "\u{1109}\u{1111}"),
+    (0x113B, "\u{1105}\u{1112}"),
+    (0x113D, "\u{113C}\u{113C}"),
+    (0x113F, "\u{113E}\u{113E}"),
+    (0x1141, "\u{110B}\u{1100}"),
+    (0x1142, "\u{110B}\u{1103}"),
+    (0x1143, "\u{110B}\u{1106}"),
+    (0x1144, "\u{110B}\u{1107}"),
+    (0x1145, "\u{110B}\u{1109}"),
+    (0x1146, "\u{110B}\u{1140}"),
+    (0x1147, "\u{110B}\u{110B}"),
+    (0x1148, "\u{110B}\u{110C}"),
+    (0x1149, "\u{110B}\u{110E}"),
+    (0x114A, "\u{110B}\u{1110}"),
+    (0x114B, "\u{110B}\u{1111}"),
+    (0x114D, "\u{110C}\u{110B}"),
+    (0x114F, "\u{114E}\u{114E}"),
+    (0x1151, "\u{1150}\u{1150}"),
+    (0x1152, "\u{110E}\u{110F}"),
+    (0x1153, "\u{110E}\u{1112}"),
+    (0x1156, "\u{1111}\u{1107}"),
+    (0x1157, "\u{1111}\u{110B}"),
+    (0x1158, "\u{1112}\u{1112}"),
+    (0x115A, "\u{1100}\u{1103}"),
+    (0x115B, "\u{1102}\u{1109}"),
+    (0x115C, "\u{1102}\u{110C}"),
+    (0x115D, "\u{1102}\u{1112}"),
+    (0x115E, "\u{1103}\u{1105}"),
+    (0x1162, "\u{1161}\u{4E28}"),
+    (0x1164, "\u{1163}\u{4E28}"),
+    (0x1166, "\u{1165}\u{4E28}"),
+    (0x1168, "\u{1167}\u{4E28}"),
+    (0x116A, "\u{1169}\u{1161}"),
+    (0x116B, "\u{1169}\u{1161}\u{4E28}"),
+    (0x116C, "\u{1169}\u{4E28}"),
+    (0x116F, "\u{116E}\u{1165}"),
+    (0x1170, "\u{116E}\u{1165}\u{4E28}"),
+    (0x1171, "\u{116E}\u{4E28}"),
+    (0x1173, "\u{30FC}"),
+    (0x1174, "\u{30FC}\u{4E28}"),
+    (0x1175, "\u{4E28}"),
+    (0x1176, "\u{1161}\u{1169}"),
+    (0x1177, "\u{1161}\u{116E}"),
+    (0x1178, "\u{1163}\u{1169}"),
+    (0x1179, "\u{1163}\u{116D}"),
+    (0x117A, "\u{1165}\u{1169}"),
+    (0x117B, "\u{1165}\u{116E}"),
+    (0x117C, "\u{1165}\u{30FC}"),
+    (0x117D, "\u{1167}\u{1169}"),
+    (0x117E, "\u{1167}\u{116E}"),
+    (0x117F, "\u{1169}\u{1165}"),
+    (0x1180, "\u{1169}\u{1165}\u{4E28}"),
+    (0x1181, "\u{1169}\u{1167}\u{4E28}"),
+    (0x1182, "\u{1169}\u{1169}"),
+    (0x1183, "\u{1169}\u{116E}"),
+    (0x1184, "\u{116D}\u{1163}"),
+    (0x1185, "\u{116D}\u{1163}\u{4E28}"),
+    (0x1186, "\u{116D}\u{1163}"),
+    (0x1187, "\u{116D}\u{1169}"),
+    (0x1188, "\u{116D}\u{4E28}"),
+    (0x1189, "\u{116E}\u{1161}"),
+    (0x118A, "\u{116E}\u{1161}\u{4E28}"),
+    (0x118B, "\u{116E}\u{1165}\u{30FC}"),
+    (0x118C, "\u{116E}\u{1167}\u{4E28}"),
+    (0x118D, "\u{116E}\u{116E}"),
+    (0x118E, "\u{1172}\u{1161}"),
+    (0x118F, "\u{1172}\u{1165}"),
+    (0x1190, "\u{1172}\u{1165}\u{4E28}"),
+    (0x1191, "\u{1172}\u{1167}"),
+    (0x1192, "\u{1172}\u{1167}\u{4E28}"),
+    (0x1193, "\u{1172}\u{116E}"),
+    (0x1194, "\u{1172}\u{4E28}"),
+    (0x1195, "\u{30FC}\u{116E}"),
+    (0x1196, "\u{30FC}\u{30FC}"),
+    (0x1197, "\u{30FC}\u{4E28}\u{116E}"),
+    (0x1198, "\u{4E28}\u{1161}"),
+    (0x1199, "\u{4E28}\u{1163}"),
+    (0x119A, "\u{4E28}\u{1169}"),
+    (0x119B, "\u{4E28}\u{116E}"),
+    (0x119C, "\u{4E28}\u{30FC}"),
+    (0x119D, "\u{4E28}\u{119E}"),
+    (0x119F, "\u{119E}\u{1165}"),
+    (0x11A0, "\u{119E}\u{116E}"),
+    (0x11A1, "\u{119E}\u{4E28}"),
+    (0x11A2, "\u{119E}\u{119E}"),
+    (0x11A3, "\u{1161}\u{30FC}"),
+    (0x11A4, "\u{1163}\u{116E}"),
+    (0x11A5, "\u{1167}\u{1163}"),
+    (0x11A6, "\u{1169}\u{1163}"),
+    (0x11A7, "\u{1169}\u{1163}\u{4E28}"),
+    (0x11A8, "\u{1100}"),
+    (0x11A9, "\u{1100}\u{1100}"),
+    (0x11AA, "\u{1100}\u{1109}"),
+    (0x11AB, "\u{1102}"),
+    (0x11AC, "\u{1102}\u{110C}"),
+    (0x11AD, "\u{1102}\u{1112}"),
+    (0x11AE, "\u{1103}"),
+    (0x11AF, "\u{1105}"),
+    (0x11B0, "\u{1105}\u{1100}"),
+    (0x11B1, "\u{1105}\u{1106}"),
+    (0x11B2, "\u{1105}\u{1107}"),
+    (0x11B3, "\u{1105}\u{1109}"),
+    (0x11B4, "\u{1105}\u{1110}"),
+    (0x11B5, "\u{1105}\u{1111}"),
+    (0x11B6, "\u{1105}\u{1112}"),
+    (0x11B7, "\u{1106}"),
+    (0x11B8, "\u{1107}"),
+    (0x11B9, "\u{1107}\u{1109}"),
+    (0x11BA, "\u{1109}"),
+    (0x11BB, "\u{1109}\u{1109}"),
+    (0x11BC, "\u{110B}"),
+    (0x11BD, "\u{110C}"),
+    (0x11BE, "\u{110E}"),
+    (0x11BF, "\u{110F}"),
+    (0x11C0, "\u{1110}"),
+    (0x11C1, "\u{1111}"),
+    (0x11C2, "\u{1112}"),
+    (0x11C3, "\u{1100}\u{1105}"),
+    (0x11C4, "\u{1100}\u{1109}\u{1100}"),
+    (0x11C5, "\u{1102}\u{1100}"),
+    (0x11C6, "\u{1102}\u{1103}"),
+    (0x11C7, "\u{1102}\u{1109}"),
+    (0x11C8, "\u{1102}\u{1140}"),
+    (0x11C9, "\u{1102}\u{1110}"),
+    (0x11CA, "\u{1103}\u{1100}"),
+    (0x11CB, "\u{1103}\u{1105}"),
+    (0x11CC, "\u{1105}\u{1100}\u{1109}"),
+    (0x11CD, "\u{1105}\u{1102}"),
+    (0x11CE, "\u{1105}\u{1103}"),
+    (0x11CF, "\u{1105}\u{1103}\u{1112}"),
+    (0x11D0, "\u{1105}\u{1105}"),
+    (0x11D1, "\u{1105}\u{1106}\u{1100}"),
+    (0x11D2, "\u{1105}\u{1106}\u{1109}"),
+    (0x11D3, "\u{1105}\u{1107}\u{1109}"),
+    (0x11D4, "\u{1105}\u{1107}\u{1112}"),
+    (0x11D5, "\u{1105}\u{1107}\u{110B}"),
+    (0x11D6, "\u{1105}\u{1109}\u{1109}"),
+    (0x11D7, "\u{1105}\u{1140}"),
+    (0x11D8, "\u{1105}\u{110F}"),
+    (0x11D9, "\u{1105}\u{1159}"),
+    (0x11DA, "\u{1106}\u{1100}"),
+    (0x11DB, "\u{1106}\u{1105}"),
+    (0x11DC, "\u{1106}\u{1107}"),
+    (0x11DD, "\u{1106}\u{1109}"),
+    (0x11DE, "\u{1106}\u{1109}\u{1109}"),
+    (0x11DF, "\u{1106}\u{1140}"),
+    (0x11E0, "\u{1106}\u{110E}"),
+    (0x11E1, "\u{1106}\u{1112}"),
+    (0x11E2, "\u{1106}\u{110B}"),
+    (0x11E3, "\u{1107}\u{1105}"),
+    (0x11E4, "\u{1107}\u{1111}"),
+    (0x11E5, "\u{1107}\u{1112}"),
+    (0x11E6, "\u{1107}\u{110B}"),
+    (0x11E7, "\u{1109}\u{1100}"),
+    (0x11E8, "\u{1109}\u{1103}"),
+    (0x11E9, "\u{1109}\u{1105}"),
+    (0x11EA, "\u{1109}\u{1107}"),
+    (0x11EB, "\u{1140}"),
+    (0x11EC, "\u{110B}\u{1100}"),
+    (0x11ED, "\u{110B}\u{1100}\u{1100}"),
+    (0x11EE, "\u{110B}\u{110B}"),
+    (0x11EF, "\u{110B}\u{110F}"),
+    (0x11F0, "\u{114C}"),
+    (0x11F1, "\u{110B}\u{1109}"),
+    (0x11F2, "\u{110B}\u{1140}"),
+    (0x11F3, "\u{1111}\u{1107}"),
+    (0x11F4, "\u{1111}\u{110B}"),
+    (0x11F5, "\u{1112}\u{1102}"),
+    (0x11F6, "\u{1112}\u{1105}"),
+    (0x11F7, "\u{1112}\u{1106}"),
+    (0x11F8, "\u{1112}\u{1107}"),
+    (0x11F9, "\u{1159}"),
+    (0x11FA, "\u{1100}\u{1102}"),
+    (0x11FB, "\u{1100}\u{1107}"),
+    (0x11FC, "\u{1100}\u{110E}"),
+    (0x11FD, "\u{1100}\u{110F}"),
+    (0x11FE, "\u{1100}\u{1112}"),
+    (0x11FF, "\u{1102}\u{1102}"),
+    (0x1200, "U"),
+    (0x1223, "\u{270}"),
+    (0x1240, "\u{3A6}"),
+    (0x1260, "\u{548}"),
+    (0x1294, "\u{571}"),
+    (0x12D0, "O"),
+    (0x13A0, "D"),
+    (0x13A1, "R"),
+    (0x13A2, "T"),
+    (0x13A4, "O'"),
+    (0x13A5, "i"),
+    (0x13A8, "\u{2C75}"),
+    (0x13A9, "Y"),
+    (0x13AA, "A"),
+    (0x13AB, "J"),
+    (0x13AC, "E"),
+    (0x13AE, "?"),
+    (0x13B0, "\u{2C75}"),
+    (0x13B1, "\u{393}"),
+    (0x13B3, "W"),
+    (0x13B7, "M"),
+    (0x13BB, "H"),
+    (0x13BD, "Y"),
+    (0x13BE, "O\u{335}"),
+    (0x13BF, "\u{1AB}"),
+    (0x13C0, "G"),
+    (0x13C2, "h"),
+    (0x13C3, "Z"),
+    (0x13C7, "\u{460}"),
+    (0x13CB, "\u{190}"),
+    (0x13CC, "U\u{335}"),
+    (0x13CE, "4"),
+    (0x13CF, "b"),
+    (0x13D2, "R"),
+    (0x13D4, "W"),
+    (0x13D5, "S"),
+    (0x13D9, "V"),
+    (0x13DA, "S"),
+    (0x13DE, "L"),
+    (0x13DF, "C"),
+    (0x13E2, "P"),
+    (0x13E6, "K"),
+    (0x13E7, "d"),
+    (0x13EB, "O\u{335}"),
+    (0x13EE, "6"),
+    (0x13F0, "\u{DF}"),
+    (0x13F2, "h\u{314}"),
+    (0x13F3, "G"),
+    (0x13F4, "B"),
+    (0x13FB, "\u{262}"),
+    (0x13FC, "\u{299}"),
+    (0x1400, "="),
+    (0x1403, "\u{394}"),
+    (0x140C, "\u{B7}\u{1401}"),
+    (0x140D, "\u{1401}\u{B7}"),
+    (0x140E, "\u{B7}\u{394}"),
+    (0x140F, "\u{394}\u{B7}"),
+    (0x1410, "\u{B7}\u{1404}"),
+    (0x1411, "\u{1404}\u{B7}"),
+    (0x1412, "\u{B7}\u{1405}"),
+    (0x1413, "\u{1405}\u{B7}"),
+    (0x1414, "\u{B7}\u{1406}"),
+    (0x1415, "\u{1406}\u{B7}"),
+    (0x1417, "\u{B7}\u{140A}"),
+    (0x1418, "\u{140A}\u{B7}"),
+    (0x1419, "\u{B7}\u{140B}"),
+    (0x141A, "\u{140B}\u{B7}"),
+    (0x1427, "\u{B7}"),
+    (0x142B, "\u{1401}\u{1420}"),
+    (0x142C, "\u{394}\u{1420}"),
+    (0x142D, "\u{1405}\u{1420}"),
+    (0x142E, "\u{140A}\u{1420}"),
+    (0x142F, "V"),
+    (0x1431, "\u{245}"),
+    (0x1433, ">"),
+    (0x1437, "\u{B7}>"),
+    (0x1438, "<"),
+    (0x143A, "\u{B7}V"),
+    (0x143B, "V\u{B7}"),
+    (0x143C, "\u{B7}\u{245}"),
+    (0x143D, "\u{245}\u{B7}"),
+    (0x143E, "\u{B7}\u{1432}"),
+    (0x143F, "\u{1432}\u{B7}"),
+    (0x1440, "\u{B7}>"),
+    (0x1441, ">\u{B7}"),
+    (0x1442, "\u{B7}\u{1434}"),
+    (0x1443, "\u{1434}\u{B7}"),
+    (0x1444, "\u{B7}<"),
+    (0x1445, "<\u{B7}"),
+    (0x1446, "\u{B7}\u{1439}"),
+    (0x1447, "\u{1439}\u{B7}"),
+    (0x144A, "'"),
+    (0x144C, "U"),
+    (0x144E, "\u{548}"),
+    (0x1454, "\u{B7}\u{1450}"),
+    (0x1457, "\u{B7}U"),
+    (0x1458, "U\u{B7}"),
+    (0x1459, "\u{B7}\u{548}"),
+    (0x145A, "\u{548}\u{B7}"),
+    (0x145B, "\u{B7}\u{144F}"),
+    (0x145C, "\u{144F}\u{B7}"),
+    (0x145D, "\u{B7}\u{1450}"),
+    (0x145E, "\u{1450}\u{B7}"),
+    (0x145F, "\u{B7}\u{1451}"),
+    (0x1460, "\u{1451}\u{B7}"),
+    (0x1461, "\u{B7}\u{1455}"),
+    (0x1462, "\u{1455}\u{B7}"),
+    (0x1463, "\u{B7}\u{1456}"),
+    (0x1464, "\u{1456}\u{B7}"),
+    (0x1467, "U'"),
+    (0x1468, "\u{548}'"),
+    (0x1469, "\u{1450}'"),
+    (0x146A, "\u{1455}'"),
+    (0x146D, "P"),
+    (0x146F, "d"),
+    (0x1472, "b"),
+    (0x1473, "b\u{307}"),
+    (0x1474, "\u{B7}\u{146B}"),
+    (0x1475, "\u{146B}\u{B7}"),
+    (0x1476, "\u{B7}P"),
+    (0x1477, "p\u{B7}"),
+    (0x1478, "\u{B7}\u{146E}"),
+    (0x1479, "\u{146E}\u{B7}"),
+    (0x147A, "\u{B7}d"),
+    (0x147B, "d\u{B7}"),
+    (0x147C, "\u{B7}\u{1470}"),
+    (0x147D, "\u{1470}\u{B7}"),
+    (0x147E, "\u{B7}b"),
+    (0x147F, "b\u{B7}"),
+    (0x1480, "\u{B7}b\u{307}"),
+    (0x1481, "b\u{307}\u{B7}"),
+    (0x1485, "\u{146B}'"),
+    (0x1486, "P'"),
+    (0x1487, "d'"),
+    (0x1488, "b'"),
+    (0x148D, "J"),
+    (0x1492, "\u{B7}\u{1489}"),
+    (0x1493, "\u{1489}\u{B7}"),
+    (0x1494, "\u{B7}\u{148B}"),
+    (0x1495, "\u{148B}\u{B7}"),
+    (0x1496, "\u{B7}\u{148C}"),
+    (0x1497, "\u{148C}\u{B7}"),
+    (0x1498, "\u{B7}J"),
+    (0x1499, "J\u{B7}"),
+    (0x149A, "\u{B7}\u{148E}"),
+    (0x149B, "\u{148E}\u{B7}"),
+    (0x149C, "\u{B7}\u{1490}"),
+    (0x149D, "\u{1490}\u{B7}"),
+    (0x149E, "\u{B7}\u{1491}"),
+    (0x149F, "\u{1491}\u{B7}"),
+    (0x14A5, "\u{393}"),
+    (0x14AA, "L"),
+    (0x14AC, "\u{B7}\u{14A3}"),
+    (0x14AD, "\u{14A3}\u{B7}"),
+    (0x14AE, "\u{B7}\u{393}"),
+    (0x14AF, "\u{393}\u{B7}"),
+    (0x14B0, "\u{B7}\u{14A6}"),
+    (0x14B1, "\u{14A6}\u{B7}"),
+    (0x14B2, "\u{B7}\u{14A7}"),
+    (0x14B3, "\u{14A7}\u{B7}"),
+    (0x14B4, "\u{B7}\u{14A8}"),
+    (0x14B5, "\u{14A8}\u{B7}"),
+    (0x14B6, "\u{B7}L"),
+    (0x14B7, "l\u{B7}"),
+    (0x14B8, "\u{B7}\u{14AB}"),
+    (0x14B9, "\u{14AB}\u{B7}"),
+    (0x14BF, "2"),
+    (0x14C9, "\u{B7}\u{14C0}"),
+    (0x14CA, "\u{14C0}\u{B7}"),
+    (0x14CB, "\u{B7}\u{14C7}"),
+    (0x14CC, "\u{14C7}\u{B7}"),
+    (0x14CD, "\u{B7}\u{14C8}"),
+    (0x14CE, "\u{14C8}\u{B7}"),
+    (0x14D1, "\u{1421}"),
+    (0x14DC, "\u{B7}\u{14D3}"),
+    (0x14DD, "\u{14D3}\u{B7}"),
+    (0x14DE, "\u{B7}\u{14D5}"),
+    (0x14DF, "\u{14D5}\u{B7}"),
+    (0x14E0, "\u{B7}\u{14D6}"),
+    (0x14E1, "\u{14D6}\u{B7}"),
+    (0x14E2, "\u{B7}\u{14D7}"),
+    (0x14E3, "\u{14D7}\u{B7}"),
+    (0x14E4, "\u{B7}\u{14D8}"),
+    (0x14E5, "\u{14D8}\u{B7}"),
+    (0x14E6, "\u{B7}\u{14DA}"),
+    (0x14E7, "\u{14DA}\u{B7}"),
+    (0x14E8, "\u{B7}\u{14DB}"),
+    (0x14E9, "\u{14DB}\u{B7}"),
+    (0x14F6, "\u{B7}\u{14ED}"),
+    (0x14F7, "\u{14ED}\u{B7}"),
+    (0x14F8, "\u{B7}\u{14EF}"),
+    (0x14F9, "\u{14EF}\u{B7}"),
+    (0x14FA, "\u{B7}\u{14F0}"),
+    (0x14FB, "\u{14F0}\u{B7}"),
+    (0x14FC, "\u{B7}\u{14F1}"),
+    (0x14FD, "\u{14F1}\u{B7}"),
+    (0x14FE, "\u{B7}\u{14F2}"),
+    (0x14FF, "\u{14F2}\u{B7}"),
+    (0x1500, "\u{B7}\u{14F4}"),
+    (0x1501, "\u{14F4}\u{B7}"),
+    (0x1502, "\u{B7}\u{14F5}"),
+    (0x1503, "\u{14F5}\u{B7}"),
+    (0x150C, "\u{150B}<"),
+    (0x150D, "\u{150B}\u{1455}"),
+    (0x150E, "\u{150B}b"),
+    (0x150F, "\u{150B}\u{1490}"),
+    (0x1517, "\u{B7}\u{1510}"),
+    (0x1518, "\u{1510}\u{B7}"),
+    (0x1519, "\u{B7}\u{1511}"),
+    (0x151A, "\u{1511}\u{B7}"),
+    (0x151B, "\u{B7}\u{1512}"),
+    (0x151C, "\u{1512}\u{B7}"),
+    (0x151D, "\u{B7}\u{1513}"),
+    (0x151E, "\u{1513}\u{B7}"),
+    (0x151F, "\u{B7}\u{1514}"),
+    (0x1520, "\u{1514}\u{B7}"),
+    (0x1521, "\u{B7}\u{1515}"),
+    (0x1522, "\u{1515}\u{B7}"),
+    (0x1523, "\u{B7}\u{1516}"),
+    (0x1524, "\u{1516}\u{B7}"),
+    (0x152F, "\u{B7}4"),
+    (0x1530, "4\u{B7}"),
+    (0x1531, "\u{B7}\u{1528}"),
+    (0x1532, "\u{1528}\u{B7}"),
+    (0x1533, "\u{B7}\u{1529}"),
+    (0x1534, "\u{1529}\u{B7}"),
+    (0x1535, "\u{B7}\u{152A}"),
+    (0x1536, "\u{152A}\u{B7}"),
+    (0x1537, "\u{B7}\u{152B}"),
+    (0x1538, "\u{152B}\u{B7}"),
+    (0x1539, "\u{B7}\u{152D}"),
+    (0x153A, "\u{152D}\u{B7}"),
+    (0x153B, "\u{B7}\u{152E}"),
+    (0x153C, "\u{152E}\u{B7}"),
+    (0x1540, "\u{1429}"),
+    (0x1541, "x"),
+    (0x154E, "\u{B7}\u{154C}"),
+    (0x154F, "\u{154C}\u{B7}"),
+    (0x155B, "\u{B7}\u{155A}"),
+    (0x155C, "\u{155A}\u{B7}"),
+    (0x1568, "\u{B7}\u{1567}"),
+    (0x1569, "\u{1567}\u{B7}"),
+    (0x1577, "\u{1E9F}"),
+    (0x157C, "H"),
+    (0x157D, "x"),
+    (0x157E, "\u{1550}\u{146C}"),
+    (0x157F, "\u{1550}P"),
+    (0x1580, "\u{1550}\u{146E}"),
+    (0x1581, "\u{1550}d"),
+    (0x1582, "\u{1550}\u{1470}"),
+    (0x1583, "\u{1550}b"),
+    (0x1584, "\u{1550}b\u{307}"),
+    (0x1585, "\u{1550}\u{1483}"),
+    (0x1587, "R"),
+    (0x158E, "\u{1595}\u{148A}"),
+    (0x158F, "\u{1595}\u{148B}"),
+    (0x1590, "\u{1595}\u{148C}"),
+    (0x1591, "\u{1595}J"),
+    (0x1592, "\u{1595}\u{148E}"),
+    (0x1593, "\u{1595}\u{1490}"),
+    (0x1594, "\u{1595}\u{1491}"),
+    (0x15AF, "b"),
+    (0x15B4, "F"),
+    (0x15B5, "\u{2132}"),
+    (0x15B7, "\u{A7FB}"),
+    (0x15C4, "\u{2C6F}"),
+    (0x15C5, "A"),
+    (0x15DE, "D"),
+    (0x15EA, "D"),
+    (0x15EF, "\u{460}"),
+    (0x15F0, "M"),
+    (0x15F7, "B"),
+    (0x1602, "\u{1490}"),
+    (0x1603, "\u{1489}"),
+    (0x1604, "\u{14D3}"),
+    (0x1607, "\u{14DA}"),
+    (0x1622, "\u{1543}"),
+    (0x1623, "\u{1546}"),
+    (0x1624, "\u{154A}"),
+    (0x162E, "\u{1B1}"),
+    (0x162F, "\u{3A9}"),
+    (0x1634, "\u{1B1}"),
+    (0x1635, "\u{3A9}"),
+    (0x166D, "X"),
+    (0x166E, "x"),
+    (0x166F, "\u{1550}\u{146B}"),
+    (0x1670, "\u{1595}\u{1489}"),
+    (0x1671, "\u{1596}\u{148B}"),
+    (0x1672, "\u{1596}\u{148C}"),
+    (0x1673, "\u{1596}J"),
+    (0x1674, "\u{1596}\u{148E}"),
+    (0x1675, "\u{1596}\u{1490}"),
+    (0x1676, "\u{1596}\u{1491}"),
+    (0x1677, "\u{15A7}\u{B7}"),
+    (0x1678, "\u{15A8}\u{B7}"),
+    (0x1679, "\u{15A9}\u{B7}"),
+    (0x167A, "\u{15AA}\u{B7}"),
+    (0x167B, "\u{15AB}\u{B7}"),
+    (0x167C, "\u{15AC}\u{B7}"),
+    (0x167D, "\u{15AD}\u{B7}"),
+    (0x1680, " "),
+    (0x16B2, "<"),
+    (0x16B7, "X"),
+    (0x16C1, "l"),
+    (0x16C2, "\u{16BD}"),
+    (0x16CC, "'"),
+    (0x16D5, "K"),
+    (0x16D6, "M"),
+    (0x16D8, "\u{3A8}"),
+    (0x16E1, "\u{16BC}"),
+    (0x16EB, "\u{B7}"),
+    (0x16EC, ":"),
+    (0x16ED, "+"),
+    (0x16F0, "\u{3A6}"),
+    (0x1734, "\u{1715}"),
+    (0x1735, "/"),
+    (0x17A3, "\u{17A2}"),
+    (0x17B7, "\u{E34}"),
+    (0x17B8, "\u{E35}"),
+    (0x17B9, "\u{E36}"),
+    (0x17BA, "\u{E37}"),
+    (0x17C6, "\u{30A}"),
+    (0x17CB, "\u{E48}"),
+    (0x17D3, "\u{30A}"),
+    (0x17D4, "\u{E2F}"),
+    (0x17D5, "\u{E5A}"),
+    (0x17D9, "\u{E4F}"),
+    (0x17DA, "\u{E5B}"),
+    (0x1803, ":"),
+    (0x1809, ":"),
+    (0x1855, "\u{1835}"),
+    (0x1896, "\u{185C}"),
+    (0x18B3, "\u{B7}\u{18B1}"),
+    (0x18B6, "\u{B7}\u{18B4}"),
+    (0x18B9, "\u{B7}\u{18B8}"),
+    (0x18C2, "\u{B7}\u{18C0}"),
+    (0x18C6, "\u{B7}\u{14C2}"),
+    (0x18C7, "\u{14C2}\u{B7}"),
+    (0x18C8, "\u{B7}\u{14C3}"),
+    (0x18C9, "\u{14C3}\u{B7}"),
+    (0x18CA, "\u{B7}\u{14C4}"),
+    (0x18CB, "\u{14C4}\u{B7}"),
+    (0x18CC, "\u{B7}\u{14C5}"),
+    (0x18CD, "\u{14C5}\u{B7}"),
+    (0x18CE, "\u{B7}\u{1543}"),
+    (0x18CF, "\u{B7}\u{1546}"),
+    (0x18D0, "\u{B7}\u{1547}"),
+    (0x18D1, "\u{B7}\u{1548}"),
+    (0x18D2, "\u{B7}\u{1549}"),
+    (0x18D3, "\u{B7}\u{154B}"),
+    (0x18DB, "\u{18F5}"),
+    (0x18DC, "\u{18DF}\u{141E}"),
+    (0x18DD, "\u{141E}\u{18DF}"),
+    (0x18E0, "\u{1543}\u{B7}"),
+    (0x18E3, "\u{155E}\u{B7}"),
+    (0x18E4, "\u{1566}\u{B7}"),
+    (0x18E5, "\u{156B}\u{B7}"),
+    (0x18E8, "\u{1586}\u{B7}"),
+    (0x18EA, "\u{1597}\u{B7}"),
+    (0x18ED, "\u{460}\u{B7}"),
+    (0x18F0, "\u{15F4}\u{B7}"),
+    (0x18F2, "\u{161B}\u{B7}"),
+    (0x19D0, "\u{199E}"),
+    (0x19D1, "\u{19B1}"),
+    (0x1A80, "\u{1A45}"),
+    (0x1A90, "\u{1A45}"),
+    (0x1AA9, "\u{1AA8}\u{1AA8}"),
+    (0x1AAB, "\u{1AAA}\u{1AA8}"),
+    (0x1AB4, "\u{6DB}"),
+    (0x1AB7, "\u{328}"),
+    (0x1B52, "\u{1B0D}"),
+    (0x1B53, "\u{1B11}"),
+    (0x1B58, "\u{1B28}"),
+    (0x1B5C, "\u{1B50}"),
+    (0x1B5F, "\u{1B5E}\u{1B5E}"),
+    (0x1C3C, "\u{1C3B}\u{1C3B}"),
+    (0x1C7F, "\u{1C7E}\u{1C7E}"),
+    (0x1CD0, "\u{302}"),
+    (0x1CD2, "\u{304}"),
+    (0x1CD3, "''"),
+    (0x1CD5, "\u{32B}"),
+    (0x1CD8, "\u{32E}"),
+    (0x1CD9, "\u{32D}"),
+    (0x1CDA, "\u{30E}"),
+    (0x1CDC, "\u{329}"),
+    (0x1CDD, "\u{323}"),
+    (0x1CDE, "\u{324}"),
+    (0x1CED, "\u{316}"),
+    (0x1D04, "c"),
+    (0x1D08, "\u{25C}"),
+    (0x1D0B, "\u{138}"),
+    (0x1D0D, "\u{28D}"),
+    (0x1D0F, "o"),
+    (0x1D10, "\u{254}"),
+    (0x1D11, "o"),
+    (0x1D14, "\u{1DD}o"),
+    (0x1D1C, "u"),
+    (0x1D20, "v"),
+    (0x1D21, "w"),
+    (0x1D22, "z"),
+    (0x1D24, "\u{1A8}"),
+    (0x1D26, "r"),
+    (0x1D27, "\u{28C}"),
+    (0x1D28, "\u{3C0}"),
+    (0x1D29, "\u{1D18}"),
+    (0x1D2B, "\u{43B}"),
+    (0x1D3E, "\u{18D6}"),
+    (0x1D52, "\u{BA}"),
+    (0x1D6B, "ue"),
+    (0x1D6E, "f\u{334}"),
+    (0x1D6F, "rn\u{334}"),
+    (0x1D70, "n\u{334}"),
+    (0x1D72, "r\u{334}"),
+    (0x1D73, "\u{27E}\u{334}"),
+    (0x1D74, "s\u{334}"),
+    (0x1D75, "t\u{334}"),
+    (0x1D76, "z\u{334}"),
+    (0x1D78, "\u{1D34}"),
+    (0x1D7B, "i\u{335}"),
+    (0x1D7C, "i\u{335}"),
+    (0x1D7D, "p\u{335}"),
+    (0x1D7E, "u\u{335}"),
+    (0x1D7F, "\u{28A}\u{335}"),
+    (0x1D83, "g"),
+    (0x1D8C, "y"),
+    (0x1D90, "\u{24B}"),
+    (0x1D9F, "\u{1D4B}"),
+    (0x1DA2, "\u{1D4D}"),
+    (0x1DBA, "\u{18D4}"),
+    (0x1DBB, "\u{1646}"),
+    (0x1DEE, "\u{2DEC}"),
+    (0x1E43, "\u{AB51}"),
+    (0x1E9A, "\u{1EA3}"),
+    (0x1E9D, "f"),
+    (0x1E9E, "\u{DF}"),
+    (0x1EFF, "y"),
+    (0x1F7D, "\u{1FF4}"),
+    (0x1FBD, "'"),
+    (0x1FBE, "i"),
+    (0x1FBF, "'"),
+    (0x1FC0, "~"),
+    (0x1FEF, "'"),
+    (0x1FF6, "\u{13EF}"),
+    (0x1FFD, "'"),
+    (0x1FFE, "'"),
+    (0x2000, " "),
+    (0x2001, " "),
+    (0x2002, " "),
+    (0x2003, " "),
+    (0x2004, " "),
+    (0x2005, " "),
+    (0x2006, " "),
+    (0x2007, " "),
+    (0x2008, " "),
+    (0x2009, " "),
+    (0x200A, " "),
+    (0x2010, "-"),
+    (0x2011, "-"),
+    (0x2012, "-"),
+    (0x2013, "-"),
+    (0x2014, "\u{30FC}"),
+    (0x2015, "\u{30FC}"),
+    (0x2016, "ll"),
+    (0x2018, "'"),
+    (0x2019, "'"),
+    (0x201A, ","),
+    (0x201B, "'"),
+    (0x201C, "''"),
+    (0x201D, "''"),
+    (0x201F, "''"),
+    (0x2022, "\u{B7}"),
+    (0x2024, "."),
+    (0x2025, ".."),
+    (0x2026, "..."),
+    (0x2027, "\u{B7}"),
+    (0x2028, " "),
+    (0x2029, " "),
+    (0x202F, " "),
+    (0x2030, "\u{BA}/\u{2080}\u{2080}"),
+    (0x2031, "\u{BA}/\u{2080}\u{2080}\u{2080}"),
+    (0x2032, "'"),
+    (0x2033, "''"),
+    (0x2034, "'''"),
+    (0x2035, "'"),
+    (0x2036, "''"),
+    (0x2037, "'''"),
+    (0x2039, "<"),
+    (0x203A, ">"),
+    (0x203C, "!!"),
+    (0x203E, "\u{2C9}"),
+    (0x2041, "/"),
+    (0x2043, "-"),
+    (0x2044, "/"),
+    (0x2047, "??"),
+    (0x2048, "?!"),
+    (0x2049, "!?"),
+    (0x204E, "*"),
+    (0x2052, "\u{BA}/\u{2080}"),
+    (0x2053, "~"),
+    (0x2057, "''''"),
+    (0x205A, ":"),
+    (0x205D, "\u{2D57}"),
+    (0x205E, "\u{2D42}"),
+    (0x205F, " "),
+    (0x2070, "\u{BA}"),
+    (0x2079, "\u{A770}"),
+    (0x20A1, "C\u{20EB}"),
+    (0x20A4, "\u{A3}"),
+    (0x20A5, "rn\u{338}"),
+    (0x20A8, "Rs"),
+    (0x20A9, "W\u{335}"),
+    (0x20AB, "d\u{335}\u{331}"),
+    (0x20AC, "\u{A792}"),
+    (0x20AD, "K\u{335}"),
+    (0x20AE, "T\u{20EB}"),
+    (0x20B6, "lt"),
+    (0x20BD, "\u{554}"),
+    (0x20DB, "\u{6DB}"),
+    (0x2100, "a/c"),
+    (0x2101, "a/s"),
+    (0x2102, "C"),
+    (0x2103, "\u{B0}C"),
+    (0x2105, "c/o"),
+    (0x2106, "c/u"),
+    (0x2107, "\u{190}"),
+    (0x2108, "\u{42D}"),
+    (0x2109, "\u{B0}F"),
+    (0x210A, "g"),
+    (0x210B, "H"),
+    (0x210C, "H"),
+    (0x210D, "H"),
+    (0x210E, "h"),
+    (0x210F, "h\u{335}"),
+    (0x2110, "l"),
+    (0x2111, "l"),
+    (0x2112, "L"),
+    (0x2113, "l"),
+    (0x2115, "N"),
+    (0x2116, "No"),
+    (0x2119, "P"),
+    (0x211A, "Q"),
+    (0x211B, "R"),
+    (0x211C, "R"),
+    (0x211D, "R"),
+    (0x2121, "TEL"),
+    (0x2124, "Z"),
+    (0x2126, "\u{3A9}"),
+    (0x2127, "\u{1B1}"),
+    (0x2128, "Z"),
+    (0x2129, "\u{27F}"),
+    (0x212A, "K"),
+    (0x212C, "B"),
+    (0x212D, "C"),
+    (0x212E, "e"),
+    (0x212F, "e"),
+    (0x2130, "E"),
+    (0x2131, "F"),
+    (0x2133, "M"),
+    (0x2134, "o"),
+    (0x2135, "\u{5D0}"),
+    (0x2136, "\u{5D1}"),
+    (0x2137, "\u{5D2}"),
+    (0x2138, "\u{5D3}"),
+    (0x2139, "i"),
+    (0x213B, "FAX"),
+    (0x213C, "\u{3C0}"),
+    (0x213D, "y"),
+    (0x213E, "\u{393}"),
+    (0x213F, "\u{3A0}"),
+    (0x2140, "\u{1A9}"),
+    (0x2141, "\u{A4E8}"),
+    (0x2142, "\u{A4F6}"),
+    (0x2143, "\u{16F00}"),
+    (0x2145, "D"),
+    (0x2146, "d"),
+    (0x2147, "e"),
+    (0x2148, "i"),
+    (0x2149, "j"),
+    (0x2160, "l"),
+    (0x2161, "ll"),
+    (0x2162, "lll"),
+    (0x2163, "lV"),
+    (0x2164, "V"),
+    (0x2165, "Vl"),
+    (0x2166, "Vll"),
+    (0x2167, "Vlll"),
+    (0x2168, "lX"),
+    (0x2169, "X"),
+    (0x216A, "Xl"),
+    (0x216B, "Xll"),
+    (0x216C, "L"),
+    (0x216D, "C"),
+    (0x216E, "D"),
+    (0x216F, "M"),
+    (0x2170, "i"),
+    (0x2171, "ii"),
+    (0x2172, "iii"),
+    (0x2173, "iv"),
+    (0x2174, "v"),
+    (0x2175, "vi"),
+    (0x2176, "vii"),
+    (0x2177, "viii"),
+    (0x2178, "ix"),
+    (0x2179, "x"),
+    (0x217A, "xi"),
+    (0x217B, "xii"),
+    (0x217C, "l"),
+    (0x217D, "c"),
+    (0x217E, "d"),
+    (0x217F, "rn"),
+    (0x2183, "\u{186}"),
+    (0x2184, "\u{254}"),
+    (0x2191, "\u{16CF}"),
+    (0x2195, "\u{16E8}"),
+    (0x21B5, "\u{21B2}"),
+    (0x21BA, "\u{1F10E}"),
+    (0x21BE, "\u{16DA}"),
+    (0x21BF, "\u{16D0}"),
+    (0x2200, "\u{2C6F}"),
+    (0x2203, "\u{18E}"),
+    (0x2206, "\u{394}"),
+    (0x220F, "\u{3A0}"),
+    (0x2211, "\u{1A9}"),
+    (0x2212, "-"),
+    (0x2214, "+\u{307}"),
+    (0x2215, "/"),
+    (0x2216, "\\"),
+    (0x2217, "*"),
+    (0x2218, "\u{B0}"),
+    (0x2219, "\u{B7}"),
+    (0x221E, "oo"),
+    (0x2223, "l"),
+    (0x2225, "ll"),
+    (0x2228, "v"),
+    (0x2229, "\u{548}"),
+    (0x222A, "U"),
+    (0x222B, "\u{283}"),
+    (0x222C, "\u{283}\u{283}"),
+    (0x222D, "\u{283}\u{283}\u{283}"),
+    (0x222F, "\u{222E}\u{222E}"),
+    (0x2230, "\u{222E}\u{222E}\u{222E}"),
+    (0x2236, ":"),
+    (0x2238, "-\u{307}"),
+    (0x223C, "~"),
+    (0x2250, "=\u{307}"),
+    (0x2251, "=\u{307}\u{323}"),
+    (0x2257, "=\u{30A}"),
+    (0x2259, "=\u{302}"),
+    (0x225A, "=\u{306}"),
+    (0x225E, "=\u{36B}"),
+    (0x2263, "\u{2261}"),
+    (0x226A, "<<"),
+    (0x226B, ">>"),
+    (0x2282, "\u{1455}"),
+    (0x2283, "\u{1450}"),
+    (0x2295, "\u{102A8}"),
+    (0x2296, "O\u{335}"),
+    (0x2299, "\u{298}"),
+    (0x229D, "O\u{335}"),
+    (0x22A4, "T"),
+    (0x22A5, "\u{A4D5}"),
+    (0x22C0, "\u{2227}"),
+    (0x22C1, "v"),
+    (0x22C2, "\u{548}"),
+    (0x22C3, "U"),
+    (0x22C4, "\u{16DC}"),
+    (0x22C5, "\u{B7}"),
+    (0x22C8, "\u{16DE}"),
+    (0x22D6, "<\u{B7}"),
+    (0x22D7, "\u{B7}>"),
+    (0x22D8, "<<<"),
+    (0x22D9, ">>>"),
+    (0x22EE, "\u{2D57}"),
+    (0x22EF, "\u{B7}\u{B7}\u{B7}"),
+    (0x22F4, "\u{A793}"),
+    (0x22FF, "E"),
+    (0x2300, "\u{2205}"),
+    (0x2325, "\u{2324}"),
+    (0x2329, "\u{276C}"),
+    (0x232A, "\u{276D}"),
+    (0x2341, "\u{303C}"),
+    (0x2359, "\u{394}\u{332}"),
+    (0x235A, "\u{16DC}\u{332}"),
+    (0x235C, "\u{B0}\u{332}"),
+    (0x235F, "\u{229B}"),
+    (0x2361, "T\u{308}"),
+    (0x2362, "\u{2207}\u{308}"),
+    (0x2363, "\u{22C6}\u{308}"),
+    (0x2364, "\u{B0}\u{308}"),
+    (0x2365, "\u{629}"),
+    (0x2368, "~\u{308}"),
+    (0x2369, "\u{1435}"),
+    (0x236B, "\u{2207}\u{334}"),
+    (0x236C, "O\u{335}"),
+    (0x2373, "i"),
+    (0x2374, "p"),
+    (0x2375, "\u{3C9}"),
+    (0x2376, "a\u{332}"),
+    (0x2377, "\u{A793}\u{332}"),
+    (0x2378, "i\u{332}"),
+    (0x2379, "\u{3C9}\u{332}"),
+    (0x237A, "a"),
+    (0x237F, "\u{16BD}"),
+    (0x239C, "\u{4E28}"),
+    (0x239F, "\u{4E28}"),
+    (0x23A2, "\u{4E28}"),
+    (0x23A5, "\u{4E28}"),
+    (0x23AA, "\u{4E28}"),
+    (0x23AE, "\u{4E28}"),
+    (0x23C1, "\u{2355}"),
+    (0x23C2, "\u{234E}"),
+    (0x23C3, "\u{234B}"),
+    (0x23C6, "\u{236D}"),
+    (0x23E8, "\u{2081}\u{2080}"),
+    (0x23FC, "\u{23FB}"),
+    (0x23FD, "l"),
+    (0x23FE, "\u{263E}"),
+    (0x244A, "\\\\"),
+    (0x2460, "\u{2780}"),
+    (0x2461, "\u{2781}"),
+    (0x2462, "\u{2782}"),
+    (0x2463, "\u{2783}"),
+    (0x2464, "\u{2784}"),
+    (0x2465, "\u{2785}"),
+    (0x2466, "\u{2786}"),
+    (0x2467, "\u{2787}"),
+    (0x2468, "\u{2788}"),
+    (0x2469, "\u{2789}"),
+    (0x2474, "(l)"),
+    (0x2475, "(2)"),
+    (0x2476, "(3)"),
+    (0x2477, "(4)"),
+    (0x2478, "(5)"),
+    (0x2479, "(6)"),
+    (0x247A, "(7)"),
+    (0x247B, "(8)"),
+    (0x247C, "(9)"),
+    (0x247D, "(lO)"),
+    (0x247E, "(ll)"),
+    (0x247F, "(l2)"),
+    (0x2480, "(l3)"),
+    (0x2481, "(l4)"),
+    (0x2482, "(l5)"),
+    (0x2483, "(l6)"),
+    (0x2484, "(l7)"),
+    (0x2485, "(l8)"),
+    (0x2486, "(l9)"),
+    (0x2487, "(2O)"),
+    (0x2488, "l."),
+    (0x2489, "2."),
+    (0x248A, "3."),
+    (0x248B, "4."),
+    (0x248C, "5."),
+    (0x248D, "6."),
+    (0x248E, "7."),
+    (0x248F, "8."),
+    (0x2490, "9."),
+    (0x2491, "lO."),
+    (0x2492, "ll."),
+    (0x2493, "l2."),
+    (0x2494, "l3."),
+    (0x2495, "l4."),
+    (0x2496, "l5."),
+    (0x2497, "l6."),
+    (0x2498, "l7."),
+    (0x2499, "l8."),
+    (0x249A, "l9."),
+    (0x249B, "2O."),
+    (0x249C, "(a)"),
+    (0x249D, "(b)"),
+    (0x249E, "(c)"),
+    (0x249F, "(d)"),
+    (0x24A0, "(e)"),
+    (0x24A1, "(f)"),
+    (0x24A2, "(g)"),
+    (0x24A3, "(h)"),
+    (0x24A4, "(i)"),
+    (0x24A5, "(j)"),
+    (0x24A6, "(k)"),
+    (0x24A7, "(l)"),
+    (0x24A8, "(rn)"),
+    (0x24A9, "(n)"),
+    (0x24AA, "(o)"),
+    (0x24AB, "(p)"),
+    (0x24AC, "(q)"),
+    (0x24AD, "(r)"),
+    (0x24AE, "(s)"),
+    (0x24AF, "(t)"),
+    (0x24B0, "(u)"),
+    (0x24B1, "(v)"),
+    (0x24B2, "(w)"),
+    (0x24B3, "(x)"),
+    (0x24B4, "(y)"),
+    (0x24B5, "(z)"),
+    (0x24B8, "\u{A9}"),
+    (0x24C5, "\u{2117}"),
+    (0x24C7, "\u{AE}"),
+    (0x24DB, "\u{24BE}"),
+    (0x24EA, "\u{1F10D}"),
+    (0x2500, "\u{30FC}"),
+    (0x2501, "\u{30FC}"),
+    (0x2503, "\u{2502}"),
+    (0x250F, "\u{250C}"),
+    (0x2523, "\u{251C}"),
+    (0x2571, "/"),
+    (0x2573, "X"),
+    (0x2588, "\u{220E}"),
+    (0x2590, "\u{258C}"),
+    (0x2594, "\u{2C9}"),
+    (0x2597, "\u{2596}"),
+    (0x259D, "\u{2598}"),
+    (0x25A0, "\u{220E}"),
+    (0x25B1, "\u{23E5}"),
+    (0x25B3, "\u{394}"),
+    (0x25B7, "\u{22B3}"),
+    (0x25B8, "\u{25B6}"),
+    (0x25BA, "\u{25B6}"),
+    (0x25BD, "\u{102BC}"),
+    (0x25C1, "\u{22B2}"),
+    (0x25C7, "\u{16DC}"),
+    (0x25CA, "\u{16DC}"),
+    (0x25CB, "\u{B0}"),
+    (0x25CE, "\u{233E}"),
+    (0x25E0, "\u{2312}"),
+    (0x25E6, "\u{B0}"),
+    (0x2609, "\u{298}"),
+    (0x2610, "\u{25A1}"),
+    (0x2625, "\u{1099E}"),
+    (0x2630, "\u{2CB6}"),
+    (0x2638, "\u{2388}"),
+    (0x264E, "\u{224F}"),
+    (0x2662, "\u{16DC}"),
+    (0x2669, "\u{1D158}\u{1D165}"),
+    (0x266A, "\u{1D158}\u{1D165}\u{1D16E}"),
+    (0x26AC, "\u{970}"),
+    (0x2768, "("),
+    (0x2769, ")"),
+    (0x276E, "<"),
+    (0x276F, ">"),
+    (0x2772, "("),
+    (0x2773, ")"),
+    (0x2774, "{"),
+    (0x2775, "}"),
+    (0x2795, "+"),
+    (0x2796, "-"),
+    (0x2797, "\u{F7}"),
+    (0x27C2, "\u{A4D5}"),
+    (0x27C8, "\\\u{1455}"),
+    (0x27C9, "\u{1450}/"),
+    (0x27CB, "/"),
+    (0x27CD, "\\"),
+    (0x27D9, "T"),
+    (0x27E8, "\u{276C}"),
+    (0x27E9, "\u{276D}"),
+    (0x292B, "x"),
+    (0x292C, "x"),
+    (0x2963, "\u{16D0}\u{16DA}"),
+    (0x2965, "\u{21C3}\u{21C2}"),
+    (0x296E, "\u{16D0}\u{21C2}"),
+    (0x296F, "\u{21C3}\u{16DA}"),
+    (0x2999, "\u{2D42}"),
+    (0x29B0, "\u{2349}"),
+    (0x29BE, "\u{233E}"),
+    (0x29C4, "\u{303C}"),
+    (0x29C5, "\u{2342}"),
+    (0x29C7, "\u{233B}"),
+    (0x29D6, "\u{102C0}"),
+    (0x29D9, "\u{299A}"),
+    (0x29F4, ":\u{2192}"),
+    (0x29F5, "\\"),
+    (0x29F6, "/\u{304}"),
+    (0x29F8, "/"),
+    (0x29F9, "\\"),
+    (0x2A00, "\u{298}"),
+    (0x2A01, "\u{102A8}"),
+    (0x2A02, "\u{2297}"),
+    (0x2A03, "\u{228D}"),
+    (0x2A04, "\u{228E}"),
+    (0x2A05, "\u{2293}"),
+    (0x2A06, "\u{2294}"),
+    (0x2A0C, "\u{283}\u{283}\u{283}\u{283}"),
+    (0x2A1D, "\u{16DE}"),
+    (0x2A20, ">>"),
+    (0x2A21, "\u{16DA}"),
+    (0x2A22, "+\u{30A}"),
+    (0x2A23, "+\u{302}"),
+    (0x2A24, "+\u{303}"),
+    (0x2A25, "+\u{323}"),
+    (0x2A26, "+\u{330}"),
+    (0x2A27, "+\u{2082}"),
+    (0x2A29, "-\u{313}"),
+    (0x2A2A, "-\u{323}"),
+    (0x2A2F, "x"),
+    (0x2A30, "x\u{307}"),
+    (0x2A3D, "\u{2319}"),
+    (0x2A3E, "\u{2A1F}"),
+    (0x2A3F, "\u{2210}"),
+    (0x2A6A, "~\u{307}"),
+    (0x2A6E, "=\u{20F0}"),
+    (0x2A74, "::="),
+    (0x2A75, "=="),
+    (0x2A76, "==="),
+    (0x2AA5, "><"),
+    (0x2AAA, "\u{15D5}"),
+    (0x2AAB, "\u{15D2}"),
+    (0x2AD7, "\u{1450}\u{1455}"),
+    (0x2AFB, "///"),
+    (0x2AFD, "//"),
+    (0x2BEC, "\u{219E}"),
+    (0x2BED, "\u{219F}"),
+    (0x2BEE, "\u{21A0}"),
+    (0x2BEF, "\u{21A1}"),
+    (0x2C67, "H\u{329}"),
+    (0x2C69, "K\u{329}"),
+    (0x2C84, "\u{393}"),
+    (0x2C85, "r"),
+    (0x2C86, "\u{394}"),
+    (0x2C88, "\u{A792}"),
+    (0x2C89, "\u{A793}"),
+    (0x2C8E, "H"),
+    (0x2C92, "l"),
+    (0x2C94, "K"),
+    (0x2C95, "\u{138}"),
+    (0x2C96, "\u{3BB}"),
+    (0x2C98, "M"),
+    (0x2C9A, "N"),
+    (0x2C9E, "O"),
+    (0x2C9F, "o"),
+    (0x2CA0, "\u{3A0}"),
+    (0x2CA2, "P"),
+    (0x2CA3, "p"),
+    (0x2CA4, "C"),
+    (0x2CA5, "c"),
+    (0x2CA6, "T"),
+    (0x2CA8, "Y"),
+    (0x2CAA, "\u{3A6}"),
+    (0x2CAB, "\u{278}"),
+    (0x2CAC, "X"),
+    (0x2CAD, "\u{3C7}"),
+    (0x2CAE, "\u{3A8}"),
+    (0x2CB1, "\u{3C9}"),
+    (0x2CB4, "<\u{B7}"),
+    (0x2CBA, "-"),
+    (0x2CBC, "\u{428}"),
+    (0x2CBD, "\u{448}"),
+    (0x2CC6, "/"),
+    (0x2CCA, "9"),
+    (0x2CCC, "3"),
+    (0x2CCD, "\u{21D}"),
+    (0x2CD0, "L"),
+    (0x2CD1, "\u{29F}"),
+    (0x2CD2, "6"),
+    (0x2CDC, "\u{3EC}"),
+    (0x2CE4, "\u{3D7}"),
+    (0x2CE9, "\u{2627}"),
+    (0x2CF9, "\\\\"),
+    (0x2D31, "O\u{335}"),
+    (0x2D37, "\u{245}"),
+    (0x2D38, "V"),
+    (0x2D39, "E"),
+    (0x2D3A, "\u{18E}"),
+    (0x2D41, "O\u{338}"),
+    (0x2D48, "\u{B7}\u{B7}\u{B7}"),
+    (0x2D49, "\u{1A9}"),
+    (0x2D4F, "l"),
+    (0x2D51, "!"),
+    (0x2D54, "O"),
+    (0x2D55, "Q"),
+    (0x2D59, "\u{298}"),
+    (0x2D5D, "X"),
+    (0x2D60, "\u{394}"),
+    (0x2D63, "\u{16EF}"),
+    (0x2DE8, "\u{1DDF}"),
+    (0x2DEA, "\u{30A}"),
+    (0x2DED, "\u{368}"),
+    (0x2DEF, "\u{36F}"),
+    (0x2DF6, "\u{363}"),
+    (0x2DF7, "\u{364}"),
+    (0x2E1A, "-\u{308}"),
+    (0x2E1E, "~\u{307}"),
+    (0x2E1F, "~\u{323}"),
+    (0x2E26, "\u{1455}"),
+    (0x2E27, "\u{1450}"),
+    (0x2E28, "(("),
+    (0x2E29, "))"),
+    (0x2E2A, "\u{2235}"),
+    (0x2E2B, "\u{2234}"),
+    (0x2E2C, "\u{2237}"),
+    (0x2E2E, "\u{61F}"),
+    (0x2E30, "\u{B0}"),
+    (0x2E31, "\u{B7}"),
+    (0x2E32, "\u{60C}"),
+    (0x2E35, "\u{61B}"),
+    (0x2E39, "\u{1E9F}"),
+    (0x2E3D, "\u{2D42}"),
+    (0x2E3F, "\u{B6}"),
+    (0x2E40, "="),
+    (0x2E82, "\u{4E5B}"),
+    (0x2E83, "\u{4E5A}"),
+    (0x2E85, "\u{4EBB}"),
+    (0x2E89, "\u{5202}"),
+    (0x2E8B, "\u{353E}"),
+    (0x2E8E, "\u{5140}"),
+    (0x2E8F, "\u{5C23}"),
+    (0x2E90, "\u{5C22}"),
+    (0x2E92, "\u{5DF3}"),
+    (0x2E93, "\u{5E7A}"),
+    (0x2E94, "\u{5F51}"),
+    (0x2E96, "\u{5FC4}"),
+    (0x2E97, "\u{38FA}"),
+    (0x2E98, "\u{624C}"),
+    (0x2E99, "\u{6535}"),
+    (0x2E9B, "\u{65E1}"),
+    (0x2E9E, "\u{6B7A}"),
+    (0x2E9F, "\u{6BCD}"),
+    (0x2EA0, "\u{6C11}"),
+    (0x2EA1, "\u{6C35}"),
+    (0x2EA2, "\u{6C3A}"),
+    (0x2EA3, "\u{706C}"),
+    (0x2EA4, "\u{722B}"),
+    (0x2EA6, "\u{4E2C}"),
+    (0x2EA8, "\u{72AD}"),
+    (0x2EAB, "\u{7F52}"),
+    (0x2EAD, "\u{793B}"),
+    (0x2EAF, "\u{7CF9}"),
+    (0x2EB1, "\u{7F53}"),
+    (0x2EB2, "\u{7F52}"),
+    (0x2EB9, "\u{8002}"),
+    (0x2EBA, "\u{8080}"),
+    (0x2EBE, "\u{8279}"),
+    (0x2EBF, "\u{8279}"),
+    (0x2EC0, "\u{8279}"),
+    (0x2EC1, "\u{864E}"),
+    (0x2EC2, "\u{8864}"),
+    (0x2EC3, "\u{8980}"),
+    (0x2EC4, "\u{897F}"),
+    (0x2EC5, "\u{89C1}"),
+    (0x2EC8, "\u{8BA0}"),
+    (0x2EC9, "\u{8D1D}"),
+    (0x2ECB, "\u{8F66}"),
+    (0x2ECC, "\u{8FB6}"),
+    (0x2ECD, "\u{8FB6}"),
+    (0x2ECF, "\u{961D}"),
+    (0x2ED0, "\u{9485}"),
+    (0x2ED1, "\u{9577}"),
+    (0x2ED2, "\u{9578}"),
+    (0x2ED3, "\u{957F}"),
+    (0x2ED4, "\u{95E8}"),
+    (0x2ED6, "\u{961D}"),
+    (0x2ED8, "\u{9752}"),
+    (0x2ED9, "\u{97E6}"),
+    (0x2EDA, "\u{9875}"),
+    (0x2EDB, "\u{98CE}"),
+    (0x2EDC, "\u{98DE}"),
+    (0x2EDD, "\u{98DF}"),
+    (0x2EDF, "\u{98E0}"),
+    (0x2EE0, "\u{9963}"),
+    (0x2EE2, "\u{9A6C}"),
+    (0x2EE4, "\u{9B3C}"),
+    (0x2EE5, "\u{9C7C}"),
+    (0x2EE8, "\u{9EA6}"),
+    (0x2EE9, "\u{9EC4}"),
+    (0x2EEB, "\u{6589}"),
+    (0x2EEC, "\u{9F50}"),
+    (0x2EED, "\u{6B6F}"),
+    (0x2EEE, "\u{9F7F}"),
+    (0x2EEF, "\u{7ADC}"),
+    (0x2EF0, "\u{9F99}"),
+    (0x2EF2, "\u{4E80}"),
+    (0x2EF3, "\u{9F9F}"),
+    (0x2F00, "\u{30FC}"),
+    (0x2F01, "\u{4E28}"),
+    (0x2F02, "\\"),
+    (0x2F03, "/"),
+    (0x2F04, "\u{4E59}"),
+    (0x2F05, "\u{4E85}"),
+    (0x2F06, "\u{4E8C}"),
+    (0x2F07, "\u{4EA0}"),
+    (0x2F08, "\u{4EBA}"),
+    (0x2F09, "\u{513F}"),
+    (0x2F0A, "\u{5165}"),
+    (0x2F0B, "\u{516B}"),
+    (0x2F0C, "\u{5182}"),
+    (0x2F0D, "\u{5196}"),
+    (0x2F0E, "\u{51AB}"),
+    (0x2F0F, "\u{51E0}"),
+    (0x2F10, "\u{51F5}"),
+    (0x2F11, "\u{5200}"),
+    (0x2F12, "\u{529B}"),
+    (0x2F13, "\u{52F9}"),
+    (0x2F14, "\u{5315}"),
+    (0x2F15, "\u{531A}"),
+    (0x2F16, "\u{5338}"),
+    (0x2F17, "\u{5341}"),
+    (0x2F18, "\u{535C}"),
+    (0x2F19, "\u{5369}"),
+    (0x2F1A, "\u{5382}"),
+    (0x2F1B, "\u{53B6}"),
+    (0x2F1C, "\u{53C8}"),
+    (0x2F1D, "\u{53E3}"),
+    (0x2F1E, "\u{53E3}"),
+    (0x2F1F, "\u{571F}"),
+    (0x2F20, "\u{571F}"),
+    (0x2F21, "\u{5902}"),
+    (0x2F22, "\u{590A}"),
+    (0x2F23, "\u{5915}"),
+    (0x2F24, "\u{5927}"),
+    (0x2F25, "\u{5973}"),
+    (0x2F26, "\u{5B50}"),
+    (0x2F27, "\u{5B80}"),
+    (0x2F28, "\u{5BF8}"),
+    (0x2F29, "\u{5C0F}"),
+    (0x2F2A, "\u{5C22}"),
+    (0x2F2B, "\u{5C38}"),
+    (0x2F2C, "\u{5C6E}"),
+    (0x2F2D, "\u{5C71}"),
+    (0x2F2E, "\u{5DDB}"),
+    (0x2F2F, "\u{5DE5}"),
+    (0x2F30, "\u{5DF1}"),
+    (0x2F31, "\u{5DFE}"),
+    (0x2F32, "\u{5E72}"),
+    (0x2F33, "\u{5E7A}"),
+    (0x2F34, "\u{5E7F}"),
+    (0x2F35, "\u{5EF4}"),
+    (0x2F36, "\u{5EFE}"),
+    (0x2F37, "\u{5F0B}"),
+    (0x2F38, "\u{5F13}"),
+    (0x2F39, "\u{5F50}"),
+    (0x2F3A, "\u{5F61}"),
+    (0x2F3B, "\u{5F73}"),
+    (0x2F3C, "\u{5FC3}"),
+    (0x2F3D, "\u{6208}"),
+    (0x2F3E, "\u{6236}"),
+    (0x2F3F, "\u{624B}"),
+    (0x2F40, "\u{652F}"),
+    (0x2F41, "\u{6534}"),
+    (0x2F42, "\u{6587}"),
+    (0x2F43, "\u{6597}"),
+    (0x2F44, "\u{65A4}"),
+    (0x2F45, "\u{65B9}"),
+    (0x2F46, "\u{65E0}"),
+    (0x2F47, "\u{65E5}"),
+    (0x2F48, "\u{66F0}"),
+    (0x2F49, "\u{6708}"),
+    (0x2F4A, "\u{6728}"),
+    (0x2F4B, "\u{6B20}"),
+    (0x2F4C, "\u{6B62}"),
+    (0x2F4D, "\u{6B79}"),
+    (0x2F4E, "\u{6BB3}"),
+    (0x2F4F, "\u{6BCB}"),
+    (0x2F50, "\u{6BD4}"),
+    (0x2F51, "\u{6BDB}"),
+    (0x2F52, "\u{6C0F}"),
+    (0x2F53, "\u{6C14}"),
+    (0x2F54, "\u{6C34}"),
+    (0x2F55, "\u{706B}"),
+    (0x2F56, "\u{722A}"),
+    (0x2F57, "\u{7236}"),
+    (0x2F58, "\u{723B}"),
+    (0x2F59, "\u{723F}"),
+    (0x2F5A, "\u{7247}"),
+    (0x2F5B, "\u{7259}"),
+    (0x2F5C, "\u{725B}"),
+    (0x2F5D, "\u{72AC}"),
+    (0x2F5E, "\u{7384}"),
+    (0x2F5F, "\u{7389}"),
+    (0x2F60, "\u{74DC}"),
+    (0x2F61, "\u{74E6}"),
+    (0x2F62, "\u{7518}"),
+    (0x2F63, "\u{751F}"),
+    (0x2F64, "\u{7528}"),
+    (0x2F65, "\u{7530}"),
+    (0x2F66, "\u{758B}"),
+    (0x2F67, "\u{7592}"),
+    (0x2F68, "\u{7676}"),
+    (0x2F69, "\u{767D}"),
+    (0x2F6A, "\u{76AE}"),
+    (0x2F6B, "\u{76BF}"),
+    (0x2F6C, "\u{76EE}"),
+    (0x2F6D, "\u{77DB}"),
+    (0x2F6E, "\u{77E2}"),
+    (0x2F6F, "\u{77F3}"),
+    (0x2F70, "\u{793A}"),
+    (0x2F71, "\u{79B8}"),
+    (0x2F72, "\u{79BE}"),
+    (0x2F73, "\u{7A74}"),
+    (0x2F74, "\u{7ACB}"),
+    (0x2F75, "\u{7AF9}"),
+    (0x2F76, "\u{7C73}"),
+    (0x2F77, "\u{7CF8}"),
+    (0x2F78, "\u{7F36}"),
+    (0x2F79, "\u{7F51}"),
+    (0x2F7A, "\u{7F8A}"),
+    (0x2F7B, "\u{7FBD}"),
+    (0x2F7C, "\u{8001}"),
+    (0x2F7D, "\u{800C}"),
+    (0x2F7E, "\u{8012}"),
+    (0x2F7F, "\u{8033}"),
+    (0x2F80, "\u{807F}"),
+    (0x2F81, "\u{8089}"),
+    (0x2F82, "\u{81E3}"),
+    (0x2F83, "\u{81EA}"),
+    (0x2F84, "\u{81F3}"),
+    (0x2F85, "\u{81FC}"),
+    (0x2F86, "\u{820C}"),
+    (0x2F87, "\u{821B}"),
+    (0x2F88, "\u{821F}"),
+    (0x2F89, "\u{826E}"),
+    (0x2F8A, "\u{8272}"),
+    (0x2F8B, "\u{8278}"),
+    (0x2F8C, "\u{864D}"),
+    (0x2F8D, "\u{866B}"),
+    (0x2F8E, "\u{8840}"),
+    (0x2F8F, "\u{884C}"),
+    (0x2F90, "\u{8863}"),
+    (0x2F91, "\u{897E}"),
+    (0x2F92, "\u{898B}"),
+    (0x2F93, "\u{89D2}"),
+    (0x2F94, "\u{8A00}"),
+    (0x2F95, "\u{8C37}"),
+    (0x2F96, "\u{8C46}"),
+    (0x2F97, "\u{8C55}"),
+    (0x2F98, "\u{8C78}"),
+    (0x2F99, "\u{8C9D}"),
+    (0x2F9A, "\u{8D64}"),
+    (0x2F9B, "\u{8D70}"),
+    (0x2F9C, "\u{8DB3}"),
+    (0x2F9D, "\u{8EAB}"),
+    (0x2F9E, "\u{8ECA}"),
+    (0x2F9F, "\u{8F9B}"),
+    (0x2FA0, "\u{8FB0}"),
+    (0x2FA1, "\u{8FB5}"),
+    (0x2FA2, "\u{9091}"),
+    (0x2FA3, "\u{9149}"),
+    (0x2FA4, "\u{91C6}"),
+    (0x2FA5, "\u{91CC}"),
+    (0x2FA6, "\u{91D1}"),
+    (0x2FA7, "\u{9577}"),
+    (0x2FA8, "\u{9580}"),
+    (0x2FA9, "\u{961C}"),
+    (0x2FAA, "\u{96B6}"),
+    (0x2FAB, "\u{96B9}"),
+    (0x2FAC, "\u{96E8}"),
+    (0x2FAD, "\u{9751}"),
+    (0x2FAE, "\u{975E}"),
+    (0x2FAF, "\u{9762}"),
+    (0x2FB0, "\u{9769}"),
+    (0x2FB1, "\u{97CB}"),
+    (0x2FB2, "\u{97ED}"),
+    (0x2FB3, "\u{97F3}"),
+    (0x2FB4, "\u{9801}"),
+    (0x2FB5, "\u{98A8}"),
+    (0x2FB6, "\u{98DB}"),
+    (0x2FB7, "\u{98DF}"),
+    (0x2FB8, "\u{9996}"),
+    (0x2FB9, "\u{9999}"),
+    (0x2FBA, "\u{99AC}"),
+    (0x2FBB, "\u{9AA8}"),
+    (0x2FBC, "\u{9AD8}"),
+    (0x2FBD, "\u{9ADF}"),
+    (0x2FBE, "\u{9B25}"),
+    (0x2FBF, "\u{9B2F}"),
+    (0x2FC0, "\u{9B32}"),
+    (0x2FC1, "\u{9B3C}"),
+    (0x2FC2, "\u{9B5A}"),
+    (0x2FC3, "\u{9CE5}"),
+    (0x2FC4, "\u{9E75}"),
+    (0x2FC5, "\u{9E7F}"),
+    (0x2FC6, "\u{9EA5}"),
+    (0x2FC7, "\u{9EBB}"),
+    (0x2FC8, "\u{9EC3}"),
+    (0x2FC9, "\u{9ECD}"),
+    (0x2FCA, "\u{9ED1}"),
+    (0x2FCB, "\u{9EF9}"),
+    (0x2FCC, "\u{9EFD}"),
+    (0x2FCD, "\u{9F0E}"),
+    (0x2FCE, "\u{9F13}"),
+    (0x2FCF, "\u{9F20}"),
+    (0x2FD0, "\u{9F3B}"),
+    (0x2FD1, "\u{9F4A}"),
+    (0x2FD2, "\u{9F52}"),
+    (0x2FD3, "\u{9F8D}"),
+    (0x2FD4, "\u{9F9C}"),
+    (0x2FD5, "\u{9FA0}"),
+    (0x3002, "\u{2F3}"),
+    (0x3003, "''"),
+    (0x3007, "O"),
+    (0x3008, "\u{276C}"),
+    (0x3009, "\u{276D}"),
+    (0x3012, "\u{20B8}"),
+    (0x3014, "("),
+    (0x3015, ")"),
+    (0x301A, "\u{27E6}"),
+    (0x301B, "\u{27E7}"),
+    (0x302C, "\u{309}"),
+    (0x302D, "\u{325}"),
+    (0x3033, "/"),
+    (0x3036, "\u{20B8}"),
+    (0x3038, "\u{5341}"),
+    (0x3039, "\u{5344}"),
+    (0x303A, "\u{5345}"),
+    (0x304F, "\u{276C}"),
+    (0x309A, "\u{30A}"),
+    (0x309B, "\u{FF9E}"),
+    (0x309C, "\u{FF9F}"),
+    (0x30A0, "="),
+    (0x30A4, "\u{4EBB}"),
+    (0x30A8, "\u{5DE5}"),
+    (0x30AB, "\u{529B}"),
+    (0x30BF, "\u{5915}"),
+    (0x30C8, "\u{535C}"),
+    (0x30CB, "\u{4E8C}"),
+    (0x30CE, "/"),
+    (0x30CF, "\u{516B}"),
+    (0x30D8, "\u{3078}"),
+    (0x30ED, "\u{53E3}"),
+    (0x30FB, "\u{B7}"),
+    (0x3126, "\u{513F}"),
+    (0x3131, "\u{1100}"),
+    (0x3132, "\u{1100}\u{1100}"),
+    (0x3133, "\u{1100}\u{1109}"),
+    (0x3134, "\u{1102}"),
+    (0x3135, "\u{1102}\u{110C}"),
+    (0x3136, "\u{1102}\u{1112}"),
+    (0x3137, "\u{1103}"),
+    (0x3138, "\u{1103}\u{1103}"),
+    (0x3139, "\u{1105}"),
+    (0x313A, "\u{1105}\u{1100}"),
+    (0x313B, "\u{1105}\u{1106}"),
+    (0x313C, "\u{1105}\u{1107}"),
+    (0x313D, "\u{1105}\u{1109}"),
+    (0x313E, "\u{1105}\u{1110}"),
+    (0x313F, "\u{1105}\u{1111}"),
+    (0x3140, "\u{1105}\u{1112}"),
+    (0x3141, "\u{1106}"),
+    (0x3142, "\u{1107}"),
+    (0x3143, "\u{1107}\u{1107}"),
+    (0x3144, "\u{1107}\u{1109}"),
+    (0x3145, "\u{1109}"),
+    (0x3146, "\u{1109}\u{1109}"),
+    (0x3147, "\u{110B}"),
+    (0x3148, "\u{110C}"),
+    (0x3149, "\u{110C}\u{110C}"),
+    (0x314A, "\u{110E}"),
+    (0x314B, "\u{110F}"),
+    (0x314C, "\u{1110}"),
+    (0x314D, "\u{1111}"),
+    (0x314E, "\u{1112}"),
+    (0x314F, "\u{1161}"),
+    (0x3150, "\u{1161}\u{4E28}"),
+    (0x3151, "\u{1163}"),
+    (0x3152, "\u{1163}\u{4E28}"),
+    (0x3153, "\u{1165}"),
+    (0x3154, "\u{1165}\u{4E28}"),
+    (0x3155, "\u{1167}"),
+    (0x3156, "\u{1167}\u{4E28}"),
+    (0x3157, "\u{1169}"),
+    (0x3158, "\u{1169}\u{1161}"),
+    (0x3159, "\u{1169}\u{1161}\u{4E28}"),
+    (0x315A, "\u{1169}\u{4E28}"),
+    (0x315B, "\u{116D}"),
+    (0x315C, "\u{116E}"),
+    (0x315D, "\u{116E}\u{1165}"),
+    (0x315E, "\u{116E}\u{1165}\u{4E28}"),
+    (0x315F, "\u{116E}\u{4E28}"),
+    (0x3160, "\u{1172}"),
+    (0x3161, "\u{30FC}"),
+    (0x3162, "\u{30FC}\u{4E28}"),
+    (0x3163, "\u{4E28}"),
+    (0x3164, "\u{1160}"),
+    (0x3165, "\u{1102}\u{1102}"),
+    (0x3166, "\u{1102}\u{1103}"),
+    (0x3167, "\u{1102}\u{1109}"),
+    (0x3168, "\u{1102}\u{1140}"),
+    (0x3169, "\u{1105}\u{1100}\u{1109}"),
+    (0x316A, "\u{1105}\u{1103}"),
+    (0x316B, "\u{1105}\u{1107}\u{1109}"),
+    (0x316C, "\u{1105}\u{1140}"),
+    (0x316D, "\u{1105}\u{1159}"),
+    (0x316E, "\u{1106}\u{1107}"),
+    (0x316F, "\u{1106}\u{1109}"),
+    (0x3170, "\u{1106}\u{1140}"),
+    (0x3171, "\u{1106}\u{110B}"),
+    (0x3172, "\u{1107}\u{1100}"),
+    (0x3173, "\u{1107}\u{1103}"),
+    (0x3174, "\u{1107}\u{1109}\u{1100}"),
+    (0x3175, "\u{1107}\u{1109}\u{1103}"),
+    (0x3176, "\u{1107}\u{110C}"),
+    (0x3177, "\u{1107}\u{1110}"),
+    (0x3178, "\u{1107}\u{110B}"),
+    (0x3179, "\u{1107}\u{1107}\u{110B}"),
+    (0x317A, "\u{1109}\u{1100}"),
+    (0x317B, "\u{1109}\u{1102}"),
+    (0x317C, "\u{1109}\u{1103}"),
+    (0x317D, "\u{1109}\u{1107}"),
+    (0x317E, "\u{1109}\u{110C}"),
+    (0x317F, "\u{1140}"),
+    (0x3180, "\u{110B}\u{110B}"),
+    (0x3181, "\u{114C}"),
+    (0x3182, "\u{110B}\u{1109}"),
+    (0x3183, "\u{110B}\u{1140}"),
+    (0x3184, "\u{1111}\u{110B}"),
+    (0x3185, "\u{1112}\u{1112}"),
+    (0x3186, "\u{1159}"),
+    (0x3187, "\u{116D}\u{1163}"),
+    (0x3188, "\u{116D}\u{1163}\u{4E28}"),
+    (0x3189, "\u{116D}\u{4E28}"),
+    (0x318A, "\u{1172}\u{1167}"),
+    (0x318B, "\u{1172}\u{1167}\u{4E28}"),
+    (0x318C, "\u{1172}\u{4E28}"),
+    (0x318D, "\u{119E}"),
+    (0x318E, "\u{119E}\u{4E28}"),
+    (0x31D0, "\u{30FC}"),
+    (0x31D1, "\u{4E28}"),
+    (0x31D3, "/"),
+    (0x31D4, "\\"),
+    (0x31D6, "\u{4E5B}"),
+    (0x31DA, "\u{4E85}"),
+    (0x31DB, "\u{276C}"),
+    (0x31DF, "\u{4E5A}"),
+    (0x31E0, "\u{4E59}"),
+    (0x3200, "(\u{1100})"),
+    (0x3201, "(\u{1102})"),
+    (0x3202, "(\u{1103})"),
+    (0x3203, "(\u{1105})"),
+    (0x3204, "(\u{1106})"),
+    (0x3205, "(\u{1107})"),
+    (0x3206, "(\u{1109})"),
+    (0x3207, "(\u{110B})"),
+    (0x3208, "(\u{110C})"),
+    (0x3209, "(\u{110E})"),
+    (0x320A, "(\u{110F})"),
+    (0x320B, "(\u{1110})"),
+    (0x320C, "(\u{1111})"),
+    (0x320D, "(\u{1112})"),
+    (0x320E, "(\u{AC00})"),
+    (0x320F, "(\u{B098})"),
+    (0x3210, "(\u{B2E4})"),
+    (0x3211, "(\u{B77C})"),
+    (0x3212, "(\u{B9C8})"),
+    (0x3213, "(\u{BC14})"),
+    (0x3214, "(\u{C0AC})"),
+    (0x3215, "(\u{C544})"),
+    (0x3216, "(\u{C790})"),
+    (0x3217, "(\u{CC28})"),
+    (0x3218, "(\u{CE74})"),
+    (0x3219, "(\u{D0C0})"),
+    (0x321A, "(\u{D30C})"),
+    (0x321B, "(\u{D558})"),
+    (0x321C, "(\u{C8FC})"),
+    (0x321D, "(\u{C624}\u{C804})"),
+    (0x321E, "(\u{C624}\u{D6C4})"),
+    (0x3220, "(\u{30FC})"),
+    (0x3221, "(\u{4E8C})"),
+    (0x3222, "(\u{4E09})"),
+    (0x3223, "(\u{56DB})"),
+    (0x3224, "(\u{4E94})"),
+    (0x3225, "(\u{516D})"),
+    (0x3226, "(\u{4E03})"),
+    (0x3227, "(\u{516B})"),
+    (0x3228, "(\u{4E5D})"),
+    (0x3229, "(\u{5341})"),
+    (0x322A, "(\u{6708})"),
+    (0x322B, "(\u{706B})"),
+    (0x322C, "(\u{6C34})"),
+    (0x322D, "(\u{6728})"),
+    (0x322E, "(\u{91D1})"),
+    (0x322F, "(\u{571F})"),
+    (0x3230, "(\u{65E5})"),
+    (0x3231, "(\u{682A})"),
+    (0x3232, "(\u{6709})"),
+    (0x3233, "(\u{793E})"),
+    (0x3234, "(\u{540D})"),
+    (0x3235, "(\u{7279})"),
+    (0x3236, "(\u{8CA1})"),
+    (0x3237, "(\u{795D})"),
+    (0x3238, "(\u{52B4})"),
+    (0x3239, "(\u{4EE3})"),
+    (0x323A, "(\u{547C})"),
+    (0x323B, "(\u{5B66})"),
+    (0x323C, "(\u{76E3})"),
+    (0x323D, "(\u{4F01})"),
+    (0x323E, "(\u{8CC7})"),
+    (0x323F, "(\u{5354})"),
+    (0x3240, "(\u{796D})"),
+    (0x3241, "(\u{4F11})"),
+    (0x3242, "(\u{81EA})"),
+    (0x3243, "(\u{81F3})"),
+    (0x32C0, "l\u{6708}"),
+    (0x32C1, "2\u{6708}"),
+    (0x32C2, "3\u{6708}"),
+    (0x32C3, "4\u{6708}"),
+    (0x32C4, "5\u{6708}"),
+    (0x32C5, "6\u{6708}"),
+    (0x32C6, "7\u{6708}"),
+    (0x32C7, "8\u{6708}"),
+    (0x32C8, "9\u{6708}"),
+    (0x32C9, "lO\u{6708}"),
+    (0x32CA, "ll\u{6708}"),
+    (0x32CB, "l2\u{6708}"),
+    (0x3358, "O\u{70B9}"),
+    (0x3359, "l\u{70B9}"),
+    (0x335A, "2\u{70B9}"),
+    (0x335B, "3\u{70B9}"),
+    (0x335C, "4\u{70B9}"),
+    (0x335D, "5\u{70B9}"),
+    (0x335E, "6\u{70B9}"),
+    (0x335F, "7\u{70B9}"),
+    (0x3360, "8\u{70B9}"),
+    (0x3361, "9\u{70B9}"),
+    (0x3362, "lO\u{70B9}"),
+    (0x3363, "ll\u{70B9}"),
+    (0x3364, "l2\u{70B9}"),
+    (0x3365, "l3\u{70B9}"),
+    (0x3366, "l4\u{70B9}"),
+    (0x3367, "l5\u{70B9}"),
+    (0x3368, "l6\u{70B9}"),
+    (0x3369, "l7\u{70B9}"),
+    (0x336A, "l8\u{70B9}"),
+    (0x336B, "l9\u{70B9}"),
+    (0x336C, "2O\u{70B9}"),
+    (0x336D, "2l\u{70B9}"),
+    (0x336E, "22\u{70B9}"),
+    (0x336F, "23\u{70B9}"),
+    (0x3370, "24\u{70B9}"),
+    (0x33E0, "l\u{65E5}"),
+    (0x33E1, "2\u{65E5}"),
+    (0x33E2, "3\u{65E5}"),
+    (0x33E3, "4\u{65E5}"),
+    (0x33E4, "5\u{65E5}"),
+    (0x33E5, "6\u{65E5}"),
+    (0x33E6, "7\u{65E5}"),
+    (0x33E7, "8\u{65E5}"),
+    (0x33E8, "9\u{65E5}"),
+    (0x33E9, "lO\u{65E5}"),
+    (0x33EA, "ll\u{65E5}"),
+    (0x33EB, "l2\u{65E5}"),
+    (0x33EC, "l3\u{65E5}"),
+    (0x33ED, "l4\u{65E5}"),
+    (0x33EE, "l5\u{65E5}"),
+    (0x33EF, "l6\u{65E5}"),
+    (0x33F0, "l7\u{65E5}"),
+    (0x33F1, "l8\u{65E5}"),
+    (0x33F2, "l9\u{65E5}"),
+    (0x33F3, "2O\u{65E5}"),
+    (0x33F4, "2l\u{65E5}"),
+    (0x33F5, "22\u{65E5}"),
+    (0x33F6, "23\u{65E5}"),
+    (0x33F7, "24\u{65E5}"),
+    (0x33F8, "25\u{65E5}"),
+    (0x33F9, "26\u{65E5}"),
+    (0x33FA, "27\u{65E5}"),
+    (0x33FB, "28\u{65E5}"),
+    (0x33FC, "29\u{65E5}"),
+    (0x33FD, "3O\u{65E5}"),
+    (0x33FE, "3l\u{65E5}"),
+    (0x39B3, "\u{363D}"),
+    (0x439B, "\u{3588}"),
+    (0x4420, "\u{3B3B}"),
+    (0x4E00, "\u{30FC}"),
+    (0x4E36, "\\"),
+    (0x4E3F, "/"),
+    (0x5002, "\u{4F75}"),
+    (0x503C, "\u{5024}"),
+    (0x555F, "\u{5553}"),
+    (0x56D7, "\u{53E3}"),
+    (0x586B, "\u{5861}"),
+    (0x58EB, "\u{571F}"),
+    (0x58FF, "\u{58AB}"),
+    (0x5B00, "\u{5AAF}"),
+    (0x5E32, "\u{5E21}"),
+    (0x5E50, "\u{3B3A}"),
+    (0x6238, "\u{6236}"),
+    (0x6409, "\u{3A41}"),
+    (0x6663, "\u{403F}"),
+    (0x6669, "\u{665A}"),
+    (0x66F6, "\u{3ADA}"),
+    (0x6726, "\u{4443}"),
+    (0x67FF, "\u{676E}"),
+    (0x69E9, "\u{3BA3}"),
+    (0x6A27, "\u{699D}"),
+    (0x6F59, "\u{6E88}"),
+    (0x784F, "\u{7814}"),
+    (0x7D76, "\u{7D55}"),
+    (0x80A6, "\u{670C}"),
+    (0x80CA, "\u{6710}"),
+    (0x80D0, "\u{670F}"),
+    (0x80F6, "\u{3B35}"),
+    (0x8101, "\u{6713}"),
+    (0x8127, "\u{6718}"),
+    (0x8141, "\u{80FC}"),
+    (0x81A7, "\u{6723}"),
+    (0x853F, "\u{848D}"),
+    (0x8641, "\u{8637}"),
+    (0x8A1E, "\u{46B6}"),
+    (0x8A7D, "\u{8A2E}"),
+    (0x8B8F, "\u{8B86}"),
+    (0x8C63, "\u{8C5C}"),
+    (0x8D86, "\u{8D7F}"),
+    (0x8DFA, "\u{8DE5}"),
+    (0x8E9B, "\u{8E97}"),
+    (0x8F27, "\u{8EFF}"),
+    (0x90DE, "\u{90CE}"),
+    (0x93AE, "\u{93AD}"),
+    (0x96B8, "\u{96B7}"),
+    (0x9E43, "\u{9E42}"),
+    (0x9ED2, "\u{9ED1}"),
+    (0x9FC3, "\u{4039}"),
+    (0xA494, "\u{A2CD}"),
+    (0xA49C, "\u{A0C0}"),
+    (0xA49E, "\u{A04A}"),
+    (0xA4A7, "\u{A458}"),
+    (0xA4A8, "\u{A132}"),
+    (0xA4AC, "\u{A050}"),
+    (0xA4B0, "\u{A3C2}"),
+    (0xA4BA, "\u{A3BF}"),
+    (0xA4BE, "\u{A2B1}"),
+    (0xA4BF, "\u{A259}"),
+    (0xA4C0, "\u{A3AB}"),
+    (0xA4C2, "\u{A3B5}"),
+    (0xA4D0, "B"),
+    (0xA4D1, "P"),
+    (0xA4D2, "d"),
+    (0xA4D3, "D"),
+    (0xA4D4, "T"),
+    (0xA4D6, "G"),
+    (0xA4D7, "K"),
+    (0xA4D9, "J"),
+    (0xA4DA, "C"),
+    (0xA4DB, "\u{186}"),
+    (0xA4DC, "Z"),
+    (0xA4DD, "F"),
+    (0xA4DE, "\u{2132}"),
+    (0xA4DF, "M"),
+    (0xA4E0, "N"),
+    (0xA4E1, "L"),
+    (0xA4E2, "S"),
+    (0xA4E3, "R"),
+    (0xA4E5, "\u{245}"),
+    (0xA4E6, "V"),
+    (0xA4E7, "H"),
+    (0xA4EA, "W"),
+    (0xA4EB, "X"),
+    (0xA4EC, "Y"),
+    (0xA4ED, "\u{1660}"),
+    (0xA4EE, "A"),
+    (0xA4EF, "\u{2C6F}"),
+    (0xA4F0, "E"),
+    (0xA4F1, "\u{18E}"),
+    (0xA4F2, "l"),
+    (0xA4F3, "O"),
+    (0xA4F4, "U"),
+    (0xA4F5, "\u{548}"),
+    (0xA4F7, "\u{15E1}"),
+    (0xA4F8, "."),
+    (0xA4F9, ","),
+    (0xA4FA, ".."),
+    (0xA4FB, ".,"),
+    (0xA4FD, ":"),
+    (0xA4FE, "-."),
+    (0xA4FF, "="),
+    (0xA60E, "."),
+    (0xA644, "2"),
+    (0xA645, "\u{1A8}"),
+    (0xA647, "i"),
+    (0xA64D, "\u{3C9}"),
+    (0xA650, "\u{42A}l"),
+    (0xA651, "\u{2C9}bi"),
+    (0xA668, "\u{298}"),
+    (0xA66F, "\u{20E9}"),
+    (0xA67C, "\u{306}"),
+    (0xA67E, "\u{2C7}"),
+    (0xA695, "h\u{314}"),
+    (0xA698, "OO"),
+    (0xA699, "oo"),
+    (0xA69A, "\u{102A8}"),
+    (0xA6A1, "\u{418}"),
+    (0xA6B0, "\u{16B9}"),
+    (0xA6B1, "\u{2C75}"),
+    (0xA6CD, "\u{2A1}"),
+    (0xA6CE, "\u{245}"),
+    (0xA6DB, "\u{3A0}"),
+    (0xA6DF, "V"),
+    (0xA6EB, "?"),
+    (0xA6EF, "2"),
+    (0xA6F0, "\u{302}"),
+    (0xA6F1, "\u{304}"),
+    (0xA6F4, "\u{A6F3}\u{A6F3}"),
+    (0xA714, "\u{2EB}"),
+    (0xA716, "\u{2EA}"),
+    (0xA728, "T3"),
+    (0xA729, "t\u{21D}"),
+    (0xA731, "s"),
+    (0xA732, "AA"),
+    (0xA733, "aa"),
+    (0xA734, "AO"),
+    (0xA735, "ao"),
+    (0xA736, "AU"),
+    (0xA737, "au"),
+    (0xA738, "AV"),
+    (0xA739, "av"),
+    (0xA73A, "AV"),
+    (0xA73B, "av"),
+    (0xA73C, "AY"),
+    (0xA73D, "ay"),
+    (0xA740, "K\u{335}"),
+    (0xA74A, "O\u{335}"),
+    (0xA74B, "o\u{335}"),
+    (0xA74E, "OO"),
+    (0xA74F, "oo"),
+    (0xA75A, "2"),
+    (0xA761, "w\u{326}"),
+    (0xA76A, "3"),
+    (0xA76B, "\u{21D}"),
+    (0xA76E, "9"),
+    (0xA777, "tf"),
+    (0xA778, "&"),
+    (0xA77A, "\u{A779}"),
+    (0xA789, ":"),
+    (0xA78C, "'"),
+    (0xA78F, "\u{B7}"),
+    (0xA795, "\u{A727}"),
+    (0xA798, "F"),
+    (0xA799, "f"),
+    (0xA79A, "\u{10412}"),
+    (0xA79B, "\u{1043A}"),
+    (0xA79D, "\u{29A}"),
+    (0xA79E, "\u{A4E4}"),
+    (0xA79F, "u"),
+    (0xA7AB, "3"),
+    (0xA7B1, "\u{A4D5}"),
+    (0xA7B2, "J"),
+    (0xA7B3, "X"),
+    (0xA7B4, "B"),
+    (0xA7B5, "\u{DF}"),
+    (0xA7B6, "\u{A64C}"),
+    (0xA7B7, "\u{3C9}"),
+    (0xA7D6, "\u{DF}"),
+    (0xA7DA, "\u{245}"),
+    (0xA7DB, "\u{3BB}"),
+    (0xA7DC, "\u{245}\u{338}"),
+    (0xA7F7, "\u{30FC}"),
+    (0xA830, "\u{964}"),
+    (0xA960, "\u{1103}\u{1106}"),
+    (0xA961, "\u{1103}\u{1107}"),
+    (0xA962, "\u{1103}\u{1109}"),
+    (0xA963, "\u{1103}\u{110C}"),
+    (0xA964, "\u{1105}\u{1100}"),
+    (0xA965, "\u{1105}\u{1100}\u{1100}"),
+    (0xA966, "\u{1105}\u{1103}"),
+    (0xA967, "\u{1105}\u{1103}\u{1103}"),
+    (0xA968, "\u{1105}\u{1106}"),
+    (0xA969, "\u{1105}\u{1107}"),
+    (0xA96A, "\u{1105}\u{1107}\u{1107}"),
+    (0xA96B, "\u{1105}\u{1107}\u{110B}"),
+    (0xA96C, "\u{1105}\u{1109}"),
+    (0xA96D, "\u{1105}\u{110C}"),
+    (0xA96E, "\u{1105}\u{110F}"),
+    (0xA96F, "\u{1106}\u{1100}"),
+    (0xA970, "\u{1106}\u{1103}"),
+    (0xA971, "\u{1106}\u{1109}"),
+    (0xA972, "\u{1107}\u{1109}\u{1110}"),
+    (0xA973, "\u{1107}\u{110F}"),
+    (0xA974, "\u{1107}\u{1112}"),
+    (0xA975, "\u{1109}\u{1109}\u{1107}"),
+    (0xA976, "\u{110B}\u{1105}"),
+    (0xA977, "\u{110B}\u{1112}"),
+    (0xA978, "\u{110C}\u{110C}\u{1112}"),
+    (0xA979, "\u{1110}\u{1110}"),
+    (0xA97A, "\u{1111}\u{1112}"),
+    (0xA97B, "\u{1112}\u{1109}"),
+    (0xA97C, "\u{1159}\u{1159}"),
+    (0xA992, "\u{2C3F}"),
+    (0xA9A3, "\u{A99D}"),
+    (0xA9C6, "\u{A9D0}"),
+    (0xA9CF, "\u{662}"),
+    (0xAA53, "\u{AA01}"),
+    (0xAA56, "\u{AA23}"),
+    (0xAB32, "e"),
+    (0xAB35, "f"),
+    (0xAB3D, "o"),
+    (0xAB3E, "o\u{338}"),
+    (0xAB3F, "\u{254}\u{338}"),
+    (0xAB41, "\u{1DD}o\u{338}"),
+    (0xAB42, "\u{1DD}o\u{335}"),
+    (0xAB47, "r"),
+    (0xAB48, "r"),
+    (0xAB4D, "\u{283}"),
+    (0xAB4E, "u"),
+    (0xAB52, "u"),
+    (0xAB53, "\u{3C7}"),
+    (0xAB55, "\u{3C7}"),
+    (0xAB5A, "y"),
+    (0xAB60, "\u{459}"),
+    (0xAB62, "\u{254}e"),
+    (0xAB63, "uo"),
+    (0xAB70, "\u{1D05}"),
+    (0xAB71, "\u{280}"),
+    (0xAB72, "\u{1D1B}"),
+    (0xAB74, "o\u{31B}"),
+    (0xAB75, "i"),
+    (0xAB7A, "\u{1D00}"),
+    (0xAB7B, "\u{1D0A}"),
+    (0xAB7C, "\u{1D07}"),
+    (0xAB7E, "\u{242}"),
+    (0xAB80, "\u{2C76}"),
+    (0xAB81, "r"),
+    (0xAB83, "w"),
+    (0xAB87, "\u{28D}"),
+    (0xAB8B, "\u{29C}"),
+    (0xAB8E, "o\u{335}"),
+    (0xAB90, "\u{262}"),
+    (0xAB93, "z"),
+    (0xAB9B, "\u{A793}"),
+    (0xAB9C, "u\u{335}"),
+    (0xAB9F, "\u{185}"),
+    (0xABA2, "\u{280}"),
+    (0xABA9, "v"),
+    (0xABAA, "s"),
+    (0xABAE, "\u{29F}"),
+    (0xABAF, "c"),
+    (0xABB2, "\u{1D18}"),
+    (0xABB6, "\u{138}"),
+    (0xABBB, "o\u{335}"),
+    (0xD7B0, "\u{1169}\u{1167}"),
+    (0xD7B1, "\u{1169}\u{1169}\u{4E28}"),
+    (0xD7B2, "\u{116D}\u{1161}"),
+    (0xD7B3, "\u{116D}\u{1161}\u{4E28}"),
+    (0xD7B4, "\u{116D}\u{1165}"),
+    (0xD7B5, "\u{116E}\u{1167}"),
+    (0xD7B6, "\u{116E}\u{4E28}\u{4E28}"),
+    (0xD7B7, "\u{1172}\u{1161}\u{4E28}"),
+    (0xD7B8, "\u{1172}\u{1169}"),
+    (0xD7B9, "\u{30FC}\u{1161}"),
+    (0xD7BA, "\u{30FC}\u{1165}"),
+    (0xD7BB, "\u{30FC}\u{1165}\u{4E28}"),
+    (0xD7BC, "\u{30FC}\u{1169}"),
+    (0xD7BD, "\u{4E28}\u{1163}\u{1169}"),
+    (0xD7BE, "\u{4E28}\u{1163}\u{4E28}"),
+    (0xD7BF, "\u{4E28}\u{1167}"),
+    (0xD7C0, "\u{4E28}\u{1167}\u{4E28}"),
+    (0xD7C1, "\u{4E28}\u{1169}\u{4E28}"),
+    (0xD7C2, "\u{4E28}\u{116D}"),
+    (0xD7C3, "\u{4E28}\u{1172}"),
+    (0xD7C4, "\u{4E28}\u{4E28}"),
+    (0xD7C5, "\u{119E}\u{1161}"),
+    (0xD7C6, "\u{119E}\u{1165}\u{4E28}"),
+    (0xD7CB, "\u{1102}\u{1105}"),
+    (0xD7CC, "\u{1102}\u{110E}"),
+    (0xD7CD, "\u{1103}\u{1103}"),
+    (0xD7CE, "\u{1103}\u{1103}\u{1107}"),
+    (0xD7CF, "\u{1103}\u{1107}"),
+    (0xD7D0, "\u{1103}\u{1109}"),
+    (0xD7D1, "\u{1103}\u{1109}\u{1100}"),
+    (0xD7D2, "\u{1103}\u{110C}"),
+    (0xD7D3, "\u{1103}\u{110E}"),
+    (0xD7D4, "\u{1103}\u{1110}"),
+    (0xD7D5, "\u{1105}\u{1100}\u{1100}"),
+    (0xD7D6, "\u{1105}\u{1100}\u{1112}"),
+    (0xD7D7, "\u{1105}\u{1105}\u{110F}"),
+    (0xD7D8, "\u{1105}\u{1106}\u{1112}"),
+    (0xD7D9, "\u{1105}\u{1107}\u{1103}"),
+    (0xD7DA, "\u{1105}\u{1107}\u{1111}"),
+    (0xD7DB, "\u{1105}\u{114C}"),
+    (0xD7DC, "\u{1105}\u{1159}\u{1112}"),
+    (0xD7DD, "\u{1105}\u{110B}"),
+    (0xD7DE, "\u{1106}\u{1102}"),
+    (0xD7DF, "\u{1106}\u{1102}\u{1102}"),
+    (0xD7E0, "\u{1106}\u{1106}"),
+    (0xD7E1, "\u{1106}\u{1107}\u{1109}"),
+    (0xD7E2, "\u{1106}\u{110C}"),
+    (0xD7E3, "\u{1107}\u{1103}"),
+    (0xD7E4, "\u{1107}\u{1105}\u{1111}"),
+    (0xD7E5, "\u{1107}\u{1106}"),
+    (0xD7E6, "\u{1107}\u{1107}"),
+    (0xD7E7, "\u{1107}\u{1109}\u{1103}"),
+    (0xD7E8, "\u{1107}\u{110C}"),
+    (0xD7E9, "\u{1107}\u{110E}"),
+    (0xD7EA, "\u{1109}\u{1106}"),
+    (0xD7EB, "\u{1109}\u{1107}\u{110B}"),
+    (0xD7EC, "\u{1109}\u{1109}\u{1100}"),
+    (0xD7ED, "\u{1109}\u{1109}\u{1103}"),
+    (0xD7EE, "\u{1109}\u{1140}"),
+    (0xD7EF, "\u{1109}\u{110C}"),
+    (0xD7F0, "\u{1109}\u{110E}"),
+    (0xD7F1, "\u{1109}\u{1110}"),
+    (0xD7F2, "\u{1105}\u{1112}"),
+    (0xD7F3, "\u{1140}\u{1107}"),
+    (0xD7F4, "\u{1140}\u{1107}\u{110B}"),
+    (0xD7F5, "\u{114C}\u{1106}"),
+    (0xD7F6, "\u{114C}\u{1112}"),
+    (0xD7F7, "\u{110C}\u{1107}"),
+    (0xD7F8, "\u{110C}\u{1107}\u{1107}"),
+    (0xD7F9, "\u{110C}\u{110C}"),
+    (0xD7FA, "\u{1111}\u{1109}"),
+    (0xD7FB, "\u{1111}\u{1110}"),
+    (0xF900, "\u{8C48}"),
+    (0xF901, "\u{66F4}"),
+    (0xF902, "\u{8ECA}"),
+    (0xF903, "\u{8CC8}"),
+    (0xF904, "\u{6ED1}"),
+    (0xF905, "\u{4E32}"),
+    (0xF906, "\u{53E5}"),
+    (0xF907, "\u{9F9C}"),
+    (0xF908, "\u{9F9C}"),
+    (0xF909, "\u{5951}"),
+    (0xF90A, "\u{91D1}"),
+    (0xF90B, "\u{5587}"),
+    (0xF90C, "\u{5948}"),
+    (0xF90D, "\u{61F6}"),
+    (0xF90E, "\u{7669}"),
+    (0xF90F, "\u{7F85}"),
+    (0xF910, "\u{863F}"),
+    (0xF911, "\u{87BA}"),
+    (0xF912, "\u{88F8}"),
+    (0xF913, "\u{908F}"),
+    (0xF914, "\u{6A02}"),
+    (0xF915, "\u{6D1B}"),
+    (0xF916, "\u{70D9}"),
+    (0xF917, "\u{73DE}"),
+    (0xF918, "\u{843D}"),
+    (0xF919, "\u{916A}"),
+    (0xF91A, "\u{99F1}"),
+    (0xF91B, "\u{4E82}"),
+    (0xF91C, "\u{5375}"),
+    (0xF91D, "\u{6B04}"),
+    (0xF91E, "\u{721B}"),
+    (0xF91F, "\u{862D}"),
+    (0xF920, "\u{9E1E}"),
+    (0xF921, "\u{5D50}"),
+    (0xF922, "\u{6FEB}"),
+    (0xF923, "\u{85CD}"),
+    (0xF924, "\u{8964}"),
+    (0xF925, "\u{62C9}"),
+    (0xF926, "\u{81D8}"),
+    (0xF927, "\u{881F}"),
+    (0xF928, "\u{5ECA}"),
+    (0xF929, "\u{6717}"),
+    (0xF92A, "\u{6D6A}"),
+    (0xF92B, "\u{72FC}"),
+    (0xF92C, "\u{90CE}"),
+    (0xF92D, "\u{4F86}"),
+    (0xF92E, "\u{51B7}"),
+    (0xF92F, "\u{52DE}"),
+    (0xF930, "\u{64C4}"),
+    (0xF931, "\u{6AD3}"),
+    (0xF932, "\u{7210}"),
+    (0xF933, "\u{76E7}"),
+    (0xF934, "\u{8001}"),
+    (0xF935, "\u{8606}"),
+    (0xF936, "\u{865C}"),
+    (0xF937, "\u{8DEF}"),
+    (0xF938, "\u{9732}"),
+    (0xF939, "\u{9B6F}"),
+    (0xF93A, "\u{9DFA}"),
+    (0xF93B, "\u{788C}"),
+    (0xF93C, "\u{797F}"),
+    (0xF93D, "\u{7DA0}"),
+    (0xF93E, "\u{83C9}"),
+    (0xF93F, "\u{9304}"),
+    (0xF940, "\u{9E7F}"),
+    (0xF941, "\u{8AD6}"),
+    (0xF942, "\u{58DF}"),
+    (0xF943, "\u{5F04}"),
+    (0xF944, "\u{7C60}"),
+    (0xF945, "\u{807E}"),
+    (0xF946, "\u{7262}"),
+    (0xF947, "\u{78CA}"),
+    (0xF948, "\u{8CC2}"),
+    (0xF949, "\u{96F7}"),
+    (0xF94A, "\u{58D8}"),
+    (0xF94B, "\u{5C62}"),
+    (0xF94C, "\u{6A13}"),
+    (0xF94D, "\u{6DDA}"),
+    (0xF94E, "\u{6F0F}"),
+    (0xF94F, "\u{7D2F}"),
+    (0xF950, "\u{7E37}"),
+    (0xF951, "\u{964B}"),
+    (0xF952, "\u{52D2}"),
+    (0xF953, "\u{808B}"),
+    (0xF954, "\u{51DC}"),
+    (0xF955, "\u{51CC}"),
+    (0xF956, "\u{7A1C}"),
+    (0xF957, "\u{7DBE}"),
+    (0xF958, "\u{83F1}"),
+    (0xF959, "\u{9675}"),
+    (0xF95A, "\u{8B80}"),
+    (0xF95B, "\u{62CF}"),
+    (0xF95C, "\u{6A02}"),
+    (0xF95D, "\u{8AFE}"),
+    (0xF95E, "\u{4E39}"),
+    (0xF95F, "\u{5BE7}"),
+    (0xF960, "\u{6012}"),
+    (0xF961, "\u{7387}"),
+    (0xF962, "\u{7570}"),
+    (0xF963, "\u{5317}"),
+    (0xF964, "\u{78FB}"),
+    (0xF965, "\u{4FBF}"),
+    (0xF966, "\u{5FA9}"),
+    (0xF967, "\u{4E0D}"),
+    (0xF968, "\u{6CCC}"),
+    (0xF969, "\u{6578}"),
+    (0xF96A, "\u{7D22}"),
+    (0xF96B, "\u{53C3}"),
+    (0xF96C, "\u{585E}"),
+    (0xF96D, "\u{7701}"),
+    (0xF96E, "\u{8449}"),
+    (0xF96F, "\u{8AAA}"),
+    (0xF970, "\u{6BBA}"),
+    (0xF971, "\u{8FB0}"),
+    (0xF972, "\u{6C88}"),
+    (0xF973, "\u{62FE}"),
+    (0xF974, "\u{82E5}"),
+    (0xF975, "\u{63A0}"),
+    (0xF976, "\u{7565}"),
+    (0xF977, "\u{4EAE}"),
+    (0xF978, "\u{5169}"),
+    (0xF979, "\u{51C9}"),
+    (0xF97A, "\u{6881}"),
+    (0xF97B, "\u{7CE7}"),
+    (0xF97C, "\u{826F}"),
+    (0xF97D, "\u{8AD2}"),
+    (0xF97E, "\u{91CF}"),
+    (0xF97F, "\u{52F5}"),
+    (0xF980, "\u{5442}"),
+    (0xF981, "\u{5973}"),
+    (0xF982, "\u{5EEC}"),
+    (0xF983, "\u{65C5}"),
+    (0xF984, "\u{6FFE}"),
+    (0xF985, "\u{792A}"),
+    (0xF986, "\u{95AD}"),
+    (0xF987, "\u{9A6A}"),
+    (0xF988, "\u{9E97}"),
+    (0xF989, "\u{9ECE}"),
+    (0xF98A, "\u{529B}"),
+    (0xF98B, "\u{66C6}"),
+    (0xF98C, "\u{6B77}"),
+    (0xF98D, "\u{8F62}"),
+    (0xF98E, "\u{5E74}"),
+    (0xF98F, "\u{6190}"),
+    (0xF990, "\u{6200}"),
+    (0xF991, "\u{649A}"),
+    (0xF992, "\u{6F23}"),
+    (0xF993, "\u{7149}"),
+    (0xF994, "\u{7489}"),
+    (0xF995, "\u{79CA}"),
+    (0xF996, "\u{7DF4}"),
+    (0xF997, "\u{806F}"),
+    (0xF998, "\u{8F26}"),
+    (0xF999, "\u{84EE}"),
+    (0xF99A, "\u{9023}"),
+    (0xF99B, "\u{934A}"),
+    (0xF99C, "\u{5217}"),
+    (0xF99D, "\u{52A3}"),
+    (0xF99E, "\u{54BD}"),
+    (0xF99F, "\u{70C8}"),
+    (0xF9A0, "\u{88C2}"),
+    (0xF9A1, "\u{8AAA}"),
+    (0xF9A2, "\u{5EC9}"),
+    (0xF9A3, "\u{5FF5}"),
+    (0xF9A4, "\u{637B}"),
+    (0xF9A5, "\u{6BAE}"),
+    (0xF9A6, "\u{7C3E}"),
+    (0xF9A7, "\u{7375}"),
+    (0xF9A8, "\u{4EE4}"),
+    (0xF9A9, "\u{56F9}"),
+    (0xF9AA, "\u{5BE7}"),
+    (0xF9AB, "\u{5DBA}"),
+    (0xF9AC, "\u{601C}"),
+    (0xF9AD, "\u{73B2}"),
+    (0xF9AE, "\u{7469}"),
+    (0xF9AF, "\u{7F9A}"),
+    (0xF9B0, "\u{8046}"),
+    (0xF9B1, "\u{9234}"),
+    (0xF9B2, "\u{96F6}"),
+    (0xF9B3, "\u{9748}"),
+    (0xF9B4, "\u{9818}"),
+    (0xF9B5, "\u{4F8B}"),
+    (0xF9B6, "\u{79AE}"),
+    (0xF9B7, "\u{91B4}"),
+    (0xF9B8, "\u{96B7}"),
+    (0xF9B9, "\u{60E1}"),
+    (0xF9BA, "\u{4E86}"),
+    (0xF9BB, "\u{50DA}"),
+    (0xF9BC, "\u{5BEE}"),
+    (0xF9BD, "\u{5C3F}"),
+    (0xF9BE, "\u{6599}"),
+    (0xF9BF, "\u{6A02}"),
+    (0xF9C0, "\u{71CE}"),
+    (0xF9C1, "\u{7642}"),
+    (0xF9C2, "\u{84FC}"),
+    (0xF9C3, "\u{907C}"),
+    (0xF9C4, "\u{9F8D}"),
+    (0xF9C5, "\u{6688}"),
+    (0xF9C6, "\u{962E}"),
+    (0xF9C7, "\u{5289}"),
+    (0xF9C8, "\u{677B}"),
+    (0xF9C9, "\u{67F3}"),
+    (0xF9CA, "\u{6D41}"),
+    (0xF9CB, "\u{6E9C}"),
+    (0xF9CC, "\u{7409}"),
+    (0xF9CD, "\u{7559}"),
+    (0xF9CE, "\u{786B}"),
+    (0xF9CF, "\u{7D10}"),
+    (0xF9D0, "\u{985E}"),
+    (0xF9D1, "\u{516D}"),
+    (0xF9D2, "\u{622E}"),
+    (0xF9D3, "\u{9678}"),
+    (0xF9D4, "\u{502B}"),
+    (0xF9D5, "\u{5D19}"),
+    (0xF9D6, "\u{6DEA}"),
+    (0xF9D7, "\u{8F2A}"),
+    (0xF9D8, "\u{5F8B}"),
+    (0xF9D9, "\u{6144}"),
+    (0xF9DA, "\u{6817}"),
+    (0xF9DB, "\u{7387}"),
+    (0xF9DC, "\u{9686}"),
+    (0xF9DD, "\u{5229}"),
+    (0xF9DE, "\u{540F}"),
+    (0xF9DF, "\u{5C65}"),
+    (0xF9E0, "\u{6613}"),
+    (0xF9E1, "\u{674E}"),
+    (0xF9E2, "\u{68A8}"),
+    (0xF9E3, "\u{6CE5}"),
+    (0xF9E4, "\u{7406}"),
+    (0xF9E5, "\u{75E2}"),
+    (0xF9E6, "\u{7F79}"),
+    (0xF9E7, "\u{88CF}"),
+    (0xF9E8, "\u{88E1}"),
+    (0xF9E9, "\u{91CC}"),
+    (0xF9EA, "\u{96E2}"),
+    (0xF9EB, "\u{533F}"),
+    (0xF9EC, "\u{6EBA}"),
+    (0xF9ED, "\u{541D}"),
+    (0xF9EE, "\u{71D0}"),
+    (0xF9EF, "\u{7498}"),
+    (0xF9F0, "\u{85FA}"),
+    (0xF9F1, "\u{96A3}"),
+    (0xF9F2, "\u{9C57}"),
+    (0xF9F3, "\u{9E9F}"),
+    (0xF9F4, "\u{6797}"),
+    (0xF9F5, "\u{6DCB}"),
+    (0xF9F6, "\u{81E8}"),
+    (0xF9F7, "\u{7ACB}"),
+    (0xF9F8, "\u{7B20}"),
+    (0xF9F9, "\u{7C92}"),
+    (0xF9FA, "\u{72C0}"),
+    (0xF9FB, "\u{7099}"),
+    (0xF9FC, "\u{8B58}"),
+    (0xF9FD, "\u{4EC0}"),
+    (0xF9FE, "\u{8336}"),
+    (0xF9FF, "\u{523A}"),
+    (0xFA00, "\u{5207}"),
+    (0xFA01, "\u{5EA6}"),
+    (0xFA02, "\u{62D3}"),
+    (0xFA03, "\u{7CD6}"),
+    (0xFA04, "\u{5B85}"),
+    (0xFA05, "\u{6D1E}"),
+    (0xFA06, "\u{66B4}"),
+    (0xFA07, "\u{8F3B}"),
+    (0xFA08, "\u{884C}"),
+    (0xFA09, "\u{964D}"),
+    (0xFA0A, "\u{898B}"),
+    (0xFA0B, "\u{5ED3}"),
+    (0xFA0C, "\u{5140}"),
+    (0xFA0D, "\u{55C0}"),
+    (0xFA10, "\u{585A}"),
+    (0xFA12, "\u{6674}"),
+    (0xFA15, "\u{51DE}"),
+    (0xFA16, "\u{732A}"),
+    (0xFA17, "\u{76CA}"),
+    (0xFA18, "\u{793C}"),
+    (0xFA19, "\u{795E}"),
+    (0xFA1A, "\u{7965}"),
+    (0xFA1B, "\u{798F}"),
+    (0xFA1C, "\u{9756}"),
+    (0xFA1D, "\u{7CBE}"),
+    (0xFA1E, "\u{7FBD}"),
+    (0xFA20, "\u{8612}"),
+    (0xFA22, "\u{8AF8}"),
+    (0xFA25, "\u{9038}"),
+    (0xFA26, "\u{90FD}"),
+    (0xFA2A, "\u{98EF}"),
+    (0xFA2B, "\u{98FC}"),
+    (0xFA2C, "\u{9928}"),
+    (0xFA2D, "\u{9DB4}"),
+    (0xFA2E, "\u{90CE}"),
+    (0xFA2F, "\u{96B7}"),
+    (0xFA30, "\u{4FAE}"),
+    (0xFA31, "\u{50E7}"),
+    (0xFA32, "\u{514D}"),
+    (0xFA33, "\u{52C9}"),
+    (0xFA34, "\u{52E4}"),
+    (0xFA35, "\u{5351}"),
+    (0xFA36, "\u{559D}"),
+    (0xFA37, "\u{5606}"),
+    (0xFA38, "\u{5668}"),
+    (0xFA39, "\u{5840}"),
+    (0xFA3A, "\u{58A8}"),
+    (0xFA3B, "\u{5C64}"),
+    (0xFA3C, "\u{5C6E}"),
+    (0xFA3D, "\u{6094}"),
+    (0xFA3E, "\u{6168}"),
+    (0xFA3F, "\u{618E}"),
+    (0xFA40, "\u{61F2}"),
+    (0xFA41, "\u{654F}"),
+    (0xFA42, "\u{65E2}"),
+    (0xFA43, "\u{6691}"),
+    (0xFA44, "\u{6885}"),
+    (0xFA45, "\u{6D77}"),
+    (0xFA46, "\u{6E1A}"),
+    (0xFA47, "\u{6F22}"),
+    (0xFA48, "\u{716E}"),
+    (0xFA49, "\u{722B}"),
+    (0xFA4A, "\u{7422}"),
+    (0xFA4B, "\u{7891}"),
+    (0xFA4C, "\u{793E}"),
+    (0xFA4D, "\u{7949}"),
+    (0xFA4E, "\u{7948}"),
+    (0xFA4F, "\u{7950}"),
+    (0xFA50, "\u{7956}"),
+    (0xFA51, "\u{795D}"),
+    (0xFA52, "\u{798D}"),
+    (0xFA53, "\u{798E}"),
+    (0xFA54, "\u{7A40}"),
+    (0xFA55, "\u{7A81}"),
+    (0xFA56, "\u{7BC0}"),
+    (0xFA57, "\u{7DF4}"),
+    (0xFA58, "\u{7E09}"),
+    (0xFA59, "\u{7E41}"),
+    (0xFA5A, "\u{7F72}"),
+    (0xFA5B, "\u{8005}"),
+    (0xFA5C, "\u{81ED}"),
+    (0xFA5D, "\u{8279}"),
+    (0xFA5E, "\u{8279}"),
+    (0xFA5F, "\u{8457}"),
+    (0xFA60, "\u{8910}"),
+    (0xFA61, "\u{8996}"),
+    (0xFA62, "\u{8B01}"),
+    (0xFA63, "\u{8B39}"),
+    (0xFA64, "\u{8CD3}"),
+    (0xFA65, "\u{8D08}"),
+    (0xFA66, "\u{8FB6}"),
+    (0xFA67, "\u{9038}"),
+    (0xFA68, "\u{96E3}"),
+    (0xFA69, "\u{97FF}"),
+    (0xFA6A, "\u{983B}"),
+    (0xFA6B, "\u{6075}"),
+    (0xFA6C, "\u{242EE}"),
+    (0xFA6D, "\u{8218}"),
+    (0xFA70, "\u{4E26}"),
+    (0xFA71, "\u{51B5}"),
+    (0xFA72, "\u{5168}"),
+    (0xFA73, "\u{4F80}"),
+    (0xFA74, "\u{5145}"),
+    (0xFA75, "\u{5180}"),
+    (0xFA76, "\u{52C7}"),
+    (0xFA77, "\u{52FA}"),
+    (0xFA78, "\u{559D}"),
+    (0xFA79, "\u{5555}"),
+    (0xFA7A, "\u{5599}"),
+    (0xFA7B, "\u{55E2}"),
+    (0xFA7C, "\u{585A}"),
+    (0xFA7D, "\u{58B3}"),
+    (0xFA7E, "\u{5944}"),
+    (0xFA7F, "\u{5954}"),
+    (0xFA80, "\u{5A62}"),
+    (0xFA81, "\u{5B28}"),
+    (0xFA82, "\u{5ED2}"),
+    (0xFA83, "\u{5ED9}"),
+    (0xFA84, "\u{5F69}"),
+    (0xFA85, "\u{5FAD}"),
+    (0xFA86, "\u{60D8}"),
+    (0xFA87, "\u{614E}"),
+    (0xFA88, "\u{6108}"),
+    (0xFA89, "\u{618E}"),
+    (0xFA8A, "\u{6160}"),
+    (0xFA8B, "\u{61F2}"),
+    (0xFA8C, "\u{6234}"),
+    (0xFA8D, "\u{63C4}"),
+    (0xFA8E, "\u{641C}"),
+    (0xFA8F, "\u{6452}"),
+    (0xFA90, "\u{6556}"),
+    (0xFA91, "\u{6674}"),
+    (0xFA92, "\u{6717}"),
+    (0xFA93, "\u{671B}"),
+    (0xFA94, "\u{6756}"),
+    (0xFA95, "\u{6B79}"),
+    (0xFA96, "\u{6BBA}"),
+    (0xFA97, "\u{6D41}"),
+    (0xFA98, "\u{6EDB}"),
+    (0xFA99, "\u{6ECB}"),
+    (0xFA9A, "\u{6F22}"),
+    (0xFA9B, "\u{701E}"),
+    (0xFA9C, "\u{716E}"),
+    (0xFA9D, "\u{77A7}"),
+    (0xFA9E, "\u{7235}"),
+    (0xFA9F, "\u{72AF}"),
+    (0xFAA0, "\u{732A}"),
+    (0xFAA1, "\u{7471}"),
+    (0xFAA2, "\u{7506}"),
+    (0xFAA3, "\u{753B}"),
+    (0xFAA4, "\u{761D}"),
+    (0xFAA5, "\u{761F}"),
+    (0xFAA6, "\u{76CA}"),
+    (0xFAA7, "\u{76DB}"),
+    (0xFAA8, "\u{76F4}"),
+    (0xFAA9, "\u{774A}"),
+    (0xFAAA, "\u{7740}"),
+    (0xFAAB, "\u{78CC}"),
+    (0xFAAC, "\u{7AB1}"),
+    (0xFAAD, "\u{7BC0}"),
+    (0xFAAE, "\u{7C7B}"),
+    (0xFAAF, "\u{7D5B}"),
+    (0xFAB0, "\u{7DF4}"),
+    (0xFAB1, "\u{7F3E}"),
+    (0xFAB2, "\u{8005}"),
+    (0xFAB3, "\u{8352}"),
+    (0xFAB4, "\u{83EF}"),
+    (0xFAB5, "\u{8779}"),
+    (0xFAB6, "\u{8941}"),
+    (0xFAB7, "\u{8986}"),
+    (0xFAB8, "\u{8996}"),
+    (0xFAB9, "\u{8ABF}"),
+    (0xFABA, "\u{8AF8}"),
+    (0xFABB, "\u{8ACB}"),
+    (0xFABC, "\u{8B01}"),
+    (0xFABD, "\u{8AFE}"),
+    (0xFABE, "\u{8AED}"),
+    (0xFABF, "\u{8B39}"),
+    (0xFAC0, "\u{8B8A}"),
+    (0xFAC1, "\u{8D08}"),
+    (0xFAC2, "\u{8F38}"),
+    (0xFAC3, "\u{9072}"),
+    (0xFAC4, "\u{9199}"),
+    (0xFAC5, "\u{9276}"),
+    (0xFAC6, "\u{967C}"),
+    (0xFAC7, "\u{96E3}"),
+    (0xFAC8, "\u{9756}"),
+    (0xFAC9, "\u{97DB}"),
+    (0xFACA, "\u{97FF}"),
+    (0xFACB, "\u{980B}"),
+    (0xFACC, "\u{983B}"),
+    (0xFACD, "\u{9B12}"),
+    (0xFACE, "\u{9F9C}"),
+    (0xFACF, "\u{2284A}"),
+    (0xFAD0, "\u{22844}"),
+    (0xFAD1, "\u{233D5}"),
+    (0xFAD2, "\u{3B9D}"),
+    (0xFAD3, "\u{4018}"),
+    (0xFAD4, "\u{4039}"),
+    (0xFAD5, "\u{25249}"),
+    (0xFAD6, "\u{25CD0}"),
+    (0xFAD7, "\u{27ED3}"),
+    (0xFAD8, "\u{9F43}"),
+    (0xFAD9, "\u{9F8E}"),
+    (0xFB00, "ff"),
+    (0xFB01, "fi"),
+    (0xFB02, "fl"),
+    (0xFB03, "ffi"),
+    (0xFB04, "ffl"),
+    (0xFB06, "st"),
+    (0xFB13, "\u{574}\u{576}"),
+    (0xFB14, "\u{574}\u{565}"),
+    (0xFB15, "\u{574}\u{56B}"),
+    (0xFB16, "\u{57E}\u{576}"),
+    (0xFB17, "\u{574}\u{56D}"),
+    (0xFB20, "\u{5E2}"),
+    (0xFB21, "\u{5D0}"),
+    (0xFB22, "\u{5D3}"),
+    (0xFB23, "\u{5D4}"),
+    (0xFB24, "\u{5DB}"),
+    (0xFB25, "\u{5DC}"),
+    (0xFB26, "\u{5DD}"),
+    (0xFB27, "\u{5E8}"),
+    (0xFB28, "\u{5EA}"),
+    (0xFB29, "-\u{307}"),
+    (0xFB2B, "\u{FB2A}"),
+    (0xFB2D, "\u{FB2C}"),
+    (0xFB2F, "\u{FB2E}"),
+    (0xFB30, "\u{FB2E}"),
+    (0xFB39, "\u{FB1D}"),
+    (0xFB49, "\u{FB2A}"),
+    (0xFB4F, "\u{5D0}\u{5DC}"),
+    (0xFB50, "\u{671}"),
+    (0xFB51, "\u{671}"),
+    (0xFB52, "\u{67B}"),
+    (0xFB53, "\u{67B}"),
+    (0xFB54, "\u{67B}"),
+    (0xFB55, "\u{67B}"),
+    (0xFB56, "\u{649}\u{6DB}"),
+    (0xFB57, "\u{649}\u{6DB}"),
+    (0xFB58, "\u{649}\u{6DB}"),
+    (0xFB59, "\u{649}\u{6DB}"),
+    (0xFB5A, "\u{680}"),
+    (0xFB5B, "\u{680}"),
+    (0xFB5C, "\u{680}"),
+    (0xFB5D, "\u{680}"),
+    (0xFB5E, "\u{67A}"),
+    (0xFB5F, "\u{67A}"),
+    (0xFB60, "\u{67A}"),
+    (0xFB61, "\u{67A}"),
+    (0xFB62, "\u{67F}"),
+    (0xFB63, "\u{67F}"),
+    (0xFB64, "\u{67F}"),
+    (0xFB65, "\u{67F}"),
+    (0xFB66, "\u{649}\u{615}"),
+    (0xFB67, "\u{649}\u{615}"),
+    (0xFB68, "\u{649}\u{615}"),
+    (0xFB69, "\u{649}\u{615}"),
+    (0xFB6A, "\u{6A1}\u{6DB}"),
+    (0xFB6B, "\u{6A1}\u{6DB}"),
+    (0xFB6C, "\u{6A1}\u{6DB}"),
+    (0xFB6D, "\u{6A1}\u{6DB}"),
+    (0xFB6E, "\u{6A6}"),
+    (0xFB6F, "\u{6A6}"),
+    (0xFB70, "\u{6A6}"),
+    (0xFB71, "\u{6A6}"),
+    (0xFB72, "\u{684}"),
+    (0xFB73, "\u{684}"),
+    (0xFB74, "\u{684}"),
+    (0xFB75, "\u{684}"),
+    (0xFB76, "\u{683}"),
+    (0xFB77, "\u{683}"),
+    (0xFB78, "\u{683}"),
+    (0xFB79, "\u{683}"),
+    (0xFB7A, "\u{686}"),
+    (0xFB7B, "\u{686}"),
+    (0xFB7C, "\u{686}"),
+    (0xFB7D, "\u{686}"),
+    (0xFB7E, "\u{687}"),
+    (0xFB7F, "\u{687}"),
+    (0xFB80, "\u{687}"),
+    (0xFB81, "\u{687}"),
+    (0xFB82, "\u{68D}"),
+    (0xFB83, "\u{68D}"),
+    (0xFB84, "\u{68C}"),
+    (0xFB85, "\u{68C}"),
+    (0xFB86, "\u{62F}\u{6DB}"),
+    (0xFB87, "\u{62F}\u{6DB}"),
+    (0xFB88, "\u{62F}\u{615}"),
+    (0xFB89, "\u{62F}\u{615}"),
+    (0xFB8A, "\u{631}\u{6DB}"),
+    (0xFB8B, "\u{631}\u{6DB}"),
+    (0xFB8C, "\u{631}\u{615}"),
+    (0xFB8D, "\u{631}\u{615}"),
+    (0xFB8E, "\u{643}"),
+    (0xFB8F, "\u{643}"),
+    (0xFB90, "\u{643}"),
+    (0xFB91, "\u{643}"),
+    (0xFB92, "\u{6AF}"),
+    (0xFB93, "\u{6AF}"),
+    (0xFB94, "\u{6AF}"),
+    (0xFB95, "\u{6AF}"),
+    (0xFB96, "\u{6B3}"),
+    (0xFB97, "\u{6B3}"),
+    (0xFB98, "\u{6B3}"),
+    (0xFB99, "\u{6B3}"),
+    (0xFB9A, "\u{6B1}"),
+    (0xFB9B, "\u{6B1}"),
+    (0xFB9C, "\u{6B1}"),
+    (0xFB9D, "\u{6B1}"),
+    (0xFB9E, "\u{649}"),
+    (0xFB9F, "\u{649}"),
+    (0xFBA0, "\u{649}\u{615}"),
+    (0xFBA1, "\u{649}\u{615}"),
+    (0xFBA2, "\u{649}\u{615}"),
+    (0xFBA3, "\u{649}\u{615}"),
+    (0xFBA4, "\u{6C0}"),
+    (0xFBA5, "\u{6C0}"),
+    (0xFBA6, "o"),
+    (0xFBA7, "o"),
+    (0xFBA8, "o"),
+    (0xFBA9, "o"),
+    (0xFBAA, "o"),
+    (0xFBAB, "o"),
+    (0xFBAC, "o"),
+    (0xFBAD, "o"),
+    (0xFBAE, "\u{649}"),
+    (0xFBAF, "\u{649}"),
+    (0xFBB0, "\u{6D3}"),
+    (0xFBB1, "\u{6D3}"),
+    (0xFBD3, "\u{643}\u{6DB}"),
+    (0xFBD4, "\u{643}\u{6DB}"),
+    (0xFBD5, "\u{643}\u{6DB}"),
+    (0xFBD6, "\u{643}\u{6DB}"),
+    (0xFBD7, "\u{648}\u{313}"),
+    (0xFBD8, "\u{648}\u{313}"),
+    (0xFBD9, "\u{648}\u{306}"),
+    (0xFBDA, "\u{648}\u{306}"),
+    (0xFBDB, "\u{648}\u{670}"),
+    (0xFBDC, "\u{648}\u{670}"),
+    (0xFBDD, "\u{648}\u{313}\u{674}"),
+    (0xFBDE, "\u{648}\u{6DB}"),
+    (0xFBDF, "\u{648}\u{6DB}"),
+    (0xFBE0, "\u{6C5}"),
+    (0xFBE1, "\u{6C5}"),
+    (0xFBE2, "\u{648}\u{302}"),
+    (0xFBE3, "\u{648}\u{302}"),
+    (0xFBE4, "\u{67B}"),
+    (0xFBE5, "\u{67B}"),
+    (0xFBE6, "\u{67B}"),
+    (0xFBE7, "\u{67B}"),
+    (0xFBE8, "\u{649}"),
+    (0xFBE9, "\u{649}"),
+    (0xFBEA, "\u{649}\u{674}l"),
+    (0xFBEB, "\u{649}\u{674}l"),
+    (0xFBEC, "\u{649}\u{674}o"),
+    (0xFBED, "\u{649}\u{674}o"),
+    (0xFBEE, "\u{649}\u{674}\u{648}"),
+    (0xFBEF, "\u{649}\u{674}\u{648}"),
+    (0xFBF0, "\u{649}\u{674}\u{648}\u{313}"),
+    (0xFBF1, "\u{649}\u{674}\u{648}\u{313}"),
+    (0xFBF2, "\u{649}\u{674}\u{648}\u{306}"),
+    (0xFBF3, "\u{649}\u{674}\u{648}\u{306}"),
+    (0xFBF4, "\u{649}\u{674}\u{648}\u{670}"),
+    (0xFBF5, "\u{649}\u{674}\u{648}\u{670}"),
+    (0xFBF6, "\u{649}\u{674}\u{67B}"),
+    (0xFBF7, "\u{649}\u{674}\u{67B}"),
+    (0xFBF8, "\u{649}\u{674}\u{67B}"),
+    (0xFBF9, "\u{649}\u{674}\u{649}"),
+    (0xFBFA, "\u{649}\u{674}\u{649}"),
+    (0xFBFB, "\u{649}\u{674}\u{649}"),
+    (0xFBFC, "\u{649}"),
+    (0xFBFD, "\u{649}"),
+    (0xFBFE, "\u{649}"),
+    (0xFBFF, "\u{649}"),
+    (0xFC00, "\u{649}\u{674}\u{62C}"),
+    (0xFC01, "\u{649}\u{674}\u{62D}"),
+    (0xFC02, "\u{649}\u{674}\u{645}"),
+    (0xFC03, "\u{649}\u{674}\u{649}"),
+    (0xFC04, "\u{649}\u{674}\u{649}"),
+    (0xFC05, "\u{628}\u{62C}"),
+    (0xFC06, "\u{628}\u{62D}"),
+    (0xFC07, "\u{628}\u{62E}"),
+    (0xFC08, "\u{628}\u{645}"),
+    (0xFC09, "\u{628}\u{649}"),
+    (0xFC0A, "\u{628}\u{649}"),
+    (0xFC0B, "\u{62A}\u{62C}"),
+    (0xFC0C, "\u{62A}\u{62D}"),
+    (0xFC0D, "\u{62A}\u{62E}"),
+    (0xFC0E, "\u{62A}\u{645}"),
+    (0xFC0F, "\u{62A}\u{649}"),
+    (0xFC10, "\u{62A}\u{649}"),
+    (0xFC11, "\u{649}\u{6DB}\u{62C}"),
+    (0xFC12, "\u{649}\u{6DB}\u{645}"),
+    (0xFC13, "\u{649}\u{6DB}\u{649}"),
+    (0xFC14, "\u{649}\u{6DB}\u{649}"),
+    (0xFC15, "\u{62C}\u{62D}"),
+    (0xFC16, "\u{62C}\u{645}"),
+    (0xFC17, "\u{62D}\u{62C}"),
+    (0xFC18, "\u{62D}\u{645}"),
+    (0xFC19, "\u{62E}\u{62C}"),
+    (0xFC1A, "\u{62E}\u{62D}"),
+    (0xFC1B, "\u{62E}\u{645}"),
+    (0xFC1C, "\u{633}\u{62C}"),
+    (0xFC1D, "\u{633}\u{62D}"),
+    (0xFC1E, "\u{633}\u{62E}"),
+    (0xFC1F, "\u{633}\u{645}"),
+    (0xFC20, "\u{635}\u{62D}"),
+    (0xFC21, "\u{635}\u{645}"),
+    (0xFC22, "\u{636}\u{62C}"),
+    (0xFC23, "\u{636}\u{62D}"),
+    (0xFC24, "\u{636}\u{62E}"),
+    (0xFC25, "\u{636}\u{645}"),
+    (0xFC26, "\u{637}\u{62D}"),
+    (0xFC27, "\u{637}\u{645}"),
+    (0xFC28, "\u{638}\u{645}"),
+    (0xFC29, "\u{639}\u{62C}"),
+    (0xFC2A, "\u{639}\u{645}"),
+    (0xFC2B, "\u{63A}\u{62C}"),
+    (0xFC2C, "\u{63A}\u{645}"),
+    (0xFC2D, "\u{641}\u{62C}"),
+    (0xFC2E, "\u{641}\u{62D}"),
+    (0xFC2F, "\u{641}\u{62E}"),
+    (0xFC30, "\u{641}\u{645}"),
+    (0xFC31, "\u{641}\u{649}"),
+    (0xFC32, "\u{641}\u{649}"),
+    (0xFC33, "\u{642}\u{62D}"),
+    (0xFC34, "\u{642}\u{645}"),
+    (0xFC35, "\u{642}\u{649}"),
+    (0xFC36, "\u{642}\u{649}"),
+    (0xFC37, "\u{643}l"),
+    (0xFC38, "\u{643}\u{62C}"),
+    (0xFC39, "\u{643}\u{62D}"),
+    (0xFC3A, "\u{643}\u{62E}"),
+    (0xFC3B, "\u{643}\u{644}"),
+    (0xFC3C, "\u{643}\u{645}"),
+    (0xFC3D, "\u{643}\u{649}"),
+    (0xFC3E, "\u{643}\u{649}"),
+    (0xFC3F, "\u{644}\u{62C}"),
+    (0xFC40, "\u{644}\u{62D}"),
+    (0xFC41, "\u{644}\u{62E}"),
+    (0xFC42, "\u{644}\u{645}"),
+    (0xFC43, "\u{644}\u{649}"),
+    (0xFC44, "\u{644}\u{649}"),
+    (0xFC45, "\u{645}\u{62C}"),
+    (0xFC46, "\u{645}\u{62D}"),
+    (0xFC47, "\u{645}\u{62E}"),
+    (0xFC48, "\u{645}\u{645}"),
+    (0xFC49, "\u{645}\u{649}"),
+    (0xFC4A, "\u{645}\u{649}"),
+    (0xFC4B, "\u{628}\u{62E}"),
+    (0xFC4C, "\u{646}\u{62D}"),
+    (0xFC4D, "\u{646}\u{62E}"),
+    (0xFC4E, "\u{646}\u{645}"),
+    (0xFC4F, "\u{646}\u{649}"),
+    (0xFC50, "\u{646}\u{649}"),
+    (0xFC51, "o\u{62C}"),
+    (0xFC52, "o\u{645}"),
+    (0xFC53, "o\u{649}"),
+    (0xFC54, "o\u{649}"),
+    (0xFC55, "\u{649}\u{62C}"),
+    (0xFC56, "\u{649}\u{62D}"),
+    (0xFC57, "\u{649}\u{62E}"),
+    (0xFC58, "\u{649}\u{645}"),
+    (0xFC59, "\u{649}\u{649}"),
+    (0xFC5A, "\u{649}\u{649}"),
+    (0xFC5B, "\u{630}\u{670}"),
+    (0xFC5C, "\u{631}\u{670}"),
+    (0xFC5D, "\u{649}\u{670}"),
+    (0xFC5E, "\u{FE72}\u{651}"),
+    (0xFC5F, "\u{FE74}\u{651}"),
+    (0xFC60, "\u{FE76}\u{651}"),
+    (0xFC61, "\u{FE78}\u{651}"),
+    (0xFC62, "\u{FE7A}\u{651}"),
+    (0xFC63, "\u{FE7C}\u{670}"),
+    (0xFC64, "\u{649}\u{674}\u{631}"),
+    (0xFC65, "\u{649}\u{674}\u{632}"),
+    (0xFC66, "\u{649}\u{674}\u{645}"),
+    (0xFC67, "\u{649}\u{674}\u{646}"),
+    (0xFC68, "\u{649}\u{674}\u{649}"),
+    (0xFC69, "\u{649}\u{674}\u{649}"),
+    (0xFC6A, "\u{628}\u{631}"),
+    (0xFC6B, "\u{628}\u{632}"),
+    (0xFC6C, "\u{628}\u{645}"),
+    (0xFC6D, "\u{628}\u{646}"),
+    (0xFC6E, "\u{628}\u{649}"),
+    (0xFC6F, "\u{628}\u{649}"),
+    (0xFC70, "\u{62A}\u{631}"),
+    (0xFC71, "\u{62A}\u{632}"),
+    (0xFC72, "\u{62A}\u{645}"),
+    (0xFC73, "\u{62A}\u{646}"),
+    (0xFC74, "\u{62A}\u{649}"),
+    (0xFC75, "\u{62A}\u{649}"),
+    (0xFC76, "\u{649}\u{6DB}\u{631}"),
+    (0xFC77, "\u{649}\u{6DB}\u{632}"),
+    (0xFC78, "\u{649}\u{6DB}\u{645}"),
+    (0xFC79, "\u{649}\u{6DB}\u{646}"),
+    (0xFC7A, "\u{649}\u{6DB}\u{649}"),
+    (0xFC7B, "\u{649}\u{6DB}\u{649}"),
+    (0xFC7C, "\u{641}\u{649}"),
+    (0xFC7D, "\u{641}\u{649}"),
+    (0xFC7E, "\u{642}\u{649}"),
+    (0xFC7F, "\u{642}\u{649}"),
+    (0xFC80, "\u{643}l"),
+    (0xFC81, "\u{643}\u{644}"),
+    (0xFC82, "\u{643}\u{645}"),
+    (0xFC83, "\u{643}\u{649}"),
+    (0xFC84, "\u{643}\u{649}"),
+    (0xFC85, "\u{644}\u{645}"),
+    (0xFC86, "\u{644}\u{649}"),
+    (0xFC87, "\u{644}\u{649}"),
+    (0xFC88, "\u{645}l"),
+    (0xFC89, "\u{645}\u{645}"),
+    (0xFC8A, "\u{646}\u{631}"),
+    (0xFC8B, "\u{646}\u{632}"),
+    (0xFC8C, "\u{646}\u{645}"),
+    (0xFC8D, "\u{646}\u{646}"),
+    (0xFC8E, "\u{646}\u{649}"),
+    (0xFC8F, "\u{646}\u{649}"),
+    (0xFC90, "\u{649}\u{670}"),
+    (0xFC91, "\u{649}\u{631}"),
+    (0xFC92, "\u{649}\u{632}"),
+    (0xFC93, "\u{649}\u{645}"),
+    (0xFC94, "\u{649}\u{646}"),
+    (0xFC95, "\u{649}\u{649}"),
+    (0xFC96, "\u{649}\u{649}"),
+    (0xFC97, "\u{649}\u{674}\u{62C}"),
+    (0xFC98, "\u{649}\u{674}\u{62D}"),
+    (0xFC99, "\u{649}\u{674}\u{62E}"),
+    (0xFC9A, "\u{649}\u{674}\u{645}"),
+    (0xFC9B, "\u{649}\u{674}o"),
+    (0xFC9C, "\u{628}\u{62C}"),
+    (0xFC9D, "\u{628}\u{62D}"),
+    (0xFC9E, "\u{628}\u{62E}"),
+    (0xFC9F, "\u{628}\u{645}"),
+    (0xFCA0, "\u{628}o"),
+    (0xFCA1, "\u{62A}\u{62C}"),
+    (0xFCA2, "\u{62A}\u{62D}"),
+    (0xFCA3, "\u{62A}\u{62E}"),
+    (0xFCA4, "\u{62A}\u{645}"),
+    (0xFCA5, "\u{62A}o"),
+    (0xFCA6, "\u{649}\u{6DB}\u{645}"),
+    (0xFCA7, "\u{62C}\u{62D}"),
+    (0xFCA8, "\u{62C}\u{645}"),
+    (0xFCA9, "\u{62D}\u{62C}"),
+    (0xFCAA, "\u{62D}\u{645}"),
+    (0xFCAB, "\u{62E}\u{62C}"),
+    (0xFCAC, "\u{62E}\u{645}"),
+    (0xFCAD, "\u{633}\u{62C}"),
+    (0xFCAE, "\u{633}\u{62D}"),
+    (0xFCAF, "\u{633}\u{62E}"),
+    (0xFCB0, "\u{633}\u{645}"),
+    (0xFCB1, "\u{635}\u{62D}"),
+    (0xFCB2, "\u{635}\u{62E}"),
+    (0xFCB3, "\u{635}\u{645}"),
+    (0xFCB4, "\u{636}\u{62C}"),
+    (0xFCB5, "\u{636}\u{62D}"),
+    (0xFCB6, "\u{636}\u{62E}"),
+    (0xFCB7, "\u{636}\u{645}"),
+    (0xFCB8, "\u{637}\u{62D}"),
+    (0xFCB9, "\u{638}\u{645}"),
+    (0xFCBA, "\u{639}\u{62C}"),
+    (0xFCBB, "\u{639}\u{645}"),
+    (0xFCBC, "\u{63A}\u{62C}"),
+    (0xFCBD, "\u{63A}\u{645}"),
+    (0xFCBE, "\u{641}\u{62C}"),
+    (0xFCBF, "\u{641}\u{62D}"),
+    (0xFCC0, "\u{641}\u{62E}"),
+    (0xFCC1, "\u{641}\u{645}"),
+    (0xFCC2, "\u{642}\u{62D}"),
+    (0xFCC3, "\u{642}\u{645}"),
+    (0xFCC4, "\u{643}\u{62C}"),
+    (0xFCC5, "\u{643}\u{62D}"),
+    (0xFCC6, "\u{643}\u{62E}"),
+    (0xFCC7, "\u{643}\u{644}"),
+    (0xFCC8, "\u{643}\u{645}"),
+    (0xFCC9, "\u{644}\u{62C}"),
+    (0xFCCA, "\u{644}\u{62D}"),
+    (0xFCCB, "\u{644}\u{62E}"),
+    (0xFCCC, "\u{644}\u{645}"),
+    (0xFCCD, "\u{644}o"),
+    (0xFCCE, "\u{645}\u{62C}"),
+    (0xFCCF, "\u{645}\u{62D}"),
+    (0xFCD0, "\u{645}\u{62E}"),
+    (0xFCD1, "\u{645}\u{645}"),
+    (0xFCD2, "\u{628}\u{62E}"),
+    (0xFCD3, "\u{646}\u{62D}"),
+    (0xFCD4, "\u{646}\u{62E}"),
+    (0xFCD5, "\u{646}\u{645}"),
+    (0xFCD6, "\u{646}o"),
+    (0xFCD7, "o\u{62C}"),
+    (0xFCD8, "o\u{645}"),
+    (0xFCD9, "o\u{670}"),
+    (0xFCDA, "\u{649}\u{62C}"),
+    (0xFCDB, "\u{649}\u{62D}"),
+    (0xFCDC, "\u{649}\u{62E}"),
+    (0xFCDD, "\u{649}\u{645}"),
+    (0xFCDE, "\u{649}o"),
+    (0xFCDF, "\u{649}\u{674}\u{645}"),
+    (0xFCE0, "\u{649}\u{674}o"),
+    (0xFCE1, "\u{628}\u{645}"),
+    (0xFCE2, "\u{628}o"),
+    (0xFCE3, "\u{62A}\u{645}"),
+    (0xFCE4, "\u{62A}o"),
+    (0xFCE5, "\u{649}\u{6DB}\u{645}"),
+    (0xFCE6, "\u{649}\u{6DB}o"),
+    (0xFCE7, "\u{633}\u{645}"),
+    (0xFCE8, "\u{633}o"),
+    (0xFCE9, "\u{633}\u{6DB}\u{645}"),
+    (0xFCEA, "\u{633}\u{6DB}o"),
+    (0xFCEB, "\u{643}\u{644}"),
+    (0xFCEC, "\u{643}\u{645}"),
+    (0xFCED, "\u{644}\u{645}"),
+    (0xFCEE, "\u{646}\u{645}"),
+    (0xFCEF, "\u{646}o"),
+    (0xFCF0, "\u{649}\u{645}"),
+    (0xFCF1, "\u{649}o"),
+    (0xFCF2, "\u{FE77}\u{651}"),
+    (0xFCF3, "\u{FE79}\u{651}"),
+    (0xFCF4, "\u{FE7B}\u{651}"),
+    (0xFCF5, "\u{637}\u{649}"),
+    (0xFCF6, "\u{637}\u{649}"),
+    (0xFCF7, "\u{639}\u{649}"),
+    (0xFCF8, "\u{639}\u{649}"),
+    (0xFCF9, "\u{63A}\u{649}"),
+    (0xFCFA, "\u{63A}\u{649}"),
+    (0xFCFB, "\u{633}\u{649}"),
+    (0xFCFC, "\u{633}\u{649}"),
+    (0xFCFD, "\u{633}\u{6DB}\u{649}"),
+    (0xFCFE, "\u{633}\u{6DB}\u{649}"),
+    (0xFCFF, "\u{62D}\u{649}"),
+    (0xFD00, "\u{62D}\u{649}"),
+    (0xFD01, "\u{62C}\u{649}"),
+    (0xFD02, "\u{62C}\u{649}"),
+    (0xFD03, "\u{62E}\u{649}"),
+    (0xFD04, "\u{62E}\u{649}"),
+    (0xFD05, "\u{635}\u{649}"),
+    (0xFD06, "\u{635}\u{649}"),
+    (0xFD07, "\u{636}\u{649}"),
+    (0xFD08, "\u{636}\u{649}"),
+    (0xFD09, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD0A, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD0B, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD0C, "\u{633}\u{6DB}\u{645}"),
+    (0xFD0D, "\u{633}\u{6DB}\u{631}"),
+    (0xFD0E, "\u{633}\u{631}"),
+    (0xFD0F, "\u{635}\u{631}"),
+    (0xFD10, "\u{636}\u{631}"),
+    (0xFD11, "\u{637}\u{649}"),
+    (0xFD12, "\u{637}\u{649}"),
+    (0xFD13, "\u{639}\u{649}"),
+    (0xFD14, "\u{639}\u{649}"),
+    (0xFD15, "\u{63A}\u{649}"),
+    (0xFD16, "\u{63A}\u{649}"),
+    (0xFD17, "\u{633}\u{649}"),
+    (0xFD18, "\u{633}\u{649}"),
+    (0xFD19, "\u{633}\u{6DB}\u{649}"),
+    (0xFD1A, "\u{633}\u{6DB}\u{649}"),
+    (0xFD1B, "\u{62D}\u{649}"),
+    (0xFD1C, "\u{62D}\u{649}"),
+    (0xFD1D, "\u{62C}\u{649}"),
+    (0xFD1E, "\u{62C}\u{649}"),
+    (0xFD1F, "\u{62E}\u{649}"),
+    (0xFD20, "\u{62E}\u{649}"),
+    (0xFD21, "\u{635}\u{649}"),
+    (0xFD22, "\u{635}\u{649}"),
+    (0xFD23, "\u{636}\u{649}"),
+    (0xFD24, "\u{636}\u{649}"),
+    (0xFD25, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD26, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD27, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD28, "\u{633}\u{6DB}\u{645}"),
+    (0xFD29, "\u{633}\u{6DB}\u{631}"),
+    (0xFD2A, "\u{633}\u{631}"),
+    (0xFD2B, "\u{635}\u{631}"),
+    (0xFD2C, "\u{636}\u{631}"),
+    (0xFD2D, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD2E, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD2F, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD30, "\u{633}\u{6DB}\u{645}"),
+    (0xFD31, "\u{633}o"),
+    (0xFD32, "\u{633}\u{6DB}o"),
+    (0xFD33, "\u{637}\u{645}"),
+    (0xFD34, "\u{633}\u{62C}"),
+    (0xFD35, "\u{633}\u{62D}"),
+    (0xFD36, "\u{633}\u{62E}"),
+    (0xFD37, "\u{633}\u{6DB}\u{62C}"),
+    (0xFD38, "\u{633}\u{6DB}\u{62D}"),
+    (0xFD39, "\u{633}\u{6DB}\u{62E}"),
+    (0xFD3A, "\u{637}\u{645}"),
+    (0xFD3B, "\u{638}\u{645}"),
+    (0xFD3C, "l\u{30B}"),
+    (0xFD3D, "l\u{30B}"),
+    (0xFD3E, "("),
+    (0xFD3F, ")"),
+    (0xFD50, "\u{62A}\u{62C}\u{645}"),
+    (0xFD51, "\u{62A}\u{62D}\u{62C}"),
+    (0xFD52, "\u{62A}\u{62D}\u{62C}"),
+    (0xFD53, "\u{62A}\u{62D}\u{645}"),
+    (0xFD54, "\u{62A}\u{62E}\u{645}"),
+    (0xFD55, "\u{62A}\u{645}\u{62C}"),
+    (0xFD56, "\u{62A}\u{645}\u{62D}"),
+    (0xFD57, "\u{62A}\u{645}\u{62E}"),
+    (0xFD58, "\u{62C}\u{645}\u{62D}"),
+    (0xFD59, "\u{62C}\u{645}\u{62D}"),
+    (0xFD5A, "\u{62D}\u{645}\u{649}"),
+    (0xFD5B, "\u{62D}\u{645}\u{649}"),
+    (0xFD5C, "\u{633}\u{62D}\u{62C}"),
+    (0xFD5D, "\u{633}\u{62C}\u{62D}"),
+    (0xFD5E, "\u{633}\u{62C}\u{649}"),
+    (0xFD5F, "\u{633}\u{645}\u{62D}"),
+    (0xFD60, "\u{633}\u{645}\u{62D}"),
+    (0xFD61, "\u{633}\u{645}\u{62C}"),
+    (0xFD62, "\u{633}\u{645}\u{645}"),
+    (0xFD63, "\u{633}\u{645}\u{645}"),
+    (0xFD64, "\u{635}\u{62D}\u{62D}"),
+    (0xFD65, "\u{635}\u{62D}\u{62D}"),
+    (0xFD66, "\u{635}\u{645}\u{645}"),
+    (0xFD67, "\u{633}\u{6DB}\u{62D}\u{645}"),
+    (0xFD68, "\u{633}\u{6DB}\u{62D}\u{645}"),
+    (0xFD69, "\u{633}\u{6DB}\u{62C}\u{649}"),
+    (0xFD6A, "\u{633}\u{6DB}\u{645}\u{62E}"),
+    (0xFD6B, "\u{633}\u{6DB}\u{645}\u{62E}"),
+    (0xFD6C, "\u{633}\u{6DB}\u{645}\u{645}"),
+    (0xFD6D, "\u{633}\u{6DB}\u{645}\u{645}"),
+    (0xFD6E, "\u{636}\u{62D}\u{649}"),
+    (0xFD6F, "\u{636}\u{62E}\u{645}"),
+    (0xFD70, "\u{636}\u{62E}\u{645}"),
+    (0xFD71, "\u{637}\u{645}\u{62D}"),
+    (0xFD72, "\u{637}\u{645}\u{62D}"),
+    (0xFD73, "\u{637}\u{645}\u{645}"),
+    (0xFD74, "\u{637}\u{645}\u{649}"),
+    (0xFD75, "\u{639}\u{62C}\u{645}"),
+    (0xFD76, "\u{639}\u{645}\u{645}"),
+    (0xFD77, "\u{639}\u{645}\u{645}"),
+    (0xFD78, "\u{639}\u{645}\u{649}"),
+    (0xFD79, "\u{63A}\u{645}\u{645}"),
+    (0xFD7A, "\u{63A}\u{645}\u{649}"),
+    (0xFD7B, "\u{63A}\u{645}\u{649}"),
+    (0xFD7C, "\u{641}\u{62E}\u{645}"),
+    (0xFD7D, "\u{641}\u{62E}\u{645}"),
+    (0xFD7E, "\u{642}\u{645}\u{62D}"),
+    (0xFD7F, "\u{642}\u{645}\u{645}"),
+    (0xFD80, "\u{644}\u{62D}\u{645}"),
+    (0xFD81, "\u{644}\u{62D}\u{649}"),
+    (0xFD82, "\u{644}\u{62D}\u{649}"),
+    (0xFD83, "\u{644}\u{62C}\u{62C}"),
+    (0xFD84, "\u{644}\u{62C}\u{62C}"),
+    (0xFD85, "\u{644}\u{62E}\u{645}"),
+    (0xFD86, "\u{644}\u{62E}\u{645}"),
+    (0xFD87, "\u{644}\u{645}\u{62D}"),
+    (0xFD88, "\u{644}\u{645}\u{62D}"),
+    (0xFD89, "\u{645}\u{62D}\u{62C}"),
+    (0xFD8A, "\u{645}\u{62D}\u{645}"),
+    (0xFD8B, "\u{645}\u{62D}\u{649}"),
+    (0xFD8C, "\u{645}\u{62C}\u{62D}"),
+    (0xFD8D, "\u{645}\u{62C}\u{645}"),
+    (0xFD8E, "\u{645}\u{62E}\u{62C}"),
+    (0xFD8F, "\u{645}\u{62E}\u{645}"),
+    (0xFD92, "\u{645}\u{62C}\u{62E}"),
+    (0xFD93, "o\u{645}\u{62C}"),
+    (0xFD94, "o\u{645}\u{645}"),
+    (0xFD95, "\u{646}\u{62D}\u{645}"),
+    (0xFD96, "\u{646}\u{62D}\u{649}"),
+    (0xFD97, "\u{646}\u{62C}\u{645}"),
+    (0xFD98, "\u{646}\u{62C}\u{645}"),
+    (0xFD99, "\u{646}\u{62C}\u{649}"),
+    (0xFD9A, "\u{646}\u{645}\u{649}"),
+    (0xFD9B, "\u{646}\u{645}\u{649}"),
+    (0xFD9C, "\u{649}\u{645}\u{645}"),
+    (0xFD9D, "\u{649}\u{645}\u{645}"),
+    (0xFD9E, "\u{628}\u{62E}\u{649}"),
+    (0xFD9F, "\u{62A}\u{62C}\u{649}"),
+    (0xFDA0, "\u{62A}\u{62C}\u{649}"),
+    (0xFDA1, "\u{62A}\u{62E}\u{649}"),
+    (0xFDA2, "\u{62A}\u{62E}\u{649}"),
+    (0xFDA3, "\u{62A}\u{645}\u{649}"),
+    (0xFDA4, "\u{62A}\u{645}\u{649}"),
+    (0xFDA5, "\u{62C}\u{645}\u{649}"),
+    (0xFDA6, "\u{62C}\u{62D}\u{649}"),
+    (0xFDA7, "\u{62C}\u{645}\u{649}"),
+    (0xFDA8, "\u{633}\u{62E}\u{649}"),
+    (0xFDA9, "\u{635}\u{62D}\u{649}"),
+    (0xFDAA, "\u{633}\u{6DB}\u{62D}\u{649}"),
+    (0xFDAB, "\u{636}\u{62D}\u{649}"),
+    (0xFDAC, "\u{644}\u{62C}\u{649}"),
+    (0xFDAD, "\u{644}\u{645}\u{649}"),
+    (0xFDAE, "\u{649}\u{62D}\u{649}"),
+    (0xFDAF, "\u{649}\u{62C}\u{649}"),
+    (0xFDB0, "\u{649}\u{645}\u{649}"),
+    (0xFDB1, "\u{645}\u{645}\u{649}"),
+    (0xFDB2, "\u{642}\u{645}\u{649}"),
+    (0xFDB3, "\u{646}\u{62D}\u{649}"),
+    (0xFDB4, "\u{642}\u{645}\u{62D}"),
+    (0xFDB5, "\u{644}\u{62D}\u{645}"),
+    (0xFDB6, "\u{639}\u{645}\u{649}"),
+    (0xFDB7, "\u{643}\u{645}\u{649}"),
+    (0xFDB8, "\u{646}\u{62C}\u{62D}"),
+    (0xFDB9, "\u{645}\u{62E}\u{649}"),
+    (0xFDBA, "\u{644}\u{62C}\u{645}"),
+    (0xFDBB, "\u{643}\u{645}\u{645}"),
+    (0xFDBC, "\u{644}\u{62C}\u{645}"),
+    (0xFDBD, "\u{646}\u{62C}\u{62D}"),
+    (0xFDBE, "\u{62C}\u{62D}\u{649}"),
+    (0xFDBF, "\u{62D}\u{62C}\u{649}"),
+    (0xFDC0, "\u{645}\u{62C}\u{649}"),
+    (0xFDC1, "\u{641}\u{645}\u{649}"),
+    (0xFDC2, "\u{628}\u{62D}\u{649}"),
+    (0xFDC3, "\u{643}\u{645}\u{645}"),
+    (0xFDC4, "\u{639}\u{62C}\u{645}"),
+    (0xFDC5, "\u{635}\u{645}\u{645}"),
+    (0xFDC6, "\u{633}\u{62E}\u{649}"),
+    (0xFDC7, "\u{646}\u{62C}\u{649}"),
+    (0xFDF0, "\u{635}\u{644}\u{649}"),
+    (0xFDF1, "\u{642}\u{644}\u{649}"),
+    (0xFDF2, "l\u{644}\u{644}\u{651}\u{670}o"),
+    (0xFDF3, "l\u{643}\u{628}\u{631}"),
+    (0xFDF4, "\u{645}\u{62D}\u{645}\u{62F}"),
+    (0xFDF5, "\u{635}\u{644}\u{639}\u{645}"),
+    (0xFDF6, "\u{631}\u{633}\u{648}\u{644}"),
+    (0xFDF7, "\u{639}\u{644}\u{649}o"),
+    (0xFDF8, "\u{648}\u{633}\u{644}\u{645}"),
+    (0xFDF9, "\u{635}\u{644}\u{649}"),
+    (0xFDFA, "\u{635}\u{644}\u{649} l\u{644}\u{644}o \u{639}\u{644}\u{649}o \u{648}\u{633}\u{644}\u{645}"),
+    (0xFDFB, "\u{62C}\u{644} \u{62C}\u{644}l\u{644}o"),
+    (0xFDFC, "\u{631}\u{649}l\u{644}"),
+    (0xFE19, "\u{2D57}"),
+    (0xFE30, ":"),
+    (0xFE31, "\u{2502}"),
+    (0xFE34, "\u{2307}"),
+    (0xFE35, "\u{23DC}"),
+    (0xFE36, "\u{23DD}"),
+    (0xFE37, "\u{23DE}"),
+    (0xFE38, "\u{23DF}"),
+    (0xFE39, "\u{23E0}"),
+    (0xFE3A, "\u{23E1}"),
+    (0xFE49, "\u{2C9}"),
+    (0xFE4A, "\u{2C9}"),
+    (0xFE4B, "\u{2C9}"),
+    (0xFE4C, "\u{2C9}"),
+    (0xFE4D, "_"),
+    (0xFE4E, "_"),
+    (0xFE4F, "_"),
+    (0xFE58, "-"),
+    (0xFE68, "\\"),
+    (0xFE80, "\u{621}"),
+    (0xFE81, "\u{622}"),
+    (0xFE82, "\u{622}"),
+    (0xFE83, "l\u{674}"),
+    (0xFE84, "l\u{674}"),
+    (0xFE85, "\u{648}\u{674}"),
+    (0xFE86, "\u{648}\u{674}"),
+    (0xFE87, "l\u{655}"),
+    (0xFE88, "l\u{655}"),
+    (0xFE89, "\u{649}\u{674}"),
+    (0xFE8A, "\u{649}\u{674}"),
+    (0xFE8B, "\u{649}\u{674}"),
+    (0xFE8C, "\u{649}\u{674}"),
+    (0xFE8D, "l"),
+    (0xFE8E, "l"),
+    (0xFE8F, "\u{628}"),
+    (0xFE90, "\u{628}"),
+    (0xFE91, "\u{628}"),
+    (0xFE92, "\u{628}"),
+    (0xFE93, "\u{629}"),
+    (0xFE94, "\u{629}"),
+    (0xFE95, "\u{62A}"),
+    (0xFE96, "\u{62A}"),
+    (0xFE97, "\u{62A}"),
+    (0xFE98, "\u{62A}"),
+    (0xFE99, "\u{649}\u{6DB}"),
+    (0xFE9A, "\u{649}\u{6DB}"),
+    (0xFE9B, "\u{649}\u{6DB}"),
+    (0xFE9C, "\u{649}\u{6DB}"),
+    (0xFE9D, "\u{62C}"),
+    (0xFE9E, "\u{62C}"),
+    (0xFE9F, "\u{62C}"),
+    (0xFEA0, "\u{62C}"),
+    (0xFEA1, "\u{62D}"),
+    (0xFEA2, "\u{62D}"),
+    (0xFEA3, "\u{62D}"),
+    (0xFEA4, "\u{62D}"),
+    (0xFEA5, "\u{62E}"),
+    (0xFEA6, "\u{62E}"),
+    (0xFEA7, "\u{62E}"),
+    (0xFEA8, "\u{62E}"),
+    (0xFEA9, "\u{62F}"),
+    (0xFEAA, "\u{62F}"),
+    (0xFEAB, "\u{630}"),
+    (0xFEAC, "\u{630}"),
+    (0xFEAD, "\u{631}"),
+    (0xFEAE, "\u{631}"),
+    (0xFEAF, "\u{632}"),
+    (0xFEB0, "\u{632}"),
+    (0xFEB1, "\u{633}"),
+    (0xFEB2, "\u{633}"),
+    (0xFEB3, "\u{633}"),
+    (0xFEB4, "\u{633}"),
+    (0xFEB5, "\u{633}\u{6DB}"),
+    (0xFEB6, "\u{633}\u{6DB}"),
+    (0xFEB7, "\u{633}\u{6DB}"),
+    (0xFEB8, "\u{633}\u{6DB}"),
+    (0xFEB9, "\u{635}"),
+    (0xFEBA, "\u{635}"),
+    (0xFEBB, "\u{635}"),
+    (0xFEBC, "\u{635}"),
+    (0xFEBD, "\u{636}"),
+    (0xFEBE, "\u{636}"),
+    (0xFEBF, "\u{636}"),
+    (0xFEC0, "\u{636}"),
+    (0xFEC1, "\u{637}"),
+    (0xFEC2, "\u{637}"),
+    (0xFEC3, "\u{637}"),
+    (0xFEC4, "\u{637}"),
+    (0xFEC5, "\u{638}"),
+    (0xFEC6, "\u{638}"),
+    (0xFEC7, "\u{638}"),
+    (0xFEC8, "\u{638}"),
+    (0xFEC9, "\u{639}"),
+    (0xFECA, "\u{639}"),
+    (0xFECB, "\u{639}"),
+    (0xFECC, "\u{639}"),
+    (0xFECD, "\u{63A}"),
+    (0xFECE, "\u{63A}"),
+    (0xFECF, "\u{63A}"),
+    (0xFED0, "\u{63A}"),
+    (0xFED1, "\u{641}"),
+    (0xFED2, "\u{641}"),
+    (0xFED3, "\u{641}"),
+    (0xFED4, "\u{641}"),
+    (0xFED5, "\u{642}"),
+    (0xFED6, "\u{642}"),
+    (0xFED7, "\u{642}"),
+    (0xFED8, "\u{642}"),
+    (0xFED9, "\u{643}"),
+    (0xFEDA, "\u{643}"),
+    (0xFEDB, "\u{643}"),
+    (0xFEDC, "\u{643}"),
+    (0xFEDD, "\u{644}"),
+    (0xFEDE, "\u{644}"),
+    (0xFEDF, "\u{644}"),
+    (0xFEE0, "\u{644}"),
+    (0xFEE1, "\u{645}"),
+    (0xFEE2, "\u{645}"),
+    (0xFEE3, "\u{645}"),
+    (0xFEE4, "\u{645}"),
+    (0xFEE5, "\u{646}"),
+    (0xFEE6, "\u{646}"),
+    (0xFEE7, "\u{646}"),
+    (0xFEE8, "\u{646}"),
+    (0xFEE9, "o"),
+    (0xFEEA, "o"),
+    (0xFEEB, "o"),
+    (0xFEEC, "o"),
+    (0xFEED, "\u{648}"),
+    (0xFEEE, "\u{648}"),
+    (0xFEEF, "\u{649}"),
+    (0xFEF0, "\u{649}"),
+    (0xFEF1, "\u{649}"),
+    (0xFEF2, "\u{649}"),
+    (0xFEF3, "\u{649}"),
+    (0xFEF4, "\u{649}"),
+    (0xFEF5, "\u{644}\u{622}"),
+    (0xFEF6, "\u{644}\u{622}"),
+    (0xFEF7, "\u{644}l\u{674}"),
+    (0xFEF8, "\u{644}l\u{674}"),
+    (0xFEF9, "\u{644}l\u{655}"),
+    (0xFEFA, "\u{644}l\u{655}"),
+    (0xFEFB, "\u{644}l"),
+    (0xFEFC, "\u{644}l"),
+    (0xFF01, "!"),
+    (0xFF02, "''"),
+    (0xFF07, "'"),
+    (0xFF0D, "\u{30FC}"),
+    (0xFF1A, ":"),
+    (0xFF21, "A"),
+    (0xFF22, "B"),
+    (0xFF23, "C"),
+    (0xFF25, "E"),
+    (0xFF28, "H"),
+    (0xFF29, "l"),
+    (0xFF2A, "J"),
+    (0xFF2B, "K"),
+    (0xFF2D, "M"),
+    (0xFF2E, "N"),
+    (0xFF2F, "O"),
+    (0xFF30, "P"),
+    (0xFF33, "S"),
+    (0xFF34, "T"),
+    (0xFF38, "X"),
+    (0xFF39, "Y"),
+    (0xFF3A, "Z"),
+    (0xFF3B, "("),
+    (0xFF3C, "\\"),
+    (0xFF3D, ")"),
+    (0xFF3E, "\u{FE3F}"),
+    (0xFF40, "'"),
+    (0xFF41, "a"),
+    (0xFF43, "c"),
+    (0xFF45, "e"),
+    (0xFF47, "g"),
+    (0xFF48, "h"),
+    (0xFF49, "i"),
+    (0xFF4A, "j"),
+    (0xFF4C, "l"),
+    (0xFF4F, "o"),
+    (0xFF50, "p"),
+    (0xFF53, "s"),
+    (0xFF56, "v"),
+    (0xFF58, "x"),
+    (0xFF59, "y"),
+    (0xFF5C, "\u{2502}"),
+    (0xFF5E, "\u{301C}"),
+    (0xFF65, "\u{B7}"),
+    (0xFFE3, "\u{2C9}"),
+    (0xFFE8, "l"),
+    (0xFFED, "\u{25AA}"),
+    (0x10101, "\u{B7}"),
+    (0x1018E, "N\u{30A}"),
+    (0x10196, "X\u{335}"),
+    (0x10197, "V\u{335}"),
+    (0x10198, "l\u{335}l\u{335}S\u{335}"),
+    (0x10199, "l\u{335}l\u{335}"),
+    (0x101A0, "\u{2CE8}"),
+    (0x10282, "B"),
+    (0x10285, "\u{394}"),
+    (0x10286, "E"),
+    (0x10287, "F"),
+    (0x1028A, "l"),
+    (0x1028D, "\u{245}"),
+    (0x10290, "X"),
+    (0x10292, "O"),
+    (0x10294, "\u{16DC}"),
+    (0x10295, "P"),
+    (0x10296, "S"),
+    (0x10297, "T"),
+    (0x1029B, "+"),
+    (0x102A0, "A"),
+    (0x102A1, "B"),
+    (0x102A2, "C"),
+    (0x102A3, "\u{394}"),
+    (0x102A5, "F"),
+    (0x102AB, "O"),
+    (0x102AD, "\u{3D8}"),
+    (0x102B0, "M"),
+    (0x102B1, "T"),
+    (0x102B2, "Y"),
+    (0x102B3, "\u{3A6}"),
+    (0x102B4, "X"),
+    (0x102B5, "\u{3A8}"),
+    (0x102B6, "\u{3A9}"),
+    (0x102B8, "\u{2D40}"),
+    (0x102CF, "H"),
+    (0x102E1, "\u{62F}"),
+    (0x102E4, "\u{648}"),
+    (0x102E8, "\u{637}"),
+    (0x102F2, "\u{635}"),
+    (0x102F5, "Z"),
+    (0x10301, "B"),
+    (0x10302, "C"),
+    (0x10309, "l"),
+    (0x10311, "M"),
+    (0x10312, "\u{3D8}"),
+    (0x10315, "T"),
+    (0x10317, "X"),
+    (0x1031A, "8"),
+    (0x1031F, "*"),
+    (0x10320, "l"),
+    (0x10322, "X"),
+    (0x103D1, "\u{10382}"),
+    (0x103D3, "\u{10393}"),
+    (0x10401, "\u{190}"),
+    (0x10404, "O"),
+    (0x10411, "\u{A4F6}"),
+    (0x10415, "C"),
+    (0x1041B, "L"),
+    (0x1041F, "\u{2C70}"),
+    (0x10420, "S"),
+    (0x10423, "\u{186}"),
+    (0x10425, "\u{418}"),
+    (0x10429, "\u{A793}"),
+    (0x1042A, "\u{29A}"),
+    (0x1042C, "o"),
+    (0x1043D, "c"),
+    (0x1043F, "\u{277}"),
+    (0x10442, "\u{25E}"),
+    (0x10443, "\u{29F}"),
+    (0x10448, "s"),
+    (0x1044B, "\u{254}"),
+    (0x1044D, "\u{1D0E}"),
+    (0x104A0, "\u{10486}"),
+    (0x104B0, "\u{245}"),
+    (0x104B4, "R"),
+    (0x104BC, "\u{4C3}"),
+    (0x104C2, "O"),
+    (0x104C3, "\u{298}"),
+    (0x104C4, "\u{DE}"),
+    (0x104CD, "\u{40B}"),
+    (0x104CE, "U"),
+    (0x104D0, "\u{16E6}"),
+    (0x104D1, "\u{3A8}"),
+    (0x104D2, "7"),
+    (0x104D8, "\u{28C}"),
+    (0x104DB, "\u{3BB}"),
+    (0x104EA, "o"),
+    (0x104EB, "\u{A669}"),
+    (0x104F6, "u"),
+    (0x104F9, "\u{3C8}"),
+    (0x10513, "N"),
+    (0x10516, "O"),
+    (0x10518, "K"),
+    (0x1051C, "C"),
+    (0x1051D, "V"),
+    (0x10525, "F"),
+    (0x10526, "L"),
+    (0x10527, "X"),
+    (0x10A3A, "\u{323}"),
+    (0x10A50, "."),
+    (0x10A57, "\u{10A56}\u{10A56}"),
+    (0x10CFA, "\u{10CA5}"),
+    (0x10CFC, "\u{10C82}"),
+    (0x110BB, "\u{970}"),
+    (0x111C7, "\u{970}"),
+    (0x111CA, "\u{323}"),
+    (0x111CB, "\u{93A}"),
+    (0x111DB, "\u{A8FC}"),
+    (0x111DC, "\u{A8FB}"),
+    (0x111DE, "\u{2248}"),
+    (0x11300, "\u{30A}"),
+    (0x11413, "\u{11434}\u{11442}\u{11412}"),
+    (0x11419, "\u{11434}\u{11442}\u{11418}"),
+    (0x11424, "\u{11434}\u{11442}\u{11423}"),
+    (0x1142A, "\u{11434}\u{11442}\u{11429}"),
+    (0x1142D, "\u{11434}\u{11442}\u{1142C}"),
+    (0x1142F, "\u{11434}\u{11442}\u{1142E}"),
+    (0x1144C, "\u{1144B}\u{1144B}"),
+    (0x11492, "\u{998}"),
+    (0x11494, "\u{99A}"),
+    (0x11496, "\u{99C}"),
+    (0x11498, "\u{99E}"),
+    (0x11499, "\u{99F}"),
+    (0x1149B, "\u{9A1}"),
+    (0x1149D, "\u{9B2}"),
+    (0x1149E, "\u{9A4}"),
+    (0x1149F, "\u{9A5}"),
+    (0x114A0, "\u{9A6}"),
+    (0x114A1, "\u{9A7}"),
+    (0x114A2, "\u{9A8}"),
+    (0x114A3, "\u{9AA}"),
+    (0x114A7, "\u{9AE}"),
+    (0x114A8, "\u{9AF}"),
+    (0x114A9, "\u{9AC}"),
+    (0x114AA, "\u{9A3}"),
+    (0x114AB, "\u{9B0}"),
+    (0x114AD, "\u{9B7}"),
+    (0x114AE, "\u{9B8}"),
+    (0x114B0, "\u{9BE}"),
+    (0x114B1, "\u{9BF}"),
+    (0x114B9, "\u{9C7}"),
+    (0x114BC, "\u{9CB}"),
+    (0x114BD, "\u{9D7}"),
+    (0x114BE, "\u{9CC}"),
+    (0x114BF, "\u{306}\u{307}"),
+    (0x114C1, "\u{983}"),
+    (0x114C2, "\u{9CD}"),
+    (0x114C3, "\u{323}"),
+    (0x114C4, "\u{9BD}"),
+    (0x114C5, "w\u{307}"),
+    (0x114D0, "O"),
+    (0x114D1, "\u{9E7}"),
+    (0x114D2, "\u{9E8}"),
+    (0x114D6, "\u{9EC}"),
+    (0x115D8, "\u{11582}"),
+    (0x115D9, "\u{11582}"),
+    (0x115DA, "\u{11583}"),
+    (0x115DB, "\u{11584}"),
+    (0x115DC, "\u{115B2}"),
+    (0x115DD, "\u{115B3}"),
+    (0x11642, "\u{11641}\u{11641}"),
+    (0x11700, "rn"),
+    (0x11706, "v"),
+    (0x1170A, "w"),
+    (0x1170E, "w"),
+    (0x1170F, "w"),
+    (0x118A0, "V"),
+    (0x118A2, "F"),
+    (0x118A3, "L"),
+    (0x118A4, "Y"),
+    (0x118A6, "E"),
+    (0x118A8, "\u{2207}"),
+    (0x118A9, "Z"),
+    (0x118AC, "9"),
+    (0x118AE, "E"),
+    (0x118AF, "4"),
+    (0x118B2, "L"),
+    (0x118B5, "O"),
+    (0x118B7, "\u{16DC}"),
+    (0x118B8, "U"),
+    (0x118BB, "5"),
+    (0x118BC, "T"),
+    (0x118C0, "v"),
+    (0x118C1, "s"),
+    (0x118C2, "F"),
+    (0x118C3, "i"),
+    (0x118C4, "z"),
+    (0x118C6, "7"),
+    (0x118C8, "o"),
+    (0x118CA, "3"),
+    (0x118CC, "9"),
+    (0x118CE, "\u{A793}"),
+    (0x118D5, "6"),
+    (0x118D6, "9"),
+    (0x118D7, "o"),
+    (0x118D8, "u"),
+    (0x118DC, "y"),
+    (0x118E0, "O"),
+    (0x118E3, "rn"),
+    (0x118E4, "\u{669}"),
+    (0x118E5, "Z"),
+    (0x118E6, "W"),
+    (0x118E9, "C"),
+    (0x118EC, "X"),
+    (0x118EF, "W"),
+    (0x118F2, "C"),
+    (0x11AE6, "\u{11AE5}\u{11AEF}"),
+    (0x11AE7, "\u{11AE5}\u{11AF0}"),
+    (0x11AE8, "\u{11AE5}\u{11AE5}"),
+    (0x11AE9, "\u{11AE5}\u{11AE5}\u{11AEF}"),
+    (0x11AEA, "\u{11AE5}\u{11AE5}\u{11AF0}"),
+    (0x11AEC, "\u{11AEB}\u{11AEF}"),
+    (0x11AED, "\u{11AEB}\u{11AEB}"),
+    (0x11AEE, "\u{11AEB}\u{11AEB}\u{11AEF}"),
+    (0x11AF4, "\u{11AF3}\u{11AEF}"),
+    (0x11AF5, "\u{11AF3}\u{11AF0}"),
+    (0x11AF6, "\u{11AF3}\u{11AF3}"),
+    (0x11AF7, "\u{11AF3}\u{11AF3}\u{11AEF}"),
+    (0x11AF8, "\u{11AF3}\u{11AF3}\u{11AF0}"),
+    (0x11C42, "\u{11C41}\u{11C41}"),
+    (0x11CB2, "\u{11CAA}"),
+    (0x12038, "\u{1039A}"),
+    (0x132F9, "\u{1099E}"),
+    (0x16F07, "\u{393}"),
+    (0x16F08, "V"),
+    (0x16F0A, "T"),
+    (0x16F16, "L"),
+    (0x16F1A, "\u{394}"),
+    (0x16F1C, "\u{A658}"),
+    (0x16F26, "\u{A4F6}"),
+    (0x16F28, "l"),
+    (0x16F2D, "\u{190}"),
+    (0x16F35, "R"),
+    (0x16F3A, "S"),
+    (0x16F3B, "3"),
+    (0x16F3D, "\u{245}"),
+    (0x16F3F, ">"),
+    (0x16F40, "A"),
+    (0x16F42, "U"),
+    (0x16F43, "Y"),
+    (0x16F51, "'"),
+    (0x16F52, "'"),
+    (0x1CCD6, "A"),
+    (0x1CCD7, "B"),
+    (0x1CCD8, "C"),
+    (0x1CCD9, "D"),
+    (0x1CCDA, "E"),
+    (0x1CCDB, "F"),
+    (0x1CCDC, "G"),
+    (0x1CCDD, "H"),
+    (0x1CCDE, "l"),
+    (0x1CCDF, "J"),
+    (0x1CCE0, "K"),
+    (0x1CCE1, "L"),
+    (0x1CCE2, "M"),
+    (0x1CCE3, "N"),
+    (0x1CCE4, "O"),
+    (0x1CCE5, "P"),
+    (0x1CCE6, "Q"),
+    (0x1CCE7, "R"),
+    (0x1CCE8, "S"),
+    (0x1CCE9, "T"),
+    (0x1CCEA, "U"),
+    (0x1CCEB, "V"),
+    (0x1CCEC, "W"),
+    (0x1CCED, "X"),
+    (0x1CCEE, "Y"),
+    (0x1CCEF, "Z"),
+    (0x1CCF0, "O"),
+    (0x1CCF1, "l"),
+    (0x1CCF2, "2"),
+    (0x1CCF3, "3"),
+    (0x1CCF4, "4"),
+    (0x1CCF5, "5"),
+    (0x1CCF6, "6"),
+    (0x1CCF7, "7"),
+    (0x1CCF8, "8"),
+    (0x1CCF9, "9"),
+    (0x1D114, "{"),
+    (0x1D16D, "."),
+    (0x1D202, "\u{4FE}"),
+    (0x1D206, "3"),
+    (0x1D20B, "\u{418}"),
+    (0x1D20D, "V"),
+    (0x1D20F, "\\"),
+    (0x1D212, "7"),
+    (0x1D213, "F"),
+    (0x1D214, "\u{102BC}"),
+    (0x1D215, "\u{A4F6}"),
+    (0x1D216, "R"),
+    (0x1D217, "\u{2C6F}"),
+    (0x1D21A, "O\u{335}"),
+    (0x1D21B, "\u{2144}"),
+    (0x1D21C, "\u{A4D5}"),
+    (0x1D221, "\u{190}"),
+    (0x1D222, "\u{460}"),
+    (0x1D22A, "L"),
+    (0x1D22B, "\u{A4F6}"),
+    (0x1D230, "\u{A7FB}"),
+    (0x1D236, "<"),
+    (0x1D237, ">"),
+    (0x1D238, "\u{228F}"),
+    (0x1D239, "\u{2290}"),
+    (0x1D23A, "/"),
+    (0x1D23B, "\\"),
+    (0x1D23F, "\u{16CB}"),
+    (0x1D245, "\u{548}"),
+    (0x1D400, "A"),
+    (0x1D401, "B"),
+    (0x1D402, "C"),
+    (0x1D403, "D"),
+    (0x1D404, "E"),
+    (0x1D405, "F"),
+    (0x1D406, "G"),
+    (0x1D407, "H"),
+    (0x1D408, "l"),
+    (0x1D409, "J"),
+    (0x1D40A, "K"),
+    (0x1D40B, "L"),
+    (0x1D40C, "M"),
+    (0x1D40D, "N"),
+    (0x1D40E, "O"),
+    (0x1D40F, "P"),
+    (0x1D410, "Q"),
+    (0x1D411, "R"),
+    (0x1D412, "S"),
+    (0x1D413, "T"),
+    (0x1D414, "U"),
+    (0x1D415, "V"),
+    (0x1D416, "W"),
+    (0x1D417, "X"),
+    (0x1D418, "Y"),
+    (0x1D419, "Z"),
+    (0x1D41A, "a"),
+    (0x1D41B, "b"),
+    (0x1D41C, "c"),
+    (0x1D41D, "d"),
+    (0x1D41E, "e"),
+    (0x1D41F, "f"),
+    (0x1D420, "g"),
+    (0x1D421, "h"),
+    (0x1D422, "i"),
+    (0x1D423, "j"),
+    (0x1D424, "k"),
+    (0x1D425, "l"),
+    (0x1D426, "rn"),
+    (0x1D427, "n"),
+    (0x1D428, "o"),
+    (0x1D429, "p"),
+    (0x1D42A, "q"),
+    (0x1D42B, "r"),
+    (0x1D42C, "s"),
+    (0x1D42D, "t"),
+    (0x1D42E, "u"),
+    (0x1D42F, "v"),
+    (0x1D430, "w"),
+    (0x1D431, "x"),
+    (0x1D432, "y"),
+    (0x1D433, "z"),
+    (0x1D434, "A"),
+    (0x1D435, "B"),
+    (0x1D436, "C"),
+    (0x1D437, "D"),
+    (0x1D438, "E"),
+    (0x1D439, "F"),
+    (0x1D43A, "G"),
+    (0x1D43B, "H"),
+    (0x1D43C, "l"),
+    (0x1D43D, "J"),
+    (0x1D43E, "K"),
+    (0x1D43F, "L"),
+    (0x1D440, "M"),
+    (0x1D441, "N"),
+    (0x1D442, "O"),
+    (0x1D443, "P"),
+    (0x1D444, "Q"),
+    (0x1D445, "R"),
+    (0x1D446, "S"),
+    (0x1D447, "T"),
+    (0x1D448, "U"),
+    (0x1D449, "V"),
+    (0x1D44A, "W"),
+    (0x1D44B, "X"),
+    (0x1D44C, "Y"),
+    (0x1D44D, "Z"),
+    (0x1D44E, "a"),
+    (0x1D44F, "b"),
+    (0x1D450, "c"),
+    (0x1D451, "d"),
+    (0x1D452, "e"),
+    (0x1D453, "f"),
+    (0x1D454, "g"),
+    (0x1D456, "i"),
+    (0x1D457, "j"),
+    (0x1D458, "k"),
+    (0x1D459, "l"),
+    (0x1D45A, "rn"),
+    (0x1D45B, "n"),
+    (0x1D45C, "o"),
+    (0x1D45D, "p"),
+    (0x1D45E, "q"),
+    (0x1D45F, "r"),
+    (0x1D460, "s"),
+    (0x1D461, "t"),
+    (0x1D462, "u"),
+    (0x1D463, "v"),
+    (0x1D464, "w"),
+    (0x1D465, "x"),
+    (0x1D466, "y"),
+    (0x1D467, "z"),
+    (0x1D468, "A"),
+    (0x1D469, "B"),
+    (0x1D46A, "C"),
+    (0x1D46B, "D"),
+    (0x1D46C, "E"),
+    (0x1D46D, "F"),
+    (0x1D46E, "G"),
+    (0x1D46F, "H"),
+    (0x1D470, "l"),
+    (0x1D471, "J"),
+    (0x1D472, "K"),
+    (0x1D473, "L"),
+    (0x1D474, "M"),
+    (0x1D475, "N"),
+    (0x1D476, "O"),
+    (0x1D477, "P"),
+    (0x1D478, "Q"),
+    (0x1D479, "R"),
+    (0x1D47A, "S"),
+    (0x1D47B, "T"),
+    (0x1D47C, "U"),
+    (0x1D47D, "V"),
+    (0x1D47E, "W"),
+    (0x1D47F, "X"),
+    (0x1D480, "Y"),
+    (0x1D481, "Z"),
+    (0x1D482, "a"),
+    (0x1D483, "b"),
+    (0x1D484, "c"),
+    (0x1D485, "d"),
+    (0x1D486, "e"),
+    (0x1D487, "f"),
+    (0x1D488, "g"),
+    (0x1D489, "h"),
+    (0x1D48A, "i"),
+    (0x1D48B, "j"),
+    (0x1D48C, "k"),
+    (0x1D48D, "l"),
+    (0x1D48E, "rn"),
+    (0x1D48F, "n"),
+    (0x1D490, "o"),
+    (0x1D491, "p"),
+    (0x1D492, "q"),
+    (0x1D493, "r"),
+    (0x1D494, "s"),
+    (0x1D495, "t"),
+    (0x1D496, "u"),
+    (0x1D497, "v"),
+    (0x1D498, "w"),
+    (0x1D499, "x"),
+    (0x1D49A, "y"),
+    (0x1D49B, "z"),
+    (0x1D49C, "A"),
+    (0x1D49E, "C"),
+    (0x1D49F, "D"),
+    (0x1D4A2, "G"),
+    (0x1D4A5, "J"),
+    (0x1D4A6, "K"),
+    (0x1D4A9, "N"),
+    (0x1D4AA, "O"),
+    (0x1D4AB, "P"),
+    (0x1D4AC, "Q"),
+    (0x1D4AE, "S"),
+    (0x1D4AF, "T"),
+    (0x1D4B0, "U"),
+    (0x1D4B1, "V"),
+    (0x1D4B2, "W"),
+    (0x1D4B3, "X"),
+    (0x1D4B4, "Y"),
+    (0x1D4B5, "Z"),
+    (0x1D4B6, "a"),
+    (0x1D4B7, "b"),
+    (0x1D4B8, "c"),
+    (0x1D4B9, "d"),
+    (0x1D4BB, "f"),
+    (0x1D4BD, "h"),
+    (0x1D4BE, "i"),
+    (0x1D4BF, "j"),
+    (0x1D4C0, "k"),
+    (0x1D4C1, "l"),
+    (0x1D4C2, "rn"),
+    (0x1D4C3, "n"),
+    (0x1D4C5, "p"),
+    (0x1D4C6, "q"),
+    (0x1D4C7, "r"),
+    (0x1D4C8, "s"),
+    (0x1D4C9, "t"),
+    (0x1D4CA, "u"),
+    (0x1D4CB, "v"),
+    (0x1D4CC, "w"),
+    (0x1D4CD, "x"),
+    (0x1D4CE, "y"),
+    (0x1D4CF, "z"),
+    (0x1D4D0, "A"),
+    (0x1D4D1, "B"),
+    (0x1D4D2, "C"),
+    (0x1D4D3, "D"),
+    (0x1D4D4, "E"),
+    (0x1D4D5, "F"),
+    (0x1D4D6, "G"),
+    (0x1D4D7, "H"),
+    (0x1D4D8, "l"),
+    (0x1D4D9, "J"),
+    (0x1D4DA, "K"),
+    (0x1D4DB, "L"),
+    (0x1D4DC, "M"),
+    (0x1D4DD, "N"),
+    (0x1D4DE, "O"),
+    (0x1D4DF, "P"),
+    (0x1D4E0, "Q"),
+    (0x1D4E1, "R"),
+    (0x1D4E2, "S"),
+    (0x1D4E3, "T"),
+    (0x1D4E4, "U"),
+    (0x1D4E5, "V"),
+    (0x1D4E6, "W"),
+    (0x1D4E7, "X"),
+    (0x1D4E8, "Y"),
+    (0x1D4E9, "Z"),
+    (0x1D4EA, "a"),
+    (0x1D4EB, "b"),
+    (0x1D4EC, "c"),
+    (0x1D4ED, "d"),
+    (0x1D4EE, "e"),
+    (0x1D4EF, "f"),
+    (0x1D4F0, "g"),
+    (0x1D4F1, "h"),
+    (0x1D4F2, "i"),
+    (0x1D4F3, "j"),
+    (0x1D4F4, "k"),
+    (0x1D4F5, "l"),
+    (0x1D4F6, "rn"),
+    (0x1D4F7, "n"),
+    (0x1D4F8, "o"),
+    (0x1D4F9, "p"),
+    (0x1D4FA, "q"),
+    (0x1D4FB, "r"),
+    (0x1D4FC, "s"),
+    (0x1D4FD, "t"),
+    (0x1D4FE, "u"),
+    (0x1D4FF, "v"),
+    (0x1D500, "w"),
+    (0x1D501, "x"),
+    (0x1D502, "y"),
+    (0x1D503, "z"),
+    (0x1D504, "A"),
+    (0x1D505, "B"),
+    (0x1D507, "D"),
+    (0x1D508, "E"),
+    (0x1D509, "F"),
+    (0x1D50A, "G"),
+    (0x1D50D, "J"),
+    (0x1D50E, "K"),
+    (0x1D50F, "L"),
+    (0x1D510, "M"),
+    (0x1D511, "N"),
+    (0x1D512, "O"),
+    (0x1D513, "P"),
+    (0x1D514, "Q"),
+    (0x1D516, "S"),
+    (0x1D517, "T"),
+    (0x1D518, "U"),
+    (0x1D519, "V"),
+    (0x1D51A, "W"),
+    (0x1D51B, "X"),
+    (0x1D51C, "Y"),
+    (0x1D51E, "a"),
+    (0x1D51F, "b"),
+    (0x1D520, "c"),
+    (0x1D521, "d"),
+    (0x1D522, "e"),
+    (0x1D523, "f"),
+    (0x1D524, "g"),
+    (0x1D525, "h"),
+    (0x1D526, "i"),
+    (0x1D527, "j"),
+    (0x1D528, "k"),
+    (0x1D529, "l"),
+    (0x1D52A, "rn"),
+    (0x1D52B, "n"),
+    (0x1D52C, "o"),
+    (0x1D52D, "p"),
+    (0x1D52E, "q"),
+    (0x1D52F, "r"),
+    (0x1D530, "s"),
+    (0x1D531, "t"),
+    (0x1D532, "u"),
+    (0x1D533, "v"),
+    (0x1D534, "w"),
+    (0x1D535, "x"),
+    (0x1D536, "y"),
+    (0x1D537, "z"),
+    (0x1D538, "A"),
+    (0x1D539, "B"),
+    (0x1D53B, "D"),
+    (0x1D53C, "E"),
+    (0x1D53D, "F"),
+    (0x1D53E, "G"),
+    (0x1D540, "l"),
+    (0x1D541, "J"),
+    (0x1D542, "K"),
+    (0x1D543, "L"),
+    (0x1D544, "M"),
+    (0x1D546, "O"),
+    (0x1D54A, "S"),
+    (0x1D54B, "T"),
+    (0x1D54C, "U"),
+    (0x1D54D, "V"),
+    (0x1D54E, "W"),
+    (0x1D54F, "X"),
+    (0x1D550, "Y"),
+    (0x1D552, "a"),
+    (0x1D553, "b"),
+    (0x1D554, "c"),
+    (0x1D555, "d"),
+    (0x1D556, "e"),
+    (0x1D557, "f"),
+    (0x1D558, "g"),
+    (0x1D559, "h"),
+    (0x1D55A, "i"),
+    (0x1D55B, "j"),
+    (0x1D55C, "k"),
+    (0x1D55D, "l"),
+    (0x1D55E, "rn"),
+    (0x1D55F, "n"),
+    (0x1D560, "o"),
+    (0x1D561, "p"),
+    (0x1D562, "q"),
+    (0x1D563, "r"),
+    (0x1D564, "s"),
+    (0x1D565, "t"),
+    (0x1D566, "u"),
+    (0x1D567, "v"),
+    (0x1D568, "w"),
+    (0x1D569, "x"),
+    (0x1D56A, "y"),
+    (0x1D56B, "z"),
+    (0x1D56C, "A"),
+    (0x1D56D, "B"),
+    (0x1D56E, "C"),
+    (0x1D56F, "D"),
+    (0x1D570, "E"),
+    (0x1D571, "F"),
+    (0x1D572, "G"),
+    (0x1D573, "H"),
+    (0x1D574, "l"),
+    (0x1D575, "J"),
+    (0x1D576, "K"),
+    (0x1D577, "L"),
+    (0x1D578, "M"),
+    (0x1D579, "N"),
+    (0x1D57A, "O"),
+    (0x1D57B, "P"),
+    (0x1D57C, "Q"),
+    (0x1D57D, "R"),
+    (0x1D57E, "S"),
+    (0x1D57F, "T"),
+    (0x1D580, "U"),
+    (0x1D581, "V"),
+    (0x1D582, "W"),
+    (0x1D583, "X"),
+    (0x1D584, "Y"),
+    (0x1D585, "Z"),
+    (0x1D586, "a"),
+    (0x1D587, "b"),
+    (0x1D588, "c"),
+    (0x1D589, "d"),
+    (0x1D58A, "e"),
+    (0x1D58B, "f"),
+    (0x1D58C, "g"),
+    (0x1D58D, "h"),
+    (0x1D58E, "i"),
+    (0x1D58F, "j"),
+    (0x1D590, "k"),
+    (0x1D591, "l"),
+    (0x1D592, "rn"),
+    (0x1D593, "n"),
+    (0x1D594, "o"),
+    (0x1D595, "p"),
+    (0x1D596, "q"),
+    (0x1D597, "r"),
+    (0x1D598, "s"),
+    (0x1D599, "t"),
+    (0x1D59A, "u"),
+    (0x1D59B, "v"),
+    (0x1D59C, "w"),
+    (0x1D59D, "x"),
+    (0x1D59E, "y"),
+    (0x1D59F, "z"),
+    (0x1D5A0, "A"),
+    (0x1D5A1, "B"),
+    (0x1D5A2, "C"),
+    (0x1D5A3, "D"),
+    (0x1D5A4, "E"),
+    (0x1D5A5, "F"),
+    (0x1D5A6, "G"),
+    (0x1D5A7, "H"),
+    (0x1D5A8, "l"),
+    (0x1D5A9, "J"),
+    (0x1D5AA, "K"),
+    (0x1D5AB, "L"),
+    (0x1D5AC, "M"),
+    (0x1D5AD, "N"),
+    (0x1D5AE, "O"),
+    (0x1D5AF, "P"),
+    (0x1D5B0, "Q"),
+    (0x1D5B1, "R"),
+    (0x1D5B2, "S"),
+    (0x1D5B3, "T"),
+    (0x1D5B4, "U"),
+    (0x1D5B5, "V"),
+    (0x1D5B6, "W"),
+    (0x1D5B7, "X"),
+    (0x1D5B8, "Y"),
+    (0x1D5B9, "Z"),
+    (0x1D5BA, "a"),
+    (0x1D5BB, "b"),
+    (0x1D5BC, "c"),
+    (0x1D5BD, "d"),
+    (0x1D5BE, "e"),
+    (0x1D5BF, "f"),
+    (0x1D5C0, "g"),
+    (0x1D5C1, "h"),
+    (0x1D5C2, "i"),
+    (0x1D5C3, "j"),
+    (0x1D5C4, "k"),
+    (0x1D5C5, "l"),
+    (0x1D5C6, "rn"),
+    (0x1D5C7, "n"),
+    (0x1D5C8, "o"),
+    (0x1D5C9, "p"),
+    (0x1D5CA, "q"),
+    (0x1D5CB, "r"),
+    (0x1D5CC, "s"),
+    (0x1D5CD, "t"),
+    (0x1D5CE, "u"),
+    (0x1D5CF, "v"),
+    (0x1D5D0, "w"),
+    (0x1D5D1, "x"),
+    (0x1D5D2, "y"),
+    (0x1D5D3, "z"),
+    (0x1D5D4, "A"),
+    (0x1D5D5, "B"),
+    (0x1D5D6, "C"),
+    (0x1D5D7, "D"),
+    (0x1D5D8, "E"),
+    (0x1D5D9, "F"),
+    (0x1D5DA, "G"),
+    (0x1D5DB, "H"),
+    (0x1D5DC, "l"),
+    (0x1D5DD, "J"),
+    (0x1D5DE, "K"),
+    (0x1D5DF, "L"),
+    (0x1D5E0, "M"),
+    (0x1D5E1, "N"),
+    (0x1D5E2, "O"),
+    (0x1D5E3, "P"),
+    (0x1D5E4, "Q"),
+    (0x1D5E5, "R"),
+    (0x1D5E6, "S"),
+    (0x1D5E7, "T"),
+    (0x1D5E8, "U"),
+    (0x1D5E9, "V"),
+    (0x1D5EA, "W"),
+    (0x1D5EB, "X"),
+    (0x1D5EC, "Y"),
+    (0x1D5ED, "Z"),
+    (0x1D5EE, "a"),
+    (0x1D5EF, "b"),
+    (0x1D5F0, "c"),
+    (0x1D5F1, "d"),
+    (0x1D5F2, "e"),
+    (0x1D5F3, "f"),
+    (0x1D5F4, "g"),
+    (0x1D5F5, "h"),
+    (0x1D5F6, "i"),
+    (0x1D5F7, "j"),
+    (0x1D5F8, "k"),
+    (0x1D5F9, "l"),
+    (0x1D5FA, "rn"),
+    (0x1D5FB, "n"),
+    (0x1D5FC, "o"),
+    (0x1D5FD, "p"),
+    (0x1D5FE, "q"),
+    (0x1D5FF, "r"),
+    (0x1D600, "s"),
+    (0x1D601, "t"),
+    (0x1D602, "u"),
+    (0x1D603, "v"),
+    (0x1D604, "w"),
+    (0x1D605, "x"),
+    (0x1D606, "y"),
+    (0x1D607, "z"),
+    (0x1D608, "A"),
+    (0x1D609, "B"),
+    (0x1D60A, "C"),
+    (0x1D60B, "D"),
+    (0x1D60C, "E"),
+    (0x1D60D, "F"),
+    (0x1D60E, "G"),
+    (0x1D60F, "H"),
+    (0x1D610, "l"),
+    (0x1D611, "J"),
+    (0x1D612, "K"),
+    (0x1D613, "L"),
+    (0x1D614, "M"),
+    (0x1D615, "N"),
+    (0x1D616, "O"),
+    (0x1D617, "P"),
+    (0x1D618, "Q"),
+    (0x1D619, "R"),
+    (0x1D61A, "S"),
+    (0x1D61B, "T"),
+    (0x1D61C, "U"),
+    (0x1D61D, "V"),
+    (0x1D61E, "W"),
+    (0x1D61F, "X"),
+    (0x1D620, "Y"),
+    (0x1D621, "Z"),
+    (0x1D622, "a"),
+    (0x1D623, "b"),
+    (0x1D624, "c"),
+    (0x1D625, "d"),
+    (0x1D626, "e"),
+    (0x1D627, "f"),
+    (0x1D628, "g"),
+    (0x1D629, "h"),
+    (0x1D62A, "i"),
+    (0x1D62B, "j"),
+    (0x1D62C, "k"),
+    (0x1D62D, "l"),
+    (0x1D62E, "rn"),
+    (0x1D62F, "n"),
+    (0x1D630, "o"),
+    (0x1D631, "p"),
+    (0x1D632, "q"),
+    (0x1D633, "r"),
+    (0x1D634, "s"),
+    (0x1D635, "t"),
+    (0x1D636, "u"),
+    (0x1D637, "v"),
+    (0x1D638, "w"),
+    (0x1D639, "x"),
+    (0x1D63A, "y"),
+    (0x1D63B, "z"),
+    (0x1D63C, "A"),
+    (0x1D63D, "B"),
+    (0x1D63E, "C"),
+    (0x1D63F, "D"),
+    (0x1D640, "E"),
+    (0x1D641, "F"),
+    (0x1D642, "G"),
+    (0x1D643, "H"),
+    (0x1D644, "l"),
+    (0x1D645, "J"),
+    (0x1D646, "K"),
+    (0x1D647, "L"),
+    (0x1D648, "M"),
+    (0x1D649, "N"),
+    (0x1D64A, "O"),
+    (0x1D64B, "P"),
+    (0x1D64C, "Q"),
+    (0x1D64D, "R"),
+    (0x1D64E, "S"),
+    (0x1D64F, "T"),
+    (0x1D650, "U"),
+    (0x1D651, "V"),
+    (0x1D652, "W"),
+    (0x1D653, "X"),
+    (0x1D654, "Y"),
+    (0x1D655, "Z"),
+    (0x1D656, "a"),
+    (0x1D657, "b"),
+    (0x1D658, "c"),
+    (0x1D659, "d"),
+    (0x1D65A, "e"),
+    (0x1D65B, "f"),
+    (0x1D65C, "g"),
+    (0x1D65D, "h"),
+    (0x1D65E, "i"),
+    (0x1D65F, "j"),
+    (0x1D660, "k"),
+    (0x1D661, "l"),
+    (0x1D662, "rn"),
+    (0x1D663, "n"),
+    (0x1D664, "o"),
+    (0x1D665, "p"),
+    (0x1D666, "q"),
+    (0x1D667, "r"),
+    (0x1D668, "s"),
+    (0x1D669, "t"),
+    (0x1D66A, "u"),
+    (0x1D66B, "v"),
+    (0x1D66C, "w"),
+    (0x1D66D, "x"),
+    (0x1D66E, "y"),
+    (0x1D66F, "z"),
+    (0x1D670, "A"),
+    (0x1D671, "B"),
+    (0x1D672, "C"),
+    (0x1D673, "D"),
+    (0x1D674, "E"),
+    (0x1D675, "F"),
+    (0x1D676, "G"),
+    (0x1D677, "H"),
+    (0x1D678, "l"),
+    (0x1D679, "J"),
+    (0x1D67A, "K"),
+    (0x1D67B, "L"),
+    (0x1D67C, "M"),
+    (0x1D67D, "N"),
+    (0x1D67E, "O"),
+    (0x1D67F, "P"),
+    (0x1D680, "Q"),
+    (0x1D681, "R"),
+    (0x1D682, "S"),
+    (0x1D683, "T"),
+    (0x1D684, "U"),
+    (0x1D685, "V"),
+    (0x1D686, "W"),
+    (0x1D687, "X"),
+    (0x1D688, "Y"),
+    (0x1D689, "Z"),
+    (0x1D68A, "a"),
+    (0x1D68B, "b"),
+    (0x1D68C, "c"),
+    (0x1D68D, "d"),
+    (0x1D68E, "e"),
+    (0x1D68F, "f"),
+    (0x1D690, "g"),
+    (0x1D691, "h"),
+    (0x1D692, "i"),
+    (0x1D693, "j"),
+    (0x1D694, "k"),
+    (0x1D695, "l"),
+    (0x1D696, "rn"),
+    (0x1D697, "n"),
+    (0x1D698, "o"),
+    (0x1D699, "p"),
+    (0x1D69A, "q"),
+    (0x1D69B, "r"),
+    (0x1D69C, "s"),
+    (0x1D69D, "t"),
+    (0x1D69E, "u"),
+    (0x1D69F, "v"),
+    (0x1D6A0, "w"),
+    (0x1D6A1, "x"),
+    (0x1D6A2, "y"),
+    (0x1D6A3, "z"),
+    (0x1D6A4, "i"),
+    (0x1D6A5, "\u{237}"),
+    (0x1D6A8, "A"),
+    (0x1D6A9, "B"),
+    (0x1D6AA, "\u{393}"),
+    (0x1D6AB, "\u{394}"),
+    (0x1D6AC, "E"),
+    (0x1D6AD, "Z"),
+    (0x1D6AE, "H"),
+    (0x1D6AF, "O\u{335}"),
+    (0x1D6B0, "l"),
+    (0x1D6B1, "K"),
+    (0x1D6B2, "\u{245}"),
+    (0x1D6B3, "M"),
+    (0x1D6B4, "N"),
+    (0x1D6B5, "\u{39E}"),
+    (0x1D6B6, "O"),
+    (0x1D6B7, "\u{3A0}"),
+    (0x1D6B8, "P"),
+    (0x1D6B9, "O\u{335}"),
+    (0x1D6BA, "\u{1A9}"),
+    (0x1D6BB, "T"),
+    (0x1D6BC, "Y"),
+    (0x1D6BD, "\u{3A6}"),
+    (0x1D6BE, "X"),
+    (0x1D6BF, "\u{3A8}"),
+    (0x1D6C0, "\u{3A9}"),
+    (0x1D6C1, "\u{2207}"),
+    (0x1D6C2, "a"),
+    (0x1D6C3, "\u{DF}"),
+    (0x1D6C4, "y"),
+    (0x1D6C5, "\u{1E9F}"),
+    (0x1D6C6, "\u{A793}"),
+    (0x1D6C7, "\u{3B6}"),
+    (0x1D6C8, "n\u{329}"),
+    (0x1D6C9, "O\u{335}"),
+    (0x1D6CA, "i"),
+    (0x1D6CB, "\u{138}"),
+    (0x1D6CC, "\u{3BB}"),
+    (0x1D6CD, "\u{3BC}"),
+    (0x1D6CE, "v"),
+    (0x1D6CF, "\u{3BE}"),
+    (0x1D6D0, "o"),
+    (0x1D6D1, "\u{3C0}"),
+    (0x1D6D2, "p"),
+    (0x1D6D3, "\u{3C2}"),
+    (0x1D6D4, "o"),
+    (0x1D6D5, "\u{1D1B}"),
+    (0x1D6D6, "u"),
+    (0x1D6D7, "\u{278}"),
+    (0x1D6D8, "\u{3C7}"),
+    (0x1D6D9, "\u{3C8}"),
+    (0x1D6DA, "\u{3C9}"),
+    (0x1D6DB, "\u{2202}"),
+    (0x1D6DC, "\u{A793}"),
+    (0x1D6DD, "O\u{335}"),
+    (0x1D6DE, "\u{138}"),
+    (0x1D6DF, "\u{278}"),
+    (0x1D6E0, "p"),
+    (0x1D6E1, "\u{3C0}"),
+    (0x1D6E2, "A"),
+    (0x1D6E3, "B"),
+    (0x1D6E4, "\u{393}"),
+    (0x1D6E5, "\u{394}"),
+    (0x1D6E6, "E"),
+    (0x1D6E7, "Z"),
+    (0x1D6E8, "H"),
+    (0x1D6E9, "O\u{335}"),
+    (0x1D6EA, "l"),
+    (0x1D6EB, "K"),
+    (0x1D6EC, "\u{245}"),
+    (0x1D6ED, "M"),
+    (0x1D6EE, "N"),
+    (0x1D6EF, "\u{39E}"),
+    (0x1D6F0, "O"),
+    (0x1D6F1, "\u{3A0}"),
+    (0x1D6F2, "P"),
+    (0x1D6F3, "O\u{335}"),
+    (0x1D6F4, "\u{1A9}"),
+    (0x1D6F5, "T"),
+    (0x1D6F6, "Y"),
+    (0x1D6F7, "\u{3A6}"),
+    (0x1D6F8, "X"),
+    (0x1D6F9, "\u{3A8}"),
+    (0x1D6FA, "\u{3A9}"),
+    (0x1D6FB, "\u{2207}"),
+    (0x1D6FC, "a"),
+    (0x1D6FD, "\u{DF}"),
+    (0x1D6FE, "y"),
+    (0x1D6FF, "\u{1E9F}"),
+    (0x1D700, "\u{A793}"),
+    (0x1D701, "\u{3B6}"),
+    (0x1D702, "n\u{329}"),
+    (0x1D703, "O\u{335}"),
+    (0x1D704, "i"),
+    (0x1D705, "\u{138}"),
+    (0x1D706, "\u{3BB}"),
+    (0x1D707, "\u{3BC}"),
+    (0x1D708, "v"),
+    (0x1D709, "\u{3BE}"),
+    (0x1D70A, "o"),
+    (0x1D70B, "\u{3C0}"),
+    (0x1D70C, "p"),
+    (0x1D70D, "\u{3C2}"),
+    (0x1D70E, "o"),
+    (0x1D70F, "\u{1D1B}"),
+    (0x1D710, "u"),
+    (0x1D711, "\u{278}"),
+    (0x1D712, "\u{3C7}"),
+    (0x1D713, "\u{3C8}"),
+    (0x1D714, "\u{3C9}"),
+    (0x1D715, "\u{2202}"),
+    (0x1D716, "\u{A793}"),
+    (0x1D717, "O\u{335}"),
+    (0x1D718, "\u{138}"),
+    (0x1D719, "\u{278}"),
+    (0x1D71A, "p"),
+    (0x1D71B, "\u{3C0}"),
+    (0x1D71C, "A"),
+    (0x1D71D, "B"),
+    (0x1D71E, "\u{393}"),
+    (0x1D71F, "\u{394}"),
+    (0x1D720, "E"),
+    (0x1D721, "Z"),
+    (0x1D722, "H"),
+    (0x1D723, "O\u{335}"),
+    (0x1D724, "l"),
+    (0x1D725, "K"),
+    (0x1D726, "\u{245}"),
+    (0x1D727, "M"),
+    (0x1D728, "N"),
+    (0x1D729, "\u{39E}"),
+    (0x1D72A, "O"),
+    (0x1D72B, "\u{3A0}"),
+    (0x1D72C, "P"),
+    (0x1D72D, "O\u{335}"),
+    (0x1D72E, "\u{1A9}"),
+    (0x1D72F, "T"),
+    (0x1D730, "Y"),
+    (0x1D731, "\u{3A6}"),
+    (0x1D732, "X"),
+    (0x1D733, "\u{3A8}"),
+    (0x1D734, "\u{3A9}"),
+    (0x1D735, "\u{2207}"),
+    (0x1D736, "a"),
+    (0x1D737, "\u{DF}"),
+    (0x1D738, "y"),
+    (0x1D739, "\u{1E9F}"),
+    (0x1D73A, "\u{A793}"),
+    (0x1D73B, "\u{3B6}"),
+    (0x1D73C, "n\u{329}"),
+    (0x1D73D, "O\u{335}"),
+    (0x1D73E, "i"),
+    (0x1D73F, "\u{138}"),
+    (0x1D740, "\u{3BB}"),
+    (0x1D741, "\u{3BC}"),
+    (0x1D742, "v"),
+    (0x1D743, "\u{3BE}"),
+    (0x1D744, "o"),
+    (0x1D745, "\u{3C0}"),
+    (0x1D746, "p"),
+    (0x1D747, "\u{3C2}"),
+    (0x1D748, "o"),
+    (0x1D749, "\u{1D1B}"),
+    (0x1D74A, "u"),
+    (0x1D74B, "\u{278}"),
+    (0x1D74C, "\u{3C7}"),
+    (0x1D74D, "\u{3C8}"),
+    (0x1D74E, "\u{3C9}"),
+    (0x1D74F, "\u{2202}"),
+    (0x1D750, "\u{A793}"),
+    (0x1D751, "O\u{335}"),
+    (0x1D752, "\u{138}"),
+    (0x1D753, "\u{278}"),
+    (0x1D754, "p"),
+    (0x1D755, "\u{3C0}"),
+    (0x1D756, "A"),
+    (0x1D757, "B"),
+    (0x1D758, "\u{393}"),
+    (0x1D759, "\u{394}"),
+    (0x1D75A, "E"),
+    (0x1D75B, "Z"),
+    (0x1D75C, "H"),
+    (0x1D75D, "O\u{335}"),
+    (0x1D75E, "l"),
+    (0x1D75F, "K"),
+    (0x1D760, "\u{245}"),
+    (0x1D761, "M"),
+    (0x1D762, "N"),
+    (0x1D763, "\u{39E}"),
+    (0x1D764, "O"),
+    (0x1D765, "\u{3A0}"),
+    (0x1D766, "P"),
+    (0x1D767, "O\u{335}"),
+    (0x1D768, "\u{1A9}"),
+    (0x1D769, "T"),
+    (0x1D76A, "Y"),
+    (0x1D76B, "\u{3A6}"),
+    (0x1D76C, "X"),
+    (0x1D76D, "\u{3A8}"),
+    (0x1D76E, "\u{3A9}"),
+    (0x1D76F, "\u{2207}"),
+    (0x1D770, "a"),
+    (0x1D771, "\u{DF}"),
+    (0x1D772, "y"),
+    (0x1D773, "\u{1E9F}"),
+    (0x1D774, "\u{A793}"),
+    (0x1D775, "\u{3B6}"),
+    (0x1D776, "n\u{329}"),
+    (0x1D777, "O\u{335}"),
+    (0x1D778, "i"),
+    (0x1D779, "\u{138}"),
+    (0x1D77A, "\u{3BB}"),
+    (0x1D77B, "\u{3BC}"),
+    (0x1D77C, "v"),
+    (0x1D77D, "\u{3BE}"),
+    (0x1D77E, "o"),
+    (0x1D77F, "\u{3C0}"),
+    (0x1D780, "p"),
+    (0x1D781, "\u{3C2}"),
+    (0x1D782, "o"),
+    (0x1D783, "\u{1D1B}"),
+    (0x1D784, "u"),
+    (0x1D785, "\u{278}"),
+    (0x1D786, "\u{3C7}"),
+    (0x1D787, "\u{3C8}"),
+    (0x1D788, "\u{3C9}"),
+    (0x1D789, "\u{2202}"),
+    (0x1D78A, "\u{A793}"),
+    (0x1D78B, "O\u{335}"),
+    (0x1D78C, "\u{138}"),
+    (0x1D78D, "\u{278}"),
+    (0x1D78E, "p"),
+    (0x1D78F, "\u{3C0}"),
+    (0x1D790, "A"),
+    (0x1D791, "B"),
+    (0x1D792, "\u{393}"),
+    (0x1D793, "\u{394}"),
+    (0x1D794, "E"),
+    (0x1D795, "Z"),
+    (0x1D796, "H"),
+    (0x1D797, "O\u{335}"),
+    (0x1D798, "l"),
+    (0x1D799, "K"),
+    (0x1D79A, "\u{245}"),
+    (0x1D79B, "M"),
+    (0x1D79C, "N"),
+    (0x1D79D, "\u{39E}"),
+    (0x1D79E, "O"),
+    (0x1D79F, "\u{3A0}"),
+    (0x1D7A0, "P"),
+    (0x1D7A1, "O\u{335}"),
+    (0x1D7A2, "\u{1A9}"),
+    (0x1D7A3, "T"),
+    (0x1D7A4, "Y"),
+    (0x1D7A5, "\u{3A6}"),
+    (0x1D7A6, "X"),
+    (0x1D7A7, "\u{3A8}"),
+    (0x1D7A8, "\u{3A9}"),
+    (0x1D7A9, "\u{2207}"),
+    (0x1D7AA, "a"),
+    (0x1D7AB, "\u{DF}"),
+    (0x1D7AC, "y"),
+    (0x1D7AD, "\u{1E9F}"),
+    (0x1D7AE, "\u{A793}"),
+    (0x1D7AF, "\u{3B6}"),
+    (0x1D7B0, "n\u{329}"),
+    (0x1D7B1, "O\u{335}"),
+    (0x1D7B2, "i"),
+    (0x1D7B3, "\u{138}"),
+    (0x1D7B4, "\u{3BB}"),
+    (0x1D7B5, "\u{3BC}"),
+    (0x1D7B6, "v"),
+    (0x1D7B7, "\u{3BE}"),
+    (0x1D7B8, "o"),
+    (0x1D7B9, "\u{3C0}"),
+    (0x1D7BA, "p"),
+    (0x1D7BB, "\u{3C2}"),
+    (0x1D7BC, "o"),
+    (0x1D7BD, "\u{1D1B}"),
+    (0x1D7BE, "u"),
+    (0x1D7BF, "\u{278}"),
+    (0x1D7C0, "\u{3C7}"),
+    (0x1D7C1, "\u{3C8}"),
+    (0x1D7C2, "\u{3C9}"),
+    (0x1D7C3, "\u{2202}"),
+    (0x1D7C4, "\u{A793}"),
+    (0x1D7C5, "O\u{335}"),
+    (0x1D7C6, "\u{138}"),
+    (0x1D7C7, "\u{278}"),
+    (0x1D7C8, "p"),
+    (0x1D7C9, "\u{3C0}"),
+    (0x1D7CA, "F"),
+    (0x1D7CB, "\u{3DD}"),
+    (0x1D7CE, "O"),
+    (0x1D7CF, "l"),
+    (0x1D7D0, "2"),
+    (0x1D7D1, "3"),
+    (0x1D7D2, "4"),
+    (0x1D7D3, "5"),
+    (0x1D7D4, "6"),
+    (0x1D7D5, "7"),
+    (0x1D7D6, "8"),
+    (0x1D7D7, "9"),
+    (0x1D7D8, "O"),
+    (0x1D7D9, "l"),
+    (0x1D7DA, "2"),
+    (0x1D7DB, "3"),
+    (0x1D7DC, "4"),
+    (0x1D7DD, "5"),
+    (0x1D7DE, "6"),
+    (0x1D7DF, "7"),
+    (0x1D7E0, "8"),
+    (0x1D7E1, "9"),
+    (0x1D7E2, "O"),
+    (0x1D7E3, "l"),
+    (0x1D7E4, "2"),
+    (0x1D7E5, "3"),
+    (0x1D7E6, "4"),
+    (0x1D7E7, "5"),
+    (0x1D7E8, "6"),
+    (0x1D7E9, "7"),
+    (0x1D7EA, "8"),
+    (0x1D7EB, "9"),
+    (0x1D7EC, "O"),
+    (0x1D7ED, "l"),
+    (0x1D7EE, "2"),
+    (0x1D7EF, "3"),
+    (0x1D7F0, "4"),
+    (0x1D7F1, "5"),
+    (0x1D7F2, "6"),
+    (0x1D7F3, "7"),
+    (0x1D7F4, "8"),
+    (0x1D7F5, "9"),
+    (0x1D7F6, "O"),
+    (0x1D7F7, "l"),
+    (0x1D7F8, "2"),
+    (0x1D7F9, "3"),
+    (0x1D7FA, "4"),
+    (0x1D7FB, "5"),
+    (0x1D7FC, "6"),
+    (0x1D7FD, "7"),
+    (0x1D7FE, "8"),
+    (0x1D7FF, "9"),
+    (0x1E8C7, "l"),
+    (0x1E8C8, "\u{2220}"),
+    (0x1E8C9, "\u{663}"),
+    (0x1E8CB, "8"),
+    (0x1E8CC, "\u{2202}"),
+    (0x1E8CD, "\u{2202}\u{335}"),
+    (0x1EE00, "l"),
+    (0x1EE01, "\u{628}"),
+    (0x1EE02, "\u{62C}"),
+    (0x1EE03, "\u{62F}"),
+    (0x1EE05, "\u{648}"),
+    (0x1EE06, "\u{632}"),
+    (0x1EE07, "\u{62D}"),
+    (0x1EE08, "\u{637}"),
+    (0x1EE09, "\u{649}"),
+    (0x1EE0A, "\u{643}"),
+    (0x1EE0B, "\u{644}"),
+    (0x1EE0C, "\u{645}"),
+    (0x1EE0D, "\u{646}"),
+    (0x1EE0E, "\u{633}"),
+    (0x1EE0F, "\u{639}"),
+    (0x1EE10, "\u{641}"),
+    (0x1EE11, "\u{635}"),
+    (0x1EE12, "\u{642}"),
+    (0x1EE13, "\u{631}"),
+    (0x1EE14, "\u{633}\u{6DB}"),
+    (0x1EE15, "\u{62A}"),
+    (0x1EE16, "\u{649}\u{6DB}"),
+    (0x1EE17, "\u{62E}"),
+    (0x1EE18, "\u{630}"),
+    (0x1EE19, "\u{636}"),
+    (0x1EE1A, "\u{638}"),
+    (0x1EE1B, "\u{63A}"),
+    (0x1EE1C, "\u{649}"),
+    (0x1EE1D, "\u{649}"),
+    (0x1EE1E, "\u{6A1}"),
+    (0x1EE1F, "\u{6A1}"),
+    (0x1EE21, "\u{628}"),
+    (0x1EE22, "\u{62C}"),
+    (0x1EE24, "o"),
+    (0x1EE27, "\u{62D}"),
+    (0x1EE29, "\u{649}"),
+    (0x1EE2A, "\u{643}"),
+    (0x1EE2B, "\u{644}"),
+    (0x1EE2C, "\u{645}"),
+    (0x1EE2D, "\u{646}"),
+    (0x1EE2E, "\u{633}"),
+    (0x1EE2F, "\u{639}"),
+    (0x1EE30, "\u{641}"),
+    (0x1EE31, "\u{635}"),
+    (0x1EE32, "\u{642}"),
+    (0x1EE34, "\u{633}\u{6DB}"),
+    (0x1EE35, "\u{62A}"),
+    (0x1EE36, "\u{649}\u{6DB}"),
+    (0x1EE37, "\u{62E}"),
+    (0x1EE39, "\u{636}"),
+    (0x1EE3B, "\u{63A}"),
+    (0x1EE42, "\u{62C}"),
+    (0x1EE47, "\u{62D}"),
+    (0x1EE49, "\u{649}"),
+    (0x1EE4B, "\u{644}"),
+    (0x1EE4D, "\u{646}"),
+    (0x1EE4E, "\u{633}"),
+    (0x1EE4F, "\u{639}"),
+    (0x1EE51, "\u{635}"),
+    (0x1EE52, "\u{642}"),
+    (0x1EE54, "\u{633}\u{6DB}"),
+    (0x1EE57, "\u{62E}"),
+    (0x1EE59, "\u{636}"),
+    (0x1EE5B, "\u{63A}"),
+    (0x1EE5D, "\u{649}"),
+    (0x1EE5F, "\u{6A1}"),
+    (0x1EE61, "\u{628}"),
+    (0x1EE62, "\u{62C}"),
+    (0x1EE64, "o"),
+    (0x1EE67, "\u{62D}"),
+    (0x1EE68, "\u{637}"),
+    (0x1EE69, "\u{649}"),
+    (0x1EE6A, "\u{643}"),
+    (0x1EE6C, "\u{645}"),
+    (0x1EE6D, "\u{646}"),
+    (0x1EE6E, "\u{633}"),
+    (0x1EE6F, "\u{639}"),
+    (0x1EE70, "\u{641}"),
+    (0x1EE71, "\u{635}"),
+    (0x1EE72, "\u{642}"),
+    (0x1EE74, "\u{633}\u{6DB}"),
+    (0x1EE75, "\u{62A}"),
+    (0x1EE76, "\u{649}\u{6DB}"),
+    (0x1EE77, "\u{62E}"),
+    (0x1EE79, "\u{636}"),
+    (0x1EE7A, "\u{638}"),
+    (0x1EE7B, "\u{63A}"),
+    (0x1EE7C, "\u{649}"),
+    (0x1EE7E, "\u{6A1}"),
+    (0x1EE80, "l"),
+    (0x1EE81, "\u{628}"),
+    (0x1EE82, "\u{62C}"),
+    (0x1EE83, "\u{62F}"),
+    (0x1EE84, "o"),
+    (0x1EE85, "\u{648}"),
+    (0x1EE86, "\u{632}"),
+    (0x1EE87, "\u{62D}"),
+    (0x1EE88, "\u{637}"),
+    (0x1EE89, "\u{649}"),
+    (0x1EE8B, "\u{644}"),
+    (0x1EE8C, "\u{645}"),
+    (0x1EE8D, "\u{646}"),
+    (0x1EE8E, "\u{633}"),
+    (0x1EE8F, "\u{639}"),
+    (0x1EE90, "\u{641}"),
+    (0x1EE91, "\u{635}"),
+    (0x1EE92, "\u{642}"),
+    (0x1EE93, "\u{631}"),
+    (0x1EE94, "\u{633}\u{6DB}"),
+    (0x1EE95, "\u{62A}"),
+    (0x1EE96, "\u{649}\u{6DB}"),
+    (0x1EE97, "\u{62E}"),
+    (0x1EE98, "\u{630}"),
+    (0x1EE99, "\u{636}"),
+    (0x1EE9A, "\u{638}"),
+    (0x1EE9B, "\u{63A}"),
+    (0x1EEA1, "\u{628}"),
+    (0x1EEA2, "\u{62C}"),
+    (0x1EEA3, "\u{62F}"),
+    (0x1EEA5, "\u{648}"),
+    (0x1EEA6, "\u{632}"),
+    (0x1EEA7, "\u{62D}"),
+    (0x1EEA8, "\u{637}"),
+    (0x1EEA9, "\u{649}"),
+    (0x1EEAB, "\u{644}"),
+    (0x1EEAC, "\u{645}"),
+    (0x1EEAD, "\u{646}"),
+    (0x1EEAE, "\u{633}"),
+    (0x1EEAF, "\u{639}"),
+    (0x1EEB0, "\u{641}"),
+    (0x1EEB1, "\u{635}"),
+    (0x1EEB2, "\u{642}"),
+    (0x1EEB3, "\u{631}"),
+    (0x1EEB4, "\u{633}\u{6DB}"),
+    (0x1EEB5, "\u{62A}"),
+    (0x1EEB6, "\u{649}\u{6DB}"),
+    (0x1EEB7, "\u{62E}"),
+    (0x1EEB8, "\u{630}"),
+    (0x1EEB9, "\u{636}"),
+    (0x1EEBA, "\u{638}"),
+    (0x1EEBB, "\u{63A}"),
+    (0x1F100, "O."),
+    (0x1F101, "O,"),
+    (0x1F102, "l,"),
+    (0x1F103, "2,"),
+    (0x1F104, "3,"),
+    (0x1F105, "4,"),
+    (0x1F106, "5,"),
+    (0x1F107, "6,"),
+    (0x1F108, "7,"),
+    (0x1F109, "8,"),
+    (0x1F10A, "9,"),
+    (0x1F10F, "$\u{20E0}"),
+    (0x1F110, "(A)"),
+    (0x1F111, "(B)"),
+    (0x1F112, "(C)"),
+    (0x1F113, "(D)"),
+    (0x1F114, "(E)"),
+    (0x1F115, "(F)"),
+    (0x1F116, "(G)"),
+    (0x1F117, "(H)"),
+    (0x1F118, "(l)"),
+    (0x1F119, "(J)"),
+    (0x1F11A, "(K)"),
+    (0x1F11B, "(L)"),
+    (0x1F11C, "(M)"),
+    (0x1F11D, "(N)"),
+    (0x1F11E, "(O)"),
+    (0x1F11F, "(P)"),
+    (0x1F120, "(Q)"),
+    (0x1F121, "(R)"),
+    (0x1F122, "(S)"),
+    (0x1F123, "(T)"),
+    (0x1F124, "(U)"),
+    (0x1F125, "(V)"),
+    (0x1F126, "(W)"),
+    (0x1F127, "(X)"),
+    (0x1F128, "(Y)"),
+    (0x1F129, "(Z)"),
+    (0x1F12A, "(S)"),
+    (0x1F16D, "\u{33C4}\u{9}\u{20DD}"),
+    (0x1F16E, "C\u{20E0}"),
+    (0x1F240, "(\u{672C})"),
+    (0x1F241, "(\u{4E09})"),
+    (0x1F242, "(\u{4E8C})"),
+    (0x1F243, "(\u{5B89})"),
+    (0x1F244, "(\u{70B9})"),
+    (0x1F245, "(\u{6253})"),
+    (0x1F246, "(\u{76D7})"),
+    (0x1F247, "(\u{52DD})"),
+    (0x1F248, "(\u{6557})"),
+    (0x1F312, "\u{263D}"),
+    (0x1F318, "\u{263E}"),
+    (0x1F319, "\u{263D}"),
+    (0x1F700, "QE"),
+    (0x1F701, "\u{A658}"),
+    (0x1F702, "\u{394}"),
+    (0x1F704, "\u{102BC}"),
+    (0x1F707, "AR"),
+    (0x1F708, "V\u{1DE4}"),
+    (0x1F70A, "\u{2629}"),
+    (0x1F714, "O\u{335}"),
+    (0x1F728, "\u{102A8}"),
+    (0x1F73A, "\u{29DF}"),
+    (0x1F74C, "C"),
+    (0x1F754, "\u{16DC}"),
+    (0x1F755, "\u{22A1}"),
+    (0x1F75C, "sss"),
+    (0x1F75E, "\u{224F}"),
+    (0x1F768, "T"),
+    (0x1F76B, "MB"),
+    (0x1F76C, "VB"),
+    (0x1F771, "\u{22A0}"),
+    (0x1FBF0, "O"),
+    (0x1FBF1, "l"),
+    (0x1FBF2, "2"),
+    (0x1FBF3, "3"),
+    (0x1FBF4, "4"),
+    (0x1FBF5, "5"),
+    (0x1FBF6, "6"),
+    (0x1FBF7, "7"),
+    (0x1FBF8, "8"),
+    (0x1FBF9, "9"),
+    (0x21FE8, "\u{276C}"),
+    (0x2F800, "\u{4E3D}"),
+    (0x2F801, "\u{4E38}"),
+    (0x2F802, "\u{4E41}"),
+    (0x2F803, "\u{20122}"),
+    (0x2F804, "\u{4F60}"),
+    (0x2F805, "\u{4FAE}"),
+    (0x2F806, "\u{4FBB}"),
+    (0x2F807, "\u{4F75}"),
+    (0x2F808, "\u{507A}"),
+    (0x2F809, "\u{5099}"),
+    (0x2F80A, "\u{50E7}"),
+    (0x2F80B, "\u{50CF}"),
+    (0x2F80C, "\u{349E}"),
+    (0x2F80D, "\u{2063A}"),
+    (0x2F80E, "\u{514D}"),
+    (0x2F80F, "\u{5154}"),
+    (0x2F810, "\u{5164}"),
+    (0x2F811, "\u{5177}"),
+    (0x2F812, "\u{2051C}"),
+    (0x2F813, "\u{34B9}"),
+    (0x2F814, "\u{5167}"),
+    (0x2F815, "\u{518D}"),
+    (0x2F816, "\u{2054B}"),
+    (0x2F817, "\u{5197}"),
+    (0x2F818, "\u{51A4}"),
+    (0x2F819, "\u{4ECC}"),
+    (0x2F81A, "\u{51AC}"),
+    (0x2F81B, "\u{51B5}"),
+    (0x2F81C, "\u{291DF}"),
+    (0x2F81D, "\u{51F5}"),
+    (0x2F81E, "\u{5203}"),
+    (0x2F81F, "\u{34DF}"),
+    (0x2F820, "\u{523B}"),
+    (0x2F821, "\u{5246}"),
+    (0x2F822, "\u{5272}"),
+    (0x2F823, "\u{5277}"),
+    (0x2F824, "\u{3515}"),
+    (0x2F825, "\u{52C7}"),
+    (0x2F826, "\u{52C9}"),
+    (0x2F827, "\u{52E4}"),
+    (0x2F828, "\u{52FA}"),
+    (0x2F829, "\u{5305}"),
+    (0x2F82A, "\u{5306}"),
+    (0x2F82B, "\u{5317}"),
+    (0x2F82C, "\u{5349}"),
+    (0x2F82D, "\u{5351}"),
+    (0x2F82E, "\u{535A}"),
+    (0x2F82F, "\u{5373}"),
+    (0x2F830, "\u{537D}"),
+    (0x2F831, "\u{537F}"),
+    (0x2F832, "\u{537F}"),
+    (0x2F833, "\u{537F}"),
+    (0x2F834, "\u{20A2C}"),
+    (0x2F835, "\u{7070}"),
+    (0x2F836, "\u{53CA}"),
+    (0x2F837, "\u{53DF}"),
+    (0x2F838, "\u{20B63}"),
+    (0x2F839, "\u{53EB}"),
+    (0x2F83A, "\u{53F1}"),
+    (0x2F83B, "\u{5406}"),
+    (0x2F83C, "\u{549E}"),
+    (0x2F83D, "\u{5438}"),
+    (0x2F83E, "\u{5448}"),
+    (0x2F83F, "\u{5468}"),
+    (0x2F840, "\u{54A2}"),
+    (0x2F841, "\u{54F6}"),
+    (0x2F842, "\u{5510}"),
+    (0x2F843, "\u{5553}"),
+    (0x2F844, "\u{5563}"),
+    (0x2F845, "\u{5584}"),
+    (0x2F846, "\u{5584}"),
+    (0x2F847, "\u{5599}"),
+    (0x2F848, "\u{55AB}"),
+    (0x2F849, "\u{55B3}"),
+    (0x2F84A, "\u{55C2}"),
+    (0x2F84B, "\u{5716}"),
+    (0x2F84C, "\u{5606}"),
+    (0x2F84D, "\u{5717}"),
+    (0x2F84E, "\u{5651}"),
+    (0x2F84F, "\u{5674}"),
+    (0x2F850, "\u{5207}"),
+    (0x2F851, "\u{58EE}"),
+    (0x2F852, "\u{57CE}"),
+    (0x2F853, "\u{57F4}"),
+    (0x2F854, "\u{580D}"),
+    (0x2F855, "\u{578B}"),
+    (0x2F856, "\u{5832}"),
+    (0x2F857, "\u{5831}"),
+    (0x2F858, "\u{58AC}"),
+    (0x2F859, "\u{214E4}"),
+    (0x2F85A, "\u{58F2}"),
+    (0x2F85B, "\u{58F7}"),
+    (0x2F85C, "\u{5906}"),
+    (0x2F85D, "\u{591A}"),
+    (0x2F85E, "\u{5922}"),
+    (0x2F85F, "\u{5962}"),
+    (0x2F860, "\u{216A8}"),
+    (0x2F861, "\u{216EA}"),
+    (0x2F862, "\u{59EC}"),
+    (0x2F863, "\u{5A1B}"),
+    (0x2F864, "\u{5A27}"),
+    (0x2F865, "\u{59D8}"),
+    (0x2F866, "\u{5A66}"),
+    (0x2F867, "\u{36EE}"),
+    (0x2F868, "\u{36FC}"),
+    (0x2F869, "\u{5B08}"),
+    (0x2F86A, "\u{5B3E}"),
+    (0x2F86B, "\u{5B3E}"),
+    (0x2F86C, "\u{219C8}"),
+    (0x2F86D, "\u{5BC3}"),
+    (0x2F86E, "\u{5BD8}"),
+    (0x2F86F, "\u{5BE7}"),
+    (0x2F870, "\u{5BF3}"),
+    (0x2F871, "\u{21B18}"),
+    (0x2F872, "\u{5BFF}"),
+    (0x2F873, "\u{5C06}"),
+    (0x2F874, "\u{5F53}"),
+    (0x2F875, "\u{5C22}"),
+    (0x2F876, "\u{3781}"),
+    (0x2F877, "\u{5C60}"),
+    (0x2F878, "\u{5C6E}"),
+    (0x2F879, "\u{5CC0}"),
+    (0x2F87A, "\u{5C8D}"),
+    (0x2F87B, "\u{21DE4}"),
+    (0x2F87C, "\u{5D43}"),
+    (0x2F87D, "\u{21DE6}"),
+    (0x2F87E, "\u{5D6E}"),
+    (0x2F87F, "\u{5D6B}"),
+    (0x2F880, "\u{5D7C}"),
+    (0x2F881, "\u{5DE1}"),
+    (0x2F882, "\u{5DE2}"),
+    (0x2F883, "\u{382F}"),
+    (0x2F884, "\u{5DFD}"),
+    (0x2F885, "\u{5E28}"),
+    (0x2F886, "\u{5E3D}"),
+    (0x2F887, "\u{5E69}"),
+    (0x2F888, "\u{3862}"),
+    (0x2F889, "\u{22183}"),
+    (0x2F88A, "\u{387C}"),
+    (0x2F88B, "\u{5EB0}"),
+    (0x2F88C, "\u{5EB3}"),
+    (0x2F88D, "\u{5EB6}"),
+    (0x2F88E, "\u{5ECA}"),
+    (0x2F88F, "\u{2A392}"),
+    (0x2F890, "\u{5EFE}"),
+    (0x2F891, "\u{22331}"),
+    (0x2F892, "\u{22331}"),
+    (0x2F893, "\u{8201}"),
+    (0x2F894, "\u{5F22}"),
+    (0x2F895, "\u{5F22}"),
+    (0x2F896, "\u{38C7}"),
+    (0x2F897, "\u{232B8}"),
+    (0x2F898, "\u{261DA}"),
+    (0x2F899, "\u{5F62}"),
+    (0x2F89A, "\u{5F6B}"),
+    (0x2F89B, "\u{38E3}"),
+    (0x2F89C, "\u{5F9A}"),
+    (0x2F89D, "\u{5FCD}"),
+    (0x2F89E, "\u{5FD7}"),
+    (0x2F89F, "\u{5FF9}"),
+    (0x2F8A0, "\u{6081}"),
+    (0x2F8A1, "\u{393A}"),
+    (0x2F8A2, "\u{391C}"),
+    (0x2F8A3, "\u{6094}"),
+    (0x2F8A4, "\u{226D4}"),
+    (0x2F8A5, "\u{60C7}"),
+    (0x2F8A6, "\u{6148}"),
+    (0x2F8A7, "\u{614C}"),
+    (0x2F8A8, "\u{614E}"),
+    (0x2F8A9, "\u{614C}"),
+    (0x2F8AA, "\u{617A}"),
+    (0x2F8AB, "\u{618E}"),
+    (0x2F8AC, "\u{61B2}"),
+    (0x2F8AD, "\u{61A4}"),
+    (0x2F8AE, "\u{61AF}"),
+    (0x2F8AF, "\u{61DE}"),
+    (0x2F8B0, "\u{61F2}"),
+    (0x2F8B1, "\u{61F6}"),
+    (0x2F8B2, "\u{6210}"),
+    (0x2F8B3, "\u{621B}"),
+    (0x2F8B4, "\u{625D}"),
+    (0x2F8B5, "\u{62B1}"),
+    (0x2F8B6, "\u{62D4}"),
+    (0x2F8B7, "\u{6350}"),
+    (0x2F8B8, "\u{22B0C}"),
+    (0x2F8B9, "\u{633D}"),
+    (0x2F8BA, "\u{62FC}"),
+    (0x2F8BB, "\u{6368}"),
+    (0x2F8BC, "\u{6383}"),
+    (0x2F8BD, "\u{63E4}"),
+    (0x2F8BE, "\u{22BF1}"),
+    (0x2F8BF, "\u{6422}"),
+    (0x2F8C0, "\u{63C5}"),
+    (0x2F8C1, "\u{63A9}"),
+    (0x2F8C2, "\u{3A2E}"),
+    (0x2F8C3, "\u{6469}"),
+    (0x2F8C4, "\u{647E}"),
+    (0x2F8C5, "\u{649D}"),
+    (0x2F8C6, "\u{6477}"),
+    (0x2F8C7, "\u{3A6C}"),
+    (0x2F8C8, "\u{654F}"),
+    (0x2F8C9, "\u{656C}"),
+    (0x2F8CA, "\u{2300A}"),
+    (0x2F8CB, "\u{65E3}"),
+    (0x2F8CC, "\u{66F8}"),
+    (0x2F8CD, "\u{6649}"),
+    (0x2F8CE, "\u{3B19}"),
+    (0x2F8CF, "\u{6691}"),
+    (0x2F8D0, "\u{3B08}"),
+    (0x2F8D1, "\u{3AE4}"),
+    (0x2F8D2, "\u{5192}"),
+    (0x2F8D3, "\u{5195}"),
+    (0x2F8D4, "\u{6700}"),
+    (0x2F8D5, "\u{669C}"),
+    (0x2F8D6, "\u{80AD}"),
+    (0x2F8D7, "\u{43D9}"),
+    (0x2F8D8, "\u{6717}"),
+    (0x2F8D9, "\u{671B}"),
+    (0x2F8DA, "\u{6721}"),
+    (0x2F8DB, "\u{675E}"),
+    (0x2F8DC, "\u{6753}"),
+    (0x2F8DD, "\u{233C3}"),
+    (0x2F8DE, "\u{3B49}"),
+    (0x2F8DF, "\u{67FA}"),
+    (0x2F8E0, "\u{6785}"),
+    (0x2F8E1, "\u{6852}"),
+    (0x2F8E2, "\u{6885}"),
+    (0x2F8E3, "\u{2346D}"),
+    (0x2F8E4, "\u{688E}"),
+    (0x2F8E5, "\u{681F}"),
+    (0x2F8E6, "\u{6914}"),
+    (0x2F8E7, "\u{3B9D}"),
+    (0x2F8E8, "\u{6942}"),
+    (0x2F8E9, "\u{69A3}"),
+    (0x2F8EA, "\u{69EA}"),
+    (0x2F8EB, "\u{6AA8}"),
+    (0x2F8EC, "\u{236A3}"),
+    (0x2F8ED, "\u{6ADB}"),
+    (0x2F8EE, "\u{3C18}"),
+    (0x2F8EF, "\u{6B21}"),
+    (0x2F8F0, "\u{238A7}"),
+    (0x2F8F1, "\u{6B54}"),
+    (0x2F8F2, "\u{3C4E}"),
+    (0x2F8F3, "\u{6B72}"),
+    (0x2F8F4, "\u{6B9F}"),
+    (0x2F8F5, "\u{6BBA}"),
+    (0x2F8F6, "\u{6BBB}"),
+    (0x2F8F7, "\u{23A8D}"),
+    (0x2F8F8, "\u{21D0B}"),
+    (0x2F8F9, "\u{23AFA}"),
+    (0x2F8FA, "\u{6C4E}"),
+    (0x2F8FB, "\u{23CBC}"),
+    (0x2F8FC, "\u{6CBF}"),
+    (0x2F8FD, "\u{6CCD}"),
+    (0x2F8FE, "\u{6C67}"),
+    (0x2F8FF, "\u{6D16}"),
+    (0x2F900, "\u{6D3E}"),
+    (0x2F901, "\u{6D77}"),
+    (0x2F902, "\u{6D41}"),
+    (0x2F903, "\u{6D69}"),
+    (0x2F904, "\u{6D78}"),
+    (0x2F905, "\u{6D85}"),
+    (0x2F906, "\u{23D1E}"),
+    (0x2F907, "\u{6D34}"),
+    (0x2F908, "\u{6E2F}"),
+    (0x2F909, "\u{6E6E}"),
+    (0x2F90A, "\u{3D33}"),
+    (0x2F90B, "\u{6ECB}"),
+    (0x2F90C, "\u{6EC7}"),
+    (0x2F90D, "\u{23ED1}"),
+    (0x2F90E, "\u{6DF9}"),
+    (0x2F90F, "\u{6F6E}"),
+    (0x2F910, "\u{23F5E}"),
+    (0x2F911, "\u{23F8E}"),
+    (0x2F912, "\u{6FC6}"),
+    (0x2F913, "\u{7039}"),
+    (0x2F914, "\u{701E}"),
+    (0x2F915, "\u{701B}"),
+    (0x2F916, "\u{3D96}"),
+    (0x2F917, "\u{704A}"),
+    (0x2F918, "\u{707D}"),
+    (0x2F919, "\u{7077}"),
+    (0x2F91A, "\u{70AD}"),
+    (0x2F91B, "\u{20525}"),
+    (0x2F91C, "\u{7145}"),
+    (0x2F91D, "\u{24263}"),
+    (0x2F91E, "\u{719C}"),
+    (0x2F91F, "\u{243AB}"),
+    (0x2F920, "\u{7228}"),
+    (0x2F921, "\u{7235}"),
+    (0x2F922, "\u{7250}"),
+    (0x2F923, "\u{24608}"),
+    (0x2F924, "\u{7280}"),
+    (0x2F925, "\u{7295}"),
+    (0x2F926, "\u{24735}"),
+    (0x2F927, "\u{24814}"),
+    (0x2F928, "\u{737A}"),
+    (0x2F929, "\u{738B}"),
+    (0x2F92A, "\u{3EAC}"),
+    (0x2F92B, "\u{73A5}"),
+    (0x2F92C, "\u{3EB8}"),
+    (0x2F92D, "\u{3EB8}"),
+    (0x2F92E, "\u{7447}"),
+    (0x2F92F, "\u{745C}"),
+    (0x2F930, "\u{7471}"),
+    (0x2F931, "\u{7485}"),
+    (0x2F932, "\u{74CA}"),
+    (0x2F933, "\u{3F1B}"),
+    (0x2F934, "\u{7524}"),
+    (0x2F935, "\u{24C36}"),
+    (0x2F936, "\u{753E}"),
+    (0x2F937, "\u{24C92}"),
+    (0x2F938, "\u{7570}"),
+    (0x2F939, "\u{2219F}"),
+    (0x2F93A, "\u{7610}"),
+    (0x2F93B, "\u{24FA1}"),
+    (0x2F93C, "\u{24FB8}"),
+    (0x2F93D, "\u{25044}"),
+    (0x2F93E, "\u{3FFC}"),
+    (0x2F93F, "\u{4008}"),
+    (0x2F940, "\u{76F4}"),
+    (0x2F941, "\u{250F3}"),
+    (0x2F942, "\u{250F2}"),
+    (0x2F943, "\u{25119}"),
+    (0x2F944, "\u{25133}"),
+    (0x2F945, "\u{771E}"),
+    (0x2F946, "\u{771F}"),
+    (0x2F947, "\u{771F}"),
+    (0x2F948, "\u{774A}"),
+    (0x2F949, "\u{4039}"),
+    (0x2F94A, "\u{778B}"),
+    (0x2F94B, "\u{4046}"),
+    (0x2F94C, "\u{4096}"),
+    (0x2F94D, "\u{2541D}"),
+    (0x2F94E, "\u{784E}"),
+    (0x2F94F, "\u{788C}"),
+    (0x2F950, "\u{78CC}"),
+    (0x2F951, "\u{40E3}"),
+    (0x2F952, "\u{25626}"),
+    (0x2F953, "\u{7956}"),
+    (0x2F954, "\u{2569A}"),
+    (0x2F955, "\u{256C5}"),
+    (0x2F956, "\u{798F}"),
+    (0x2F957, "\u{79EB}"),
+    (0x2F958, "\u{412F}"),
+    (0x2F959, "\u{7A40}"),
+    (0x2F95A, "\u{7A4A}"),
+    (0x2F95B, "\u{7A4F}"),
+    (0x2F95C, "\u{2597C}"),
+    (0x2F95D, "\u{25AA7}"),
+    (0x2F95E, "\u{25AA7}"),
+    (0x2F95F, "\u{7AEE}"),
+    (0x2F960, "\u{4202}"),
+    (0x2F961, "\u{25BAB}"),
+    (0x2F962, "\u{7BC6}"),
+    (0x2F963, "\u{7BC9}"),
+    (0x2F964, "\u{4227}"),
+    (0x2F965, "\u{25C80}"),
+    (0x2F966, "\u{7CD2}"),
+    (0x2F967, "\u{42A0}"),
+    (0x2F968, "\u{7CE8}"),
+    (0x2F969, "\u{7CE3}"),
+    (0x2F96A, "\u{7D00}"),
+    (0x2F96B, "\u{25F86}"),
+    (0x2F96C, "\u{7D63}"),
+    (0x2F96D, "\u{4301}"),
+    (0x2F96E, "\u{7DC7}"),
+    (0x2F96F, "\u{7E02}"),
+    (0x2F970, "\u{7E45}"),
+    (0x2F971, "\u{4334}"),
+    (0x2F972, "\u{26228}"),
+    (0x2F973, "\u{26247}"),
+    (0x2F974, "\u{4359}"),
+    (0x2F975, "\u{262D9}"),
+    (0x2F976, "\u{7F7A}"),
+    (0x2F977, "\u{2633E}"),
+    (0x2F978, "\u{7F95}"),
+    (0x2F979, "\u{7FFA}"),
+    (0x2F97A, "\u{8005}"),
+    (0x2F97B, "\u{264DA}"),
+    (0x2F97C, "\u{26523}"),
+    (0x2F97D, "\u{8060}"),
+    (0x2F97E, "\u{265A8}"),
+    (0x2F97F, "\u{8070}"),
+    (0x2F980, "\u{2335F}"),
+    (0x2F981, "\u{43D5}"),
+    (0x2F982, "\u{80B2}"),
+    (0x2F983, "\u{8103}"),
+    (0x2F984, "\u{440B}"),
+    (0x2F985, "\u{813E}"),
+    (0x2F986, "\u{5AB5}"),
+    (0x2F987, "\u{267A7}"),
+    (0x2F988, "\u{267B5}"),
+    (0x2F989, "\u{23393}"),
+    (0x2F98A, "\u{2339C}"),
+    (0x2F98B, "\u{8201}"),
+    (0x2F98C, "\u{8204}"),
+    (0x2F98D, "\u{8F9E}"),
+    (0x2F98E, "\u{446B}"),
+    (0x2F98F, "\u{8291}"),
+    (0x2F990, "\u{828B}"),
+    (0x2F991, "\u{829D}"),
+    (0x2F992, "\u{52B3}"),
+    (0x2F993, "\u{82B1}"),
+    (0x2F994, "\u{82B3}"),
+    (0x2F995, "\u{82BD}"),
+    (0x2F996, "\u{82E6}"),
+    (0x2F997, "\u{26B3C}"),
+    (0x2F998, "\u{82E5}"),
+    (0x2F999, "\u{831D}"),
+    (0x2F99A, "\u{8363}"),
+    (0x2F99B, "\u{83AD}"),
+    (0x2F99C, "\u{8323}"),
+    (0x2F99D, "\u{83BD}"),
+    (0x2F99E, "\u{83E7}"),
+    (0x2F99F, "\u{8457}"),
+    (0x2F9A0, "\u{8353}"),
+    (0x2F9A1, "\u{83CA}"),
+    (0x2F9A2, "\u{83CC}"),
+    (0x2F9A3, "\u{83DC}"),
+    (0x2F9A4, "\u{26C36}"),
+    (0x2F9A5, "\u{26D6B}"),
+    (0x2F9A6, "\u{26CD5}"),
+    (0x2F9A7, "\u{452B}"),
+    (0x2F9A8, "\u{84F1}"),
+    (0x2F9A9, "\u{84F3}"),
+    (0x2F9AA, "\u{8516}"),
+    (0x2F9AB, "\u{273CA}"),
+    (0x2F9AC, "\u{8564}"),
+    (0x2F9AD, "\u{26F2C}"),
+    (0x2F9AE, "\u{455D}"),
+    (0x2F9AF, "\u{4561}"),
+    (0x2F9B0, "\u{26FB1}"),
+    (0x2F9B1, "\u{270D2}"),
+    (0x2F9B2, "\u{456B}"),
+    (0x2F9B3, "\u{8650}"),
+    (0x2F9B4, "\u{865C}"),
+    (0x2F9B5, "\u{8667}"),
+    (0x2F9B6, "\u{8669}"),
+    (0x2F9B7, "\u{86A9}"),
+    (0x2F9B8, "\u{8688}"),
+    (0x2F9B9, "\u{870E}"),
+    (0x2F9BA, "\u{86E2}"),
+    (0x2F9BB, "\u{8779}"),
+    (0x2F9BC, "\u{8728}"),
+    (0x2F9BD, "\u{876B}"),
+    (0x2F9BE, "\u{8786}"),
+    (0x2F9BF, "\u{45D7}"),
+    (0x2F9C0, "\u{87E1}"),
+    (0x2F9C1, "\u{8801}"),
+    (0x2F9C2, "\u{45F9}"),
+    (0x2F9C3, "\u{8860}"),
+    (0x2F9C4, "\u{8863}"),
+    (0x2F9C5, "\u{27667}"),
+    (0x2F9C6, "\u{88D7}"),
+    (0x2F9C7, "\u{88DE}"),
+    (0x2F9C8, "\u{4635}"),
+    (0x2F9C9, "\u{88FA}"),
+    (0x2F9CA, "\u{34BB}"),
+    (0x2F9CB, "\u{278AE}"),
+    (0x2F9CC, "\u{27966}"),
+    (0x2F9CD, "\u{46BE}"),
+    (0x2F9CE, "\u{46C7}"),
+    (0x2F9CF, "\u{8AA0}"),
+    (0x2F9D0, "\u{8AED}"),
+    (0x2F9D1, "\u{8B8A}"),
+    (0x2F9D2, "\u{8C55}"),
+    (0x2F9D3, "\u{27CA8}"),
+    (0x2F9D4, "\u{8CAB}"),
+    (0x2F9D5, "\u{8CC1}"),
+    (0x2F9D6, "\u{8D1B}"),
+    (0x2F9D7, "\u{8D77}"),
+    (0x2F9D8, "\u{27F2F}"),
+    (0x2F9D9, "\u{20804}"),
+    (0x2F9DA, "\u{8DCB}"),
+    (0x2F9DB, "\u{8DBC}"),
+    (0x2F9DC, "\u{8DF0}"),
+    (0x2F9DD, "\u{208DE}"),
+    (0x2F9DE, "\u{8ED4}"),
+    (0x2F9DF, "\u{8F38}"),
+    (0x2F9E0, "\u{285D2}"),
+    (0x2F9E1, "\u{285ED}"),
+    (0x2F9E2, "\u{9094}"),
+    (0x2F9E3, "\u{90F1}"),
+    (0x2F9E4, "\u{9111}"),
+    (0x2F9E5, "\u{2872E}"),
+    (0x2F9E6, "\u{911B}"),
+    (0x2F9E7, "\u{9238}"),
+    (0x2F9E8, "\u{92D7}"),
+    (0x2F9E9, "\u{92D8}"),
+    (0x2F9EA, "\u{927C}"),
+    (0x2F9EB, "\u{93F9}"),
+    (0x2F9EC, "\u{9415}"),
+    (0x2F9ED, "\u{28BFA}"),
+    (0x2F9EE, "\u{958B}"),
+    (0x2F9EF, "\u{4995}"),
+    (0x2F9F0, "\u{95B7}"),
+    (0x2F9F1, "\u{28D77}"),
+    (0x2F9F2, "\u{49E6}"),
+    (0x2F9F3, "\u{96C3}"),
+    (0x2F9F4, "\u{5DB2}"),
+    (0x2F9F5, "\u{9723}"),
+    (0x2F9F6, "\u{29145}"),
+    (0x2F9F7, "\u{2921A}"),
+    (0x2F9F8, "\u{4A6E}"),
+    (0x2F9F9, "\u{4A76}"),
+    (0x2F9FA, "\u{97E0}"),
+    (0x2F9FB, "\u{2940A}"),
+    (0x2F9FC, "\u{4AB2}"),
+    (0x2F9FD, "\u{29496}"),
+    (0x2F9FE, "\u{980B}"),
+    (0x2F9FF, "\u{980B}"),
+    (0x2FA00, "\u{9829}"),
+    (0x2FA01, "\u{295B6}"),
+    (0x2FA02, "\u{98E2}"),
+    (0x2FA03, "\u{4B33}"),
+    (0x2FA04, "\u{9929}"),
+    (0x2FA05, "\u{99A7}"),
+    (0x2FA06, "\u{99C2}"),
+    (0x2FA07, "\u{99FE}"),
+    (0x2FA08, "\u{4BCE}"),
+    (0x2FA09, "\u{29B30}"),
+    (0x2FA0A, "\u{9B12}"),
+    (0x2FA0B, "\u{9C40}"),
+    (0x2FA0C, "\u{9CFD}"),
+    (0x2FA0D, "\u{4CCE}"),
+    (0x2FA0E, "\u{4CED}"),
+    (0x2FA0F, "\u{9D67}"),
+    (0x2FA10, "\u{2A0CE}"),
+    (0x2FA11, "\u{4CF8}"),
+    (0x2FA12, "\u{2A105}"),
+    (0x2FA13, "\u{2A20E}"),
+    (0x2FA14, "\u{2A291}"),
+    (0x2FA15, "\u{9EBB}"),
+    (0x2FA16, "\u{4D56}"),
+    (0x2FA17, "\u{9EF9}"),
+    (0x2FA18, "\u{9EFE}"),
+    (0x2FA19, "\u{9F05}"),
+    (0x2FA1A, "\u{9F0F}"),
+    (0x2FA1B, "\u{9F16}"),
+    (0x2FA1C, "\u{9F3B}"),
+    (0x2FA1D, "\u{2A600}"),
+];
@@ -0,0 +1,280 @@
+/// A `reg-name` that is a valid DNS hostname.
+///
+/// - each label is 1 to 63 octets of letters, digits and hyphens, and does not begin or end with a hyphen
+/// - the name is at most 253 octets, not counting a single trailing dot
+///
+/// <https://datatracker.ietf.org/doc/html/rfc1123#section-2.1>
+/// <https://datatracker.ietf.org/doc/html/rfc1035#section-2.3.4>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DnsName<'a> {
+    name: &'a str,
+    fully_qualified: bool,
+}
+
+const MAX_LABEL_LEN: usize = 63;
+const MAX_NAME_LEN: usize = 253;
+
+impl<'a> DnsName<'a> {
+    /// Validates `s` as a DNS hostname.
+    ///
+    /// A single trailing dot (`example.com.`) is accepted and marks the name as fully qualified.
+    pub fn new(s: &'a str) -> Result<Self, DnsNameError> {
+        let (name, fully_qualified) = match s.strip_suffix('.') {
+            Some(name) => (name, true),
+            None => (s, false),
+        };
+        if name.is_empty() {
+            return Err(DnsNameError::Empty);
+        }
+        if name.len() > MAX_NAME_LEN {
+            return Err(DnsNameError::NameTooLong { len: name.len() });
+        }
+
+        let mut offset = 0;
+        for label in name.split('.') {
+            validate_label(label, offset)?;
+            offset += label.len() + 1;
+        }
+
+        Ok(Self {
+            name,
+            fully_qualified,
+        })
+    }
+
+    /// Returns the name without the trailing dot.
+    pub fn as_str(&self) -> &'a str {
+        self.name
+    }
+
+    /// Returns `true` if the name was written with a trailing dot.
+    pub fn is_fully_qualified(&self) -> bool {
+        self.fully_qualified
+    }
+
+    /// Returns an iterator over the labels, from left to right.
+    pub fn labels(&self) -> Labels<'a> {
+        Labels {
+            inner: self.name.split('.'),
+        }
+    }
+}
+
+impl core::fmt::Display for DnsName<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name)?;
+        if self.fully_qualified {
+            f.write_str(".")?;
+        }
+        Ok(())
+    }
+}
+
+fn validate_label(label: &str, offset: usize) -> Result<(), DnsNameError> {
+    if label.is_empty() {
+        return Err(DnsNameError::EmptyLabel { offset });
+    }
+    if label.len() > MAX_LABEL_LEN {
+        return Err(DnsNameError::LabelTooLong {
+            offset,
+            len: label.len(),
+        });
+    }
+    if let Some((i, c)) = label
+        .char_indices()
+        .find(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '-'))
+    {
+        return Err(if c == '%' {
+            DnsNameError::PercentEncoded { offset: offset + i }
+        } else {
+            DnsNameError::InvalidCharacter {
+                offset: offset + i,
+                c,
+            }
+        });
+    }
+    if label.starts_with('-') {
+        return Err(DnsNameError::LeadingHyphen { offset });
+    }
+    if label.ends_with('-') {
+        return Err(DnsNameError::TrailingHyphen {
+            offset: offset + label.len() - 1,
+        });
+    }
+    Ok(())
+}
+
+/// An iterator over the labels of a [`DnsName`].
+#[derive(Clone, Debug)]
+pub struct Labels<'a> {
+    inner: core::str::Split<'a, char>,
+}
+
+impl<'a> Iterator for Labels<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl DoubleEndedIterator for Labels<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+/// The reason a host is not a valid DNS hostname.
+///
+/// Offsets are byte offsets into the `reg-name`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DnsNameError {
+    /// The host is an IP literal or an IPv4 address, not a `reg-name`.
+    NotRegName,
+    /// The name is empty (or is only a dot).
+    Empty,
+    /// The name is longer than 253 octets.
+    NameTooLong { len: usize },
+    /// A label is empty, e.g. `a..b` or `.a`.
+    EmptyLabel { offset: usize },
+    /// A label is longer than 63 octets.
+    LabelTooLong { offset: usize, len: usize },
+    /// A label contains a character other than a letter, digit or hyphen.
+    InvalidCharacter { offset: usize, c: char },
+    /// A label contains a percent-encoded octet.
+    PercentEncoded { offset: usize },
+    /// A label begins with a hyphen.
+    LeadingHyphen { offset: usize },
+    /// A label ends with a hyphen.
+    TrailingHyphen { offset: usize },
+}
+
+impl core::fmt::Display for DnsNameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DnsNameError::NotRegName => write!(f, "host is not a reg-name"),
+            DnsNameError::Empty => write!(f, "name is empty"),
+            DnsNameError::NameTooLong { len } => {
+                write!(f, "name is {} octets long (max {})", len, MAX_NAME_LEN)
+            }
+            DnsNameError::EmptyLabel { offset } => write!(f, "empty label at offset {}", offset),
+            DnsNameError::LabelTooLong { offset, len } => write!(
+                f,
+                "label at offset {} is {} octets long (max {})",
+                offset, len, MAX_LABEL_LEN
+            ),
+            DnsNameError::InvalidCharacter { offset, c } => {
+                write!(f, "invalid character {:?} at offset {}", c, offset)
+            }
+            DnsNameError::PercentEncoded { offset } => {
+                write!(f, "percent-encoded octet at offset {}", offset)
+            }
+            DnsNameError::LeadingHyphen { offset } => {
+                write!(f, "label at offset {} begins with a hyphen", offset)
+            }
+            DnsNameError::TrailingHyphen { offset } => {
+                write!(f, "label ends with a hyphen at offset {}", offset)
+            }
+        }
+    }
+}
+
+impl core::error::Error for DnsNameError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        for s in [
+            "example.com",
+            "sub.example.com",
+            "localhost",
+            "a",
+            "1.2.3",
+            "xn--bcher-kva.example",
+            "a-b.c-d",
+            "EXAMPLE.COM",
+        ] {
+            assert_eq!(DnsName::new(s).map(|n| n.as_str()), Ok(s));
+        }
+
+        assert_eq!(DnsName::new(""), Err(DnsNameError::Empty));
+        assert_eq!(DnsName::new("."), Err(DnsNameError::Empty));
+        assert_eq!(
+            DnsName::new("a..b"),
+            Err(DnsNameError::EmptyLabel { offset: 2 })
+        );
+        assert_eq!(
+            DnsName::new(".a"),
+            Err(DnsNameError::EmptyLabel { offset: 0 })
+        );
+        assert_eq!(
+            DnsName::new("a.."),
+            Err(DnsNameError::EmptyLabel { offset: 2 })
+        );
+        assert_eq!(
+            DnsName::new("a_b.com"),
+            Err(DnsNameError::InvalidCharacter { offset: 1, c: '_' })
+        );
+        assert_eq!(
+            DnsName::new("example.com!"),
+            Err(DnsNameError::InvalidCharacter { offset: 11, c: '!' })
+        );
+        assert_eq!(
+            DnsName::new("ex%61mple.com"),
+            Err(DnsNameError::PercentEncoded { offset: 2 })
+        );
+        assert_eq!(
+            DnsName::new("a.-b"),
+            Err(DnsNameError::LeadingHyphen { offset: 2 })
+        );
+        assert_eq!(
+            DnsName::new("a.b-"),
+            Err(DnsNameError::TrailingHyphen { offset: 3 })
+        );
+    }
+
+    #[test]
+    fn test_new_length_limits() {
+        let label63 = "a".repeat(63);
+        assert!(DnsName::new(&label63).is_ok());
+        assert_eq!(
+            DnsName::new(&format!("x.{}", "a".repeat(64))),
+            Err(DnsNameError::LabelTooLong { offset: 2, len: 64 })
+        );
+
+        // 63 + 1 + 63 + 1 + 63 + 1 + 61 = 253
+        let name253 = format!("{0}.{0}.{0}.{1}", label63, "a".repeat(61));
+        assert_eq!(name253.len(), 253);
+        assert!(DnsName::new(&name253).is_ok());
+        assert!(DnsName::new(&format!("{}.", name253)).is_ok());
+        assert_eq!(
+            DnsName::new(&format!("{}a", name253)),
+            Err(DnsNameError::NameTooLong { len: 254 })
+        );
+    }
+
+    #[test]
+    fn test_trailing_dot() {
+        let name = DnsName::new("example.com.").unwrap();
+        assert_eq!(name.as_str(), "example.com");
+        assert!(name.is_fully_qualified());
+        assert_eq!(name.to_string(), "example.com.");
+
+        let name = DnsName::new("example.com").unwrap();
+        assert!(!name.is_fully_qualified());
+        assert_eq!(name.to_string(), "example.com");
+    }
+
+    #[test]
+    fn test_labels() {
+        let name = DnsName::new("www.example.com.").unwrap();
+        assert_eq!(
+            name.labels().collect::<Vec<_>>(),
+            vec!["www", "example", "com"]
+        );
+        assert_eq!(name.labels().next_back(), Some("com"));
+    }
+}
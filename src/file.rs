@@ -0,0 +1,527 @@
+//! The `file` URI scheme
+//!
+//! ```text
+//! file-URI       = file-scheme ":" file-hier-part
+//! file-hier-part = ( "//" auth-path ) / local-path
+//! auth-path      = [ file-auth ] path-absolute
+//! local-path     = [ drive-letter ] path-absolute
+//! file-auth      = "localhost" / host
+//! drive-letter   = ALPHA ":"
+//! ```
+//!
+//! Besides these, the UNC forms of Appendix E.3 are accepted: `file:////host/share/path` and
+//! `file://///host/share/path` name the same file as `file://host/share/path`.
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc8089#section-2>
+//! <https://datatracker.ietf.org/doc/html/rfc8089#appendix-E>
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use crate::{Host, ParseError, Uri};
+#[cfg(feature = "alloc")]
+use crate::{UriBuf, percent_encoding};
+
+/// An error returned when a URI is not a `file` URI, or when it cannot be converted to or from a path.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FileError {
+    /// The input is not a `URI`.
+    Syntax(ParseError),
+    /// The scheme is not `file`.
+    Scheme,
+    /// The authority has a `userinfo` or a port, or the UNC host is not a `host`.
+    Authority,
+    /// The URI has a query.
+    Query,
+    /// The path is not absolute.
+    NotAbsolute,
+    /// The file is on a remote host, which a Unix path cannot name.
+    Remote,
+    /// The path has a DOS drive letter, which a Unix path cannot name.
+    DriveLetter,
+    /// The path is local and has no drive letter, which a Windows path needs.
+    NoDriveLetter,
+    /// The path decodes to a NUL or to a path separator within a segment.
+    ForbiddenCharacter(char),
+    /// The path does not decode to UTF-8.
+    InvalidUtf8,
+}
+
+impl core::fmt::Display for FileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FileError::Syntax(e) => write!(f, "{}", e),
+            FileError::Scheme => f.write_str("scheme is not file"),
+            FileError::Authority => f.write_str("invalid file authority"),
+            FileError::Query => f.write_str("file URI with a query"),
+            FileError::NotAbsolute => f.write_str("path is not absolute"),
+            FileError::Remote => f.write_str("file is on a remote host"),
+            FileError::DriveLetter => f.write_str("path has a drive letter"),
+            FileError::NoDriveLetter => f.write_str("path has no drive letter"),
+            FileError::ForbiddenCharacter(c) => write!(f, "path decodes to {:?}", c),
+            FileError::InvalidUtf8 => f.write_str("path is not UTF-8"),
+        }
+    }
+}
+
+impl core::error::Error for FileError {}
+
+/// A parsed `file` URI.
+///
+/// ```
+/// use nom_uri::{Host, file::FileUri};
+///
+/// let uri = FileUri::parse("file:///c:/Program%20Files/a.txt").unwrap();
+/// assert_eq!(uri.host(), None);
+/// assert_eq!(uri.drive_letter(), Some('c'));
+/// assert_eq!(uri.path(), "/Program%20Files/a.txt");
+///
+/// let uri = FileUri::parse("file:////server/share/a.txt").unwrap();
+/// assert_eq!(uri.host(), Some(Host::RegName("server")));
+/// assert_eq!(uri.path(), "/share/a.txt");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FileUri<'a> {
+    uri: Uri<'a>,
+    host: Option<Host<'a>>,
+    drive_letter: Option<char>,
+    path: &'a str,
+}
+
+impl<'a> FileUri<'a> {
+    /// Parses `s` as a `file` URI.
+    pub fn parse(s: &'a str) -> Result<Self, FileError> {
+        Self::try_from(Uri::parse(s).map_err(FileError::Syntax)?)
+    }
+
+    pub fn as_uri(&self) -> Uri<'a> {
+        self.uri
+    }
+
+    /// Returns the host of a remote file, from the authority or from a UNC path. It is `None` for a local file,
+    /// whose authority is absent, empty or `localhost`.
+    pub fn host(&self) -> Option<Host<'a>> {
+        self.host
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.host.is_none()
+    }
+
+    /// Returns the DOS drive letter of a local path, as written.
+    pub fn drive_letter(&self) -> Option<char> {
+        self.drive_letter
+    }
+
+    /// Returns the `path-absolute` after the drive letter and the UNC host, not percent-decoded. It is empty for a
+    /// bare drive (`file:///c:`).
+    pub fn path(&self) -> &'a str {
+        self.path
+    }
+
+    /// Converts the URI to a Windows path: `c:\dir\file` for a drive letter, `\\host\share\file` for a remote
+    /// file.
+    ///
+    /// ```
+    /// use nom_uri::file::FileUri;
+    ///
+    /// let uri = FileUri::parse("file:///c:/Program%20Files/a.txt").unwrap();
+    /// assert_eq!(uri.to_windows_path().unwrap(), r"c:\Program Files\a.txt");
+    /// let uri = FileUri::parse("file:////server/share/a.txt").unwrap();
+    /// assert_eq!(uri.to_windows_path().unwrap(), r"\\server\share\a.txt");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_windows_path(&self) -> Result<String, FileError> {
+        let path = decode_utf8(self.path)?.replace('/', "\\");
+        match (self.host, self.drive_letter) {
+            (Some(host), _) => Ok(format!("\\\\{}{}", decode_utf8(&host.to_string())?, path)),
+            (None, Some(drive)) if path.is_empty() => Ok(format!("{}:\\", drive)),
+            (None, Some(drive)) => Ok(format!("{}:{}", drive, path)),
+            (None, None) => Err(FileError::NoDriveLetter),
+        }
+    }
+
+    /// Converts a Windows path to a `file` URI. A path with a drive letter becomes `file:///c:/dir/file` and a UNC
+    /// path becomes `file://host/share/file`; `\\?\` long paths are accepted.
+    ///
+    /// ```
+    /// use nom_uri::file::FileUri;
+    ///
+    /// assert_eq!(
+    ///     FileUri::from_windows_path(r"C:\My Documents\ü.txt").unwrap().as_str(),
+    ///     "file:///C:/My%20Documents/%C3%BC.txt"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_windows_path(s: &str) -> Result<UriBuf, FileError> {
+        let s = match s.strip_prefix(r"\\?\") {
+            Some(s) => match s.strip_prefix(r"UNC\") {
+                Some(unc) => return from_unc_path(unc),
+                None => s,
+            },
+            None => s,
+        };
+        if let Some(unc) = s.strip_prefix(r"\\") {
+            return from_unc_path(unc);
+        }
+        let path = match split_drive_letter(s) {
+            Some((drive, path)) if path.is_empty() || path.starts_with(['\\', '/']) => {
+                format!("/{}:{}", drive, encode_windows_path(path))
+            }
+            _ => return Err(FileError::NotAbsolute),
+        };
+        UriBuf::parse(&format!("file://{}", path)).map_err(FileError::Syntax)
+    }
+
+    /// Converts the URI to a file system path.
+    ///
+    /// On Unix, the URI must be local without a drive letter, and the path is percent-decoded to bytes. On Windows,
+    /// see [`to_windows_path`](Self::to_windows_path).
+    #[cfg(all(feature = "std", unix))]
+    pub fn to_file_path(&self) -> Result<std::path::PathBuf, FileError> {
+        use std::os::unix::ffi::OsStringExt;
+
+        if self.host.is_some() {
+            return Err(FileError::Remote);
+        }
+        if self.drive_letter.is_some() {
+            return Err(FileError::DriveLetter);
+        }
+        check_encoded(self.path)?;
+        let bytes = percent_encoding::decode(self.path);
+        Ok(std::ffi::OsString::from_vec(bytes).into())
+    }
+
+    /// Converts the URI to a file system path. See [`to_windows_path`](Self::to_windows_path).
+    #[cfg(all(feature = "std", windows))]
+    pub fn to_file_path(&self) -> Result<std::path::PathBuf, FileError> {
+        self.to_windows_path().map(Into::into)
+    }
+
+    /// Converts an absolute file system path to a `file` URI with an empty authority.
+    ///
+    /// On Unix, every byte outside the unreserved characters, the sub-delimiters, `:`, `@` and `/` is
+    /// percent-encoded, and leading slashes are collapsed into one so that the URI cannot be read as a UNC path.
+    /// The `:` of a first segment such as `c:` is percent-encoded too, so that it is not read as a drive letter.
+    /// On Windows, see [`from_windows_path`](Self::from_windows_path).
+    ///
+    /// ```
+    /// # #[cfg(unix)] {
+    /// use std::path::Path;
+    ///
+    /// use nom_uri::file::FileUri;
+    ///
+    /// let uri = FileUri::from_file_path(Path::new("/tmp/a b#1.txt")).unwrap();
+    /// assert_eq!(uri.as_str(), "file:///tmp/a%20b%231.txt");
+    /// assert_eq!(
+    ///     FileUri::try_from(uri.as_uri()).unwrap().to_file_path().unwrap(),
+    ///     Path::new("/tmp/a b#1.txt")
+    /// );
+    /// # }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn from_file_path(path: &std::path::Path) -> Result<UriBuf, FileError> {
+        use std::os::unix::ffi::OsStrExt;
+
+        if !path.is_absolute() {
+            return Err(FileError::NotAbsolute);
+        }
+        let bytes = path.as_os_str().as_bytes();
+        let start = bytes.iter().position(|b| *b != b'/').unwrap_or(bytes.len());
+        let mut s = String::from("file:///");
+        let bytes = &bytes[start..];
+        for (i, b) in bytes.iter().enumerate() {
+            // `|` is always encoded
+            if i == 1 && *b == b':' && bytes[0].is_ascii_alphabetic() {
+                s.push_str("%3A");
+            } else {
+                encode_byte(*b, &mut s);
+            }
+        }
+        UriBuf::parse(&s).map_err(FileError::Syntax)
+    }
+
+    /// Converts an absolute file system path to a `file` URI. See [`from_windows_path`](Self::from_windows_path).
+    #[cfg(all(feature = "std", windows))]
+    pub fn from_file_path(path: &std::path::Path) -> Result<UriBuf, FileError> {
+        Self::from_windows_path(path.to_str().ok_or(FileError::InvalidUtf8)?)
+    }
+}
+
+impl<'a> TryFrom<Uri<'a>> for FileUri<'a> {
+    type Error = FileError;
+
+    fn try_from(uri: Uri<'a>) -> Result<Self, Self::Error> {
+        if !uri.scheme().eq_ignore_ascii_case("file") {
+            return Err(FileError::Scheme);
+        }
+        if uri.query().is_some() {
+            return Err(FileError::Query);
+        }
+        if uri.userinfo().is_some() || uri.port().is_some() {
+            return Err(FileError::Authority);
+        }
+        let mut path = uri.path();
+        let mut host = match uri.host() {
+            Some(Host::RegName(h)) if h.is_empty() || h.eq_ignore_ascii_case("localhost") => None,
+            host => host,
+        };
+        // `file:////host/share` and `file://///host/share`
+        if uri.authority() == Some("")
+            && let Some(unc) = path.strip_prefix("//")
+        {
+            let unc = unc.strip_prefix('/').unwrap_or(unc);
+            let (name, rest) = unc.split_at(unc.find('/').unwrap_or(unc.len()));
+            host = Some(Host::parse(name).map_err(|_| FileError::Authority)?);
+            path = rest;
+        }
+        let mut drive_letter = None;
+        if host.is_none() {
+            let local = match uri.authority() {
+                Some(_) => path.strip_prefix('/'),
+                None => path.strip_prefix('/').or(Some(path)),
+            };
+            if let Some((drive, rest)) = local.and_then(split_drive_letter)
+                && (rest.is_empty() || rest.starts_with('/'))
+            {
+                drive_letter = Some(drive);
+                path = rest;
+            }
+        }
+        if !path.starts_with('/') && drive_letter.is_none() {
+            return Err(FileError::NotAbsolute);
+        }
+        Ok(Self {
+            uri,
+            host,
+            drive_letter,
+            path,
+        })
+    }
+}
+
+impl core::fmt::Display for FileUri<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.uri)
+    }
+}
+
+/// Splits `ALPHA ":"` off the start of `s`.
+fn split_drive_letter(s: &str) -> Option<(char, &str)> {
+    match s.as_bytes() {
+        [letter, b':', ..] if letter.is_ascii_alphabetic() => Some((*letter as char, &s[2..])),
+        _ => None,
+    }
+}
+
+/// Fails if a triplet of `path` decodes to a NUL or a path separator.
+#[cfg(feature = "alloc")]
+fn check_encoded(path: &str) -> Result<(), FileError> {
+    for (i, _) in path.match_indices('%') {
+        if let Some(b @ (b'\0' | b'/' | b'\\')) =
+            percent_encoding::decode_hex_pair(&path.as_bytes()[i + 1..i + 3])
+        {
+            return Err(FileError::ForbiddenCharacter(b as char));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+fn decode_utf8(s: &str) -> Result<String, FileError> {
+    check_encoded(s)?;
+    String::from_utf8(percent_encoding::decode(s)).map_err(|_| FileError::InvalidUtf8)
+}
+
+/// Converts `host\share\path` to a `file` URI.
+#[cfg(feature = "alloc")]
+fn from_unc_path(unc: &str) -> Result<UriBuf, FileError> {
+    let (host, path) = unc.split_at(unc.find(['\\', '/']).unwrap_or(unc.len()));
+    // `\\.\` and `\\?\` name devices
+    if host.is_empty() || host == "." || host == "?" {
+        return Err(FileError::NotAbsolute);
+    }
+    Host::parse(host).map_err(|_| FileError::Authority)?;
+    UriBuf::parse(&format!("file://{}{}", host, encode_windows_path(path)))
+        .map_err(FileError::Syntax)
+}
+
+/// Percent-encodes a Windows path, changing `\` to `/`.
+#[cfg(feature = "alloc")]
+fn encode_windows_path(path: &str) -> String {
+    let mut s = String::with_capacity(path.len());
+    for c in path.chars() {
+        match c {
+            '\\' => s.push('/'),
+            c if c.is_ascii() => encode_byte(c as u8, &mut s),
+            c => percent_encoding::encode_char(c, &mut s),
+        }
+    }
+    s
+}
+
+/// Appends `b`, percent-encoded unless it is allowed in a `path-absolute`.
+#[cfg(feature = "alloc")]
+fn encode_byte(b: u8, s: &mut String) {
+    match b {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => s.push(b as char),
+        b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'=' => {
+            s.push(b as char)
+        }
+        b':' | b'@' | b'/' => s.push(b as char),
+        b => s.push_str(&format!("%{:02X}", b)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let f = |s| FileUri::parse(s).map(|u| (u.host(), u.drive_letter(), u.path()));
+        assert_eq!(f("file:///path/to/file"), Ok((None, None, "/path/to/file")));
+        assert_eq!(f("file:/path/to/file"), Ok((None, None, "/path/to/file")));
+        assert_eq!(f("FILE://localhost/a"), Ok((None, None, "/a")));
+        assert_eq!(f("file://LocalHost/a"), Ok((None, None, "/a")));
+        assert_eq!(
+            f("file://host.example.com/a"),
+            Ok((Some(Host::RegName("host.example.com")), None, "/a"))
+        );
+        assert_eq!(
+            f("file://[::1]/a"),
+            Ok((Some(Host::Ipv6(core::net::Ipv6Addr::LOCALHOST)), None, "/a"))
+        );
+        assert_eq!(f("file:c:/path"), Ok((None, Some('c'), "/path")));
+        assert_eq!(f("file:/C:/path"), Ok((None, Some('C'), "/path")));
+        assert_eq!(f("file:///c:/path"), Ok((None, Some('c'), "/path")));
+        assert_eq!(
+            f("file://localhost/c:/path"),
+            Ok((None, Some('c'), "/path"))
+        );
+        assert_eq!(f("file:///c:"), Ok((None, Some('c'), "")));
+        assert_eq!(f("file:///c:d"), Ok((None, None, "/c:d")));
+        assert_eq!(
+            f("file://host/c:/path"),
+            Ok((Some(Host::RegName("host")), None, "/c:/path"))
+        );
+        assert_eq!(
+            f("file:////host/share/a"),
+            Ok((Some(Host::RegName("host")), None, "/share/a"))
+        );
+        assert_eq!(
+            f("file://///host/share/a"),
+            Ok((Some(Host::RegName("host")), None, "/share/a"))
+        );
+        assert_eq!(f("file:///a#f"), Ok((None, None, "/a")));
+
+        assert_eq!(f("http:///a"), Err(FileError::Scheme));
+        assert_eq!(f("file:///a?q"), Err(FileError::Query));
+        assert_eq!(f("file://u@host/a"), Err(FileError::Authority));
+        assert_eq!(f("file://host:1/a"), Err(FileError::Authority));
+        assert_eq!(f("file:////h:1/a"), Err(FileError::Authority));
+        assert_eq!(f("file:a/b"), Err(FileError::NotAbsolute));
+        assert_eq!(f("file:"), Err(FileError::NotAbsolute));
+        assert_eq!(f("file://host"), Err(FileError::NotAbsolute));
+        assert_eq!(f("file:c:path"), Err(FileError::NotAbsolute));
+        assert_eq!(f("file:// /"), Err(FileError::Syntax(ParseError::new(7))));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_windows_path() {
+        let f = |s| FileUri::parse(s).unwrap().to_windows_path();
+        assert_eq!(f("file:///C:/a/b%20c"), Ok(r"C:\a\b c".to_owned()));
+        assert_eq!(f("file:c:/"), Ok(r"c:\".to_owned()));
+        assert_eq!(f("file:///c:"), Ok(r"c:\".to_owned()));
+        assert_eq!(f("file://host/share/a"), Ok(r"\\host\share\a".to_owned()));
+        assert_eq!(
+            f("file:////host/share/%C3%BC"),
+            Ok(r"\\host\share\ü".to_owned())
+        );
+        assert_eq!(f("file:///a"), Err(FileError::NoDriveLetter));
+        assert_eq!(
+            f("file:///c:/a%5Cb"),
+            Err(FileError::ForbiddenCharacter('\\'))
+        );
+        assert_eq!(
+            f("file:///c:/a%2fb"),
+            Err(FileError::ForbiddenCharacter('/'))
+        );
+        assert_eq!(f("file:///c:/a%FF"), Err(FileError::InvalidUtf8));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_windows_path() {
+        let f = |s| FileUri::from_windows_path(s).map(|u| u.into_string());
+        assert_eq!(f(r"C:\a\b c"), Ok("file:///C:/a/b%20c".to_owned()));
+        assert_eq!(f("c:/a/b"), Ok("file:///c:/a/b".to_owned()));
+        assert_eq!(f("c:"), Ok("file:///c:".to_owned()));
+        assert_eq!(f(r"C:\100%\#"), Ok("file:///C:/100%25/%23".to_owned()));
+        assert_eq!(f(r"\\host\share\a"), Ok("file://host/share/a".to_owned()));
+        assert_eq!(f(r"\\?\C:\a"), Ok("file:///C:/a".to_owned()));
+        assert_eq!(f(r"\\?\UNC\host\share"), Ok("file://host/share".to_owned()));
+        assert_eq!(f(r"\\.\COM1"), Err(FileError::NotAbsolute));
+        assert_eq!(f(r"\\a b\share"), Err(FileError::Authority));
+        assert_eq!(f(r"a\b"), Err(FileError::NotAbsolute));
+        assert_eq!(f(r"\a\b"), Err(FileError::NotAbsolute));
+        assert_eq!(f(r"c:a"), Err(FileError::NotAbsolute));
+
+        // round trip
+        for s in [r"C:\a\b c\ü", r"\\host\share\a%b"] {
+            let uri = FileUri::from_windows_path(s).unwrap();
+            assert_eq!(
+                FileUri::try_from(uri.as_uri()).unwrap().to_windows_path(),
+                Ok(s.to_owned())
+            );
+        }
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    #[test]
+    fn test_file_path() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+        let to = |s| FileUri::parse(s).unwrap().to_file_path();
+        assert_eq!(
+            to("file:///tmp/a%20b"),
+            Ok(Path::new("/tmp/a b").to_owned())
+        );
+        assert_eq!(to("file://localhost/"), Ok(Path::new("/").to_owned()));
+        assert_eq!(
+            to("file:///a%FF"),
+            Ok(Path::new(OsStr::from_bytes(b"/a\xFF")).to_owned())
+        );
+        assert_eq!(to("file://host/a"), Err(FileError::Remote));
+        assert_eq!(to("file:///c:/a"), Err(FileError::DriveLetter));
+        assert_eq!(to("file:///a%2Fb"), Err(FileError::ForbiddenCharacter('/')));
+        assert_eq!(to("file:///a%00"), Err(FileError::ForbiddenCharacter('\0')));
+
+        let from = |s: &[u8]| {
+            FileUri::from_file_path(Path::new(OsStr::from_bytes(s))).map(|u| u.into_string())
+        };
+        assert_eq!(from(b"/"), Ok("file:///".to_owned()));
+        assert_eq!(from(b"//tmp/x"), Ok("file:///tmp/x".to_owned()));
+        assert_eq!(
+            from("/ü/%?#[]\\".as_bytes()),
+            Ok("file:///%C3%BC/%25%3F%23%5B%5D%5C".to_owned())
+        );
+        assert_eq!(from(b"/a\xFF"), Ok("file:///a%FF".to_owned()));
+        assert_eq!(from(b"a/b"), Err(FileError::NotAbsolute));
+        assert_eq!(from(b"/c:/x"), Ok("file:///c%3A/x".to_owned()));
+        assert_eq!(from(b"/c:"), Ok("file:///c%3A".to_owned()));
+        assert_eq!(from(b"/c|/x"), Ok("file:///c%7C/x".to_owned()));
+        assert_eq!(from(b"/ab:/x"), Ok("file:///ab:/x".to_owned()));
+        for s in [&b"/a b/c:d@e"[..], b"/x\xFE", b"/c:/x", b"/c:", b"/c|"] {
+            let uri = from(s).unwrap();
+            assert_eq!(
+                FileUri::parse(&uri).unwrap().to_file_path(),
+                Ok(Path::new(OsStr::from_bytes(s)).to_owned())
+            );
+        }
+    }
+}
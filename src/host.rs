@@ -0,0 +1,402 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+use crate::{DnsName, DnsNameError, ParseError, parse_error::parse_all, parser};
+#[cfg(feature = "alloc")]
+use crate::{
+    idna::{self, IdnaError},
+    percent_encoding,
+};
+
+/// A typed `host` component.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2.2>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Host<'a> {
+    /// `IP-literal` containing an `IPv6address`.
+    Ipv6(Ipv6Addr),
+    /// `IP-literal` containing an `IPvFuture`, without the brackets.
+    IpvFuture(&'a str),
+    /// `IPv4address`.
+    Ipv4(Ipv4Addr),
+    /// `reg-name`, as written (not percent-decoded).
+    RegName(&'a str),
+}
+
+impl<'a> Host<'a> {
+    /// Parses `s` as a whole `host`.
+    pub fn parse(s: &'a str) -> Result<Self, ParseError> {
+        parse_all(parser::host, s).map(Self::from)
+    }
+
+    /// Validates this host as a DNS hostname.
+    ///
+    /// Only `reg-name` hosts can be DNS names. See [`DnsName::new`] for the rules.
+    pub fn as_dns_name(&self) -> Result<DnsName<'a>, DnsNameError> {
+        match self {
+            Host::RegName(s) => DnsName::new(s),
+            Host::Ipv6(_) | Host::IpvFuture(_) | Host::Ipv4(_) => Err(DnsNameError::NotRegName),
+        }
+    }
+
+    /// Interprets this host as an IPv4 address the way `inet_aton` and most resolvers do.
+    ///
+    /// Besides `IPv4address`, a `reg-name` of one to four numbers separated by `.` is an address: each number is
+    /// decimal, octal with a leading `0`, or hexadecimal with a leading `0x`, and the last number fills the
+    /// remaining bytes (`127.1` is `127.0.0.1`, `2130706433` is `127.0.0.1`). A trailing `.` and
+    /// percent-encoded octets are accepted. Such a host is not canonical: RFC 3986 treats it as a name, but a
+    /// resolver connects to the address, so it must be checked like one.
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    ///
+    /// use nom_uri::{Host, LegacyIpv4};
+    ///
+    /// assert_eq!(
+    ///     Host::parse("0x7f.1").unwrap().interpret_ipv4(),
+    ///     Some(LegacyIpv4 { addr: Ipv4Addr::LOCALHOST, canonical: false })
+    /// );
+    /// assert_eq!(Host::parse("example.com").unwrap().interpret_ipv4(), None);
+    /// ```
+    pub fn interpret_ipv4(&self) -> Option<LegacyIpv4> {
+        match self {
+            Host::Ipv4(addr) => Some(LegacyIpv4 {
+                addr: *addr,
+                canonical: true,
+            }),
+            Host::RegName(s) => inet_aton(s).map(|addr| LegacyIpv4 {
+                addr,
+                canonical: false,
+            }),
+            Host::Ipv6(_) | Host::IpvFuture(_) => None,
+        }
+    }
+}
+
+/// An IPv4 address interpreted from a host by [`Host::interpret_ipv4`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LegacyIpv4 {
+    pub addr: Ipv4Addr,
+    /// Whether the host is an `IPv4address` in dotted-decimal form.
+    pub canonical: bool,
+}
+
+/// Parses the `inet_aton` forms of an IPv4 address from a percent-encoded `reg-name`.
+fn inet_aton(s: &str) -> Option<Ipv4Addr> {
+    let mut bytes = s.bytes();
+    let hex = |b: Option<u8>| char::from(b?).to_digit(16);
+    parse_ipv4(core::iter::from_fn(|| match bytes.next()? {
+        // an invalid triplet yields its `%`, which is not part of a number
+        b'%' => Some(
+            hex(bytes.next())
+                .zip(hex(bytes.next()))
+                .map_or(b'%', |(hi, lo)| (hi * 16 + lo) as u8),
+        ),
+        b => Some(b),
+    }))
+}
+
+/// Parses one to four numbers separated by `.`, the last of which fills the remaining bytes, with a single
+/// trailing `.` allowed. This is the form that `inet_aton` accepts and the URL Standard adopts.
+///
+/// <https://url.spec.whatwg.org/#concept-ipv4-parser>
+pub(crate) fn parse_ipv4(bytes: impl IntoIterator<Item = u8>) -> Option<Ipv4Addr> {
+    let mut parts = [0; 4];
+    let mut n = 0;
+    let mut number = InetNumber::default();
+    for b in bytes {
+        if b == b'.' {
+            *parts.get_mut(n)? = number.finish()?;
+            n += 1;
+            number = InetNumber::default();
+        } else {
+            number.push(b)?;
+        }
+    }
+    // a trailing `.` ends the last number
+    if number.len > 0 || n == 0 {
+        *parts.get_mut(n)? = number.finish()?;
+        n += 1;
+    }
+
+    let (last, init) = parts[..n].split_last().expect("a number");
+    if init.iter().any(|p| *p > 255) || *last >= 1 << (8 * (5 - n)) {
+        return None;
+    }
+    let addr = init
+        .iter()
+        .enumerate()
+        .fold(*last, |addr, (i, p)| addr | p << (8 * (3 - i)));
+    Some(Ipv4Addr::from(addr as u32))
+}
+
+/// Parses one number of [`parse_ipv4`].
+///
+/// <https://url.spec.whatwg.org/#ipv4-number-parser>
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_ipv4_number(bytes: &[u8]) -> Option<u64> {
+    let mut number = InetNumber::default();
+    for b in bytes {
+        number.push(*b)?;
+    }
+    number.finish()
+}
+
+/// A number of [`parse_ipv4`]: decimal, octal with a leading `0`, or hexadecimal with a leading `0x`. The value
+/// saturates, as any value above `u32::MAX` is rejected.
+#[derive(Default)]
+struct InetNumber {
+    len: usize,
+    radix: u32,
+    value: u64,
+}
+
+impl InetNumber {
+    fn push(&mut self, b: u8) -> Option<()> {
+        self.len += 1;
+        match (self.len, b) {
+            (1, b'0') => {
+                self.radix = 8;
+                return Some(());
+            }
+            (1, _) => self.radix = 10,
+            (2, b'x' | b'X') if self.radix == 8 => {
+                self.radix = 16;
+                return Some(());
+            }
+            _ => {}
+        }
+        let digit = char::from(b).to_digit(self.radix)?;
+        self.value = self
+            .value
+            .saturating_mul(u64::from(self.radix))
+            .saturating_add(u64::from(digit));
+        Some(())
+    }
+
+    fn finish(&self) -> Option<u64> {
+        (self.len > 0).then_some(self.value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Host<'_> {
+    /// Converts a `reg-name` to its ASCII (A-label) form.
+    ///
+    /// The `reg-name` is percent-decoded as UTF-8 first. Other hosts are returned as they are.
+    pub fn to_ascii(&self) -> Result<String, IdnaError> {
+        match self {
+            Host::RegName(s) => idna::to_ascii(&decode_reg_name(s)?),
+            Host::Ipv6(_) | Host::IpvFuture(_) | Host::Ipv4(_) => Ok(self.to_string()),
+        }
+    }
+
+    /// Converts a `reg-name` to its Unicode form for display.
+    ///
+    /// The `reg-name` is percent-decoded as UTF-8 first. Other hosts are returned as they are.
+    pub fn to_unicode(&self) -> Result<String, IdnaError> {
+        match self {
+            Host::RegName(s) => idna::to_unicode(&decode_reg_name(s)?),
+            Host::Ipv6(_) | Host::IpvFuture(_) | Host::Ipv4(_) => Ok(self.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn decode_reg_name(s: &str) -> Result<String, IdnaError> {
+    String::from_utf8(percent_encoding::decode(s)).map_err(|_| IdnaError::InvalidUtf8)
+}
+
+impl<'a> From<parser::host::Token<'a>> for Host<'a> {
+    fn from(token: parser::host::Token<'a>) -> Self {
+        Self::new(token.kind, token.span.fragment())
+    }
+}
+
+impl<'a> Host<'a> {
+    /// `s` must have been matched by `host` as `kind`.
+    pub(crate) fn new(kind: parser::host::Kind, s: &'a str) -> Self {
+        match kind {
+            parser::host::Kind::IpLiteral => {
+                let inner = &s[1..s.len() - 1];
+                if inner.starts_with(['v', 'V']) {
+                    Host::IpvFuture(inner)
+                } else {
+                    // the grammar has already accepted it as IPv6address
+                    Host::Ipv6(inner.parse().expect("IPv6address"))
+                }
+            }
+            parser::host::Kind::Ipv4Address => Host::Ipv4(s.parse().expect("IPv4address")),
+            parser::host::Kind::RegName => Host::RegName(s),
+        }
+    }
+}
+
+impl core::fmt::Display for Host<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Host::Ipv6(addr) => write!(f, "[{}]", addr),
+            Host::IpvFuture(s) => write!(f, "[{}]", s),
+            Host::Ipv4(addr) => write!(f, "{}", addr),
+            Host::RegName(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Host::parse("[::1]"), Ok(Host::Ipv6(Ipv6Addr::LOCALHOST)));
+        assert_eq!(
+            Host::parse("[::ffff:192.0.2.128]"),
+            Ok(Host::Ipv6("::ffff:192.0.2.128".parse().unwrap()))
+        );
+        assert_eq!(Host::parse("[v1.12345]"), Ok(Host::IpvFuture("v1.12345")));
+        assert_eq!(
+            Host::parse("127.0.0.1"),
+            Ok(Host::Ipv4(Ipv4Addr::LOCALHOST))
+        );
+        assert_eq!(Host::parse("256.0.0.1"), Ok(Host::RegName("256.0.0.1")));
+        assert_eq!(Host::parse("example.com"), Ok(Host::RegName("example.com")));
+        assert_eq!(Host::parse(""), Ok(Host::RegName("")));
+
+        assert_eq!(Host::parse("example.com:80"), Err(ParseError::new(11)));
+        assert_eq!(Host::parse("[::1"), Err(ParseError::new(0)));
+    }
+
+    #[test]
+    fn test_interpret_ipv4() {
+        let f = |s| {
+            Host::parse(s)
+                .unwrap()
+                .interpret_ipv4()
+                .map(|i| (i.addr.to_string(), i.canonical))
+        };
+        let legacy = |s: &str| Some((s.to_owned(), false));
+        assert_eq!(f("127.0.0.1"), Some(("127.0.0.1".to_owned(), true)));
+        assert_eq!(f("0x7f.1"), legacy("127.0.0.1"));
+        assert_eq!(f("2130706433"), legacy("127.0.0.1"));
+        assert_eq!(f("0177.0.0.01"), legacy("127.0.0.1"));
+        assert_eq!(f("127.1"), legacy("127.0.0.1"));
+        assert_eq!(f("10.1.65535"), legacy("10.1.255.255"));
+        assert_eq!(f("0X7F000001"), legacy("127.0.0.1"));
+        assert_eq!(f("0x"), legacy("0.0.0.0"));
+        assert_eq!(f("127.1."), legacy("127.0.0.1"));
+        assert_eq!(f("%31%32%37.0.0.1"), legacy("127.0.0.1"));
+        assert_eq!(f("4294967295"), legacy("255.255.255.255"));
+        let padded = format!("0x{}7f000001", "0".repeat(100));
+        assert_eq!(f(&padded), legacy("127.0.0.1"));
+
+        assert_eq!(f("4294967296"), None);
+        assert_eq!(f("256.0.0.1"), None);
+        assert_eq!(f("1.2.65536"), None);
+        assert_eq!(f("01.2.3.4.5"), None);
+        assert_eq!(f("08.0.0.1"), None);
+        assert_eq!(f("1..2"), None);
+        assert_eq!(f(""), None);
+        assert_eq!(f("."), None);
+        assert_eq!(f("example.com"), None);
+        assert_eq!(f("[::1]"), None);
+        assert_eq!(f("0x%37f.1"), legacy("127.0.0.1"));
+        assert_eq!(Host::RegName("1.%7").interpret_ipv4(), None);
+        assert_eq!(Host::RegName("1.%zz").interpret_ipv4(), None);
+    }
+
+    #[test]
+    fn test_parse_ipv4() {
+        let f = |s: &str| parse_ipv4(s.bytes());
+        let ok = |a, b, c, d| Some(Ipv4Addr::new(a, b, c, d));
+        // octal and hexadecimal
+        assert_eq!(f("017.0x1f.0X0A.00"), ok(15, 31, 10, 0));
+        assert_eq!(f("0xffffffff"), ok(255, 255, 255, 255));
+        assert_eq!(f("037777777777"), ok(255, 255, 255, 255));
+        assert_eq!(f("0"), ok(0, 0, 0, 0));
+        assert_eq!(f("09"), None);
+        assert_eq!(f("0xg"), None);
+        // each part but the last is one byte, and the last fills the rest
+        assert_eq!(f("1.0xffffff"), ok(1, 255, 255, 255));
+        assert_eq!(f("0x100.1"), None);
+        assert_eq!(f("1.0x1000000"), None);
+        assert_eq!(f("1.2.256"), ok(1, 2, 1, 0));
+        assert_eq!(f("1.2.3.256"), None);
+        assert_eq!(f("4294967296"), None);
+        // empty parts and trailing dots
+        assert_eq!(f(""), None);
+        assert_eq!(f("."), None);
+        assert_eq!(f(".1"), None);
+        assert_eq!(f("1..2"), None);
+        assert_eq!(f("1.2.3.4."), ok(1, 2, 3, 4));
+        assert_eq!(f("1."), ok(0, 0, 0, 1));
+        assert_eq!(f("1.2.3.4.."), None);
+        assert_eq!(f("1.2.3.4.5"), None);
+    }
+
+    #[test]
+    fn test_as_dns_name() {
+        assert_eq!(
+            Host::parse("example.com")
+                .unwrap()
+                .as_dns_name()
+                .map(|n| n.as_str()),
+            Ok("example.com")
+        );
+        assert_eq!(
+            Host::parse("127.0.0.1").unwrap().as_dns_name(),
+            Err(DnsNameError::NotRegName)
+        );
+        assert_eq!(
+            Host::parse("[::1]").unwrap().as_dns_name(),
+            Err(DnsNameError::NotRegName)
+        );
+        assert_eq!(
+            Host::parse("a!b").unwrap().as_dns_name(),
+            Err(DnsNameError::InvalidCharacter { offset: 1, c: '!' })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(
+            Host::parse("b%C3%BCcher.example").unwrap().to_ascii(),
+            Ok("xn--bcher-kva.example".to_owned())
+        );
+        assert_eq!(
+            Host::parse("Example.COM").unwrap().to_ascii(),
+            Ok("example.com".to_owned())
+        );
+        assert_eq!(
+            Host::parse("[::1]").unwrap().to_ascii(),
+            Ok("[::1]".to_owned())
+        );
+        assert_eq!(
+            Host::parse("%FF.example").unwrap().to_ascii(),
+            Err(IdnaError::InvalidUtf8)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_unicode() {
+        assert_eq!(
+            Host::parse("xn--bcher-kva.example").unwrap().to_unicode(),
+            Ok("bücher.example".to_owned())
+        );
+        assert_eq!(
+            Host::parse("127.0.0.1").unwrap().to_unicode(),
+            Ok("127.0.0.1".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        for s in ["[::1]", "[v1.12345]", "127.0.0.1", "example.com"] {
+            assert_eq!(Host::parse(s).unwrap().to_string(), s);
+        }
+    }
+}
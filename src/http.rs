@@ -0,0 +1,630 @@
+//! The `http` and `https` URI schemes
+//!
+//! ```text
+//! http-URI  = "http" "://" authority path-abempty [ "?" query ]
+//! https-URI = "https" "://" authority path-abempty [ "?" query ]
+//! ```
+//!
+//! <https://www.rfc-editor.org/rfc/rfc9110#section-4.2>
+
+use crate::{Host, ParseError, Uri, parse_error::parse_all, parser};
+
+/// An error returned when a URI is not a valid `http` or `https` URI.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HttpError {
+    /// The input is not a `URI`.
+    Syntax(ParseError),
+    /// The scheme is not `http` or `https`.
+    Scheme,
+    /// The URI has no authority, or its host is empty.
+    MissingHost,
+    /// The URI has a userinfo, which is deprecated in `http` and `https` URIs.
+    Userinfo,
+    /// The port is greater than 65535.
+    Port,
+}
+
+impl core::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HttpError::Syntax(e) => write!(f, "{}", e),
+            HttpError::Scheme => f.write_str("scheme is not http or https"),
+            HttpError::MissingHost => f.write_str("missing host"),
+            HttpError::Userinfo => f.write_str("userinfo is not allowed"),
+            HttpError::Port => f.write_str("port out of range"),
+        }
+    }
+}
+
+impl core::error::Error for HttpError {}
+
+/// A parsed `http` or `https` URI.
+///
+/// ```
+/// use nom_uri::http::HttpUri;
+///
+/// let uri = HttpUri::parse("https://example.com/a/b?q=1#top").unwrap();
+/// assert!(uri.is_https());
+/// assert_eq!(uri.effective_port(), 443);
+/// assert_eq!(format!("{}", uri.origin()), "https://example.com");
+/// assert_eq!(format!("{}", uri.request_target()), "/a/b?q=1");
+///
+/// let uri = HttpUri::parse("http://example.com:8080").unwrap();
+/// assert_eq!(uri.effective_port(), 8080);
+/// assert_eq!(format!("{}", uri.request_target()), "/");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HttpUri<'a> {
+    uri: Uri<'a>,
+    https: bool,
+    host: Host<'a>,
+    port: Option<u16>,
+}
+
+impl<'a> HttpUri<'a> {
+    /// Parses `s` as an `http` or `https` URI.
+    pub fn parse(s: &'a str) -> Result<Self, HttpError> {
+        Self::try_from(Uri::parse(s).map_err(HttpError::Syntax)?)
+    }
+
+    pub fn as_uri(&self) -> Uri<'a> {
+        self.uri
+    }
+
+    pub fn is_https(&self) -> bool {
+        self.https
+    }
+
+    pub fn host(&self) -> Host<'a> {
+        self.host
+    }
+
+    /// Returns the port, or `None` if it is absent or empty.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Returns the port, or the default port of the scheme: 80 for `http` and 443 for `https`.
+    pub fn effective_port(&self) -> u16 {
+        self.port.unwrap_or(if self.https { 443 } else { 80 })
+    }
+
+    /// Returns the scheme, the host and the effective port.
+    ///
+    /// <https://www.rfc-editor.org/rfc/rfc9110#section-4.3.1>
+    pub fn origin(&self) -> Origin<'a> {
+        Origin {
+            https: self.https,
+            host: self.host,
+            port: self.effective_port(),
+        }
+    }
+
+    /// Returns the path and the query in `origin-form`, the request target sent to an origin server.
+    ///
+    /// <https://www.rfc-editor.org/rfc/rfc9112#section-3.2.1>
+    pub fn request_target(&self) -> OriginForm<'a> {
+        OriginForm {
+            path: self.uri.path(),
+            query: self.uri.query(),
+        }
+    }
+}
+
+impl<'a> TryFrom<Uri<'a>> for HttpUri<'a> {
+    type Error = HttpError;
+
+    fn try_from(uri: Uri<'a>) -> Result<Self, Self::Error> {
+        let https = if uri.scheme().eq_ignore_ascii_case("http") {
+            false
+        } else if uri.scheme().eq_ignore_ascii_case("https") {
+            true
+        } else {
+            return Err(HttpError::Scheme);
+        };
+        let host = match uri.host() {
+            Some(Host::RegName("")) | None => return Err(HttpError::MissingHost),
+            Some(host) => host,
+        };
+        if uri.userinfo().is_some() {
+            return Err(HttpError::Userinfo);
+        }
+        let port = match uri.port() {
+            Some("") | None => None,
+            Some(_) => Some(uri.port_u16().ok_or(HttpError::Port)?),
+        };
+        Ok(Self {
+            uri,
+            https,
+            host,
+            port,
+        })
+    }
+}
+
+impl core::fmt::Display for HttpUri<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.uri)
+    }
+}
+
+/// The origin of an [`HttpUri`].
+///
+/// Origins are equal if their schemes and ports are equal and their hosts are equal ignoring ASCII case. The
+/// [`Display`](core::fmt::Display) output omits the default port.
+///
+/// ```
+/// use nom_uri::http::HttpUri;
+///
+/// let a = HttpUri::parse("http://Example.COM/a").unwrap();
+/// let b = HttpUri::parse("HTTP://example.com:80/b").unwrap();
+/// assert_eq!(a.origin(), b.origin());
+/// let c = HttpUri::parse("https://example.com/").unwrap();
+/// assert_ne!(a.origin(), c.origin());
+/// ```
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct Origin<'a> {
+    https: bool,
+    host: Host<'a>,
+    port: u16,
+}
+
+impl<'a> Origin<'a> {
+    /// Returns `http` or `https`.
+    pub fn scheme(&self) -> &'static str {
+        if self.https { "https" } else { "http" }
+    }
+
+    pub fn host(&self) -> Host<'a> {
+        self.host
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl PartialEq for Origin<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.https == other.https && self.port == other.port && host_eq(self.host, other.host)
+    }
+}
+
+impl core::fmt::Display for Origin<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}://{}", self.scheme(), self.host)?;
+        if self.port != if self.https { 443 } else { 80 } {
+            write!(f, ":{}", self.port)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares hosts ignoring ASCII case.
+fn host_eq(a: Host<'_>, b: Host<'_>) -> bool {
+    match (a, b) {
+        (Host::RegName(a), Host::RegName(b)) | (Host::IpvFuture(a), Host::IpvFuture(b)) => {
+            a.eq_ignore_ascii_case(b)
+        }
+        (a, b) => a == b,
+    }
+}
+
+/// A request target in `origin-form`.
+///
+/// ```text
+/// origin-form = absolute-path [ "?" query ]
+/// ```
+///
+/// <https://www.rfc-editor.org/rfc/rfc9112#section-3.2.1>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OriginForm<'a> {
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+impl<'a> OriginForm<'a> {
+    /// Returns the path, or `/` if it is empty.
+    pub fn path(&self) -> &'a str {
+        match self.path {
+            "" => "/",
+            path => path,
+        }
+    }
+
+    pub fn query(&self) -> Option<&'a str> {
+        self.query
+    }
+}
+
+impl core::fmt::Display for OriginForm<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.path())?;
+        if let Some(query) = self.query {
+            write!(f, "?{}", query)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error returned when a request target is invalid for its method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequestTargetError {
+    /// The input is not a `request-target`, or not an `authority-form` for `CONNECT`.
+    Syntax(ParseError),
+    /// The form is not allowed for the method: `authority-form` is only for `CONNECT`, and `asterisk-form` is
+    /// only for `OPTIONS`.
+    Method,
+    /// The port of an `authority-form` is empty or greater than 65535.
+    Port,
+}
+
+impl core::fmt::Display for RequestTargetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RequestTargetError::Syntax(e) => write!(f, "{}", e),
+            RequestTargetError::Method => {
+                f.write_str("request target form not allowed for the method")
+            }
+            RequestTargetError::Port => f.write_str("invalid port"),
+        }
+    }
+}
+
+impl core::error::Error for RequestTargetError {}
+
+/// The target of an HTTP/1.1 request line.
+///
+/// ```text
+/// request-target = origin-form
+///                / absolute-form
+///                / authority-form
+///                / asterisk-form
+/// ```
+///
+/// ```
+/// use nom_uri::Host;
+/// use nom_uri::http::{RequestTarget, RequestTargetError};
+///
+/// assert!(matches!(RequestTarget::parse("GET", "/where?q=now"), Ok(RequestTarget::Origin(_))));
+/// assert!(matches!(RequestTarget::parse("GET", "http://example.com/"), Ok(RequestTarget::Absolute(_))));
+/// assert_eq!(
+///     RequestTarget::parse("CONNECT", "example.com:443"),
+///     Ok(RequestTarget::Authority { host: Host::RegName("example.com"), port: 443 })
+/// );
+/// assert_eq!(RequestTarget::parse("OPTIONS", "*"), Ok(RequestTarget::Asterisk));
+/// assert_eq!(RequestTarget::parse("GET", "*"), Err(RequestTargetError::Method));
+/// ```
+///
+/// <https://www.rfc-editor.org/rfc/rfc9112#section-3.2>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequestTarget<'a> {
+    /// `origin-form`, for a request to an origin server.
+    Origin(OriginForm<'a>),
+    /// `absolute-form`, for a request to a proxy.
+    Absolute(Uri<'a>),
+    /// `authority-form`, for a `CONNECT` request.
+    Authority { host: Host<'a>, port: u16 },
+    /// `asterisk-form`, for a server-wide `OPTIONS` request.
+    Asterisk,
+}
+
+impl<'a> RequestTarget<'a> {
+    /// Parses `s` as the request target of a request with `method`, which is case-sensitive.
+    ///
+    /// A `CONNECT` request only accepts `authority-form`, so `example.com:443` is an authority for `CONNECT` and
+    /// an `absolute-URI` with the scheme `example.com` for other methods.
+    pub fn parse(method: &str, s: &'a str) -> Result<Self, RequestTargetError> {
+        if method == "CONNECT" {
+            let token = parse_all(parser::authority_form, s).map_err(RequestTargetError::Syntax)?;
+            return Ok(RequestTarget::Authority {
+                host: Host::from(token.host),
+                port: token.port.parse().map_err(|_| RequestTargetError::Port)?,
+            });
+        }
+        let token = parse_all(parser::request_target, s).map_err(RequestTargetError::Syntax)?;
+        match token.kind {
+            parser::request_target::Kind::OriginForm => {
+                let (path, query) = match s.split_once('?') {
+                    Some((path, query)) => (path, Some(query)),
+                    None => (s, None),
+                };
+                Ok(RequestTarget::Origin(OriginForm { path, query }))
+            }
+            parser::request_target::Kind::AbsoluteForm => {
+                // the whole input has been matched as `absolute-URI`
+                Ok(RequestTarget::Absolute(
+                    Uri::parse(s).expect("absolute-URI"),
+                ))
+            }
+            parser::request_target::Kind::AsteriskForm if method == "OPTIONS" => {
+                Ok(RequestTarget::Asterisk)
+            }
+            parser::request_target::Kind::AuthorityForm
+            | parser::request_target::Kind::AsteriskForm => Err(RequestTargetError::Method),
+        }
+    }
+}
+
+/// An error returned when a `Host` header field value is invalid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HostHeaderError {
+    /// The input is not `uri-host [ ":" port ]`.
+    Syntax(ParseError),
+    /// The port is greater than 65535.
+    Port,
+}
+
+impl core::fmt::Display for HostHeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HostHeaderError::Syntax(e) => write!(f, "{}", e),
+            HostHeaderError::Port => f.write_str("port out of range"),
+        }
+    }
+}
+
+impl core::error::Error for HostHeaderError {}
+
+/// A parsed `Host` header field value.
+///
+/// ```text
+/// Host = uri-host [ ":" port ]
+/// ```
+///
+/// ```
+/// use nom_uri::Host;
+/// use nom_uri::http::HostHeader;
+///
+/// let header = HostHeader::parse("www.example.org:8080").unwrap();
+/// assert_eq!(header.host(), Host::RegName("www.example.org"));
+/// assert_eq!(header.port(), Some(8080));
+/// assert!(HostHeader::parse("user@www.example.org").is_err());
+/// ```
+///
+/// <https://www.rfc-editor.org/rfc/rfc9110#section-7.2>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HostHeader<'a> {
+    host: Host<'a>,
+    port: Option<u16>,
+}
+
+impl<'a> HostHeader<'a> {
+    /// Parses `s` as a `Host` header field value. An empty value, sent for a target URI without an authority, is
+    /// an empty `reg-name`.
+    pub fn parse(s: &'a str) -> Result<Self, HostHeaderError> {
+        let token = parse_all(parser::host_header, s).map_err(HostHeaderError::Syntax)?;
+        let port = match token.port.map(|p| *p.fragment()) {
+            Some("") | None => None,
+            Some(port) => Some(port.parse().map_err(|_| HostHeaderError::Port)?),
+        };
+        Ok(Self {
+            host: Host::from(token.host),
+            port,
+        })
+    }
+
+    pub fn host(&self) -> Host<'a> {
+        self.host
+    }
+
+    /// Returns the port, or `None` if it is absent or empty.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Returns whether this header names the authority of `uri`, such as an `absolute-form` request target.
+    ///
+    /// The hosts are compared ignoring ASCII case, and an absent port is the default port of an `http` or
+    /// `https` URI. The userinfo of `uri` is ignored.
+    ///
+    /// ```
+    /// use nom_uri::Uri;
+    /// use nom_uri::http::HostHeader;
+    ///
+    /// let uri = Uri::parse("http://www.example.org/pub/WWW/").unwrap();
+    /// assert!(HostHeader::parse("WWW.example.org:80").unwrap().matches(&uri));
+    /// assert!(!HostHeader::parse("www.example.org:8080").unwrap().matches(&uri));
+    /// ```
+    pub fn matches(&self, uri: &Uri<'_>) -> bool {
+        let Some(host) = uri.host() else {
+            return false;
+        };
+        let port = match uri.port() {
+            Some("") | None => None,
+            Some(_) => match uri.port_u16() {
+                Some(port) => Some(port),
+                None => return false,
+            },
+        };
+        let default_port = if uri.scheme().eq_ignore_ascii_case("http") {
+            Some(80)
+        } else if uri.scheme().eq_ignore_ascii_case("https") {
+            Some(443)
+        } else {
+            None
+        };
+        host_eq(self.host, host) && self.port.or(default_port) == port.or(default_port)
+    }
+}
+
+impl core::fmt::Display for HostHeader<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::net::Ipv6Addr;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let f =
+            |s| HttpUri::parse(s).map(|u| (u.is_https(), u.host(), u.port(), u.effective_port()));
+        assert_eq!(
+            f("http://example.com"),
+            Ok((false, Host::RegName("example.com"), None, 80))
+        );
+        assert_eq!(
+            f("HTTPS://example.com:/"),
+            Ok((true, Host::RegName("example.com"), None, 443))
+        );
+        assert_eq!(
+            f("https://[::1]:8443/"),
+            Ok((true, Host::Ipv6(Ipv6Addr::LOCALHOST), Some(8443), 8443))
+        );
+        assert_eq!(
+            f("http://example.com:0"),
+            Ok((false, Host::RegName("example.com"), Some(0), 0))
+        );
+        assert_eq!(f("ftp://example.com"), Err(HttpError::Scheme));
+        assert_eq!(f("http:/a"), Err(HttpError::MissingHost));
+        assert_eq!(f("http:///a"), Err(HttpError::MissingHost));
+        assert_eq!(f("http://:80/a"), Err(HttpError::MissingHost));
+        assert_eq!(f("http://user@example.com"), Err(HttpError::Userinfo));
+        assert_eq!(f("http://@example.com"), Err(HttpError::Userinfo));
+        assert_eq!(f("http://example.com:65536"), Err(HttpError::Port));
+    }
+
+    #[test]
+    fn test_origin() {
+        let f = |s| HttpUri::parse(s).unwrap().origin();
+        assert_eq!(f("http://a.example/x"), f("http://A.EXAMPLE:80/y?z"));
+        assert_eq!(f("http://[v1.A]/"), f("http://[v1.a]/"));
+        assert_ne!(f("http://a.example/"), f("http://a.example:8080/"));
+        assert_ne!(f("http://a.example/"), f("https://a.example:80/"));
+        assert_ne!(f("http://127.0.0.1/"), f("http://localhost/"));
+        assert_eq!(f("https://a.example:443/").to_string(), "https://a.example");
+        assert_eq!(
+            f("https://a.example:80/").to_string(),
+            "https://a.example:80"
+        );
+        assert_eq!(f("http://[::1]:8080/").to_string(), "http://[::1]:8080");
+        assert_eq!(f("HTTPS://a.example/").scheme(), "https");
+    }
+
+    #[test]
+    fn test_request_target() {
+        let f = |s| HttpUri::parse(s).unwrap().request_target().to_string();
+        assert_eq!(f("http://a"), "/");
+        assert_eq!(f("http://a?q"), "/?q");
+        assert_eq!(f("http://a/"), "/");
+        assert_eq!(f("http://a/b/../c?"), "/b/../c?");
+        assert_eq!(f("http://a/b?q=1&r#f"), "/b?q=1&r");
+        let target = HttpUri::parse("http://a?q").unwrap().request_target();
+        assert_eq!((target.path(), target.query()), ("/", Some("q")));
+    }
+
+    #[test]
+    fn test_request_target_parse() {
+        let f = RequestTarget::parse;
+        assert_eq!(
+            f("GET", "//a?"),
+            Ok(RequestTarget::Origin(OriginForm {
+                path: "//a",
+                query: Some("")
+            }))
+        );
+        assert_eq!(
+            f("POST", "http://a/b?c").map(|t| match t {
+                RequestTarget::Absolute(uri) => uri.as_str(),
+                _ => "",
+            }),
+            Ok("http://a/b?c")
+        );
+        assert_eq!(
+            f("GET", "a.example:443").map(|t| match t {
+                RequestTarget::Absolute(uri) => uri.scheme(),
+                _ => "",
+            }),
+            Ok("a.example")
+        );
+        assert_eq!(
+            f("CONNECT", "[::1]:8443"),
+            Ok(RequestTarget::Authority {
+                host: Host::Ipv6(Ipv6Addr::LOCALHOST),
+                port: 8443
+            })
+        );
+        assert_eq!(f("OPTIONS", "*"), Ok(RequestTarget::Asterisk));
+        assert!(matches!(f("OPTIONS", "/"), Ok(RequestTarget::Origin(_))));
+
+        assert_eq!(f("GET", "[::1]:443"), Err(RequestTargetError::Method));
+        assert_eq!(f("options", "*"), Err(RequestTargetError::Method));
+        assert_eq!(f("CONNECT", "a:"), Err(RequestTargetError::Port));
+        assert_eq!(f("CONNECT", "a:65536"), Err(RequestTargetError::Port));
+        assert_eq!(
+            f("CONNECT", "/"),
+            Err(RequestTargetError::Syntax(ParseError::new(0)))
+        );
+        assert_eq!(
+            f("CONNECT", "user@a:443"),
+            Err(RequestTargetError::Syntax(ParseError::new(4)))
+        );
+        assert_eq!(
+            f("GET", "/a#f"),
+            Err(RequestTargetError::Syntax(ParseError::new(2)))
+        );
+        assert_eq!(
+            f("GET", "http://a/#f"),
+            Err(RequestTargetError::Syntax(ParseError::new(9)))
+        );
+        assert_eq!(
+            f("GET", ""),
+            Err(RequestTargetError::Syntax(ParseError::new(0)))
+        );
+    }
+
+    #[test]
+    fn test_host_header() {
+        let f = |s| HostHeader::parse(s).map(|h| (h.host(), h.port()));
+        assert_eq!(f("a.example"), Ok((Host::RegName("a.example"), None)));
+        assert_eq!(f("a.example:"), Ok((Host::RegName("a.example"), None)));
+        assert_eq!(
+            f("[::1]:8080"),
+            Ok((Host::Ipv6(Ipv6Addr::LOCALHOST), Some(8080)))
+        );
+        assert_eq!(f(""), Ok((Host::RegName(""), None)));
+        assert_eq!(
+            f("user@a.example"),
+            Err(HostHeaderError::Syntax(ParseError::new(4)))
+        );
+        assert_eq!(
+            f("a.example/"),
+            Err(HostHeaderError::Syntax(ParseError::new(9)))
+        );
+        assert_eq!(f("a.example:65536"), Err(HostHeaderError::Port));
+        assert_eq!(
+            HostHeader::parse("[::1]:0080").unwrap().to_string(),
+            "[::1]:80"
+        );
+    }
+
+    #[test]
+    fn test_host_header_matches() {
+        let f = |h, u| {
+            HostHeader::parse(h)
+                .unwrap()
+                .matches(&Uri::parse(u).unwrap())
+        };
+        assert!(f("a.example", "http://a.example/"));
+        assert!(f("a.example", "http://user@A.EXAMPLE:80/"));
+        assert!(f("a.example:443", "https://a.example/"));
+        assert!(f("a.example", "https://a.example:/"));
+        assert!(f("[::1]", "http://[0::1]/"));
+        assert!(f("a.example:21", "ftp://a.example:21/"));
+        assert!(!f("a.example", "ftp://a.example:21/"));
+        assert!(!f("a.example", "https://a.example:80/"));
+        assert!(!f("a.example:8080", "http://a.example/"));
+        assert!(!f("b.example", "http://a.example/"));
+        assert!(!f("a.example", "http://a.example:99999/"));
+        assert!(!f("", "urn:a:b"));
+    }
+}
@@ -0,0 +1,294 @@
+//! Internationalized domain names
+//!
+//! Converts `reg-name` hosts between the Unicode form and the ASCII (A-label) form, following UTS #46
+//! non-transitional processing with `UseSTD3ASCIIRules`, `CheckHyphens` and `CheckJoiners`. The mapping data is
+//! bundled in `uts46_table.rs` (generated by `scripts/gen_uts46_table.py`). `CheckBidi` is not applied.
+//!
+//! <https://www.unicode.org/reports/tr46/>
+//! <https://datatracker.ietf.org/doc/html/rfc5891>
+
+pub mod punycode;
+mod uts46;
+#[rustfmt::skip]
+mod uts46_table;
+
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
+
+use crate::{DnsName, DnsNameError};
+
+use self::punycode::PunycodeError;
+
+const ACE_PREFIX: &str = "xn--";
+
+/// An error returned when a domain name cannot be converted.
+///
+/// `label` is the index of the offending label.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IdnaError {
+    /// The input contains a code point that UTS #46 disallows.
+    DisallowedCharacter { offset: usize, c: char },
+    /// A percent-encoded `reg-name` does not decode to UTF-8.
+    InvalidUtf8,
+    /// An `xn--` label is not valid Punycode.
+    Punycode { label: usize, error: PunycodeError },
+    /// An `xn--` label decodes to ASCII only or to a label that is not valid after mapping.
+    InvalidALabel { label: usize },
+    /// A label begins or ends with a hyphen.
+    HyphenStartOrEnd { label: usize },
+    /// A label has hyphens in both the third and fourth positions.
+    HyphenInThirdAndFourth { label: usize },
+    /// A label begins with a combining mark.
+    LeadingCombiningMark { label: usize },
+    /// U+200C or U+200D does not follow a virama.
+    InvalidJoiner { label: usize },
+    /// The ASCII form is not a valid DNS name.
+    Dns(DnsNameError),
+}
+
+impl core::fmt::Display for IdnaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IdnaError::DisallowedCharacter { offset, c } => {
+                write!(f, "disallowed character {:?} at offset {}", c, offset)
+            }
+            IdnaError::InvalidUtf8 => write!(f, "percent-encoded host is not UTF-8"),
+            IdnaError::Punycode { label, error } => write!(f, "label {}: {}", label, error),
+            IdnaError::InvalidALabel { label } => {
+                write!(f, "label {} is not a valid A-label", label)
+            }
+            IdnaError::HyphenStartOrEnd { label } => {
+                write!(f, "label {} begins or ends with a hyphen", label)
+            }
+            IdnaError::HyphenInThirdAndFourth { label } => {
+                write!(
+                    f,
+                    "label {} has hyphens in the third and fourth positions",
+                    label
+                )
+            }
+            IdnaError::LeadingCombiningMark { label } => {
+                write!(f, "label {} begins with a combining mark", label)
+            }
+            IdnaError::InvalidJoiner { label } => {
+                write!(
+                    f,
+                    "label {} has a joiner that does not follow a virama",
+                    label
+                )
+            }
+            IdnaError::Dns(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for IdnaError {}
+
+/// Converts `domain` to its ASCII form, e.g. `Bücher.example` to `xn--bcher-kva.example`.
+pub fn to_ascii(domain: &str) -> Result<String, IdnaError> {
+    let ascii = encode(process(domain, true)?)?;
+    DnsName::new(&ascii).map_err(IdnaError::Dns)?;
+    Ok(ascii)
+}
+
+/// Converts `domain` to its ASCII form with the settings of the URL Standard: `UseSTD3ASCIIRules` and
+/// `CheckHyphens` are unset and the DNS length is not verified.
+///
+/// <https://url.spec.whatwg.org/#concept-domain-to-ascii>
+pub(crate) fn to_ascii_for_url(domain: &str) -> Result<String, IdnaError> {
+    encode(process(domain, false)?)
+}
+
+/// Encodes the non-ASCII labels as A-labels and joins the labels.
+fn encode(labels: Vec<String>) -> Result<String, IdnaError> {
+    let mut ascii = Vec::with_capacity(labels.len());
+    for (index, label) in labels.iter().enumerate() {
+        if label.is_ascii() {
+            ascii.push(label.clone());
+        } else {
+            let encoded = punycode::encode(label).map_err(|error| IdnaError::Punycode {
+                label: index,
+                error,
+            })?;
+            ascii.push(format!("{}{}", ACE_PREFIX, encoded));
+        }
+    }
+    Ok(ascii.join("."))
+}
+
+/// Converts `domain` to its Unicode form for display, e.g. `xn--bcher-kva.example` to `bücher.example`.
+pub fn to_unicode(domain: &str) -> Result<String, IdnaError> {
+    Ok(process(domain, true)?.join("."))
+}
+
+/// Maps, normalizes, decodes and validates each label. `strict` sets `UseSTD3ASCIIRules` and `CheckHyphens`.
+///
+/// <https://www.unicode.org/reports/tr46/#Processing>
+fn process(domain: &str, strict: bool) -> Result<Vec<String>, IdnaError> {
+    let mapped = uts46::map(domain, strict)
+        .map_err(|(offset, c)| IdnaError::DisallowedCharacter { offset, c })?;
+    let normalized = uts46::nfc(&mapped);
+
+    let mut labels = vec![];
+    for (index, label) in normalized.split('.').enumerate() {
+        let label = match label.strip_prefix(ACE_PREFIX) {
+            Some(encoded) => {
+                let decoded = punycode::decode(encoded).map_err(|error| IdnaError::Punycode {
+                    label: index,
+                    error,
+                })?;
+                if decoded.is_ascii()
+                    || decoded.contains('.')
+                    || uts46::nfc(&decoded) != decoded
+                    || decoded
+                        .chars()
+                        .any(|c| uts46::status(c) != uts46::Status::Valid)
+                    || strict && !decoded.bytes().all(uts46::is_std3_valid)
+                {
+                    return Err(IdnaError::InvalidALabel { label: index });
+                }
+                decoded
+            }
+            None => label.to_owned(),
+        };
+        validate_label(&label, index, strict)?;
+        labels.push(label);
+    }
+    Ok(labels)
+}
+
+/// <https://www.unicode.org/reports/tr46/#Validity_Criteria>
+fn validate_label(label: &str, index: usize, check_hyphens: bool) -> Result<(), IdnaError> {
+    if check_hyphens && (label.starts_with('-') || label.ends_with('-')) {
+        return Err(IdnaError::HyphenStartOrEnd { label: index });
+    }
+    if check_hyphens && label.get(2..4) == Some("--") {
+        return Err(IdnaError::HyphenInThirdAndFourth { label: index });
+    }
+    if label.chars().next().is_some_and(uts46::is_combining_mark) {
+        return Err(IdnaError::LeadingCombiningMark { label: index });
+    }
+    // <https://datatracker.ietf.org/doc/html/rfc5892#appendix-A.1>
+    let mut prev = None;
+    for c in label.chars() {
+        if matches!(c, '\u{200C}' | '\u{200D}') && !prev.is_some_and(uts46::is_virama) {
+            return Err(IdnaError::InvalidJoiner { label: index });
+        }
+        prev = Some(c);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(
+            to_ascii("bücher.example").as_deref(),
+            Ok("xn--bcher-kva.example")
+        );
+        assert_eq!(
+            to_ascii("Bücher.EXAMPLE").as_deref(),
+            Ok("xn--bcher-kva.example")
+        );
+        assert_eq!(
+            to_ascii("bu\u{0308}cher.example").as_deref(),
+            Ok("xn--bcher-kva.example")
+        );
+        assert_eq!(
+            to_ascii("bücher\u{3002}example").as_deref(),
+            Ok("xn--bcher-kva.example")
+        );
+        assert_eq!(
+            to_ascii("xn--bcher-kva.example").as_deref(),
+            Ok("xn--bcher-kva.example")
+        );
+        assert_eq!(to_ascii("example.com").as_deref(), Ok("example.com"));
+        assert_eq!(to_ascii("example.com.").as_deref(), Ok("example.com."));
+        assert_eq!(
+            to_ascii("\u{65E5}\u{672C}.jp").as_deref(),
+            Ok("xn--wgv71a.jp")
+        );
+        assert_eq!(to_ascii("faß.de").as_deref(), Ok("xn--fa-hia.de"));
+        assert_eq!(
+            to_ascii("a\u{308}\u{323}.com").as_deref(),
+            Ok("xn--ssa342l.com")
+        );
+        assert_eq!(
+            to_ascii("a\u{323}\u{308}.com").as_deref(),
+            Ok("xn--ssa342l.com")
+        );
+
+        assert_eq!(
+            to_ascii("a b.example"),
+            Err(IdnaError::DisallowedCharacter { offset: 1, c: ' ' })
+        );
+        assert_eq!(
+            to_ascii("a_b.example"),
+            Err(IdnaError::DisallowedCharacter { offset: 1, c: '_' })
+        );
+        assert_eq!(
+            to_ascii("-a.example"),
+            Err(IdnaError::HyphenStartOrEnd { label: 0 })
+        );
+        assert_eq!(
+            to_ascii("ab--c.example"),
+            Err(IdnaError::HyphenInThirdAndFourth { label: 0 })
+        );
+        assert_eq!(
+            to_ascii("\u{0308}a.example"),
+            Err(IdnaError::LeadingCombiningMark { label: 0 })
+        );
+        assert_eq!(
+            to_ascii("a\u{200D}b.example"),
+            Err(IdnaError::InvalidJoiner { label: 0 })
+        );
+        assert_eq!(
+            to_ascii("xn--abc-.example"),
+            Err(IdnaError::InvalidALabel { label: 0 })
+        );
+        assert!(matches!(
+            to_ascii("xn--bcher-k.example"),
+            Err(IdnaError::Punycode { label: 0, .. })
+        ));
+        assert_eq!(
+            to_ascii("a..b"),
+            Err(IdnaError::Dns(DnsNameError::EmptyLabel { offset: 2 }))
+        );
+    }
+
+    #[test]
+    fn test_to_ascii_for_url() {
+        assert_eq!(
+            to_ascii_for_url("Bücher_-.example").as_deref(),
+            Ok("xn--bcher_--n2a.example")
+        );
+        assert_eq!(to_ascii_for_url("a..b").as_deref(), Ok("a..b"));
+        assert_eq!(
+            to_ascii_for_url("a\u{200D}b"),
+            Err(IdnaError::InvalidJoiner { label: 0 })
+        );
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        assert_eq!(
+            to_unicode("xn--bcher-kva.example").as_deref(),
+            Ok("bücher.example")
+        );
+        assert_eq!(
+            to_unicode("XN--BCHER-KVA.example").as_deref(),
+            Ok("bücher.example")
+        );
+        assert_eq!(
+            to_unicode("Bücher.example").as_deref(),
+            Ok("bücher.example")
+        );
+        assert_eq!(to_unicode("example.com").as_deref(), Ok("example.com"));
+        assert_eq!(
+            to_unicode("xn--wgv71a.jp").as_deref(),
+            Ok("\u{65E5}\u{672C}.jp")
+        );
+    }
+}
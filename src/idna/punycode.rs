@@ -0,0 +1,247 @@
+//! Punycode
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc3492>
+
+use alloc::{string::String, vec::Vec};
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: char = '-';
+
+/// An error returned when a string cannot be encoded or decoded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PunycodeError {
+    /// The input contains a character that is not a basic code point or a digit.
+    InvalidCharacter { offset: usize },
+    /// The input ends in the middle of a variable-length integer.
+    UnexpectedEnd,
+    /// An intermediate value does not fit in 32 bits.
+    Overflow,
+    /// The decoded value is not a Unicode scalar value.
+    InvalidCodePoint,
+}
+
+impl core::fmt::Display for PunycodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PunycodeError::InvalidCharacter { offset } => {
+                write!(f, "invalid punycode character at offset {}", offset)
+            }
+            PunycodeError::UnexpectedEnd => write!(f, "unexpected end of punycode input"),
+            PunycodeError::Overflow => write!(f, "punycode overflow"),
+            PunycodeError::InvalidCodePoint => {
+                write!(f, "punycode decodes to an invalid code point")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PunycodeError {}
+
+/// <https://datatracker.ietf.org/doc/html/rfc3492#section-6.1>
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+}
+
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        TMIN
+    } else if k >= bias + TMAX {
+        TMAX
+    } else {
+        k - bias
+    }
+}
+
+fn encode_digit(d: u32) -> char {
+    debug_assert!(d < BASE);
+    if d < 26 {
+        (b'a' + d as u8) as char
+    } else {
+        (b'0' + (d - 26) as u8) as char
+    }
+}
+
+fn decode_digit(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+/// Encodes `input` (a label without the `xn--` prefix).
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3492#section-6.3>
+pub fn encode(input: &str) -> Result<String, PunycodeError> {
+    let input = input.chars().map(|c| c as u32).collect::<Vec<u32>>();
+    let mut output = input
+        .iter()
+        .filter(|c| **c < 0x80)
+        .map(|c| char::from(*c as u8))
+        .collect::<String>();
+    let b = output.len() as u32;
+    let mut h = b;
+    if b > 0 {
+        output.push(DELIMITER);
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    while (h as usize) < input.len() {
+        let m = input
+            .iter()
+            .copied()
+            .filter(|c| *c >= n)
+            .min()
+            .expect("remaining non-basic code point");
+        delta = (m - n)
+            .checked_mul(h + 1)
+            .and_then(|d| delta.checked_add(d))
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+        for c in input.iter().copied() {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + ((q - t) % (BASE - t))));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, h + 1, h == b);
+                delta = 0;
+                h += 1;
+            }
+        }
+        delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+        n = n.checked_add(1).ok_or(PunycodeError::Overflow)?;
+    }
+    Ok(output)
+}
+
+/// Decodes `input` (a label without the `xn--` prefix).
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3492#section-6.2>
+pub fn decode(input: &str) -> Result<String, PunycodeError> {
+    let (basic, extended, extended_offset) = match input.rfind(DELIMITER) {
+        Some(i) => (&input[..i], &input[i + 1..], i + 1),
+        None => ("", input, 0),
+    };
+    if let Some(i) = basic.find(|c: char| !c.is_ascii()) {
+        return Err(PunycodeError::InvalidCharacter { offset: i });
+    }
+    let mut output = basic.chars().collect::<Vec<char>>();
+
+    let mut n = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut chars = extended.char_indices().peekable();
+    while chars.peek().is_some() {
+        let old_i = i;
+        let mut w: u32 = 1;
+        let mut k = BASE;
+        loop {
+            let (offset, c) = chars.next().ok_or(PunycodeError::UnexpectedEnd)?;
+            let digit = decode_digit(c).ok_or(PunycodeError::InvalidCharacter {
+                offset: extended_offset + offset,
+            })?;
+            i = digit
+                .checked_mul(w)
+                .and_then(|d| i.checked_add(d))
+                .ok_or(PunycodeError::Overflow)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t).ok_or(PunycodeError::Overflow)?;
+            k += BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len).ok_or(PunycodeError::Overflow)?;
+        i %= len;
+        let c = char::from_u32(n).ok_or(PunycodeError::InvalidCodePoint)?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Ok(output.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // <https://datatracker.ietf.org/doc/html/rfc3492#section-7.1>
+    const SAMPLES: &[(&str, &str)] = &[
+        // (A) Arabic (Egyptian)
+        (
+            "\u{0644}\u{064A}\u{0647}\u{0645}\u{0627}\u{0628}\u{062A}\u{0643}\u{0644}\u{0645}\u{0648}\u{0634}\u{0639}\u{0631}\u{0628}\u{064A}\u{061F}",
+            "egbpdaj6bu4bxfgehfvwxn",
+        ),
+        // (B) Chinese (simplified)
+        (
+            "\u{4ED6}\u{4EEC}\u{4E3A}\u{4EC0}\u{4E48}\u{4E0D}\u{8BF4}\u{4E2D}\u{6587}",
+            "ihqwcrb4cv8a8dqg056pqjye",
+        ),
+        // (L) 3<nen>B<gumi><kinpachi><sensei>
+        (
+            "3\u{5E74}B\u{7D44}\u{91D1}\u{516B}\u{5148}\u{751F}",
+            "3B-ww4c5e180e575a65lsy2b",
+        ),
+        // (P) Maji<de>Koi<suru>5<byou><mae>
+        (
+            "Maji\u{3067}Koi\u{3059}\u{308B}5\u{79D2}\u{524D}",
+            "MajiKoi5-783gue6qz075azm5e",
+        ),
+        // (S) -> $1.00 <-
+        ("-> $1.00 <-", "-> $1.00 <--"),
+    ];
+
+    #[test]
+    fn test_encode() {
+        for (decoded, encoded) in SAMPLES {
+            assert_eq!(encode(decoded).as_deref(), Ok(*encoded));
+        }
+        assert_eq!(encode("bücher").as_deref(), Ok("bcher-kva"));
+        assert_eq!(encode("").as_deref(), Ok(""));
+    }
+
+    #[test]
+    fn test_decode() {
+        for (decoded, encoded) in SAMPLES {
+            assert_eq!(decode(encoded).as_deref(), Ok(*decoded));
+        }
+        assert_eq!(decode("bcher-kva").as_deref(), Ok("bücher"));
+        assert_eq!(decode("").as_deref(), Ok(""));
+
+        assert_eq!(
+            decode("bcher-kv!"),
+            Err(PunycodeError::InvalidCharacter { offset: 8 })
+        );
+        assert_eq!(decode("bcher-k"), Err(PunycodeError::UnexpectedEnd));
+        assert_eq!(decode("99999999999"), Err(PunycodeError::Overflow));
+    }
+}
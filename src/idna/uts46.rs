@@ -0,0 +1,236 @@
+//! Unicode IDNA Compatibility Processing (non-transitional)
+//!
+//! <https://www.unicode.org/reports/tr46/>
+
+use alloc::{string::String, vec::Vec};
+
+use super::uts46_table::{
+    COMBINING_CLASS, COMBINING_MARK, COMPOSITION, DECOMPOSITION, MAPPING, VIRAMA,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Status {
+    Valid,
+    Ignored,
+    Mapped(&'static str),
+    Disallowed,
+}
+
+pub(super) fn status(c: char) -> Status {
+    let c = c as u32;
+    let i = MAPPING
+        .binary_search_by(|(start, end, _)| {
+            if c < *start {
+                core::cmp::Ordering::Greater
+            } else if c > *end {
+                core::cmp::Ordering::Less
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .expect("MAPPING covers all code points");
+    MAPPING[i].2
+}
+
+fn in_ranges(ranges: &[(u32, u32)], c: char) -> bool {
+    let c = c as u32;
+    ranges
+        .binary_search_by(|(start, end)| {
+            if c < *start {
+                core::cmp::Ordering::Greater
+            } else if c > *end {
+                core::cmp::Ordering::Less
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+pub(super) fn is_combining_mark(c: char) -> bool {
+    in_ranges(COMBINING_MARK, c)
+}
+
+pub(super) fn is_virama(c: char) -> bool {
+    in_ranges(VIRAMA, c)
+}
+
+/// Applies the mapping step. Returns the offset and the character of the first disallowed code point on failure.
+///
+/// With `std3`, `UseSTD3ASCIIRules` also disallows the US-ASCII code points other than lowercase letters, digits,
+/// `-` and `.`, including those produced by a mapping.
+pub(super) fn map(s: &str, std3: bool) -> Result<String, (usize, char)> {
+    let mut mapped = String::with_capacity(s.len());
+    for (i, c) in s.char_indices() {
+        let start = mapped.len();
+        match status(c) {
+            Status::Valid => mapped.push(c),
+            Status::Ignored => {}
+            Status::Mapped(m) => mapped.push_str(m),
+            Status::Disallowed => return Err((i, c)),
+        }
+        if std3 && !mapped[start..].bytes().all(is_std3_valid) {
+            return Err((i, c));
+        }
+    }
+    Ok(mapped)
+}
+
+/// Whether `UseSTD3ASCIIRules` allows the byte.
+pub(super) fn is_std3_valid(b: u8) -> bool {
+    !b.is_ascii() || b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'.'
+}
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    // Hangul syllables are composed algorithmically
+    if (L_BASE..L_BASE + L_COUNT).contains(&a) && (V_BASE..V_BASE + V_COUNT).contains(&b) {
+        return char::from_u32(S_BASE + ((a - L_BASE) * V_COUNT + (b - V_BASE)) * T_COUNT);
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&a)
+        && (a - S_BASE).is_multiple_of(T_COUNT)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&b)
+    {
+        return char::from_u32(a + (b - T_BASE));
+    }
+    COMPOSITION
+        .binary_search_by(|(x, y, _)| (*x, *y).cmp(&(a, b)))
+        .ok()
+        .and_then(|i| char::from_u32(COMPOSITION[i].2))
+}
+
+fn combining_class(c: char) -> u8 {
+    let c = c as u32;
+    COMBINING_CLASS
+        .binary_search_by(|(start, end, _)| {
+            if c < *start {
+                core::cmp::Ordering::Greater
+            } else if c > *end {
+                core::cmp::Ordering::Less
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .map_or(0, |i| COMBINING_CLASS[i].2)
+}
+
+/// Appends the full canonical decomposition of `c`.
+fn decompose(c: char, out: &mut Vec<char>) {
+    let s = (c as u32).wrapping_sub(S_BASE);
+    if s < S_COUNT {
+        out.extend(char::from_u32(L_BASE + s / N_COUNT));
+        out.extend(char::from_u32(V_BASE + s % N_COUNT / T_COUNT));
+        if !s.is_multiple_of(T_COUNT) {
+            out.extend(char::from_u32(T_BASE + s % T_COUNT));
+        }
+        return;
+    }
+    match DECOMPOSITION.binary_search_by_key(&(c as u32), |(x, _)| *x) {
+        Ok(i) => out.extend(DECOMPOSITION[i].1.chars()),
+        Err(_) => out.push(c),
+    }
+}
+
+/// Normalization Form C.
+///
+/// Decomposes canonically, sorts each run of non-starters by combining class and composes each character with
+/// the last starter unless a character of the same or a higher class is between them.
+///
+/// <https://www.unicode.org/reports/tr15/#Description_Norm>
+pub(super) fn nfc(s: &str) -> String {
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        decompose(c, &mut chars);
+    }
+
+    // canonical ordering; the sort is stable, so marks of the same class keep their order
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        while i < chars.len() && combining_class(chars[i]) != 0 {
+            i += 1;
+        }
+        chars[start..i].sort_by_key(|c| combining_class(*c));
+        i += 1;
+    }
+
+    let mut composed: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter = None;
+    let mut last_class = 0;
+    for c in chars {
+        let class = combining_class(c);
+        // a starter only composes with the starter right before it
+        if let Some(i) = starter.filter(|_| last_class < class || last_class == 0)
+            && let Some(x) = compose_pair(composed[i], c)
+        {
+            composed[i] = x;
+            continue;
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+        }
+        last_class = class;
+        composed.push(c);
+    }
+    composed.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status() {
+        assert_eq!(status('a'), Status::Valid);
+        assert_eq!(status('-'), Status::Valid);
+        assert_eq!(status('A'), Status::Mapped("a"));
+        assert_eq!(status('Ü'), Status::Mapped("ü"));
+        assert_eq!(status('\u{FF21}'), Status::Mapped("a"));
+        assert_eq!(status('\u{3002}'), Status::Mapped("."));
+        assert_eq!(status('\u{00AD}'), Status::Ignored);
+        assert_eq!(status('ß'), Status::Valid);
+        assert_eq!(status('_'), Status::Valid);
+        assert_eq!(status('\u{FF3F}'), Status::Mapped("_"));
+        assert_eq!(status('\u{2488}'), Status::Disallowed);
+        assert_eq!(status('\u{E000}'), Status::Disallowed);
+    }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(map("Bücher.EXAMPLE", true).as_deref(), Ok("bücher.example"));
+        assert_eq!(map("a\u{00AD}b", true).as_deref(), Ok("ab"));
+        assert_eq!(map("a b", true), Err((1, ' ')));
+        assert_eq!(map("a b", false).as_deref(), Ok("a b"));
+        assert_eq!(map("a\u{FF3F}b", true), Err((1, '\u{FF3F}')));
+        assert_eq!(map("a\u{FF3F}b", false).as_deref(), Ok("a_b"));
+    }
+
+    #[test]
+    fn test_nfc() {
+        assert_eq!(nfc("bu\u{0308}cher"), "bücher");
+        assert_eq!(nfc("\u{1100}\u{1161}\u{11A8}"), "\u{AC01}");
+        assert_eq!(nfc("\u{AC01}"), "\u{AC01}");
+        assert_eq!(nfc("abc"), "abc");
+        // marks are reordered by combining class before composing
+        assert_eq!(nfc("a\u{0308}\u{0323}"), "\u{1EA1}\u{0308}");
+        assert_eq!(nfc("a\u{0323}\u{0308}"), "\u{1EA1}\u{0308}");
+        // a mark of the same class blocks the second one
+        assert_eq!(nfc("a\u{0301}\u{0301}"), "\u{00E1}\u{0301}");
+        assert_eq!(nfc("e\u{0302}\u{0301}"), "\u{1EBF}");
+        // composition exclusions and singletons
+        assert_eq!(nfc("\u{0958}"), "\u{0915}\u{093C}");
+        assert_eq!(nfc("\u{212B}"), "\u{00C5}");
+        assert_eq!(nfc("\u{0344}"), "\u{0308}\u{0301}");
+        assert_eq!(nfc("\u{0301}a"), "\u{0301}a");
+    }
+}
@@ -0,0 +1,306 @@
+//! Conversion between IRIs and URIs
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc3987#section-3>
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    Host, IdnaError, ParseError, Uri, UriBuf, idna,
+    parse_error::parse_all,
+    parser::{self, iprivate::is_iprivate, ucschar::is_ucschar},
+    percent_encoding::{decode_hex_pair, encode_char},
+};
+
+/// An error returned when an IRI cannot be mapped to a URI.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IriError {
+    /// The input is not an `IRI`.
+    Syntax(ParseError),
+    /// The non-ASCII `ireg-name` cannot be converted to an A-label.
+    Idna(IdnaError),
+}
+
+impl core::fmt::Display for IriError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IriError::Syntax(e) => write!(f, "{}", e),
+            IriError::Idna(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl core::error::Error for IriError {}
+
+/// Maps an IRI to a URI.
+///
+/// A non-ASCII `ireg-name` is converted with IDNA ToASCII. Every other non-ASCII character is percent-encoded as
+/// UTF-8.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-3.1>
+pub fn to_uri(iri: &str) -> Result<UriBuf, IriError> {
+    let token = parse_all(parser::iri, iri).map_err(IriError::Syntax)?;
+    let host = token
+        .hier_part
+        .authority
+        .map(|a| a.host)
+        .filter(|h| h.kind == parser::host::Kind::RegName && !h.span.fragment().is_ascii());
+
+    let mut uri = String::with_capacity(iri.len());
+    let mut rest = iri;
+    if let Some(host) = host {
+        let start = host.span.location_offset();
+        let end = start + host.span.fragment().len();
+        encode_non_ascii(&iri[..start], &mut uri);
+        let ascii = Host::RegName(host.span.fragment())
+            .to_ascii()
+            .map_err(IriError::Idna)?;
+        uri.push_str(&ascii);
+        rest = &iri[end..];
+    }
+    encode_non_ascii(rest, &mut uri);
+
+    // every character outside US-ASCII is now percent-encoded or in an A-label
+    Ok(UriBuf::parse(&uri).expect("mapped IRI"))
+}
+
+fn encode_non_ascii(s: &str, out: &mut String) {
+    for c in s.chars() {
+        if c.is_ascii() {
+            out.push(c);
+        } else {
+            encode_char(c, out);
+        }
+    }
+}
+
+/// Converts a URI to an IRI for display.
+///
+/// Percent-encoded UTF-8 sequences are decoded where the resulting character is allowed in the component. Octets
+/// that are not valid UTF-8, bidirectional formatting characters, `iprivate` characters outside the query, and
+/// octets for reserved or disallowed US-ASCII characters are left percent-encoded. Encoded unreserved characters
+/// are decoded. A-labels in a `reg-name` host are converted to Unicode.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-3.2>
+pub fn to_iri(uri: &Uri<'_>) -> String {
+    let s = uri.as_str();
+    let components = uri.components();
+    let mut regions = vec![];
+    if let Some(a) = components.authority {
+        if let Some(userinfo) = a.userinfo {
+            regions.push((userinfo, Region::Userinfo));
+        }
+        if a.host_kind == parser::host::Kind::RegName {
+            regions.push((a.host, Region::RegName));
+        }
+    }
+    regions.push((components.path, Region::Path));
+    if let Some(query) = components.query {
+        regions.push((query, Region::Query));
+    }
+    if let Some(fragment) = components.fragment {
+        regions.push((fragment, Region::Fragment));
+    }
+
+    let mut iri = String::with_capacity(s.len());
+    let mut last = 0;
+    for ((start, end), region) in regions {
+        iri.push_str(&s[last..start]);
+        let part = &s[start..end];
+        match region {
+            Region::RegName => {
+                let decoded = decode_for_display(part, false);
+                let has_a_label = decoded
+                    .split('.')
+                    .any(|l| l.get(..4).is_some_and(|p| p.eq_ignore_ascii_case("xn--")));
+                match has_a_label.then(|| idna::to_unicode(&decoded)) {
+                    Some(Ok(unicode)) => iri.push_str(&unicode),
+                    Some(Err(_)) | None => iri.push_str(&decoded),
+                }
+            }
+            Region::Query => iri.push_str(&decode_for_display(part, true)),
+            Region::Userinfo | Region::Path | Region::Fragment => {
+                iri.push_str(&decode_for_display(part, false))
+            }
+        }
+        last = end;
+    }
+    iri.push_str(&s[last..]);
+    iri
+}
+
+enum Region {
+    Userinfo,
+    RegName,
+    Path,
+    Query,
+    Fragment,
+}
+
+/// U+200E, U+200F, U+202A-202E and U+2066-2069
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-4.1>
+fn is_bidi_formatting(c: char) -> bool {
+    matches!(c, '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+fn decode_for_display(s: &str, allow_private: bool) -> String {
+    let bytes = s.as_bytes();
+    let triplet = |i: usize| {
+        (bytes.get(i) == Some(&b'%'))
+            .then(|| bytes.get(i + 1..i + 3).and_then(decode_hex_pair))
+            .flatten()
+    };
+
+    let mut decoded = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match triplet(i) {
+            Some(b) if b < 0x80 => {
+                if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+                    decoded.push(b as char);
+                } else {
+                    decoded.push_str(&s[i..i + 3]);
+                }
+                i += 3;
+            }
+            Some(_) => {
+                let mut run = vec![];
+                while let Some(b) = triplet(i).filter(|b| *b >= 0x80) {
+                    run.push((b, &s[i..i + 3]));
+                    i += 3;
+                }
+                decode_run(&run, allow_private, &mut decoded);
+            }
+            None => {
+                decoded.push(bytes[i] as char);
+                i += 1;
+            }
+        }
+    }
+    decoded
+}
+
+/// Decodes a run of non-ASCII octets, keeping the original triplets for octets that must stay encoded.
+fn decode_run(run: &[(u8, &str)], allow_private: bool, out: &mut String) {
+    let octets = run.iter().map(|(b, _)| *b).collect::<Vec<u8>>();
+    let mut i = 0;
+    for chunk in octets.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            let len = c.len_utf8();
+            if (is_ucschar(c) || (allow_private && is_iprivate(c))) && !is_bidi_formatting(c) {
+                out.push(c);
+            } else {
+                run[i..i + len].iter().for_each(|(_, t)| out.push_str(t));
+            }
+            i += len;
+        }
+        let len = chunk.invalid().len();
+        run[i..i + len].iter().for_each(|(_, t)| out.push_str(t));
+        i += len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_uri() {
+        let f = |s| to_uri(s).map(|u| u.into_string());
+        assert_eq!(
+            f("http://bücher.example/"),
+            Ok("http://xn--bcher-kva.example/".to_owned())
+        );
+        assert_eq!(
+            f("http://example.com/日本?q=ü#ß"),
+            Ok("http://example.com/%E6%97%A5%E6%9C%AC?q=%C3%BC#%C3%9F".to_owned())
+        );
+        assert_eq!(
+            f("http://jürgen@bücher.example:8080/ü"),
+            Ok("http://j%C3%BCrgen@xn--bcher-kva.example:8080/%C3%BC".to_owned())
+        );
+        assert_eq!(
+            f("http://example.com/?\u{E000}"),
+            Ok("http://example.com/?%EE%80%80".to_owned())
+        );
+        assert_eq!(
+            f("http://example.com/a%20b"),
+            Ok("http://example.com/a%20b".to_owned())
+        );
+        assert_eq!(f("urn:ü"), Ok("urn:%C3%BC".to_owned()));
+
+        assert_eq!(
+            f("http://example.com/a b"),
+            Err(IriError::Syntax(ParseError::new(20)))
+        );
+        assert_eq!(
+            f("http://bü_cher.example/"),
+            Err(IriError::Idna(IdnaError::DisallowedCharacter {
+                offset: 3,
+                c: '_'
+            }))
+        );
+    }
+
+    #[test]
+    fn test_to_iri() {
+        let f = |s| to_iri(&Uri::parse(s).unwrap());
+        assert_eq!(
+            f("http://example.com/%E6%97%A5%E6%9C%AC?q=%C3%BC#%C3%9F"),
+            "http://example.com/日本?q=ü#ß"
+        );
+        assert_eq!(f("http://xn--bcher-kva.example/"), "http://bücher.example/");
+        assert_eq!(f("http://b%C3%BCcher.example/"), "http://bücher.example/");
+        assert_eq!(
+            f("http://j%C3%BCrgen@example.com/"),
+            "http://jürgen@example.com/"
+        );
+
+        // unreserved characters are decoded; reserved and disallowed US-ASCII characters are not
+        assert_eq!(
+            f("http://example.com/%7Euser/a%2Fb%20c%25"),
+            "http://example.com/~user/a%2Fb%20c%25"
+        );
+        // invalid UTF-8
+        assert_eq!(
+            f("http://example.com/%C3%28%E6%97%A5"),
+            "http://example.com/%C3%28日"
+        );
+        assert_eq!(
+            f("http://example.com/%FF%E6%97%A5"),
+            "http://example.com/%FF日"
+        );
+        // bidi formatting characters (U+202E)
+        assert_eq!(
+            f("http://example.com/a%E2%80%AEb"),
+            "http://example.com/a%E2%80%AEb"
+        );
+        // iprivate (U+E000) in the query only
+        assert_eq!(
+            f("http://example.com/%EE%80%80?%EE%80%80"),
+            "http://example.com/%EE%80%80?\u{E000}"
+        );
+        // not ucschar (U+0080, U+FFFE)
+        assert_eq!(
+            f("http://example.com/%C2%80%EF%BF%BE"),
+            "http://example.com/%C2%80%EF%BF%BE"
+        );
+        // the original case of the triplets is kept
+        assert_eq!(
+            f("http://example.com/%c2%80%c3%bc"),
+            "http://example.com/%c2%80ü"
+        );
+        assert_eq!(f("mailto:a@example.com"), "mailto:a@example.com");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for s in [
+            "http://bücher.example/日本?q=ü#ß",
+            "http://example.com/%20ü",
+            "urn:ü",
+        ] {
+            assert_eq!(to_iri(&to_uri(s).unwrap().as_uri()), s);
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Lenient parsing of real-world URIs
+//!
+//! URIs pasted from browsers, documents and logs often contain characters that RFC 3986 does not allow. The
+//! functions of this module accept these deviations, report each one as a [`Warning`] at its offset in the input
+//! and, when [`Options::repair`] is set, repair them:
+//!
+//! - a space, `"`, `<`, `>`, `^`, `` ` ``, `{`, `|`, `}`, a control character, or a `#` within the fragment is
+//!   percent-encoded;
+//! - a `%` that does not start a `pct-encoded` triplet is encoded as `%25`;
+//! - a character outside US-ASCII is percent-encoded as UTF-8;
+//! - a `\` before the query is changed to `/` and a `\` within the query or the fragment is percent-encoded;
+//! - a tab or a line break is removed.
+//!
+//! The result is then checked against the strict grammar.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    ParseError,
+    parse_error::parse_all,
+    parser,
+    percent_encoding::{decode_hex_pair, encode_char},
+};
+
+/// Settings of a lenient parse.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Options {
+    /// Whether the deviations are repaired. Defaults to `true`.
+    ///
+    /// When unset, [`Lenient::as_str`] returns the input unchanged.
+    pub repair: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { repair: true }
+    }
+}
+
+/// A deviation from the strict grammar.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Deviation {
+    /// An US-ASCII character that is not allowed where it appears.
+    IllegalCharacter(char),
+    /// A character outside US-ASCII.
+    NonAscii(char),
+    /// A `%` that does not start a `pct-encoded` triplet.
+    StrayPercent,
+    /// A `\` used as a path separator.
+    Backslash,
+    /// A tab or a line break.
+    Whitespace(char),
+}
+
+impl core::fmt::Display for Deviation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Deviation::IllegalCharacter(c) => write!(f, "illegal character {:?}", c),
+            Deviation::NonAscii(c) => write!(f, "non-ASCII character {:?}", c),
+            Deviation::StrayPercent => f.write_str("'%' not followed by two hex digits"),
+            Deviation::Backslash => f.write_str("backslash"),
+            Deviation::Whitespace(c) => write!(f, "whitespace {:?}", c),
+        }
+    }
+}
+
+/// A deviation found in the input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Warning {
+    /// The byte offset of the deviation in the input.
+    pub offset: usize,
+    pub deviation: Deviation,
+}
+
+impl core::fmt::Display for Warning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at offset {}", self.deviation, self.offset)
+    }
+}
+
+/// The result of a lenient parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lenient {
+    uri: String,
+    warnings: Vec<Warning>,
+}
+
+impl Lenient {
+    /// Returns the repaired URI, or the input if [`Options::repair`] is unset.
+    pub fn as_str(&self) -> &str {
+        &self.uri
+    }
+
+    pub fn into_string(self) -> String {
+        self.uri
+    }
+
+    /// Returns the deviations in the order of their offsets. It is empty when the input is strictly valid.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+}
+
+/// Parses `s` leniently as a `URI`.
+///
+/// An error offset points into `s`.
+///
+/// ```
+/// use nom_uri::lenient::{self, Deviation, Options};
+///
+/// let r = lenient::uri("http://example.com/a b|c", &Options::default()).unwrap();
+/// assert_eq!(r.as_str(), "http://example.com/a%20b%7Cc");
+/// assert_eq!(r.warnings()[0].offset, 20);
+/// assert_eq!(r.warnings()[1].deviation, Deviation::IllegalCharacter('|'));
+/// ```
+pub fn uri(s: &str, options: &Options) -> Result<Lenient, ParseError> {
+    parse(s, options, |s| parse_all(parser::uri, s).map(|_| ()))
+}
+
+/// Parses `s` leniently as a `URI-reference`.
+///
+/// An error offset points into `s`.
+pub fn uri_reference(s: &str, options: &Options) -> Result<Lenient, ParseError> {
+    parse(s, options, |s| {
+        parse_all(parser::uri_reference, s).map(|_| ())
+    })
+}
+
+/// Repairs `s` and checks the repaired string with `check`.
+fn parse(
+    s: &str,
+    options: &Options,
+    check: impl FnOnce(&str) -> Result<(), ParseError>,
+) -> Result<Lenient, ParseError> {
+    let (repaired, origins, warnings) = repair(s);
+    check(&repaired)
+        .map_err(|e| e.with_offset(origins.get(e.offset()).copied().unwrap_or(s.len())))?;
+    Ok(Lenient {
+        uri: if options.repair {
+            repaired
+        } else {
+            String::from(s)
+        },
+        warnings,
+    })
+}
+
+/// Returns the repaired string, the offset in `s` of each of its bytes and the deviations.
+fn repair(s: &str) -> (String, Vec<usize>, Vec<Warning>) {
+    let bytes = s.as_bytes();
+    let mut repaired = String::with_capacity(s.len());
+    let mut origins = Vec::with_capacity(s.len());
+    let mut warnings = Vec::new();
+    let mut query = false;
+    let mut fragment = false;
+    for (offset, c) in s.char_indices() {
+        let deviation = match c {
+            '\t' | '\n' | '\r' => Some(Deviation::Whitespace(c)),
+            '\\' if !query && !fragment => {
+                repaired.push('/');
+                Some(Deviation::Backslash)
+            }
+            '%' if bytes
+                .get(offset + 1..offset + 3)
+                .and_then(decode_hex_pair)
+                .is_none() =>
+            {
+                repaired.push_str("%25");
+                Some(Deviation::StrayPercent)
+            }
+            '#' if fragment => {
+                encode_char(c, &mut repaired);
+                Some(Deviation::IllegalCharacter(c))
+            }
+            ' ' | '"' | '<' | '>' | '\\' | '^' | '`' | '{' | '|' | '}' | '\0'..='\x1F' | '\x7F' => {
+                encode_char(c, &mut repaired);
+                Some(Deviation::IllegalCharacter(c))
+            }
+            _ if !c.is_ascii() => {
+                encode_char(c, &mut repaired);
+                Some(Deviation::NonAscii(c))
+            }
+            _ => {
+                match c {
+                    '?' if !fragment => query = true,
+                    '#' => fragment = true,
+                    _ => {}
+                }
+                repaired.push(c);
+                None
+            }
+        };
+        origins.resize(repaired.len(), offset);
+        if let Some(deviation) = deviation {
+            warnings.push(Warning { offset, deviation });
+        }
+    }
+    origins.push(s.len());
+    (repaired, origins, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uri() {
+        let f = |s| {
+            uri(s, &Options::default()).map(|r| {
+                let offsets = r.warnings().iter().map(|w| w.offset).collect::<Vec<_>>();
+                (r.into_string(), offsets)
+            })
+        };
+        assert_eq!(
+            f("http://example.com/"),
+            Ok(("http://example.com/".to_owned(), vec![]))
+        );
+        assert_eq!(
+            f("http://example.com/{a}^b`"),
+            Ok((
+                "http://example.com/%7Ba%7D%5Eb%60".to_owned(),
+                vec![19, 21, 22, 24]
+            ))
+        );
+        assert_eq!(
+            f("http://example.com\\a\\b?c\\d#e\\#"),
+            Ok((
+                "http://example.com/a/b?c%5Cd#e%5C%23".to_owned(),
+                vec![18, 20, 24, 28, 29]
+            ))
+        );
+        assert_eq!(
+            f("http://example.com/100%?q=%zz&r=%20"),
+            Ok((
+                "http://example.com/100%25?q=%25zz&r=%20".to_owned(),
+                vec![22, 26]
+            ))
+        );
+        assert_eq!(
+            f("http://example.com/日本 x"),
+            Ok((
+                "http://example.com/%E6%97%A5%E6%9C%AC%20x".to_owned(),
+                vec![19, 22, 25]
+            ))
+        );
+        assert_eq!(
+            f("http://example.com/a\r\n/b\t"),
+            Ok(("http://example.com/a/b".to_owned(), vec![20, 21, 24]))
+        );
+
+        // the error offset points into the input
+        assert_eq!(f("ht tp://example.com/"), Err(ParseError::new(2)));
+        assert_eq!(f("http://[::1/ x"), Err(ParseError::new(7)));
+        assert_eq!(
+            f("http://ex ample.com/"),
+            Ok(("http://ex%20ample.com/".to_owned(), vec![9]))
+        );
+    }
+
+    #[test]
+    fn test_uri_reference() {
+        let r = uri_reference("../a b", &Options { repair: false }).unwrap();
+        assert_eq!(r.as_str(), "../a b");
+        assert_eq!(
+            r.warnings(),
+            [Warning {
+                offset: 4,
+                deviation: Deviation::IllegalCharacter(' ')
+            }]
+        );
+        assert_eq!(
+            r.warnings()[0].to_string(),
+            "illegal character ' ' at offset 4"
+        );
+        assert!(uri("../a b", &Options::default()).is_err());
+    }
+}
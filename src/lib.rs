@@ -1,3 +1,50 @@
-mod parser;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-pub use self::parser::uri;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod data;
+#[cfg(feature = "alloc")]
+pub mod deceptive;
+mod dns_name;
+pub mod file;
+mod host;
+pub mod http;
+#[cfg(feature = "alloc")]
+pub mod idna;
+#[cfg(feature = "alloc")]
+pub mod iri;
+#[cfg(feature = "alloc")]
+pub mod lenient;
+#[cfg(feature = "alloc")]
+pub mod mailto;
+mod parse_error;
+pub mod parser;
+mod path;
+#[cfg(feature = "alloc")]
+mod percent_encoding;
+pub mod policy;
+mod redacted;
+pub mod tel;
+mod typed_uri;
+#[cfg(feature = "alloc")]
+mod uri_buf;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod uri_reference;
+pub mod urn;
+#[cfg(feature = "alloc")]
+pub mod whatwg;
+
+pub use self::dns_name::{DnsName, DnsNameError, Labels};
+pub use self::host::{Host, LegacyIpv4};
+#[cfg(feature = "alloc")]
+pub use self::idna::IdnaError;
+pub use self::parse_error::ParseError;
+pub use self::parser::{Limit, ParseOptions, uri};
+#[cfg(feature = "std")]
+pub use self::path::FsPathError;
+pub use self::path::{Hazard, HazardKind, Path, SafetyReport, Segments};
+pub use self::redacted::Redacted;
+pub use self::typed_uri::Uri;
+#[cfg(feature = "alloc")]
+pub use self::uri_buf::UriBuf;
@@ -0,0 +1,439 @@
+//! The `mailto` URI scheme
+//!
+//! ```text
+//! mailtoURI    = "mailto:" [ to ] [ hfields ]
+//! to           = addr-spec *("," addr-spec )
+//! hfields      = "?" hfield *( "&" hfield )
+//! hfield       = hfname "=" hfvalue
+//! hfname       = *qchar
+//! hfvalue      = *qchar
+//! qchar        = unreserved / pct-encoded / some-delims
+//! some-delims  = "!" / "$" / "'" / "(" / ")" / "*"
+//!              / "+" / "," / ";" / ":" / "@"
+//! ```
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc6068#section-2>
+
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{ParseError, Uri, UriBuf, percent_encoding, typed_uri::offset};
+
+/// An error returned when a URI is not a `mailto` URI.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MailtoError {
+    /// The input is not a `URI`.
+    Syntax(ParseError),
+    /// The scheme is not `mailto`.
+    Scheme,
+    /// The URI has an authority or a fragment.
+    Component,
+    /// The `addr-spec` at the byte offset is invalid. The offset points into the URI, or into the builder's list
+    /// of addresses.
+    Address(usize),
+    /// The `hfield` at the byte offset has no `=`.
+    Hfield(usize),
+    /// The component at the byte offset does not decode to UTF-8.
+    InvalidUtf8(usize),
+}
+
+impl core::fmt::Display for MailtoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MailtoError::Syntax(e) => write!(f, "{}", e),
+            MailtoError::Scheme => f.write_str("scheme is not mailto"),
+            MailtoError::Component => f.write_str("mailto URI with an authority or a fragment"),
+            MailtoError::Address(offset) => write!(f, "invalid address at offset {}", offset),
+            MailtoError::Hfield(offset) => write!(f, "hfield without '=' at offset {}", offset),
+            MailtoError::InvalidUtf8(offset) => write!(f, "invalid UTF-8 at offset {}", offset),
+        }
+    }
+}
+
+impl core::error::Error for MailtoError {}
+
+/// A parsed `mailto` URI with its addresses and header fields percent-decoded.
+///
+/// ```
+/// use nom_uri::mailto::Mailto;
+///
+/// let mailto = Mailto::parse("mailto:a@example.com,b@example.com?subject=Hi%20there&cc=c@example.com").unwrap();
+/// assert_eq!(mailto.to(), ["a@example.com", "b@example.com"]);
+/// assert_eq!(mailto.subject(), Some("Hi there"));
+/// assert_eq!(mailto.header("CC"), Some("c@example.com"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Mailto {
+    to: Vec<String>,
+    headers: Vec<(String, String)>,
+}
+
+impl Mailto {
+    /// Parses `s` as a `mailto` URI.
+    pub fn parse(s: &str) -> Result<Self, MailtoError> {
+        Self::try_from(Uri::parse(s).map_err(MailtoError::Syntax)?)
+    }
+
+    /// Returns a builder of a `mailto` URI.
+    ///
+    /// ```
+    /// use nom_uri::mailto::Mailto;
+    ///
+    /// let mailto = Mailto::builder()
+    ///     .to("a@example.com")
+    ///     .cc("b@example.com")
+    ///     .subject("Q&A: 100% done?")
+    ///     .body("line 1\nline 2")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     mailto.to_string(),
+    ///     "mailto:a@example.com?cc=b@example.com&subject=Q%26A:%20100%25%20done%3F&body=line%201%0D%0Aline%202"
+    /// );
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the addresses of the `to` component. Recipients in `to` header fields are not included.
+    pub fn to(&self) -> &[String] {
+        &self.to
+    }
+
+    /// Returns the header fields in order, including `subject` and `body`.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the value of the first header field named `name`, ignoring ASCII case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn subject(&self) -> Option<&str> {
+        self.header("subject")
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.header("body")
+    }
+
+    /// Returns the URI.
+    pub fn to_uri(&self) -> UriBuf {
+        UriBuf::parse(&self.to_string()).expect("encoded mailto URI")
+    }
+}
+
+impl TryFrom<Uri<'_>> for Mailto {
+    type Error = MailtoError;
+
+    fn try_from(uri: Uri<'_>) -> Result<Self, Self::Error> {
+        if !uri.scheme().eq_ignore_ascii_case("mailto") {
+            return Err(MailtoError::Scheme);
+        }
+        if uri.authority().is_some() || uri.fragment().is_some() {
+            return Err(MailtoError::Component);
+        }
+        let s = uri.as_str();
+        let mut to = Vec::new();
+        if !uri.path().is_empty() {
+            for addr in uri.path().split(',') {
+                let start = offset(s, addr);
+                let decoded = decode(addr, start)?;
+                if !is_addr_spec(&decoded) {
+                    return Err(MailtoError::Address(start));
+                }
+                to.push(decoded);
+            }
+        }
+        let mut headers = Vec::new();
+        for hfield in uri.query().iter().flat_map(|q| q.split('&')) {
+            let start = offset(s, hfield);
+            let (name, value) = hfield.split_once('=').ok_or(MailtoError::Hfield(start))?;
+            headers.push((decode(name, start)?, decode(value, start + name.len() + 1)?));
+        }
+        Ok(Self { to, headers })
+    }
+}
+
+/// Writes the URI, percent-encoding every character outside `qchar` (and `,` within an address).
+impl core::fmt::Display for Mailto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("mailto:")?;
+        for (i, addr) in self.to.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            encode(f, addr, true)?;
+        }
+        for (i, (name, value)) in self.headers.iter().enumerate() {
+            f.write_str(if i == 0 { "?" } else { "&" })?;
+            encode(f, name, false)?;
+            f.write_str("=")?;
+            encode(f, value, false)?;
+        }
+        Ok(())
+    }
+}
+
+/// A builder of a [`Mailto`].
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    mailto: Mailto,
+}
+
+impl Builder {
+    /// Adds an address to the `to` component.
+    pub fn to(mut self, addr: &str) -> Self {
+        self.mailto.to.push(addr.to_owned());
+        self
+    }
+
+    /// Adds an address to the `cc` header field.
+    pub fn cc(self, addr: &str) -> Self {
+        self.append("cc", addr)
+    }
+
+    /// Adds an address to the `bcc` header field.
+    pub fn bcc(self, addr: &str) -> Self {
+        self.append("bcc", addr)
+    }
+
+    pub fn subject(self, subject: &str) -> Self {
+        self.header("subject", subject)
+    }
+
+    /// Sets the body. Line breaks are written as CRLF, as RFC 6068 requires.
+    pub fn body(self, body: &str) -> Self {
+        let body = body.replace("\r\n", "\n").replace('\n', "\r\n");
+        self.header("body", &body)
+    }
+
+    /// Sets a header field, replacing a field of the same name.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        let headers = &mut self.mailto.headers;
+        match headers
+            .iter_mut()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        {
+            Some((_, v)) => *v = value.to_owned(),
+            None => headers.push((name.to_owned(), value.to_owned())),
+        }
+        self
+    }
+
+    fn append(mut self, name: &str, addr: &str) -> Self {
+        match self
+            .mailto
+            .headers
+            .iter_mut()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        {
+            Some((_, v)) => {
+                v.push(',');
+                v.push_str(addr);
+            }
+            None => self.mailto.headers.push((name.to_owned(), addr.to_owned())),
+        }
+        self
+    }
+
+    /// Builds the `mailto` URI, checking the addresses of the `to` component.
+    pub fn build(self) -> Result<Mailto, MailtoError> {
+        let mut offset = 0;
+        for addr in &self.mailto.to {
+            if !is_addr_spec(addr) {
+                return Err(MailtoError::Address(offset));
+            }
+            offset += addr.len() + 1;
+        }
+        Ok(self.mailto)
+    }
+}
+
+fn decode(s: &str, offset: usize) -> Result<String, MailtoError> {
+    String::from_utf8(percent_encoding::decode(s)).map_err(|_| MailtoError::InvalidUtf8(offset))
+}
+
+fn encode(f: &mut core::fmt::Formatter<'_>, s: &str, addr: bool) -> core::fmt::Result {
+    for c in s.chars() {
+        match c {
+            ',' if addr => f.write_str("%2C")?,
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '.' | '_' | '~' => write!(f, "{}", c)?,
+            '!' | '$' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | ':' | '@' => write!(f, "{}", c)?,
+            c => {
+                let mut encoded = String::new();
+                percent_encoding::encode_char(c, &mut encoded);
+                f.write_str(&encoded)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `addr-spec = local-part "@" domain`, with non-ASCII characters allowed as in RFC 6532.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc5322#section-3.4.1>
+fn is_addr_spec(s: &str) -> bool {
+    let Some((local, domain)) = s.rsplit_once('@') else {
+        return false;
+    };
+    let local = is_dot_atom_text(local) || is_quoted_string(local);
+    let domain = is_dot_atom_text(domain)
+        || domain
+            .strip_prefix('[')
+            .and_then(|d| d.strip_suffix(']'))
+            .is_some_and(|d| d.bytes().all(|b| matches!(b, 33..=90 | 94..=126)));
+    local && domain
+}
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c) || !c.is_ascii()
+}
+
+fn is_dot_atom_text(s: &str) -> bool {
+    s.split('.')
+        .all(|atom| !atom.is_empty() && atom.chars().all(is_atext))
+}
+
+fn is_quoted_string(s: &str) -> bool {
+    let Some(inner) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        return false;
+    };
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if !chars
+                    .next()
+                    .is_some_and(|c| c == '\t' || (' '..='~').contains(&c))
+                {
+                    return false;
+                }
+            }
+            '"' => return false,
+            c if c == '\t' || c == ' ' || (c.is_ascii_graphic() || !c.is_ascii()) => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let f = |s| Mailto::parse(s);
+        let mailto = f("mailto:John.Doe@example.com").unwrap();
+        assert_eq!(mailto.to(), ["John.Doe@example.com"]);
+        assert_eq!(mailto.headers(), []);
+
+        // examples of RFC 6068 section 6
+        let mailto = f("mailto:?to=addr1@an.example,addr2@an.example").unwrap();
+        assert_eq!(mailto.to(), [] as [&str; 0]);
+        assert_eq!(
+            mailto.header("to"),
+            Some("addr1@an.example,addr2@an.example")
+        );
+        let mailto =
+            f("mailto:list@example.org?In-Reply-To=%3C3469A91.D10AF4C@example.com%3E").unwrap();
+        assert_eq!(
+            mailto.header("in-reply-to"),
+            Some("<3469A91.D10AF4C@example.com>")
+        );
+        let mailto =
+            f("mailto:infobot@example.com?body=send%20current-issue%0D%0Asend%20index").unwrap();
+        assert_eq!(mailto.body(), Some("send current-issue\r\nsend index"));
+        let mailto = f("mailto:%22not%40me%22@example.org").unwrap();
+        assert_eq!(mailto.to(), ["\"not@me\"@example.org"]);
+        let mailto = f("mailto:%22oh%5C%5Cno%22@example.org").unwrap();
+        assert_eq!(mailto.to(), ["\"oh\\\\no\"@example.org"]);
+        let mailto =
+            f("mailto:user@%E7%B4%8D%E8%B1%86.example.org?subject=Test&body=NATTO").unwrap();
+        assert_eq!(mailto.to(), ["user@納豆.example.org"]);
+        assert_eq!(mailto.subject(), Some("Test"));
+        let mailto = f("mailto:a@%5B192.0.2.1%5D?subject=a+b").unwrap();
+        assert_eq!(mailto.to(), ["a@[192.0.2.1]"]);
+        assert_eq!(mailto.subject(), Some("a+b"));
+        assert_eq!(
+            f("MAILTO:a@b?=").unwrap().headers(),
+            [("".to_owned(), "".to_owned())]
+        );
+
+        assert_eq!(f("http:a@b"), Err(MailtoError::Scheme));
+        assert_eq!(f("mailto://a@b"), Err(MailtoError::Component));
+        assert_eq!(f("mailto:a@b#f"), Err(MailtoError::Component));
+        assert_eq!(f("mailto:a@b,c"), Err(MailtoError::Address(11)));
+        assert_eq!(f("mailto:a..b@c"), Err(MailtoError::Address(7)));
+        assert_eq!(f("mailto:a@b?subject"), Err(MailtoError::Hfield(11)));
+        assert_eq!(
+            f("mailto:a@b?x=1&body=%FF"),
+            Err(MailtoError::InvalidUtf8(20))
+        );
+        assert_eq!(
+            f("mailto:a b"),
+            Err(MailtoError::Syntax(ParseError::new(8)))
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let mailto = Mailto::builder()
+            .to("a@example.com")
+            .to("\"x,y\"@example.com")
+            .bcc("b@example.com")
+            .bcc("c@example.com")
+            .header("X-Tag", "a=b&c#d")
+            .subject("one")
+            .subject("ü")
+            .body("a\r\nb\nc")
+            .build()
+            .unwrap();
+        let s = mailto.to_string();
+        assert_eq!(
+            s,
+            "mailto:a@example.com,%22x%2Cy%22@example.com?bcc=b@example.com,c@example.com\
+             &X-Tag=a%3Db%26c%23d&subject=%C3%BC&body=a%0D%0Ab%0D%0Ac"
+        );
+        assert_eq!(Mailto::parse(&s), Ok(mailto.clone()));
+        assert_eq!(mailto.to_uri().as_str(), s);
+
+        let mailto = Mailto::builder()
+            .header("Cc", "a@example.com")
+            .cc("b@example.com")
+            .build()
+            .unwrap();
+        assert_eq!(mailto.to_string(), "mailto:?Cc=a@example.com,b@example.com");
+        assert_eq!(mailto.header("cc"), Some("a@example.com,b@example.com"));
+
+        assert_eq!(Mailto::builder().build().unwrap().to_string(), "mailto:");
+        assert_eq!(
+            Mailto::builder().to("a@b").to("c").build(),
+            Err(MailtoError::Address(4))
+        );
+    }
+
+    #[test]
+    fn test_is_addr_spec() {
+        assert!(is_addr_spec("a@b"));
+        assert!(is_addr_spec("a.b+c@d.e"));
+        assert!(is_addr_spec("\"a b\"@c"));
+        assert!(is_addr_spec("\"a\\\"b\"@c"));
+        assert!(is_addr_spec("a@[IPv6:::1]"));
+        assert!(!is_addr_spec("a"));
+        assert!(!is_addr_spec("@b"));
+        assert!(!is_addr_spec("a@"));
+        assert!(!is_addr_spec(".a@b"));
+        assert!(!is_addr_spec("a b@c"));
+        assert!(!is_addr_spec("\"a\"b\"@c"));
+        assert!(!is_addr_spec("a@[b]c"));
+    }
+}
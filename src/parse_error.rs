@@ -0,0 +1,73 @@
+use nom::IResult;
+
+use crate::parser::{Context, Limit, Span};
+
+/// Runs `f` on `s` and requires it to consume the whole input.
+pub(crate) fn parse_all<'a, T>(
+    f: fn(Span<'a>) -> IResult<Span<'a>, T>,
+    s: &'a str,
+) -> Result<T, ParseError> {
+    parse_all_with(f, s, Context::default())
+}
+
+/// Runs `f` on `s` with `context` and requires it to consume the whole input.
+pub(crate) fn parse_all_with<'a, T>(
+    f: fn(Span<'a>) -> IResult<Span<'a>, T>,
+    s: &'a str,
+    context: Context,
+) -> Result<T, ParseError> {
+    let (rest, t) = f(Span::new_extra(s, context)).map_err(|e| match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => ParseError {
+            offset: e.input.location_offset(),
+            limit: e.input.extra.exceeded(),
+        },
+        nom::Err::Incomplete(_) => ParseError::new(s.len()),
+    })?;
+    if !rest.is_empty() {
+        return Err(ParseError::new(rest.location_offset()));
+    }
+    Ok(t)
+}
+
+/// An error returned when a string does not match a grammar rule as a whole.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    offset: usize,
+    limit: Option<Limit>,
+}
+
+impl ParseError {
+    pub(crate) fn new(offset: usize) -> Self {
+        Self {
+            offset,
+            limit: None,
+        }
+    }
+
+    /// Returns the error moved to `offset`, such as an offset in the input before a transformation.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn with_offset(self, offset: usize) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// The byte offset at which the input stopped matching.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The [`ParseOptions`](crate::ParseOptions) limit that was exceeded, if the input was rejected for one.
+    pub fn limit(&self) -> Option<Limit> {
+        self.limit
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.limit {
+            Some(limit) => write!(f, "{} limit exceeded at offset {}", limit, self.offset),
+            None => write!(f, "invalid syntax at offset {}", self.offset),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
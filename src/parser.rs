@@ -1,54 +1,120 @@
-mod authority;
-mod dec_octet;
-mod fragment;
-mod h16;
-mod hexdig;
-mod hier_part;
-mod host;
-mod ip_literal;
-mod ipv4address;
-mod ipv6address;
-mod ipvfuture;
-mod ls32;
-mod path;
-mod path_abempty;
-mod path_absolute;
-mod path_empty;
-mod path_noscheme;
-mod path_rootless;
-mod pchar;
-mod pct_encoded;
-mod port;
-mod query;
-mod reg_name;
-mod scheme;
-mod segment;
-mod segment_nz;
-mod segment_nz_nc;
-mod sub_delims;
-mod unreserved;
-mod uri;
-mod userinfo;
+pub mod absolute_form;
+pub mod authority;
+pub mod authority_form;
+mod bytes;
+mod context;
+pub mod dec_octet;
+pub mod fragment;
+pub mod h16;
+pub mod hexdig;
+pub mod hier_part;
+pub mod host;
+pub mod host_header;
+pub mod iauthority;
+pub mod ifragment;
+pub mod ihier_part;
+pub mod ihost;
+pub mod ip_literal;
+pub mod ipath;
+pub mod ipath_abempty;
+pub mod ipath_absolute;
+pub mod ipath_noscheme;
+pub mod ipath_rootless;
+pub mod ipchar;
+pub mod iprivate;
+pub mod ipv4address;
+pub mod ipv6address;
+pub mod ipvfuture;
+pub mod iquery;
+pub mod ireg_name;
+pub mod irelative_part;
+pub mod irelative_ref;
+pub mod iri;
+pub mod iri_reference;
+pub mod isegment;
+pub mod isegment_nz;
+pub mod isegment_nz_nc;
+pub mod iunreserved;
+pub mod iuserinfo;
+pub mod ls32;
+mod mode;
+mod multi;
+pub mod origin_form;
+pub mod path;
+pub mod path_abempty;
+pub mod path_absolute;
+pub mod path_empty;
+pub mod path_noscheme;
+pub mod path_rootless;
+pub mod pchar;
+pub mod pct_encoded;
+pub mod port;
+pub mod query;
+pub mod reg_name;
+pub mod relative_part;
+pub mod relative_ref;
+pub mod request_target;
+pub mod scheme;
+pub mod segment;
+pub mod segment_nz;
+pub mod segment_nz_nc;
+pub mod sub_delims;
+pub mod ucschar;
+pub mod unreserved;
+pub mod uri;
+pub mod uri_reference;
+pub mod userinfo;
 
 use nom_locate::LocatedSpan;
 
-pub(crate) type Span<'a> = LocatedSpan<&'a str>;
-pub(crate) trait HasSpan<'a> {
+pub use self::bytes::{ByteSpan, bytes};
+pub use self::context::{Context, Limit, ParseOptions};
+pub use self::mode::Mode;
+
+pub type Span<'a> = LocatedSpan<&'a str, Context>;
+pub trait HasSpan<'a> {
     fn span(&self) -> Span<'a>;
 }
 
+pub use self::absolute_form::absolute_form;
 pub use self::authority::authority;
+pub use self::authority_form::authority_form;
 pub use self::dec_octet::dec_octet;
 pub use self::fragment::fragment;
 pub use self::h16::h16;
 pub use self::hexdig::hexdig;
 pub use self::hier_part::hier_part;
 pub use self::host::host;
+pub use self::host_header::host_header;
+pub use self::iauthority::iauthority;
+pub use self::ifragment::ifragment;
+pub use self::ihier_part::ihier_part;
+pub use self::ihost::ihost;
 pub use self::ip_literal::ip_literal;
+pub use self::ipath::ipath;
+pub use self::ipath_abempty::ipath_abempty;
+pub use self::ipath_absolute::ipath_absolute;
+pub use self::ipath_noscheme::ipath_noscheme;
+pub use self::ipath_rootless::ipath_rootless;
+pub use self::ipchar::ipchar;
+pub use self::iprivate::iprivate;
 pub use self::ipv4address::ipv4address;
 pub use self::ipv6address::ipv6address;
 pub use self::ipvfuture::ipvfuture;
+pub use self::iquery::iquery;
+pub use self::ireg_name::ireg_name;
+pub use self::irelative_part::irelative_part;
+pub use self::irelative_ref::irelative_ref;
+pub use self::iri::iri;
+pub use self::iri_reference::iri_reference;
+pub use self::isegment::isegment;
+pub use self::isegment_nz::isegment_nz;
+pub use self::isegment_nz_nc::isegment_nz_nc;
+pub use self::iunreserved::iunreserved;
+pub use self::iuserinfo::iuserinfo;
 pub use self::ls32::ls32;
+pub use self::origin_form::origin_form;
+pub use self::path::path;
 pub use self::path_abempty::path_abempty;
 pub use self::path_absolute::path_absolute;
 pub use self::path_empty::path_empty;
@@ -59,13 +125,18 @@ pub use self::pct_encoded::pct_encoded;
 pub use self::port::port;
 pub use self::query::query;
 pub use self::reg_name::reg_name;
+pub use self::relative_part::relative_part;
+pub use self::relative_ref::relative_ref;
+pub use self::request_target::request_target;
 pub use self::scheme::scheme;
 pub use self::segment::segment;
 pub use self::segment_nz::segment_nz;
 pub use self::segment_nz_nc::segment_nz_nc;
 pub use self::sub_delims::sub_delims;
+pub use self::ucschar::ucschar;
 pub use self::unreserved::unreserved;
 pub use self::uri::uri;
+pub use self::uri_reference::uri_reference;
 pub use self::userinfo::userinfo;
 
 #[cfg(test)]
@@ -75,9 +146,7 @@ mod tests {
     use super::*;
 
     pub(crate) fn err<'a, T>(f: fn(Span<'a>) -> IResult<Span<'a>, T>, s: &'a str) {
-        use nom_locate::LocatedSpan;
-
-        let s = LocatedSpan::new(s);
+        let s = Span::from(s);
         assert!(f(s).is_err());
     }
 
@@ -87,9 +156,8 @@ mod tests {
         (i, o): (&'a str, &'a str),
     ) {
         use nom::Input;
-        use nom_locate::LocatedSpan;
 
-        let s = LocatedSpan::new(s);
+        let s = Span::from(s);
         let expected = (
             s.take_from(s.rfind(i).expect("i")),
             s.take_from(s.find(o).expect("o")).take(o.len()),
@@ -0,0 +1,53 @@
+use nom::{IResult, Input as _, Offset as _};
+
+use crate::parser::uri;
+
+use super::Span;
+
+/// absolute-form  = absolute-URI
+/// absolute-URI   = scheme ":" hier-part [ "?" query ]
+///
+/// The rule stops before a `#`, so the `fragment` of the token is always `None`.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9112#section-3.2.2>
+pub fn absolute_form(i: Span) -> IResult<Span, uri::Token> {
+    let start = i;
+    let (i, mut token) = uri(i)?;
+    match token.fragment.take() {
+        Some(fragment) => {
+            let i = start.take_from(start.offset(&fragment) - 1);
+            token.span = start.take(start.offset(&i));
+            Ok((i, token))
+        }
+        None => Ok((i, token)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_absolute_form() {
+        ok(
+            absolute_form,
+            "http://www.example.org/pub/WWW/TheProject.html",
+            ("", "http://www.example.org/pub/WWW/TheProject.html"),
+        );
+        ok(absolute_form, "http://a/b?q", ("", "http://a/b?q"));
+        ok(absolute_form, "http://a/b?q#f", ("#f", "http://a/b?q"));
+        ok(absolute_form, "http://a#", ("#", "http://a"));
+        ok(absolute_form, "urn:a:b", ("", "urn:a:b"));
+        ok(absolute_form, "a.example:443", ("", "a.example:443"));
+
+        err(absolute_form, "/a");
+        err(absolute_form, "*");
+        err(absolute_form, "[::1]:443");
+
+        let (_, token) = absolute_form(Span::from("http://a?q#f")).unwrap();
+        assert_eq!(token.query.map(|q| *q.fragment()), Some("q"));
+        assert_eq!(token.fragment, None);
+    }
+}
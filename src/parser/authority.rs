@@ -1,11 +1,14 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 use crate::parser::{host, port, userinfo};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
     pub span: Span<'a>,
+    pub userinfo: Option<Span<'a>>,
+    pub host: host::Token<'a>,
+    pub port: Option<Span<'a>>,
 }
 
 impl<'a> HasSpan<'a> for Token<'a> {
@@ -19,25 +22,24 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2>
 pub fn authority(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::branch::alt((
+    let (i, (userinfo, host, port)) = nom::branch::alt((
         (
-            userinfo.map(|_| ()),
-            nom::character::complete::char('@').map(|_| ()),
-            host.map(|_| ()),
-            nom::combinator::opt((nom::character::complete::char(':'), port)),
+            userinfo.map(|t| Some(t.span())),
+            mode::char('@').map(|_| ()),
+            host,
+            nom::combinator::opt((mode::char(':'), port)),
         )
-            .map(|_| ()),
-        (
-            host.map(|_| ()),
-            nom::combinator::opt((nom::character::complete::char(':'), port)),
-        )
-            .map(|_| ()),
+            .map(|(u, _, h, p)| (u, h, p)),
+        (host, nom::combinator::opt((mode::char(':'), port))).map(|(h, p)| (None, h, p)),
     ))
     .parse(i)?;
     Ok((
         i,
         Token {
             span: start.take(start.offset(&i)),
+            userinfo,
+            host,
+            port: port.map(|(_, p)| p.span()),
         },
     ))
 }
@@ -161,4 +163,21 @@ mod tests {
             ("#fragment", "user@example.com:8080"),
         );
     }
+
+    #[test]
+    fn test_authority_components() {
+        let (_, t) = authority(Span::from("user:pass@example.com:80/path")).expect("authority");
+        assert_eq!(t.userinfo.map(|s| *s.fragment()), Some("user:pass"));
+        assert_eq!(*t.host.span.fragment(), "example.com");
+        assert_eq!(t.port.map(|s| *s.fragment()), Some("80"));
+
+        let (_, t) = authority(Span::from("example.com:")).expect("authority");
+        assert_eq!(t.userinfo, None);
+        assert_eq!(*t.host.span.fragment(), "example.com");
+        assert_eq!(t.port.map(|s| *s.fragment()), Some(""));
+
+        let (_, t) = authority(Span::from("[::1]")).expect("authority");
+        assert_eq!(*t.host.span.fragment(), "[::1]");
+        assert_eq!(t.port, None);
+    }
 }
@@ -0,0 +1,64 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{host, port};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+    pub host: host::Token<'a>,
+    pub port: Span<'a>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// authority-form = uri-host ":" port
+///
+/// This is an `authority` without a userinfo and with a port.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9112#section-3.2.3>
+pub fn authority_form(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, (host, _, port)) = (host, mode::char(':'), port).parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+            host,
+            port: port.span(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_authority_form() {
+        ok(
+            authority_form,
+            "www.example.com:80",
+            ("", "www.example.com:80"),
+        );
+        ok(authority_form, "[::1]:443", ("", "[::1]:443"));
+        ok(authority_form, "127.0.0.1:8080", ("", "127.0.0.1:8080"));
+        ok(authority_form, "a:", ("", "a:"));
+        ok(authority_form, "a:80/", ("/", "a:80"));
+
+        err(authority_form, "a");
+        err(authority_form, "user@a:80");
+        err(authority_form, "[::1]");
+
+        let (_, token) = authority_form(Span::from("a:80")).unwrap();
+        assert_eq!(*token.host.span.fragment(), "a");
+        assert_eq!(*token.port.fragment(), "80");
+    }
+}
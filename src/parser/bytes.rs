@@ -0,0 +1,115 @@
+use nom::{IResult, Input as _};
+use nom_locate::LocatedSpan;
+
+use super::{Context, Mode, Span};
+
+/// A byte input, such as raw header bytes that may not be valid UTF-8.
+pub type ByteSpan<'a> = LocatedSpan<&'a [u8], Context>;
+
+/// Adapts the rule `f` to a byte input.
+///
+/// The rule runs on the longest valid UTF-8 prefix of the input. No rule matches a byte that is not valid UTF-8,
+/// so the match stops there and a failure is reported at the offset of that byte. The tokens borrow the input and
+/// keep its offsets.
+///
+/// ```
+/// use nom_uri::parser::{ByteSpan, bytes, uri};
+///
+/// let (rest, t) = bytes(uri)(ByteSpan::from(&b"http://example.com/\xFF"[..])).unwrap();
+/// assert_eq!(*t.span.fragment(), "http://example.com/");
+/// assert_eq!(rest.location_offset(), 19);
+/// ```
+pub fn bytes<'a, T>(
+    f: fn(Span<'a>) -> IResult<Span<'a>, T>,
+) -> impl Fn(ByteSpan<'a>) -> IResult<ByteSpan<'a>, T> {
+    move |i: ByteSpan<'a>| {
+        let (valid, context) = match core::str::from_utf8(i.fragment()) {
+            Ok(s) => (s, i.extra),
+            Err(e) => {
+                let s =
+                    core::str::from_utf8(&i.fragment()[..e.valid_up_to()]).expect("valid UTF-8");
+                match e.error_len() {
+                    // the input ends inside a sequence that more input may complete
+                    None => (s, i.extra),
+                    Some(_) => (s, i.extra.with_mode(Mode::Complete)),
+                }
+            }
+        };
+        // SAFETY: `valid` starts at the same address as the fragment of `i`, so the offset and the line of `i`
+        // describe the same position in the same original input.
+        let s = unsafe {
+            Span::new_from_raw_offset(i.location_offset(), i.location_line(), valid, context)
+        };
+        let at = |span: Span<'a>| i.take_from(span.location_offset() - i.location_offset());
+        match f(s) {
+            Ok((rest, t)) => Ok((at(rest), t)),
+            Err(nom::Err::Error(e)) => {
+                Err(nom::Err::Error(nom::error::Error::new(at(e.input), e.code)))
+            }
+            Err(nom::Err::Failure(e)) => Err(nom::Err::Failure(nom::error::Error::new(
+                at(e.input),
+                e.code,
+            ))),
+            Err(nom::Err::Incomplete(n)) => Err(nom::Err::Incomplete(n)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::{HasSpan, host, uri};
+
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let f = |s: &'static [u8]| {
+            bytes(uri)(ByteSpan::from(s)).map(|(rest, t)| {
+                (
+                    *rest.fragment(),
+                    *t.span().fragment(),
+                    rest.location_offset(),
+                )
+            })
+        };
+        assert_eq!(
+            f(b"http://example.com/"),
+            Ok((&b""[..], "http://example.com/", 19))
+        );
+        assert_eq!(
+            f(b"http://example.com/a\xFFb"),
+            Ok((&b"\xFFb"[..], "http://example.com/a", 20))
+        );
+        assert_eq!(
+            f(b"http://example.com/\xC3\xBC"),
+            Ok((&b"\xC3\xBC"[..], "http://example.com/", 19))
+        );
+
+        let e = bytes(uri)(ByteSpan::from(&b"\xFFhttp:"[..])).expect_err("uri");
+        assert!(matches!(e, nom::Err::Error(e) if e.input.location_offset() == 0));
+
+        // the tokens keep the offsets of the input
+        let i = ByteSpan::from(&b"http://example.com:80/"[..]);
+        let (_, t) = bytes(uri)(i).expect("uri");
+        let authority = t.hier_part.authority.expect("authority");
+        assert_eq!(authority.host.span.location_offset(), 7);
+        assert_eq!(authority.port.expect("port").location_offset(), 19);
+
+        let (rest, t) = bytes(host)(i.take_from(7)).expect("host");
+        assert_eq!(*t.span.fragment(), "example.com");
+        assert_eq!(rest.location_offset(), 18);
+    }
+
+    #[test]
+    fn test_bytes_streaming() {
+        let f = |s: &'static [u8]| bytes(uri)(ByteSpan::new_extra(s, Mode::Streaming.into()));
+        assert!(matches!(f(b"http://exa"), Err(nom::Err::Incomplete(_))));
+        // a truncated UTF-8 sequence may be completed by more input
+        assert!(matches!(f(b"http://exa\xC3"), Err(nom::Err::Incomplete(_))));
+        // an invalid byte ends the match
+        assert_eq!(
+            f(b"http://exa\xFF").map(|(rest, _)| rest.location_offset()),
+            Ok(10)
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use nom::{IResult, Offset as _, Parser};
+
+use super::{Mode, Span};
+
+/// Resource limits checked while the rules run.
+///
+/// A rule that exceeds a limit stops at once with `nom::Err::Failure`, so no alternative is tried and the rest of
+/// the input is not scanned. Every limit defaults to `usize::MAX`.
+///
+/// ```
+/// use nom_uri::{Limit, ParseOptions, Uri};
+///
+/// let options = ParseOptions {
+///     max_query_len: 8,
+///     ..ParseOptions::default()
+/// };
+/// let e = Uri::parse_with_options("http://example.com/?q=0123456789", &options).unwrap_err();
+/// assert_eq!(e.limit(), Some(Limit::QueryLength));
+/// assert_eq!(e.offset(), 28);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseOptions {
+    /// The maximum length of the input consumed from offset 0, in bytes.
+    pub max_len: usize,
+    /// The maximum number of path segments.
+    pub max_segments: usize,
+    /// The maximum length of the query, in bytes.
+    pub max_query_len: usize,
+    /// The maximum length of the host, in bytes.
+    pub max_host_len: usize,
+    /// The maximum number of `pct-encoded` triplets.
+    pub max_pct_encoded: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_len: usize::MAX,
+            max_segments: usize::MAX,
+            max_query_len: usize::MAX,
+            max_host_len: usize::MAX,
+            max_pct_encoded: usize::MAX,
+        }
+    }
+}
+
+impl ParseOptions {
+    fn max(&self, limit: Limit) -> usize {
+        match limit {
+            Limit::Length => self.max_len,
+            Limit::Segments => self.max_segments,
+            Limit::QueryLength => self.max_query_len,
+            Limit::HostLength => self.max_host_len,
+            Limit::PctEncoded => self.max_pct_encoded,
+        }
+    }
+}
+
+/// A limit of [`ParseOptions`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Limit {
+    Length,
+    Segments,
+    QueryLength,
+    HostLength,
+    PctEncoded,
+}
+
+impl core::fmt::Display for Limit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Limit::Length => "length",
+            Limit::Segments => "path segment count",
+            Limit::QueryLength => "query length",
+            Limit::HostLength => "host length",
+            Limit::PctEncoded => "pct-encoded count",
+        })
+    }
+}
+
+/// The state carried in the `extra` field of a [`Span`].
+///
+/// Every sub-span keeps it, so the mode and the options apply to the whole grammar. The counts flow forward with
+/// the remaining input, so an alternative that is backtracked does not count.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Context {
+    pub mode: Mode,
+    pub options: ParseOptions,
+    segments: usize,
+    pct_encoded: usize,
+    exceeded: Option<Limit>,
+}
+
+impl Context {
+    pub fn new(mode: Mode, options: ParseOptions) -> Self {
+        Self {
+            mode,
+            options,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn with_mode(self, mode: Mode) -> Self {
+        Self { mode, ..self }
+    }
+
+    /// Returns the limit that a `nom::Err::Failure` was raised for, when called on the span of its error.
+    pub fn exceeded(&self) -> Option<Limit> {
+        self.exceeded
+    }
+}
+
+impl From<Mode> for Context {
+    fn from(mode: Mode) -> Self {
+        Self::new(mode, ParseOptions::default())
+    }
+}
+
+impl From<ParseOptions> for Context {
+    fn from(options: ParseOptions) -> Self {
+        Self::new(Mode::default(), options)
+    }
+}
+
+pub(crate) fn exceeded(i: Span, limit: Limit) -> nom::Err<nom::error::Error<Span>> {
+    nom::Err::Failure(nom::error::Error::new(
+        i.map_extra(|c| Context {
+            exceeded: Some(limit),
+            ..c
+        }),
+        nom::error::ErrorKind::TooLarge,
+    ))
+}
+
+/// Fails with [`Limit::Length`] when `rest` starts beyond `max_len`. `i` is the input of the step that consumed
+/// up to `rest`.
+pub(crate) fn check_len<'a>(
+    i: Span<'a>,
+    rest: Span<'a>,
+) -> Result<(), nom::Err<nom::error::Error<Span<'a>>>> {
+    if rest.location_offset() > rest.extra.options.max_len {
+        return Err(exceeded(i, Limit::Length));
+    }
+    Ok(())
+}
+
+/// Counts one match of `limit` ([`Limit::Segments`] or [`Limit::PctEncoded`]) in the remaining input `rest`.
+/// `start` is the input of the match.
+pub(crate) fn count<'a>(start: Span<'a>, rest: Span<'a>, limit: Limit) -> IResult<Span<'a>, ()> {
+    let mut context = rest.extra;
+    let n = match limit {
+        Limit::Segments => &mut context.segments,
+        Limit::PctEncoded => &mut context.pct_encoded,
+        Limit::Length | Limit::QueryLength | Limit::HostLength => unreachable!("not a count"),
+    };
+    *n += 1;
+    if *n > context.options.max(limit) {
+        return Err(exceeded(start, limit));
+    }
+    Ok((rest.map_extra(|_| context), ()))
+}
+
+/// Runs `f` and fails with `limit` ([`Limit::QueryLength`] or [`Limit::HostLength`]) once the input consumed since
+/// `start` is longer than the limit.
+pub(crate) fn bounded<'a, F>(
+    start: Span<'a>,
+    limit: Limit,
+    mut f: F,
+) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, F::Output>
+where
+    F: Parser<Span<'a>, Error = nom::error::Error<Span<'a>>>,
+{
+    move |i: Span<'a>| {
+        let (rest, o) = f.parse(i)?;
+        if start.offset(&rest) > start.extra.options.max(limit) {
+            return Err(exceeded(i, limit));
+        }
+        Ok((rest, o))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{path_abempty, pct_encoded, query, reg_name, uri};
+
+    fn limit<T>(r: IResult<Span, T>) -> Option<(usize, Limit)> {
+        match r {
+            Err(nom::Err::Failure(e)) => e
+                .input
+                .extra
+                .exceeded()
+                .map(|l| (e.input.location_offset(), l)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        let span = |s, options| Span::new_extra(s, Context::from(options));
+        let options = ParseOptions {
+            max_len: 10,
+            ..ParseOptions::default()
+        };
+        assert!(uri(span("http://a/b", options)).is_ok());
+        assert_eq!(
+            limit(uri(span("http://a/bc", options))),
+            Some((10, Limit::Length))
+        );
+        // a match that ends at the limit is not cut short by the text that follows it
+        assert!(uri(span("http://a/b HTTP/1.1", options)).is_ok());
+
+        let options = ParseOptions {
+            max_segments: 2,
+            ..ParseOptions::default()
+        };
+        assert!(path_abempty(span("/a/b", options)).is_ok());
+        assert_eq!(
+            limit(path_abempty(span("/a/b/c", options))),
+            Some((5, Limit::Segments))
+        );
+
+        let options = ParseOptions {
+            max_query_len: 3,
+            ..ParseOptions::default()
+        };
+        assert!(query(span("a=b", options)).is_ok());
+        assert_eq!(
+            limit(query(span("a=bc", options))),
+            Some((3, Limit::QueryLength))
+        );
+
+        let options = ParseOptions {
+            max_host_len: 3,
+            ..ParseOptions::default()
+        };
+        assert!(reg_name(span("abc", options)).is_ok());
+        assert_eq!(
+            limit(reg_name(span("abcd", options))),
+            Some((3, Limit::HostLength))
+        );
+        assert_eq!(
+            limit(uri(span("http://[::::::::::::::::::::::::::]/", options))),
+            Some((8, Limit::HostLength))
+        );
+
+        let options = ParseOptions {
+            max_pct_encoded: 1,
+            ..ParseOptions::default()
+        };
+        assert!(pct_encoded(span("%20", options)).is_ok());
+        assert_eq!(
+            limit(uri(span("a:%20%20", options))),
+            Some((5, Limit::PctEncoded))
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -24,29 +24,29 @@ pub fn dec_octet(i: Span) -> IResult<Span, Token> {
     let start = i;
     let (i, _) = nom::branch::alt((
         (
-            nom::character::complete::char('2'),
-            nom::character::complete::char('5'),
-            nom::character::complete::satisfy(|c| matches!(c, '0'..='5')),
+            mode::char('2'),
+            mode::char('5'),
+            mode::satisfy(|c| matches!(c, '0'..='5')),
         )
             .map(|_| ()),
         (
-            nom::character::complete::char('2'),
-            nom::character::complete::satisfy(|c| matches!(c, '0'..='4')),
-            nom::character::complete::satisfy(|c| c.is_ascii_digit()),
+            mode::char('2'),
+            mode::satisfy(|c| matches!(c, '0'..='4')),
+            mode::satisfy(|c| c.is_ascii_digit()),
         )
             .map(|_| ()),
         (
-            nom::character::complete::char('1'),
-            nom::character::complete::satisfy(|c| c.is_ascii_digit()),
-            nom::character::complete::satisfy(|c| c.is_ascii_digit()),
+            mode::char('1'),
+            mode::satisfy(|c| c.is_ascii_digit()),
+            mode::satisfy(|c| c.is_ascii_digit()),
         )
             .map(|_| ()),
         (
-            nom::character::complete::satisfy(|c| matches!(c, '1'..='9')),
-            nom::character::complete::satisfy(|c| c.is_ascii_digit()),
+            mode::satisfy(|c| matches!(c, '1'..='9')),
+            mode::satisfy(|c| c.is_ascii_digit()),
         )
             .map(|_| ()),
-        nom::character::complete::satisfy(|c| c.is_ascii_digit()).map(|_| ()),
+        mode::satisfy(|c| c.is_ascii_digit()).map(|_| ()),
     ))
     .parse(i)?;
     Ok((
@@ -2,7 +2,7 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::pchar;
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -20,10 +20,10 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.5>
 pub fn fragment(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many0(nom::branch::alt((
+    let (i, _) = nom::multi::many0_count(nom::branch::alt((
         pchar.map(|_| ()),
-        nom::character::complete::char('/').map(|_| ()),
-        nom::character::complete::char('?').map(|_| ()),
+        mode::char('/').map(|_| ()),
+        mode::char('?').map(|_| ()),
     )))
     .parse(i)?;
     Ok((
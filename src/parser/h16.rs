@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, multi};
 use crate::parser::hexdig;
 
 #[derive(Debug, PartialEq)]
@@ -20,7 +20,7 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2.2>
 pub fn h16(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many_m_n(1, 4, hexdig).parse(i)?;
+    let (i, _) = multi::many_m_n(1, 4, hexdig).parse(i)?;
     Ok((
         i,
         Token {
@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -21,7 +21,7 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc2234#section-6.1>
 pub fn hexdig(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::character::complete::satisfy(|c| c.is_ascii_hexdigit()).parse(i)?;
+    let (i, _) = mode::satisfy(|c| c.is_ascii_hexdigit()).parse(i)?;
     Ok((
         i,
         Token {
@@ -2,11 +2,13 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::{authority, path_abempty, path_absolute, path_empty, path_rootless};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
     pub span: Span<'a>,
+    pub authority: Option<authority::Token<'a>>,
+    pub path: Span<'a>,
 }
 
 impl<'a> HasSpan<'a> for Token<'a> {
@@ -23,17 +25,19 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3>
 pub fn hier_part(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::branch::alt((
-        (nom::bytes::complete::tag("//"), authority, path_abempty).map(|_| ()),
-        path_absolute.map(|_| ()),
-        path_rootless.map(|_| ()),
-        path_empty.map(|_| ()),
+    let (i, (authority, path)) = nom::branch::alt((
+        (mode::tag("//"), authority, path_abempty).map(|(_, a, p)| (Some(a), p.span())),
+        path_absolute.map(|p| (None, p.span())),
+        path_rootless.map(|p| (None, p.span())),
+        path_empty.map(|p| (None, p.span())),
     ))
     .parse(i)?;
     Ok((
         i,
         Token {
             span: start.take(start.offset(&i)),
+            authority,
+            path,
         },
     ))
 }
@@ -6,6 +6,14 @@ use crate::parser::{ip_literal, ipv4address, reg_name};
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
     pub span: Span<'a>,
+    pub kind: Kind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    IpLiteral,
+    Ipv4Address,
+    RegName,
 }
 
 impl<'a> HasSpan<'a> for Token<'a> {
@@ -19,16 +27,17 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2.2>
 pub fn host(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::branch::alt((
-        ip_literal.map(|_| ()),
-        ipv4address.map(|_| ()),
-        reg_name.map(|_| ()),
+    let (i, kind) = nom::branch::alt((
+        ip_literal.map(|_| Kind::IpLiteral),
+        ipv4address.map(|_| Kind::Ipv4Address),
+        reg_name.map(|_| Kind::RegName),
     ))
     .parse(i)?;
     Ok((
         i,
         Token {
             span: start.take(start.offset(&i)),
+            kind,
         },
     ))
 }
@@ -98,4 +107,15 @@ mod tests {
         // 1.2.3 is parsed as reg-name, not IPv4address
         ok(host, "1.2.3", ("", "1.2.3"));
     }
+
+    #[test]
+    fn test_host_kind() {
+        let f = |s| host(Span::from(s)).map(|(_, t)| t.kind).expect("host");
+        assert_eq!(f("[::1]"), Kind::IpLiteral);
+        assert_eq!(f("[v1.12345]"), Kind::IpLiteral);
+        assert_eq!(f("192.168.0.1"), Kind::Ipv4Address);
+        assert_eq!(f("256.0.0.1"), Kind::RegName);
+        assert_eq!(f("example.com"), Kind::RegName);
+        assert_eq!(f(""), Kind::RegName);
+    }
 }
@@ -0,0 +1,66 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{host, port};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+    pub host: host::Token<'a>,
+    pub port: Option<Span<'a>>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// Host = uri-host [ ":" port ]
+///
+/// This is an `authority` without a userinfo. The host may be empty.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9110#section-7.2>
+pub fn host_header(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, (host, port)) = (host, nom::combinator::opt((mode::char(':'), port))).parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+            host,
+            port: port.map(|(_, p)| p.span()),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_host_header() {
+        ok(host_header, "www.example.org", ("", "www.example.org"));
+        ok(
+            host_header,
+            "www.example.org:8080",
+            ("", "www.example.org:8080"),
+        );
+        ok(host_header, "[::1]:80", ("", "[::1]:80"));
+        ok(host_header, "192.0.2.1", ("", "192.0.2.1"));
+        ok(host_header, "a:", ("", "a:"));
+        ok(host_header, "", ("", ""));
+        ok(host_header, "a:80\r\n", ("\r\n", "a:80"));
+        ok(host_header, "user@a", ("@a", "user"));
+        ok(host_header, "a/b", ("/b", "a"));
+
+        let (_, token) = host_header(Span::from("a:80")).unwrap();
+        assert_eq!(*token.host.span.fragment(), "a");
+        assert_eq!(token.port.map(|p| *p.fragment()), Some("80"));
+        let (_, token) = host_header(Span::from("[::1]")).unwrap();
+        assert_eq!(token.port, None);
+    }
+}
@@ -0,0 +1,60 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{authority, ihost, iuserinfo, port};
+
+use super::{HasSpan, Span, mode};
+
+/// iauthority     = [ iuserinfo "@" ] ihost [ ":" port ]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn iauthority(i: Span) -> IResult<Span, authority::Token> {
+    let start = i;
+    let (i, (userinfo, host, port)) = nom::branch::alt((
+        (
+            iuserinfo.map(|t| Some(t.span())),
+            mode::char('@').map(|_| ()),
+            ihost,
+            nom::combinator::opt((mode::char(':'), port)),
+        )
+            .map(|(u, _, h, p)| (u, h, p)),
+        (ihost, nom::combinator::opt((mode::char(':'), port))).map(|(h, p)| (None, h, p)),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        authority::Token {
+            span: start.take(start.offset(&i)),
+            userinfo,
+            host,
+            port: port.map(|(_, p)| p.span()),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_iauthority() {
+        ok(iauthority, "example.com", ("", "example.com"));
+        ok(iauthority, "bücher.example:80", ("", "bücher.example:80"));
+        ok(
+            iauthority,
+            "jürgen@bücher.example:8080/ü",
+            ("/ü", "jürgen@bücher.example:8080"),
+        );
+        ok(iauthority, "[::1]:80", ("", "[::1]:80"));
+        ok(iauthority, "", ("", ""));
+    }
+
+    #[test]
+    fn test_iauthority_components() {
+        let (_, t) = iauthority(Span::from("jürgen@bücher.example:80")).expect("iauthority");
+        assert_eq!(t.userinfo.map(|s| *s.fragment()), Some("jürgen"));
+        assert_eq!(*t.host.span.fragment(), "bücher.example");
+        assert_eq!(t.port.map(|s| *s.fragment()), Some("80"));
+    }
+}
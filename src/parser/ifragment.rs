@@ -0,0 +1,40 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{fragment, ipchar};
+
+use super::{Span, mode};
+
+/// ifragment      = *( ipchar / "/" / "?" )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ifragment(i: Span) -> IResult<Span, fragment::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many0_count(nom::branch::alt((
+        ipchar.map(|_| ()),
+        mode::char('/').map(|_| ()),
+        mode::char('?').map(|_| ()),
+    )))
+    .parse(i)?;
+    Ok((
+        i,
+        fragment::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_ifragment() {
+        ok(ifragment, "", ("", ""));
+        ok(ifragment, "section-ü/?", ("", "section-ü/?"));
+        ok(ifragment, "日本#", ("#", "日本"));
+        // iprivate is allowed in iquery only
+        ok(ifragment, "\u{E000}", ("\u{E000}", ""));
+    }
+}
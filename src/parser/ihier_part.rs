@@ -0,0 +1,53 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{
+    hier_part, iauthority, ipath_abempty, ipath_absolute, ipath_rootless, path_empty,
+};
+
+use super::{HasSpan, Span, mode};
+
+/// ihier-part     = "//" iauthority ipath-abempty
+///                / ipath-absolute
+///                / ipath-rootless
+///                / ipath-empty
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ihier_part(i: Span) -> IResult<Span, hier_part::Token> {
+    let start = i;
+    let (i, (authority, path)) = nom::branch::alt((
+        (mode::tag("//"), iauthority, ipath_abempty).map(|(_, a, p)| (Some(a), p.span())),
+        ipath_absolute.map(|p| (None, p.span())),
+        ipath_rootless.map(|p| (None, p.span())),
+        path_empty.map(|p| (None, p.span())),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        hier_part::Token {
+            span: start.take(start.offset(&i)),
+            authority,
+            path,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_ihier_part() {
+        ok(ihier_part, "//bücher.example", ("", "//bücher.example"));
+        ok(
+            ihier_part,
+            "//bücher.example/日本/ü",
+            ("", "//bücher.example/日本/ü"),
+        );
+        ok(ihier_part, "/ü", ("", "/ü"));
+        ok(ihier_part, "ü:x", ("", "ü:x"));
+        ok(ihier_part, "", ("", ""));
+        ok(ihier_part, "/ü?q", ("?q", "/ü"));
+    }
+}
@@ -0,0 +1,49 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{host, ip_literal, ipv4address, ireg_name};
+
+use super::Span;
+
+/// ihost          = IP-literal / IPv4address / ireg-name
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ihost(i: Span) -> IResult<Span, host::Token> {
+    let start = i;
+    let (i, kind) = nom::branch::alt((
+        ip_literal.map(|_| host::Kind::IpLiteral),
+        ipv4address.map(|_| host::Kind::Ipv4Address),
+        ireg_name.map(|_| host::Kind::RegName),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        host::Token {
+            span: start.take(start.offset(&i)),
+            kind,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_ihost() {
+        ok(ihost, "[::1]", ("", "[::1]"));
+        ok(ihost, "192.168.0.1:80", (":80", "192.168.0.1"));
+        ok(ihost, "bücher.example", ("", "bücher.example"));
+        ok(ihost, "日本.jp/", ("/", "日本.jp"));
+        ok(ihost, "", ("", ""));
+    }
+
+    #[test]
+    fn test_ihost_kind() {
+        let f = |s| ihost(Span::from(s)).map(|(_, t)| t.kind).expect("ihost");
+        assert_eq!(f("[::1]"), host::Kind::IpLiteral);
+        assert_eq!(f("127.0.0.1"), host::Kind::Ipv4Address);
+        assert_eq!(f("bücher.example"), host::Kind::RegName);
+    }
+}
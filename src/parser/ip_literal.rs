@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 use crate::parser::{ipv6address, ipvfuture};
 
 #[derive(Debug, PartialEq)]
@@ -19,9 +19,9 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2.2>
 pub fn ip_literal(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::character::complete::char('[').parse(i)?;
+    let (i, _) = mode::char('[').parse(i)?;
     let (i, _) = nom::branch::alt((ipvfuture.map(|_| ()), ipv6address.map(|_| ()))).parse(i)?;
-    let (i, _) = nom::character::complete::char(']').parse(i)?;
+    let (i, _) = mode::char(']').parse(i)?;
     Ok((
         i,
         Token {
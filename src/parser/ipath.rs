@@ -0,0 +1,51 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{
+    ipath_abempty, ipath_absolute, ipath_noscheme, ipath_rootless, path, path_empty,
+};
+
+use super::Span;
+
+/// ipath          = ipath-abempty   ; begins with "/" or is empty
+///                / ipath-absolute  ; begins with "/" but not "//"
+///                / ipath-noscheme  ; begins with a non-colon segment
+///                / ipath-rootless  ; begins with a segment
+///                / ipath-empty     ; zero characters
+///
+/// ipath-empty matches the same (empty) input as path-empty.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ipath(i: Span) -> IResult<Span, path::Token> {
+    let start = i;
+    let (i, _) = nom::branch::alt((
+        ipath_absolute.map(|_| ()),
+        ipath_noscheme.map(|_| ()),
+        ipath_rootless.map(|_| ()),
+        ipath_abempty.map(|_| ()),
+        path_empty.map(|_| ()),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        path::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_ipath() {
+        ok(ipath, "", ("", ""));
+        ok(ipath, "/bücher/日本", ("", "/bücher/日本"));
+        ok(ipath, "bücher/日本", ("", "bücher/日本"));
+        ok(ipath, "ü:x/y", (":x/y", "ü"));
+        ok(ipath, "//ü", ("/ü", "/"));
+        ok(ipath, "/ü?q", ("?q", "/ü"));
+    }
+}
@@ -0,0 +1,36 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{isegment, path_abempty};
+
+use super::{Span, mode};
+
+/// ipath-abempty  = *( "/" isegment )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ipath_abempty(i: Span) -> IResult<Span, path_abempty::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many0_count((mode::char('/'), isegment)).parse(i)?;
+    Ok((
+        i,
+        path_abempty::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_ipath_abempty() {
+        ok(ipath_abempty, "", ("", ""));
+        ok(ipath_abempty, "ü", ("ü", ""));
+        ok(ipath_abempty, "/ü", ("", "/ü"));
+        ok(ipath_abempty, "/bücher/日本", ("", "/bücher/日本"));
+        ok(ipath_abempty, "//", ("", "//"));
+        ok(ipath_abempty, "/ü?q", ("?q", "/ü"));
+    }
+}
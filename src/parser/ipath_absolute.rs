@@ -0,0 +1,43 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{isegment, isegment_nz, path_absolute};
+
+use super::{Span, mode};
+
+/// ipath-absolute = "/" [ isegment-nz *( "/" isegment ) ]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ipath_absolute(i: Span) -> IResult<Span, path_absolute::Token> {
+    let start = i;
+    let (i, _) = mode::char('/').parse(i)?;
+    let (i, _) = nom::combinator::opt((
+        isegment_nz,
+        nom::multi::many0_count((mode::char('/'), isegment)),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        path_absolute::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_ipath_absolute() {
+        ok(ipath_absolute, "/", ("", "/"));
+        ok(ipath_absolute, "/ü", ("", "/ü"));
+        ok(ipath_absolute, "/bücher/日本/", ("", "/bücher/日本/"));
+        ok(ipath_absolute, "//ü", ("/ü", "/"));
+        ok(ipath_absolute, "/ü#f", ("#f", "/ü"));
+
+        err(ipath_absolute, "");
+        err(ipath_absolute, "ü");
+    }
+}
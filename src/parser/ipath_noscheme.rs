@@ -0,0 +1,38 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{isegment, isegment_nz_nc, path_noscheme};
+
+use super::{Span, mode};
+
+/// ipath-noscheme = isegment-nz-nc *( "/" isegment )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ipath_noscheme(i: Span) -> IResult<Span, path_noscheme::Token> {
+    let start = i;
+    let (i, _) = isegment_nz_nc.parse(i)?;
+    let (i, _) = nom::multi::many0_count((mode::char('/'), isegment)).parse(i)?;
+    Ok((
+        i,
+        path_noscheme::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_ipath_noscheme() {
+        ok(ipath_noscheme, "ü", ("", "ü"));
+        ok(ipath_noscheme, "bücher/日本:x", ("", "bücher/日本:x"));
+        ok(ipath_noscheme, "ü:x/y", (":x/y", "ü"));
+
+        err(ipath_noscheme, "");
+        err(ipath_noscheme, ":");
+        err(ipath_noscheme, "/ü");
+    }
+}
@@ -0,0 +1,37 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{isegment, isegment_nz, path_rootless};
+
+use super::{Span, mode};
+
+/// ipath-rootless = isegment-nz *( "/" isegment )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ipath_rootless(i: Span) -> IResult<Span, path_rootless::Token> {
+    let start = i;
+    let (i, _) = isegment_nz.parse(i)?;
+    let (i, _) = nom::multi::many0_count((mode::char('/'), isegment)).parse(i)?;
+    Ok((
+        i,
+        path_rootless::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_ipath_rootless() {
+        ok(ipath_rootless, "ü", ("", "ü"));
+        ok(ipath_rootless, "ü:x/y", ("", "ü:x/y"));
+        ok(ipath_rootless, "bücher/日本?q", ("?q", "bücher/日本"));
+
+        err(ipath_rootless, "");
+        err(ipath_rootless, "/ü");
+    }
+}
@@ -0,0 +1,51 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{iunreserved, pchar, pct_encoded, sub_delims};
+
+use super::{Span, mode};
+
+/// ipchar         = iunreserved / pct-encoded / sub-delims / ":"
+///                / "@"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ipchar(i: Span) -> IResult<Span, pchar::Token> {
+    let start = i;
+    let (i, _) = nom::branch::alt((
+        iunreserved.map(|_| ()),
+        pct_encoded.map(|_| ()),
+        sub_delims.map(|_| ()),
+        mode::char(':').map(|_| ()),
+        mode::char('@').map(|_| ()),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        pchar::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_ipchar() {
+        ok(ipchar, "a", ("", "a"));
+        ok(ipchar, "%20", ("", "%20"));
+        ok(ipchar, "!", ("", "!"));
+        ok(ipchar, ":", ("", ":"));
+        ok(ipchar, "@", ("", "@"));
+        ok(ipchar, "ü/", ("/", "ü"));
+
+        err(ipchar, "/");
+        err(ipchar, "?");
+        err(ipchar, "#");
+        err(ipchar, " ");
+        err(ipchar, "\u{E000}");
+        err(ipchar, "");
+    }
+}
@@ -0,0 +1,53 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// iprivate       = %xE000-F8FF / %xF0000-FFFFD / %x100000-10FFFD
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn iprivate(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, _) = mode::satisfy(is_iprivate).parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+pub(crate) fn is_iprivate(c: char) -> bool {
+    matches!(c as u32, 0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_iprivate() {
+        ok(iprivate, "\u{E000}rest", ("rest", "\u{E000}"));
+        ok(iprivate, "\u{F8FF}", ("", "\u{F8FF}"));
+        ok(iprivate, "\u{F0000}", ("", "\u{F0000}"));
+        ok(iprivate, "\u{10FFFD}", ("", "\u{10FFFD}"));
+
+        err(iprivate, "a");
+        err(iprivate, "ü");
+        err(iprivate, "\u{F900}");
+        err(iprivate, "\u{FFFFE}");
+        err(iprivate, "");
+    }
+}
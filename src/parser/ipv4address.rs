@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 use crate::parser::dec_octet;
 
 #[derive(Debug, PartialEq)]
@@ -21,11 +21,11 @@ pub fn ipv4address(i: Span) -> IResult<Span, Token> {
     let start = i;
     let (i, _) = (
         dec_octet,
-        nom::character::complete::char('.'),
+        mode::char('.'),
         dec_octet,
-        nom::character::complete::char('.'),
+        mode::char('.'),
         dec_octet,
-        nom::character::complete::char('.'),
+        mode::char('.'),
         dec_octet,
     )
         .parse(i)?;
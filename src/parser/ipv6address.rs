@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{Context, HasSpan, Limit, Mode, Span, context, mode, multi};
 use crate::parser::{h16, ls32};
 
 #[derive(Debug, PartialEq)]
@@ -32,9 +32,27 @@ pub fn ipv6address(i: Span) -> IResult<Span, Token> {
     {
         use nom::error::ParseError;
 
-        let start = i.clone();
-        let (i, i2) = nom::bytes::take_until(tag).parse(i)?;
-        let (i3, _) = parser.parse(i2)?;
+        let start = i;
+        let complete = |c: Context| c.with_mode(Mode::Complete);
+        // the tag can only be found among the characters of an IPv6address, up to the host length limit
+        let max = i.extra.options.max_host_len;
+        let scan = &i.fragment().as_bytes()[..i.input_len().min(max.saturating_add(1))];
+        let len = scan
+            .iter()
+            .position(|b| !(b.is_ascii_hexdigit() || *b == b':' || *b == b'.'))
+            .unwrap_or(scan.len());
+        if len > max {
+            return Err(context::exceeded(i, Limit::HostLength));
+        }
+        let candidates = if len < i.input_len() {
+            i.take(len).map_extra(complete)
+        } else {
+            i
+        };
+        let (_, i2) = mode::take_until(tag).parse(candidates)?;
+        let i = i.take_from(i2.input_len());
+        // the part before the tag is complete even when more input may follow the tag
+        let (i3, _) = parser.parse(i2.map_extra(complete))?;
         if i3.input_len() != 0 {
             return Err(nom::Err::Error(
                 <F as Parser<Span<'a>>>::Error::from_error_kind(
@@ -43,21 +61,17 @@ pub fn ipv6address(i: Span) -> IResult<Span, Token> {
                 ),
             ));
         }
-        Ok((i.clone(), start.take(start.offset(&i))))
+        Ok((i, start.take(start.offset(&i))))
     }
 
     let start = i;
     let (i, _) = nom::branch::alt((
         // =                            6( h16 ":" ) ls32
-        (
-            nom::multi::count((h16, nom::character::complete::char(':')), 6),
-            ls32,
-        )
-            .map(|_| ()),
+        (multi::count((h16, mode::char(':')), 6), ls32).map(|_| ()),
         // /                       "::" 5( h16 ":" ) ls32
         (
-            nom::bytes::complete::tag("::"),
-            nom::multi::count((h16, nom::character::complete::char(':')), 5),
+            mode::tag("::"),
+            multi::count((h16, mode::char(':')), 5),
             ls32,
         )
             .map(|_| ()),
@@ -65,15 +79,15 @@ pub fn ipv6address(i: Span) -> IResult<Span, Token> {
         (
             |i| f(i, nom::combinator::opt(h16), "::"),
             // nom::combinator::opt(h16),
-            nom::bytes::complete::tag("::"),
-            nom::multi::count((h16, nom::character::complete::char(':')), 4),
+            mode::tag("::"),
+            multi::count((h16, mode::char(':')), 4),
             ls32,
         )
             .map(|_| ()),
         // / [ *1( h16 ":" ) h16 ] "::" 3( h16 ":" ) ls32
         (
-            nom::bytes::complete::tag("::"),
-            nom::multi::count((h16, nom::character::complete::char(':')), 3),
+            mode::tag("::"),
+            multi::count((h16, mode::char(':')), 3),
             ls32,
         )
             .map(|_| ()),
@@ -81,22 +95,19 @@ pub fn ipv6address(i: Span) -> IResult<Span, Token> {
             |i| {
                 f(
                     i,
-                    (
-                        nom::multi::many_m_n(0, 1, (h16, nom::character::complete::char(':'))),
-                        h16,
-                    ),
+                    (multi::many_m_n(0, 1, (h16, mode::char(':'))), h16),
                     "::",
                 )
             },
-            nom::bytes::complete::tag("::"),
-            nom::multi::count((h16, nom::character::complete::char(':')), 3),
+            mode::tag("::"),
+            multi::count((h16, mode::char(':')), 3),
             ls32,
         )
             .map(|_| ()),
         // / [ *2( h16 ":" ) h16 ] "::" 2( h16 ":" ) ls32
         (
-            nom::bytes::complete::tag("::"),
-            nom::multi::count((h16, nom::character::complete::char(':')), 2),
+            mode::tag("::"),
+            multi::count((h16, mode::char(':')), 2),
             ls32,
         )
             .map(|_| ()),
@@ -104,89 +115,69 @@ pub fn ipv6address(i: Span) -> IResult<Span, Token> {
             |i| {
                 f(
                     i,
-                    (
-                        nom::multi::many_m_n(0, 2, (h16, nom::character::complete::char(':'))),
-                        h16,
-                    ),
+                    (multi::many_m_n(0, 2, (h16, mode::char(':'))), h16),
                     "::",
                 )
             },
-            nom::bytes::complete::tag("::"),
-            nom::multi::count((h16, nom::character::complete::char(':')), 2),
+            mode::tag("::"),
+            multi::count((h16, mode::char(':')), 2),
             ls32,
         )
             .map(|_| ()),
         // / [ *3( h16 ":" ) h16 ] "::"    h16 ":"   ls32
-        (
-            nom::bytes::complete::tag("::"),
-            (h16, nom::character::complete::char(':')),
-            ls32,
-        )
-            .map(|_| ()),
+        (mode::tag("::"), (h16, mode::char(':')), ls32).map(|_| ()),
         (
             |i| {
                 f(
                     i,
-                    (
-                        nom::multi::many_m_n(0, 3, (h16, nom::character::complete::char(':'))),
-                        h16,
-                    ),
+                    (multi::many_m_n(0, 3, (h16, mode::char(':'))), h16),
                     "::",
                 )
             },
-            nom::bytes::complete::tag("::"),
-            (h16, nom::character::complete::char(':')),
+            mode::tag("::"),
+            (h16, mode::char(':')),
             ls32,
         )
             .map(|_| ()),
         // / [ *4( h16 ":" ) h16 ] "::"              ls32
-        (nom::bytes::complete::tag("::"), ls32).map(|_| ()),
+        (mode::tag("::"), ls32).map(|_| ()),
         (
             |i| {
                 f(
                     i,
-                    (
-                        nom::multi::many_m_n(0, 4, (h16, nom::character::complete::char(':'))),
-                        h16,
-                    ),
+                    (multi::many_m_n(0, 4, (h16, mode::char(':'))), h16),
                     "::",
                 )
             },
-            nom::bytes::complete::tag("::"),
+            mode::tag("::"),
             ls32,
         )
             .map(|_| ()),
         // / [ *5( h16 ":" ) h16 ] "::"              h16
-        (nom::bytes::complete::tag("::"), h16).map(|_| ()),
+        (mode::tag("::"), h16).map(|_| ()),
         (
             |i| {
                 f(
                     i,
-                    (
-                        nom::multi::many_m_n(0, 5, (h16, nom::character::complete::char(':'))),
-                        h16,
-                    ),
+                    (multi::many_m_n(0, 5, (h16, mode::char(':'))), h16),
                     "::",
                 )
             },
-            nom::bytes::complete::tag("::"),
+            mode::tag("::"),
             h16,
         )
             .map(|_| ()),
         // / [ *6( h16 ":" ) h16 ] "::"
-        nom::bytes::complete::tag("::").map(|_| ()),
+        mode::tag("::").map(|_| ()),
         (
             |i| {
                 f(
                     i,
-                    (
-                        nom::multi::many_m_n(0, 6, (h16, nom::character::complete::char(':'))),
-                        h16,
-                    ),
+                    (multi::many_m_n(0, 6, (h16, mode::char(':'))), h16),
                     "::",
                 )
             },
-            nom::bytes::complete::tag("::"),
+            mode::tag("::"),
         )
             .map(|_| ()),
     ))
@@ -355,8 +346,7 @@ mod tests {
     fn test_lookahead() {
         fn f(i: Span) -> IResult<Span, Token> {
             let start = i;
-            let (i, _) =
-                nom::multi::many_m_n(0, 2, (h16, nom::character::complete::char(':'))).parse(i)?;
+            let (i, _) = multi::many_m_n(0, 2, (h16, mode::char(':'))).parse(i)?;
             Ok((
                 i,
                 Token {
@@ -371,11 +361,7 @@ mod tests {
 
         fn g(i: Span) -> IResult<Span, Token> {
             let start = i;
-            let (i, _) = (
-                nom::multi::many_m_n(0, 2, (h16, nom::character::complete::char(':'))),
-                h16,
-            )
-                .parse(i)?;
+            let (i, _) = (multi::many_m_n(0, 2, (h16, mode::char(':'))), h16).parse(i)?;
             Ok((
                 i,
                 Token {
@@ -391,9 +377,9 @@ mod tests {
         fn h(i: Span) -> IResult<Span, Token> {
             let start = i;
             let (i, _) = (
-                nom::multi::many_m_n(0, 2, (h16, nom::character::complete::char(':'))),
+                multi::many_m_n(0, 2, (h16, mode::char(':'))),
                 h16,
-                nom::bytes::complete::tag("::"),
+                mode::tag("::"),
             )
                 .parse(i)?;
             Ok((
@@ -410,19 +396,15 @@ mod tests {
 
         fn h2(i: Span) -> IResult<Span, Token> {
             let start = i;
-            let (i, i2) = nom::bytes::take_until("::").parse(i)?;
-            let (i3, _) = (
-                nom::multi::many_m_n(0, 2, (h16, nom::character::complete::char(':'))),
-                h16,
-            )
-                .parse(i2)?;
+            let (i, i2) = mode::take_until("::").parse(i)?;
+            let (i3, _) = (multi::many_m_n(0, 2, (h16, mode::char(':'))), h16).parse(i2)?;
             if !i3.is_empty() {
                 return Err(nom::Err::Error(nom::error::Error::new(
                     start,
                     nom::error::ErrorKind::ManyMN,
                 )));
             }
-            let (i, _) = nom::bytes::complete::tag("::").parse(i)?;
+            let (i, _) = mode::tag("::").parse(i)?;
             Ok((
                 i,
                 Token {
@@ -487,14 +469,8 @@ mod tests {
 
         fn h3(i: Span) -> IResult<Span, Token> {
             let start = i;
-            let (i, _) = Lookahead::new(
-                (
-                    nom::multi::many_m_n(0, 2, (h16, nom::character::complete::char(':'))),
-                    h16,
-                ),
-                "::",
-            )
-            .parse(i)?;
+            let (i, _) = Lookahead::new((multi::many_m_n(0, 2, (h16, mode::char(':'))), h16), "::")
+                .parse(i)?;
             Ok((
                 i,
                 Token {
@@ -526,10 +502,7 @@ mod tests {
         fn h4(i: Span) -> IResult<Span, Token> {
             h4_(
                 i,
-                (
-                    nom::multi::many_m_n(0, 2, (h16, nom::character::complete::char(':'))),
-                    h16,
-                ),
+                (multi::many_m_n(0, 2, (h16, mode::char(':'))), h16),
                 "::",
             )
         }
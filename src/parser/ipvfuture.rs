@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Limit, Span, context, mode};
 use crate::parser::{sub_delims, unreserved};
 
 #[derive(Debug, PartialEq)]
@@ -20,14 +20,18 @@ impl<'a> HasSpan<'a> for Token<'a> {
 pub fn ipvfuture(i: Span) -> IResult<Span, Token> {
     let start = i;
     let (i, _) = (
-        nom::character::complete::char('v'),
-        nom::multi::many1(nom::character::complete::satisfy(|c| c.is_ascii_hexdigit())),
-        nom::character::complete::char('.'),
-        nom::multi::many1(nom::branch::alt((
-            unreserved.map(|_| ()),
-            sub_delims.map(|_| ()),
-            nom::character::complete::char(':').map(|_| ()),
-        ))),
+        mode::char('v'),
+        nom::multi::many1_count(mode::satisfy(|c| c.is_ascii_hexdigit())),
+        mode::char('.'),
+        nom::multi::many1_count(context::bounded(
+            start,
+            Limit::HostLength,
+            nom::branch::alt((
+                unreserved.map(|_| ()),
+                sub_delims.map(|_| ()),
+                mode::char(':').map(|_| ()),
+            )),
+        )),
     )
         .parse(i)?;
     Ok((
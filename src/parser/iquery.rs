@@ -0,0 +1,46 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{ipchar, iprivate, query};
+
+use super::{Limit, Span, context, mode};
+
+/// iquery         = *( ipchar / iprivate / "/" / "?" )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn iquery(i: Span) -> IResult<Span, query::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many0_count(context::bounded(
+        start,
+        Limit::QueryLength,
+        nom::branch::alt((
+            ipchar.map(|_| ()),
+            iprivate.map(|_| ()),
+            mode::char('/').map(|_| ()),
+            mode::char('?').map(|_| ()),
+        )),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        query::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_iquery() {
+        ok(iquery, "", ("", ""));
+        ok(iquery, "k=v", ("", "k=v"));
+        ok(iquery, "q=bücher&l=日本", ("", "q=bücher&l=日本"));
+        ok(iquery, "\u{E000}/?", ("", "\u{E000}/?"));
+        ok(iquery, "q=ü#f", ("#f", "q=ü"));
+        ok(iquery, "q=ü v", (" v", "q=ü"));
+    }
+}
@@ -0,0 +1,48 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{iunreserved, pct_encoded, reg_name, sub_delims};
+
+use super::{Limit, Span, context};
+
+/// ireg-name      = *( iunreserved / pct-encoded / sub-delims )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ireg_name(i: Span) -> IResult<Span, reg_name::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many0_count(context::bounded(
+        start,
+        Limit::HostLength,
+        nom::branch::alt((
+            iunreserved.map(|_| ()),
+            pct_encoded.map(|_| ()),
+            sub_delims.map(|_| ()),
+        )),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        reg_name::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_ireg_name() {
+        ok(ireg_name, "", ("", ""));
+        ok(ireg_name, "example.com", ("", "example.com"));
+        ok(ireg_name, "bücher.example", ("", "bücher.example"));
+        ok(ireg_name, "日本.jp:80", (":80", "日本.jp"));
+        ok(
+            ireg_name,
+            "b%C3%BCcher.example/",
+            ("/", "b%C3%BCcher.example"),
+        );
+    }
+}
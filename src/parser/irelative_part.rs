@@ -0,0 +1,52 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{
+    iauthority, ipath_abempty, ipath_absolute, ipath_noscheme, path_empty, relative_part,
+};
+
+use super::{HasSpan, Span, mode};
+
+/// irelative-part = "//" iauthority ipath-abempty
+///                / ipath-absolute
+///                / ipath-noscheme
+///                / ipath-empty
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn irelative_part(i: Span) -> IResult<Span, relative_part::Token> {
+    let start = i;
+    let (i, (authority, path)) = nom::branch::alt((
+        (mode::tag("//"), iauthority, ipath_abempty).map(|(_, a, p)| (Some(a), p.span())),
+        ipath_absolute.map(|p| (None, p.span())),
+        ipath_noscheme.map(|p| (None, p.span())),
+        path_empty.map(|p| (None, p.span())),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        relative_part::Token {
+            span: start.take(start.offset(&i)),
+            authority,
+            path,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_irelative_part() {
+        ok(
+            irelative_part,
+            "//bücher.example/ü",
+            ("", "//bücher.example/ü"),
+        );
+        ok(irelative_part, "/日本", ("", "/日本"));
+        ok(irelative_part, "ü/x", ("", "ü/x"));
+        ok(irelative_part, "ü:x", (":x", "ü"));
+        ok(irelative_part, "", ("", ""));
+    }
+}
@@ -0,0 +1,43 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{ifragment, iquery, irelative_part, relative_ref};
+
+use super::{HasSpan, Span, mode};
+
+/// irelative-ref  = irelative-part [ "?" iquery ] [ "#" ifragment ]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn irelative_ref(i: Span) -> IResult<Span, relative_ref::Token> {
+    let start = i;
+    let (i, (relative_part, query, fragment)) = (
+        irelative_part,
+        nom::combinator::opt((mode::char('?'), iquery)),
+        nom::combinator::opt((mode::char('#'), ifragment)),
+    )
+        .parse(i)?;
+    Ok((
+        i,
+        relative_ref::Token {
+            span: start.take(start.offset(&i)),
+            relative_part,
+            query: query.map(|(_, q)| q.span()),
+            fragment: fragment.map(|(_, f)| f.span()),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_irelative_ref() {
+        ok(irelative_ref, "/ü?q=日本#ß", ("", "/ü?q=日本#ß"));
+        ok(irelative_ref, "//bücher.example", ("", "//bücher.example"));
+        ok(irelative_ref, "../ü", ("", "../ü"));
+        ok(irelative_ref, "?\u{E000}", ("", "?\u{E000}"));
+        ok(irelative_ref, "", ("", ""));
+    }
+}
@@ -0,0 +1,88 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{ifragment, ihier_part, iquery, scheme, uri};
+
+use super::{HasSpan, Span, mode};
+
+/// IRI            = scheme ":" ihier-part [ "?" iquery ]
+///                  [ "#" ifragment ]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn iri(i: Span) -> IResult<Span, uri::Token> {
+    let start = i;
+    let (i, (scheme, _, hier_part, query, fragment)) = (
+        scheme,
+        mode::char(':'),
+        ihier_part,
+        nom::combinator::opt((mode::char('?'), iquery)),
+        nom::combinator::opt((mode::char('#'), ifragment)),
+    )
+        .parse(i)?;
+    Ok((
+        i,
+        uri::Token {
+            span: start.take(start.offset(&i)),
+            scheme: scheme.span(),
+            hier_part,
+            query: query.map(|(_, q)| q.span()),
+            fragment: fragment.map(|(_, f)| f.span()),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_iri() {
+        // every URI is an IRI
+        for s in [
+            "ftp://ftp.is.co.za/rfc/rfc1808.txt",
+            "http://www.ietf.org/rfc/rfc2396.txt",
+            "mailto:John.Doe@example.com",
+            "news:comp.infosystems.www.servers.unix",
+            "tel:+1-816-555-1212",
+            "telnet://192.0.2.16:80/",
+            "urn:oasis:names:specification:docbook:dtd:xml:4.1.2",
+        ] {
+            ok(iri, s, ("", s));
+        }
+
+        ok(
+            iri,
+            "http://bücher.example/日本?q=ü#ß",
+            ("", "http://bücher.example/日本?q=ü#ß"),
+        );
+        ok(
+            iri,
+            "http://example.com/?\u{E000}",
+            ("", "http://example.com/?\u{E000}"),
+        );
+        ok(
+            iri,
+            "http://example.com/ü trailing",
+            (" trailing", "http://example.com/ü"),
+        );
+        ok(iri, "urn:ü", ("", "urn:ü"));
+
+        err(iri, "");
+        err(iri, "ü:x");
+        err(iri, "//bücher.example");
+    }
+
+    #[test]
+    fn test_iri_components() {
+        let (_, t) = iri(Span::from("http://bücher.example/ü?q=日本#f")).expect("iri");
+        assert_eq!(*t.scheme.fragment(), "http");
+        assert_eq!(
+            t.hier_part.authority.map(|a| *a.host.span.fragment()),
+            Some("bücher.example")
+        );
+        assert_eq!(*t.hier_part.path.fragment(), "/ü");
+        assert_eq!(t.query.map(|s| *s.fragment()), Some("q=日本"));
+        assert_eq!(t.fragment.map(|s| *s.fragment()), Some("f"));
+    }
+}
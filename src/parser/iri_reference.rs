@@ -0,0 +1,47 @@
+use nom::{IResult, Parser};
+
+use crate::parser::{irelative_ref, iri, uri_reference};
+
+use super::Span;
+
+/// IRI-reference  = IRI / irelative-ref
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn iri_reference(i: Span) -> IResult<Span, uri_reference::Token> {
+    nom::branch::alt((
+        iri.map(uri_reference::Token::from),
+        irelative_ref.map(uri_reference::Token::from),
+    ))
+    .parse(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_iri_reference() {
+        ok(
+            iri_reference,
+            "http://bücher.example/ü",
+            ("", "http://bücher.example/ü"),
+        );
+        ok(
+            iri_reference,
+            "//bücher.example/",
+            ("", "//bücher.example/"),
+        );
+        ok(iri_reference, "ü/日本?q#f", ("", "ü/日本?q#f"));
+        ok(iri_reference, "", ("", ""));
+    }
+
+    #[test]
+    fn test_iri_reference_components() {
+        let (_, t) = iri_reference(Span::from("ü?q")).expect("iri_reference");
+        assert_eq!(t.scheme, None);
+        assert_eq!(*t.path.fragment(), "ü");
+        assert_eq!(t.query.map(|s| *s.fragment()), Some("q"));
+    }
+}
@@ -0,0 +1,37 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{ipchar, segment};
+
+use super::{Limit, Span, context};
+
+/// isegment       = *ipchar
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn isegment(i: Span) -> IResult<Span, segment::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many0_count(ipchar).parse(i)?;
+    let (i, _) = context::count(start, i, Limit::Segments)?;
+    Ok((
+        i,
+        segment::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_isegment() {
+        ok(isegment, "", ("", ""));
+        ok(isegment, "abc", ("", "abc"));
+        ok(isegment, "bücher", ("", "bücher"));
+        ok(isegment, "日本語/x", ("/x", "日本語"));
+        ok(isegment, "a%20ü:@", ("", "a%20ü:@"));
+        ok(isegment, "ü?q", ("?q", "ü"));
+    }
+}
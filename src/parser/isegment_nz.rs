@@ -0,0 +1,37 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{ipchar, segment_nz};
+
+use super::{Limit, Span, context};
+
+/// isegment-nz    = 1*ipchar
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn isegment_nz(i: Span) -> IResult<Span, segment_nz::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many1_count(ipchar).parse(i)?;
+    let (i, _) = context::count(start, i, Limit::Segments)?;
+    Ok((
+        i,
+        segment_nz::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_isegment_nz() {
+        ok(isegment_nz, "abc", ("", "abc"));
+        ok(isegment_nz, "bücher/", ("/", "bücher"));
+        ok(isegment_nz, "ü:x", ("", "ü:x"));
+
+        err(isegment_nz, "");
+        err(isegment_nz, "/");
+    }
+}
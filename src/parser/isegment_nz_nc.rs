@@ -0,0 +1,46 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{iunreserved, pct_encoded, segment_nz_nc, sub_delims};
+
+use super::{Limit, Span, context, mode};
+
+/// isegment-nz-nc = 1*( iunreserved / pct-encoded / sub-delims
+///                      / "@" )
+///                ; non-zero-length segment without any colon ":"
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn isegment_nz_nc(i: Span) -> IResult<Span, segment_nz_nc::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many1_count(nom::branch::alt((
+        iunreserved.map(|_| ()),
+        pct_encoded.map(|_| ()),
+        sub_delims.map(|_| ()),
+        mode::char('@').map(|_| ()),
+    )))
+    .parse(i)?;
+    let (i, _) = context::count(start, i, Limit::Segments)?;
+    Ok((
+        i,
+        segment_nz_nc::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_isegment_nz_nc() {
+        ok(isegment_nz_nc, "abc", ("", "abc"));
+        ok(isegment_nz_nc, "bücher@x", ("", "bücher@x"));
+        ok(isegment_nz_nc, "ü:x", (":x", "ü"));
+        ok(isegment_nz_nc, "ü/x", ("/x", "ü"));
+
+        err(isegment_nz_nc, "");
+        err(isegment_nz_nc, ":");
+    }
+}
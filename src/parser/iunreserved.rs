@@ -0,0 +1,36 @@
+use nom::{IResult, Parser};
+
+use crate::parser::{ucschar, unreserved};
+
+use super::{HasSpan, Span};
+
+/// iunreserved    = ALPHA / DIGIT / "-" / "." / "_" / "~" / ucschar
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn iunreserved(i: Span) -> IResult<Span, unreserved::Token> {
+    nom::branch::alt((
+        unreserved,
+        ucschar.map(|t| unreserved::Token { span: t.span() }),
+    ))
+    .parse(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_iunreserved() {
+        ok(iunreserved, "arest", ("rest", "a"));
+        ok(iunreserved, "~rest", ("rest", "~"));
+        ok(iunreserved, "ürest", ("rest", "ü"));
+        ok(iunreserved, "日本", ("本", "日"));
+
+        err(iunreserved, "%20rest");
+        err(iunreserved, "!rest");
+        err(iunreserved, "\u{E000}");
+        err(iunreserved, "");
+    }
+}
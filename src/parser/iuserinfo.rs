@@ -0,0 +1,40 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{iunreserved, pct_encoded, sub_delims, userinfo};
+
+use super::{Span, mode};
+
+/// iuserinfo      = *( iunreserved / pct-encoded / sub-delims / ":" )
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn iuserinfo(i: Span) -> IResult<Span, userinfo::Token> {
+    let start = i;
+    let (i, _) = nom::multi::many0_count(nom::branch::alt((
+        iunreserved.map(|_| ()),
+        pct_encoded.map(|_| ()),
+        sub_delims.map(|_| ()),
+        mode::char(':').map(|_| ()),
+    )))
+    .parse(i)?;
+    Ok((
+        i,
+        userinfo::Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_iuserinfo() {
+        ok(iuserinfo, "", ("", ""));
+        ok(iuserinfo, "user:password", ("", "user:password"));
+        ok(iuserinfo, "jürgen:pässword", ("", "jürgen:pässword"));
+        ok(iuserinfo, "ü@host", ("@host", "ü"));
+    }
+}
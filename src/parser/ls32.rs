@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 use crate::parser::{h16, ipv4address};
 
 #[derive(Debug, PartialEq)]
@@ -23,7 +23,7 @@ pub fn ls32(i: Span) -> IResult<Span, Token> {
     let (i, _) = nom::branch::alt((
         (
             h16.map(|_| ()),
-            nom::character::complete::char(':').map(|_| ()),
+            mode::char(':').map(|_| ()),
             h16.map(|_| ()),
         )
             .map(|_| ()),
@@ -0,0 +1,133 @@
+//! Primitives that follow the [`Mode`] of their input.
+//!
+//! The rules are built on these instead of `nom::*::complete` or `nom::*::streaming`, so the same grammar can be
+//! run on a complete input or on a network buffer. They also check [`ParseOptions::max_len`](super::ParseOptions).
+
+use nom::IResult;
+
+use super::{Span, context};
+
+/// How a rule treats the end of its input.
+///
+/// The mode is carried in the [`Context`](super::Context) of the [`Span`].
+///
+/// ```
+/// use nom_uri::parser::{Mode, Span, uri};
+///
+/// // a request line split across reads
+/// let buf = Span::new_extra("http://example.com/pa", Mode::Streaming.into());
+/// assert!(matches!(uri(buf), Err(nom::Err::Incomplete(_))));
+///
+/// let buf = Span::new_extra("http://example.com/path HTTP/1.1", Mode::Streaming.into());
+/// let (rest, _) = uri(buf).unwrap();
+/// assert_eq!(*rest.fragment(), " HTTP/1.1");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// The end of the span is the end of the input. This is the mode of `Span::from`.
+    #[default]
+    Complete,
+    /// More input may follow the span. A rule that reaches the end of the span returns
+    /// `Err::Incomplete(Needed)` instead of a partial match.
+    Streaming,
+}
+
+fn checked<'a, O>(i: Span<'a>, r: IResult<Span<'a>, O>) -> IResult<Span<'a>, O> {
+    let (rest, o) = r?;
+    context::check_len(i, rest)?;
+    Ok((rest, o))
+}
+
+pub(crate) fn char<'a>(c: char) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, char> {
+    let mut complete = nom::character::complete::char(c);
+    let mut streaming = nom::character::streaming::char(c);
+    move |i: Span<'a>| {
+        checked(
+            i,
+            match i.extra.mode {
+                Mode::Complete => complete(i),
+                Mode::Streaming => streaming(i),
+            },
+        )
+    }
+}
+
+pub(crate) fn satisfy<'a, F>(cond: F) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, char>
+where
+    F: Fn(char) -> bool + Copy,
+{
+    let mut complete = nom::character::complete::satisfy(cond);
+    let mut streaming = nom::character::streaming::satisfy(cond);
+    move |i: Span<'a>| {
+        checked(
+            i,
+            match i.extra.mode {
+                Mode::Complete => complete(i),
+                Mode::Streaming => streaming(i),
+            },
+        )
+    }
+}
+
+pub(crate) fn tag<'a>(t: &'static str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Span<'a>> {
+    let complete = nom::bytes::complete::tag(t);
+    let streaming = nom::bytes::streaming::tag(t);
+    move |i: Span<'a>| {
+        checked(
+            i,
+            match i.extra.mode {
+                Mode::Complete => complete(i),
+                Mode::Streaming => streaming(i),
+            },
+        )
+    }
+}
+
+pub(crate) fn take_until<'a>(
+    t: &'static str,
+) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, Span<'a>> {
+    let mut complete = nom::bytes::complete::take_until(t);
+    let mut streaming = nom::bytes::streaming::take_until(t);
+    move |i: Span<'a>| {
+        checked(
+            i,
+            match i.extra.mode {
+                Mode::Complete => complete(i),
+                Mode::Streaming => streaming(i),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::Needed;
+
+    use super::*;
+
+    #[test]
+    fn test_char() {
+        let complete = Span::new_extra("", Mode::Complete.into());
+        let streaming = Span::new_extra("", Mode::Streaming.into());
+        assert!(matches!(char('a')(complete), Err(nom::Err::Error(_))));
+        assert_eq!(
+            char('a')(streaming),
+            Err(nom::Err::Incomplete(Needed::new(1)))
+        );
+        assert!(matches!(
+            char('a')(Span::new_extra("b", Mode::Streaming.into())),
+            Err(nom::Err::Error(_))
+        ));
+    }
+
+    #[test]
+    fn test_tag() {
+        let complete = Span::new_extra("/", Mode::Complete.into());
+        let streaming = Span::new_extra("/", Mode::Streaming.into());
+        assert!(matches!(tag("//")(complete), Err(nom::Err::Error(_))));
+        assert_eq!(
+            tag("//")(streaming),
+            Err(nom::Err::Incomplete(Needed::new(1)))
+        );
+    }
+}
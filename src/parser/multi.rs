@@ -0,0 +1,28 @@
+//! Repetitions that only count their matches, so the grammar does not need `alloc`.
+
+use nom::Parser;
+
+use super::Span;
+
+/// `m*n` repetitions of `f`.
+pub(crate) fn many_m_n<'a, F>(
+    m: usize,
+    n: usize,
+    f: F,
+) -> impl Parser<Span<'a>, Output = (), Error = nom::error::Error<Span<'a>>>
+where
+    F: Parser<Span<'a>, Error = nom::error::Error<Span<'a>>>,
+{
+    nom::multi::fold_many_m_n(m, n, f, || (), |_, _| ())
+}
+
+/// Exactly `n` repetitions of `f`.
+pub(crate) fn count<'a, F>(
+    f: F,
+    n: usize,
+) -> impl Parser<Span<'a>, Output = (), Error = nom::error::Error<Span<'a>>>
+where
+    F: Parser<Span<'a>, Error = nom::error::Error<Span<'a>>>,
+{
+    many_m_n(n, n, f)
+}
@@ -0,0 +1,69 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{query, segment};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+    pub path: Span<'a>,
+    pub query: Option<Span<'a>>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// origin-form    = absolute-path [ "?" query ]
+/// absolute-path  = 1*( "/" segment )
+///
+/// Unlike `path-absolute`, `absolute-path` may begin with `//`.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9112#section-3.2.1>
+pub fn origin_form(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, _) = nom::multi::many1_count((mode::char('/'), segment)).parse(i)?;
+    let path = start.take(start.offset(&i));
+    let (i, query) = nom::combinator::opt((mode::char('?'), query)).parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+            path,
+            query: query.map(|(_, q)| q.span()),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_origin_form() {
+        ok(origin_form, "/", ("", "/"));
+        ok(origin_form, "/where?q=now", ("", "/where?q=now"));
+        ok(origin_form, "/a/b/", ("", "/a/b/"));
+        ok(origin_form, "//a", ("", "//a"));
+        ok(origin_form, "/?", ("", "/?"));
+        ok(origin_form, "/a?b?c/d", ("", "/a?b?c/d"));
+        ok(origin_form, "/a#f", ("#f", "/a"));
+        ok(origin_form, "/a HTTP/1.1", (" HTTP/1.1", "/a"));
+
+        err(origin_form, "");
+        err(origin_form, "a");
+        err(origin_form, "?q");
+        err(origin_form, "*");
+
+        let (_, token) = origin_form(Span::from("/a?b")).unwrap();
+        assert_eq!(*token.path.fragment(), "/a");
+        assert_eq!(token.query.map(|q| *q.fragment()), Some("b"));
+        let (_, token) = origin_form(Span::from("/a")).unwrap();
+        assert_eq!(token.query, None);
+    }
+}
@@ -2,7 +2,7 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::segment;
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -20,7 +20,7 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.3>
 pub fn path_abempty(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many0((nom::character::complete::char('/'), segment)).parse(i)?;
+    let (i, _) = nom::multi::many0_count((mode::char('/'), segment)).parse(i)?;
     Ok((
         i,
         Token {
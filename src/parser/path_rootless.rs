@@ -2,7 +2,7 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::{segment, segment_nz};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -21,7 +21,7 @@ impl<'a> HasSpan<'a> for Token<'a> {
 pub fn path_rootless(i: Span) -> IResult<Span, Token> {
     let start = i;
     let (i, _) = segment_nz.parse(i)?;
-    let (i, _) = nom::multi::many0((nom::character::complete::char('/'), segment)).parse(i)?;
+    let (i, _) = nom::multi::many0_count((mode::char('/'), segment)).parse(i)?;
     Ok((
         i,
         Token {
@@ -2,7 +2,7 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::{pct_encoded, sub_delims, unreserved};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -24,10 +24,10 @@ pub fn pchar(i: Span) -> IResult<Span, Token> {
         unreserved.map(|t| Token { span: t.span() }),
         pct_encoded.map(|t| Token { span: t.span() }),
         sub_delims.map(|t| Token { span: t.span() }),
-        nom::character::complete::char(':').map(|_| Token {
+        mode::char(':').map(|_| Token {
             span: start.take(1),
         }),
-        nom::character::complete::char('@').map(|_| Token {
+        mode::char('@').map(|_| Token {
             span: start.take(1),
         }),
     ))
@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Limit, Span, context, mode};
 use crate::parser::hexdig;
 
 #[derive(Debug, PartialEq)]
@@ -20,11 +20,12 @@ impl<'a> HasSpan<'a> for Token<'a> {
 pub fn pct_encoded(i: Span) -> IResult<Span, Token> {
     let start = i;
     let (i, _) = (
-        nom::character::complete::char('%').map(|_| ()),
+        mode::char('%').map(|_| ()),
         hexdig.map(|_| ()),
         hexdig.map(|_| ()),
     )
         .parse(i)?;
+    let (i, _) = context::count(start, i, Limit::PctEncoded)?;
     Ok((
         i,
         Token {
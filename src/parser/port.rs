@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -18,8 +18,7 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2.3>
 pub fn port(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) =
-        nom::multi::many0(nom::character::complete::satisfy(|c| c.is_ascii_digit())).parse(i)?;
+    let (i, _) = nom::multi::many0_count(mode::satisfy(|c| c.is_ascii_digit())).parse(i)?;
     Ok((
         i,
         Token {
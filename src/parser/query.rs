@@ -2,7 +2,7 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::pchar;
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Limit, Span, context, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -20,11 +20,15 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.4>
 pub fn query(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many0(nom::branch::alt((
-        pchar.map(|_| ()),
-        nom::character::complete::char('/').map(|_| ()),
-        nom::character::complete::char('?').map(|_| ()),
-    )))
+    let (i, _) = nom::multi::many0_count(context::bounded(
+        start,
+        Limit::QueryLength,
+        nom::branch::alt((
+            pchar.map(|_| ()),
+            mode::char('/').map(|_| ()),
+            mode::char('?').map(|_| ()),
+        )),
+    ))
     .parse(i)?;
     Ok((
         i,
@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Limit, Span, context};
 use crate::parser::{pct_encoded, sub_delims, unreserved};
 
 #[derive(Debug, PartialEq)]
@@ -19,11 +19,15 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2.2>
 pub fn reg_name(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many0(nom::branch::alt((
-        unreserved.map(|_| ()),
-        pct_encoded.map(|_| ()),
-        sub_delims.map(|_| ()),
-    )))
+    let (i, _) = nom::multi::many0_count(context::bounded(
+        start,
+        Limit::HostLength,
+        nom::branch::alt((
+            unreserved.map(|_| ()),
+            pct_encoded.map(|_| ()),
+            sub_delims.map(|_| ()),
+        )),
+    ))
     .parse(i)?;
     Ok((
         i,
@@ -0,0 +1,80 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{authority, path_abempty, path_absolute, path_empty, path_noscheme};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+    pub authority: Option<authority::Token<'a>>,
+    pub path: Span<'a>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// relative-part = "//" authority path-abempty
+///               / path-absolute
+///               / path-noscheme
+///               / path-empty
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3986#section-4.2>
+pub fn relative_part(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, (authority, path)) = nom::branch::alt((
+        (mode::tag("//"), authority, path_abempty).map(|(_, a, p)| (Some(a), p.span())),
+        path_absolute.map(|p| (None, p.span())),
+        path_noscheme.map(|p| (None, p.span())),
+        path_empty.map(|p| (None, p.span())),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+            authority,
+            path,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_relative_part() {
+        // "//" authority path-abempty
+        ok(relative_part, "//example.com", ("", "//example.com"));
+        ok(
+            relative_part,
+            "//example.com/path",
+            ("", "//example.com/path"),
+        );
+        ok(
+            relative_part,
+            "//user@example.com:8080/path",
+            ("", "//user@example.com:8080/path"),
+        );
+
+        // path-absolute
+        ok(relative_part, "/", ("", "/"));
+        ok(relative_part, "/path/to", ("", "/path/to"));
+
+        // path-noscheme
+        ok(relative_part, "path/to", ("", "path/to"));
+        ok(relative_part, "a:b", (":b", "a"));
+        ok(relative_part, "./a:b", ("", "./a:b"));
+
+        // path-empty
+        ok(relative_part, "", ("", ""));
+        ok(relative_part, "?q", ("?q", ""));
+        ok(relative_part, "#f", ("#f", ""));
+    }
+}
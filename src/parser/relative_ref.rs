@@ -0,0 +1,61 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{fragment, query, relative_part};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+    pub relative_part: relative_part::Token<'a>,
+    pub query: Option<Span<'a>>,
+    pub fragment: Option<Span<'a>>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// relative-ref  = relative-part [ "?" query ] [ "#" fragment ]
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3986#section-4.2>
+pub fn relative_ref(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, (relative_part, query, fragment)) = (
+        relative_part,
+        nom::combinator::opt((mode::char('?'), query)),
+        nom::combinator::opt((mode::char('#'), fragment)),
+    )
+        .parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+            relative_part,
+            query: query.map(|(_, q)| q.span()),
+            fragment: fragment.map(|(_, f)| f.span()),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_relative_ref() {
+        ok(relative_ref, "//example.com", ("", "//example.com"));
+        ok(relative_ref, "/path?q=v#f", ("", "/path?q=v#f"));
+        ok(relative_ref, "path/to?q", ("", "path/to?q"));
+        ok(relative_ref, "?q", ("", "?q"));
+        ok(relative_ref, "#f", ("", "#f"));
+        ok(relative_ref, "", ("", ""));
+        ok(relative_ref, "../a/b", ("", "../a/b"));
+        ok(relative_ref, "a:b", (":b", "a"));
+        ok(relative_ref, "/path trailing", (" trailing", "/path"));
+    }
+}
@@ -0,0 +1,90 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use crate::parser::{absolute_form, authority_form, origin_form};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+    pub kind: Kind,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    OriginForm,
+    AbsoluteForm,
+    AuthorityForm,
+    AsteriskForm,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// request-target = origin-form
+///                / absolute-form
+///                / authority-form
+///                / asterisk-form
+/// asterisk-form  = "*"
+///
+/// An `authority-form` that is also an `absolute-URI` (`example.com:443`) is matched as `absolute-form`. Use
+/// `authority_form` for a `CONNECT` request.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9112#section-3.2>
+pub fn request_target(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, kind) = nom::branch::alt((
+        origin_form.map(|_| Kind::OriginForm),
+        absolute_form.map(|_| Kind::AbsoluteForm),
+        authority_form.map(|_| Kind::AuthorityForm),
+        mode::char('*').map(|_| Kind::AsteriskForm),
+    ))
+    .parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+            kind,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_request_target() {
+        ok(request_target, "/where?q=now", ("", "/where?q=now"));
+        ok(
+            request_target,
+            "http://www.example.org/pub/WWW/TheProject.html",
+            ("", "http://www.example.org/pub/WWW/TheProject.html"),
+        );
+        ok(
+            request_target,
+            "www.example.com:80",
+            ("", "www.example.com:80"),
+        );
+        ok(request_target, "[::1]:80", ("", "[::1]:80"));
+        ok(request_target, "*", ("", "*"));
+        ok(request_target, "/a HTTP/1.1", (" HTTP/1.1", "/a"));
+
+        err(request_target, "");
+        err(request_target, "?q");
+        err(request_target, "#f");
+
+        let kind = |s| request_target(Span::from(s)).unwrap().1.kind;
+        assert_eq!(kind("/"), Kind::OriginForm);
+        assert_eq!(kind("http://a/"), Kind::AbsoluteForm);
+        assert_eq!(kind("a.example:443"), Kind::AbsoluteForm);
+        assert_eq!(kind("[::1]:443"), Kind::AuthorityForm);
+        assert_eq!(kind("127.0.0.1:443"), Kind::AuthorityForm);
+        assert_eq!(kind("*"), Kind::AsteriskForm);
+    }
+}
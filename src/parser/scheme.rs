@@ -1,6 +1,6 @@
 use nom::{IResult, Input, Offset, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -18,8 +18,8 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.1>
 pub fn scheme(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::character::satisfy(|c| c.is_ascii_alphabetic()).parse(i)?;
-    let (i, _) = nom::multi::many0(nom::character::satisfy(|c| {
+    let (i, _) = mode::satisfy(|c| c.is_ascii_alphabetic()).parse(i)?;
+    let (i, _) = nom::multi::many0_count(mode::satisfy(|c| {
         c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'
     }))
     .parse(i)?;
@@ -43,6 +43,7 @@ mod tests {
         ok(scheme, "ftp://", ("://", "ftp"));
         ok(scheme, "a1+.-://", ("://", "a1+.-"));
         ok(scheme, "a://", ("://", "a"));
+        ok(scheme, "http", ("", "http"));
 
         err(scheme, "1http://");
         err(scheme, "+http://");
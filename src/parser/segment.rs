@@ -2,7 +2,7 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::pchar;
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Limit, Span, context};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -20,7 +20,8 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.3>
 pub fn segment(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many0(pchar).parse(i)?;
+    let (i, _) = nom::multi::many0_count(pchar).parse(i)?;
+    let (i, _) = context::count(start, i, Limit::Segments)?;
     Ok((
         i,
         Token {
@@ -2,7 +2,7 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::{pct_encoded, sub_delims, unreserved};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Limit, Span, context, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -21,15 +21,16 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.3>
 pub fn segment_nz_nc(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many1(nom::branch::alt((
+    let (i, _) = nom::multi::many1_count(nom::branch::alt((
         unreserved.map(|t| Token { span: t.span() }),
         pct_encoded.map(|t| Token { span: t.span() }),
         sub_delims.map(|t| Token { span: t.span() }),
-        nom::character::complete::char('@').map(|_| Token {
+        mode::char('@').map(|_| Token {
             span: start.take(1),
         }),
     )))
     .parse(i)?;
+    let (i, _) = context::count(start, i, Limit::Segments)?;
     Ok((
         i,
         Token {
@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
@@ -19,7 +19,7 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-2.2>
 pub fn sub_delims(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::character::complete::satisfy(|c| {
+    let (i, _) = mode::satisfy(|c| {
         matches!(
             c,
             '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '='
@@ -0,0 +1,82 @@
+use nom::{IResult, Input as _, Offset as _, Parser};
+
+use super::{HasSpan, Span, mode};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+/// ucschar        = %xA0-D7FF / %xF900-FDCF / %xFDF0-FFEF
+///                / %x10000-1FFFD / %x20000-2FFFD / %x30000-3FFFD
+///                / %x40000-4FFFD / %x50000-5FFFD / %x60000-6FFFD
+///                / %x70000-7FFFD / %x80000-8FFFD / %x90000-9FFFD
+///                / %xA0000-AFFFD / %xB0000-BFFFD / %xC0000-CFFFD
+///                / %xD0000-DFFFD / %xE1000-EFFFD
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3987#section-2.2>
+pub fn ucschar(i: Span) -> IResult<Span, Token> {
+    let start = i;
+    let (i, _) = mode::satisfy(is_ucschar).parse(i)?;
+    Ok((
+        i,
+        Token {
+            span: start.take(start.offset(&i)),
+        },
+    ))
+}
+
+pub(crate) fn is_ucschar(c: char) -> bool {
+    matches!(
+        c as u32,
+        0xA0..=0xD7FF
+            | 0xF900..=0xFDCF
+            | 0xFDF0..=0xFFEF
+            | 0x10000..=0x1FFFD
+            | 0x20000..=0x2FFFD
+            | 0x30000..=0x3FFFD
+            | 0x40000..=0x4FFFD
+            | 0x50000..=0x5FFFD
+            | 0x60000..=0x6FFFD
+            | 0x70000..=0x7FFFD
+            | 0x80000..=0x8FFFD
+            | 0x90000..=0x9FFFD
+            | 0xA0000..=0xAFFFD
+            | 0xB0000..=0xBFFFD
+            | 0xC0000..=0xCFFFD
+            | 0xD0000..=0xDFFFD
+            | 0xE1000..=0xEFFFD
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::{err, ok};
+
+    use super::*;
+
+    #[test]
+    fn test_ucschar() {
+        ok(ucschar, "\u{A0}rest", ("rest", "\u{A0}"));
+        ok(ucschar, "ürest", ("rest", "ü"));
+        ok(ucschar, "日本", ("本", "日"));
+        ok(ucschar, "\u{1F600}", ("", "\u{1F600}"));
+        ok(ucschar, "\u{EFFFD}", ("", "\u{EFFFD}"));
+
+        err(ucschar, "a");
+        err(ucschar, "%C3%BC");
+        err(ucschar, "\u{9F}");
+        err(ucschar, "\u{E000}");
+        err(ucschar, "\u{FDD0}");
+        err(ucschar, "\u{FFFE}");
+        err(ucschar, "\u{1FFFE}");
+        err(ucschar, "\u{E0001}");
+        err(ucschar, "");
+    }
+}
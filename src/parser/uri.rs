@@ -2,11 +2,15 @@ use nom::{IResult, Input as _, Offset as _, Parser};
 
 use crate::parser::{fragment, hier_part, query, scheme};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 
 #[derive(Debug, PartialEq)]
 pub struct Token<'a> {
     pub span: Span<'a>,
+    pub scheme: Span<'a>,
+    pub hier_part: hier_part::Token<'a>,
+    pub query: Option<Span<'a>>,
+    pub fragment: Option<Span<'a>>,
 }
 
 impl<'a> HasSpan<'a> for Token<'a> {
@@ -20,18 +24,22 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3>
 pub fn uri(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = (
+    let (i, (scheme, _, hier_part, query, fragment)) = (
         scheme,
-        nom::character::complete::char(':'),
+        mode::char(':'),
         hier_part,
-        nom::combinator::opt((nom::character::complete::char('?'), query)),
-        nom::combinator::opt((nom::character::complete::char('#'), fragment)),
+        nom::combinator::opt((mode::char('?'), query)),
+        nom::combinator::opt((mode::char('#'), fragment)),
     )
         .parse(i)?;
     Ok((
         i,
         Token {
             span: start.take(start.offset(&i)),
+            scheme: scheme.span(),
+            hier_part,
+            query: query.map(|(_, q)| q.span()),
+            fragment: fragment.map(|(_, f)| f.span()),
         },
     ))
 }
@@ -108,4 +116,35 @@ mod tests {
         err(uri, "1http://example.com");
         err(uri, "http//example.com");
     }
+
+    #[test]
+    fn test_uri_streaming() {
+        use nom::Input as _;
+
+        use crate::parser::Mode;
+
+        let s = "http://user@[::1]:8080/path/to?q=v#f HTTP/1.1";
+        // every prefix that ends inside the URI asks for more input
+        for end in 0..s.find(' ').expect("space") {
+            let i = Span::new_extra(&s[..end], Mode::Streaming.into());
+            assert!(
+                matches!(uri(i), Err(nom::Err::Incomplete(_))),
+                "{:?}",
+                &s[..end]
+            );
+        }
+        let i = Span::new_extra(s, Mode::Streaming.into());
+        let (rest, t) = uri(i).expect("uri");
+        assert_eq!(*rest.fragment(), " HTTP/1.1");
+        assert_eq!(t.span, i.take(s.find(' ').expect("space")));
+
+        // an input that cannot match further does not wait for more
+        let i = Span::new_extra("1http:", Mode::Streaming.into());
+        assert!(matches!(uri(i), Err(nom::Err::Error(_))));
+        let i = Span::new_extra("http://[1:2:3:4:5:6:7]/", Mode::Streaming.into());
+        assert_eq!(
+            uri(i).map(|(rest, _)| *rest.fragment()),
+            Ok("[1:2:3:4:5:6:7]/")
+        );
+    }
 }
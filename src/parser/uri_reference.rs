@@ -0,0 +1,107 @@
+use nom::{IResult, Parser};
+
+use crate::parser::{authority, relative_ref, uri};
+
+use super::{HasSpan, Span};
+
+#[derive(Debug, PartialEq)]
+pub struct Token<'a> {
+    pub span: Span<'a>,
+    /// `None` for a relative reference.
+    pub scheme: Option<Span<'a>>,
+    pub authority: Option<authority::Token<'a>>,
+    pub path: Span<'a>,
+    pub query: Option<Span<'a>>,
+    pub fragment: Option<Span<'a>>,
+}
+
+impl<'a> HasSpan<'a> for Token<'a> {
+    fn span(&self) -> Span<'a> {
+        self.span
+    }
+}
+
+impl<'a> From<uri::Token<'a>> for Token<'a> {
+    fn from(t: uri::Token<'a>) -> Self {
+        Token {
+            span: t.span,
+            scheme: Some(t.scheme),
+            authority: t.hier_part.authority,
+            path: t.hier_part.path,
+            query: t.query,
+            fragment: t.fragment,
+        }
+    }
+}
+
+impl<'a> From<relative_ref::Token<'a>> for Token<'a> {
+    fn from(t: relative_ref::Token<'a>) -> Self {
+        Token {
+            span: t.span,
+            scheme: None,
+            authority: t.relative_part.authority,
+            path: t.relative_part.path,
+            query: t.query,
+            fragment: t.fragment,
+        }
+    }
+}
+
+/// URI-reference = URI / relative-ref
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3986#section-4.1>
+pub fn uri_reference(i: Span) -> IResult<Span, Token> {
+    nom::branch::alt((uri.map(Token::from), relative_ref.map(Token::from))).parse(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::tests::ok;
+
+    use super::*;
+
+    #[test]
+    fn test_uri_reference() {
+        // URI
+        ok(
+            uri_reference,
+            "http://example.com/path?q#f",
+            ("", "http://example.com/path?q#f"),
+        );
+        ok(uri_reference, "a:", ("", "a:"));
+        ok(
+            uri_reference,
+            "mailto:John.Doe@example.com",
+            ("", "mailto:John.Doe@example.com"),
+        );
+
+        // relative-ref
+        ok(uri_reference, "//example.com/", ("", "//example.com/"));
+        ok(uri_reference, "/path?q#f", ("", "/path?q#f"));
+        ok(uri_reference, "path", ("", "path"));
+        ok(uri_reference, "", ("", ""));
+        ok(
+            uri_reference,
+            "http//example.com",
+            ("", "http//example.com"),
+        );
+        ok(uri_reference, "1http:", (":", "1http"));
+    }
+
+    #[test]
+    fn test_uri_reference_components() {
+        let (_, t) = uri_reference(Span::from("http://example.com/p?q#f")).expect("uri_reference");
+        assert_eq!(t.scheme.map(|s| *s.fragment()), Some("http"));
+        assert_eq!(t.authority.map(|a| *a.span.fragment()), Some("example.com"));
+        assert_eq!(*t.path.fragment(), "/p");
+        assert_eq!(t.query.map(|s| *s.fragment()), Some("q"));
+        assert_eq!(t.fragment.map(|s| *s.fragment()), Some("f"));
+
+        let (_, t) = uri_reference(Span::from("../p#f")).expect("uri_reference");
+        assert_eq!(t.scheme, None);
+        assert_eq!(t.authority, None);
+        assert_eq!(*t.path.fragment(), "../p");
+        assert_eq!(t.query, None);
+        assert_eq!(t.fragment.map(|s| *s.fragment()), Some("f"));
+    }
+}
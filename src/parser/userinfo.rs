@@ -1,6 +1,6 @@
 use nom::{IResult, Input as _, Offset as _, Parser};
 
-use super::{HasSpan, Span};
+use super::{HasSpan, Span, mode};
 use crate::parser::{pct_encoded, sub_delims, unreserved};
 
 #[derive(Debug, PartialEq)]
@@ -19,11 +19,11 @@ impl<'a> HasSpan<'a> for Token<'a> {
 /// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.2.1>
 pub fn userinfo(i: Span) -> IResult<Span, Token> {
     let start = i;
-    let (i, _) = nom::multi::many0(nom::branch::alt((
+    let (i, _) = nom::multi::many0_count(nom::branch::alt((
         unreserved.map(|_| ()),
         pct_encoded.map(|_| ()),
         sub_delims.map(|_| ()),
-        nom::character::complete::char(':').map(|_| ()),
+        mode::char(':').map(|_| ()),
     )))
     .parse(i)?;
     Ok((
@@ -0,0 +1,353 @@
+use crate::{
+    ParseError,
+    parse_error::parse_all,
+    parser::{self, Span},
+    typed_uri::offset,
+};
+
+/// A `path` component.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3986#section-3.3>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Path<'a>(&'a str);
+
+impl<'a> Path<'a> {
+    /// Parses `s` as a whole `path`.
+    pub fn parse(s: &'a str) -> Result<Self, ParseError> {
+        parse_all(parser::path, s).map(|_| Self(s))
+    }
+
+    /// `s` must match `path`.
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self(s)
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns the `segment`s, not percent-decoded. A leading `/` does not start a segment, so `/a/b/` has the
+    /// segments `a`, `b` and an empty one, and the empty path has none.
+    pub fn segments(&self) -> Segments<'a> {
+        let s = self.0.strip_prefix('/').unwrap_or(self.0);
+        Segments {
+            rest: (!self.0.is_empty()).then(|| Span::new_extra(s, Default::default())),
+        }
+    }
+
+    /// Returns the parts of the path that are unsafe to map onto a file system, in order.
+    ///
+    /// ```
+    /// use nom_uri::{Hazard, HazardKind, Path};
+    ///
+    /// let path = Path::parse("/static/.%2E/%252e%00").unwrap();
+    /// assert_eq!(
+    ///     path.safety_report().collect::<Vec<_>>(),
+    ///     [
+    ///         Hazard { span: (8, 12), kind: HazardKind::DotDot },
+    ///         Hazard { span: (13, 18), kind: HazardKind::DoubleEncoding },
+    ///         Hazard { span: (18, 21), kind: HazardKind::Nul },
+    ///     ]
+    /// );
+    /// assert_eq!(Path::parse("/static/a.css").unwrap().safety_report().next(), None);
+    /// ```
+    pub fn safety_report(&self) -> SafetyReport<'a> {
+        SafetyReport {
+            path: self.0,
+            segments: self.segments(),
+            segment: None,
+        }
+    }
+
+    /// Converts the path to a relative file system path, refusing every [`Hazard`] of
+    /// [`safety_report`](Self::safety_report).
+    ///
+    /// The segments are percent-decoded; empty and `.` segments are skipped. A segment must decode to UTF-8 and to
+    /// a plain file name on the platform, so that a segment such as `C:` on Windows cannot make the path absolute.
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    ///
+    /// use nom_uri::{FsPathError, Path};
+    ///
+    /// let path = Path::parse("/static/./my%20file.css").unwrap();
+    /// assert_eq!(
+    ///     path.to_safe_relative_fs_path(),
+    ///     Ok(PathBuf::from("static").join("my file.css"))
+    /// );
+    /// assert!(matches!(
+    ///     Path::parse("/static/%2e%2e/secret").unwrap().to_safe_relative_fs_path(),
+    ///     Err(FsPathError::Hazard(_))
+    /// ));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_safe_relative_fs_path(&self) -> Result<std::path::PathBuf, FsPathError> {
+        use std::path::{Component, PathBuf};
+
+        if let Some(hazard) = self.safety_report().next() {
+            return Err(FsPathError::Hazard(hazard));
+        }
+        let mut path = PathBuf::new();
+        for segment in self.segments() {
+            let start = offset(self.0, segment);
+            let span = (start, start + segment.len());
+            let decoded = crate::percent_encoding::decode(segment);
+            let decoded =
+                String::from_utf8(decoded).map_err(|_| FsPathError::InvalidUtf8 { span })?;
+            let mut components = std::path::Path::new(&decoded).components();
+            match (components.next(), components.next()) {
+                (None | Some(Component::CurDir), None) => {}
+                (Some(Component::Normal(name)), None) => path.push(name),
+                _ => return Err(FsPathError::NotFileName { span }),
+            }
+        }
+        Ok(path)
+    }
+}
+
+impl core::fmt::Display for Path<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// An iterator over the `segment`s of a [`Path`].
+#[derive(Clone, Debug)]
+pub struct Segments<'a> {
+    rest: Option<Span<'a>>,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take()?;
+        let (rest, token) = parser::segment(rest).expect("a path matches *( segment \"/\" )");
+        if let Some(rest) = rest.fragment().strip_prefix('/') {
+            self.rest = Some(Span::new_extra(rest, Default::default()));
+        }
+        Some(token.span.fragment())
+    }
+}
+
+/// A part of a path that is unsafe to map onto a file system.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hazard {
+    /// The byte offsets of the unsafe part within the path.
+    pub span: (usize, usize),
+    pub kind: HazardKind,
+}
+
+impl core::fmt::Display for Hazard {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.0, self.span.1)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HazardKind {
+    /// A segment that decodes to `..`, such as `..`, `%2e%2e` or `.%2E`.
+    DotDot,
+    /// `%2F` or `%5C`, a `/` or `\` within a segment.
+    EncodedSeparator,
+    /// `%00`.
+    Nul,
+    /// `%25` followed by two hex digits, such as `%252e`, which decodes to another `pct-encoded` triplet.
+    DoubleEncoding,
+}
+
+impl core::fmt::Display for HazardKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HazardKind::DotDot => f.write_str("'..' segment"),
+            HazardKind::EncodedSeparator => f.write_str("encoded path separator"),
+            HazardKind::Nul => f.write_str("encoded NUL"),
+            HazardKind::DoubleEncoding => f.write_str("double percent-encoding"),
+        }
+    }
+}
+
+/// An iterator over the [`Hazard`]s of a [`Path`].
+#[derive(Clone, Debug)]
+pub struct SafetyReport<'a> {
+    path: &'a str,
+    segments: Segments<'a>,
+    /// The segment being scanned and the offset of its rest within the path.
+    segment: Option<(&'a str, usize)>,
+}
+
+impl Iterator for SafetyReport<'_> {
+    type Item = Hazard;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((rest, start)) = self.segment else {
+                let segment = self.segments.next()?;
+                let start = offset(self.path, segment);
+                self.segment = Some((segment, start));
+                if is_dot_dot(segment) {
+                    return Some(Hazard {
+                        span: (start, start + segment.len()),
+                        kind: HazardKind::DotDot,
+                    });
+                }
+                continue;
+            };
+            let Some(i) = rest.find('%') else {
+                self.segment = None;
+                continue;
+            };
+            // a segment only has `%` in `pct-encoded` triplets
+            let (kind, len) = match decode_pair(&rest[i + 1..]) {
+                0x00 => (Some(HazardKind::Nul), 3),
+                b'/' | b'\\' => (Some(HazardKind::EncodedSeparator), 3),
+                b'%' if rest[i + 3..].get(..2).is_some_and(is_hex_pair) => {
+                    (Some(HazardKind::DoubleEncoding), 5)
+                }
+                _ => (None, 3),
+            };
+            self.segment = Some((&rest[i + len..], start + i + len));
+            if let Some(kind) = kind {
+                return Some(Hazard {
+                    span: (start + i, start + i + len),
+                    kind,
+                });
+            }
+        }
+    }
+}
+
+fn is_hex_pair(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Decodes the two hex digits at the start of `s`.
+fn decode_pair(s: &str) -> u8 {
+    u8::from_str_radix(&s[..2], 16).expect("pct-encoded")
+}
+
+/// Whether `segment` decodes to `..`.
+fn is_dot_dot(segment: &str) -> bool {
+    let mut rest = segment;
+    for _ in 0..2 {
+        rest = match rest.strip_prefix('.') {
+            Some(rest) => rest,
+            None => match rest.get(..3) {
+                Some(triplet) if triplet.eq_ignore_ascii_case("%2e") => &rest[3..],
+                _ => return false,
+            },
+        };
+    }
+    rest.is_empty()
+}
+
+/// An error returned when a path cannot be mapped onto a file system safely.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FsPathError {
+    Hazard(Hazard),
+    /// The segment does not decode to UTF-8.
+    InvalidUtf8 {
+        span: (usize, usize),
+    },
+    /// The segment does not decode to a plain file name on this platform.
+    NotFileName {
+        span: (usize, usize),
+    },
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for FsPathError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FsPathError::Hazard(hazard) => write!(f, "{}", hazard),
+            FsPathError::InvalidUtf8 { span } => {
+                write!(f, "segment at {}..{} is not UTF-8", span.0, span.1)
+            }
+            FsPathError::NotFileName { span } => {
+                write!(f, "segment at {}..{} is not a file name", span.0, span.1)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for FsPathError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments() {
+        let f = |s| Path::parse(s).unwrap().segments().collect::<Vec<_>>();
+        assert_eq!(f(""), [] as [&str; 0]);
+        assert_eq!(f("/"), [""]);
+        assert_eq!(f("/a/b%20c/"), ["a", "b%20c", ""]);
+        assert_eq!(f("a//b"), ["a", "", "b"]);
+        assert_eq!(f("/a:b/c"), ["a:b", "c"]);
+        assert!(Path::parse("/a?b").is_err());
+    }
+
+    #[test]
+    fn test_safety_report() {
+        let f = |s| {
+            Path::parse(s)
+                .unwrap()
+                .safety_report()
+                .map(|h| (h.span, h.kind))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(f("/a/b.c/.../.d/..e/%2e"), []);
+        assert_eq!(f("/a/../b"), [((3, 5), HazardKind::DotDot)]);
+        assert_eq!(f(".."), [((0, 2), HazardKind::DotDot)]);
+        assert_eq!(f("/%2e%2E"), [((1, 7), HazardKind::DotDot)]);
+        assert_eq!(f("/.%2E/"), [((1, 5), HazardKind::DotDot)]);
+        assert_eq!(f("/%2e./"), [((1, 5), HazardKind::DotDot)]);
+        assert_eq!(
+            f("/a%2fb%5Cc%2Fd"),
+            [
+                ((2, 5), HazardKind::EncodedSeparator),
+                ((6, 9), HazardKind::EncodedSeparator),
+                ((10, 13), HazardKind::EncodedSeparator)
+            ]
+        );
+        assert_eq!(f("/a%00"), [((2, 5), HazardKind::Nul)]);
+        assert_eq!(
+            f("/%252e%252E/%25/%2525"),
+            [
+                ((1, 6), HazardKind::DoubleEncoding),
+                ((6, 11), HazardKind::DoubleEncoding),
+                ((16, 21), HazardKind::DoubleEncoding)
+            ]
+        );
+        assert_eq!(f("/%25zz%41"), []);
+        assert_eq!(
+            f("/..%2f..").len(),
+            1,
+            "a segment with a separator is not '..'"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_safe_relative_fs_path() {
+        use std::path::PathBuf;
+
+        let f = |s| Path::parse(s).unwrap().to_safe_relative_fs_path();
+        assert_eq!(f(""), Ok(PathBuf::new()));
+        assert_eq!(f("/"), Ok(PathBuf::new()));
+        assert_eq!(f("/a//./b/"), Ok(PathBuf::from("a").join("b")));
+        assert_eq!(f("a/%C3%A9"), Ok(PathBuf::from("a").join("é")));
+        assert_eq!(
+            f("/a/%2E%2e/b"),
+            Err(FsPathError::Hazard(Hazard {
+                span: (3, 9),
+                kind: HazardKind::DotDot
+            }))
+        );
+        assert_eq!(f("/a%FF"), Err(FsPathError::InvalidUtf8 { span: (1, 5) }));
+        #[cfg(windows)]
+        assert_eq!(f("/C:/b"), Err(FsPathError::NotFileName { span: (1, 3) }));
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::{format, string::String, vec::Vec};
+
+/// Decodes `pct-encoded` triplets. A `%` that does not start a triplet is kept as is.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc3986#section-2.1>
+pub(crate) fn decode(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(b) = bytes.get(i + 1..i + 3).and_then(decode_hex_pair)
+        {
+            decoded.push(b);
+            i += 3;
+            continue;
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    decoded
+}
+
+/// Appends the UTF-8 octets of `c` as `pct-encoded` triplets.
+pub(crate) fn encode_char(c: char, out: &mut String) {
+    let mut buf = [0; 4];
+    for b in c.encode_utf8(&mut buf).bytes() {
+        out.push_str(&format!("%{:02X}", b));
+    }
+}
+
+pub(crate) fn decode_hex_pair(pair: &[u8]) -> Option<u8> {
+    let hi = (pair[0] as char).to_digit(16)?;
+    let lo = (pair[1] as char).to_digit(16)?;
+    Some((hi * 16 + lo) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("abc"), b"abc");
+        assert_eq!(decode("a%20b"), b"a b");
+        assert_eq!(decode("b%C3%BCcher"), "bücher".as_bytes());
+        assert_eq!(decode("%7e%7E"), b"~~");
+        assert_eq!(decode("100%"), b"100%");
+        assert_eq!(decode("%g0"), b"%g0");
+        assert_eq!(decode("%2"), b"%2");
+    }
+
+    #[test]
+    fn test_encode_char() {
+        let mut s = String::new();
+        encode_char('ü', &mut s);
+        encode_char(' ', &mut s);
+        encode_char('\u{1F600}', &mut s);
+        assert_eq!(s, "%C3%BC%20%F0%9F%98%80");
+    }
+}
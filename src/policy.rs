@@ -0,0 +1,436 @@
+//! Checking URIs against an outbound request policy
+//!
+//! A [`Policy`] decides whether a server may send a request to a URI supplied by a user, such as a webhook
+//! target, without reaching internal infrastructure (SSRF). It works on the typed [`Host`] and port of a parsed
+//! [`Uri`]: numeric hosts in any encoding are decoded and compared by address, never by string.
+//!
+//! A `reg-name` host is checked in the ASCII form that a client resolves, after percent-decoding and IDNA ToASCII, so
+//! that fullwidth digits such as `%EF%BC%91` cannot hide an IPv4 address. Without the `alloc` feature a percent-encoded
+//! `reg-name` is rejected instead. It is not resolved here. Check each address it resolves to with
+//! [`Policy::check_addr`] before connecting, and connect to that address, so that a DNS answer cannot point the request
+//! elsewhere.
+
+use core::net::IpAddr;
+
+#[cfg(not(feature = "alloc"))]
+use crate::DnsName;
+use crate::{Host, Uri};
+
+/// A range of IP addresses.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IpRange {
+    /// `127.0.0.0/8` and `::1`.
+    Loopback,
+    /// `10.0.0.0/8`, `172.16.0.0/12` and `192.168.0.0/16` (RFC 1918).
+    Private,
+    /// `169.254.0.0/16` and `fe80::/10`. Cloud metadata services live here.
+    LinkLocal,
+    /// `0.0.0.0/8` and `::`.
+    Unspecified,
+    /// `::ffff:0:0/96`. The embedded IPv4 address is also checked against the other ranges.
+    Ipv4Mapped,
+    /// `fc00::/7` (RFC 4193).
+    UniqueLocal,
+    /// An address and a prefix length.
+    Cidr(IpAddr, u8),
+}
+
+impl IpRange {
+    /// The ranges that are internal to a network or a host.
+    pub const INTERNAL: &'static [IpRange] = &[
+        IpRange::Loopback,
+        IpRange::Private,
+        IpRange::LinkLocal,
+        IpRange::Unspecified,
+        IpRange::Ipv4Mapped,
+        IpRange::UniqueLocal,
+    ];
+
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        match (self, addr) {
+            (IpRange::Loopback, IpAddr::V4(a)) => a.is_loopback(),
+            (IpRange::Loopback, IpAddr::V6(a)) => a.is_loopback(),
+            (IpRange::Private, IpAddr::V4(a)) => a.is_private(),
+            (IpRange::LinkLocal, IpAddr::V4(a)) => a.is_link_local(),
+            (IpRange::LinkLocal, IpAddr::V6(a)) => a.is_unicast_link_local(),
+            (IpRange::Unspecified, IpAddr::V4(a)) => a.octets()[0] == 0,
+            (IpRange::Unspecified, IpAddr::V6(a)) => a.is_unspecified(),
+            (IpRange::Ipv4Mapped, IpAddr::V6(a)) => a.to_ipv4_mapped().is_some(),
+            (IpRange::UniqueLocal, IpAddr::V6(a)) => a.is_unique_local(),
+            (IpRange::Cidr(IpAddr::V4(net), len), IpAddr::V4(a)) => {
+                prefix_eq(u32::from(*net).into(), u32::from(a).into(), 32, *len)
+            }
+            (IpRange::Cidr(IpAddr::V6(net), len), IpAddr::V6(a)) => {
+                prefix_eq(u128::from(*net), u128::from(a), 128, *len)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn prefix_eq(a: u128, b: u128, bits: u8, len: u8) -> bool {
+    let len = len.min(bits);
+    len == 0 || (a ^ b) >> (bits - len) == 0
+}
+
+impl core::fmt::Display for IpRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IpRange::Loopback => write!(f, "loopback"),
+            IpRange::Private => write!(f, "private"),
+            IpRange::LinkLocal => write!(f, "link-local"),
+            IpRange::Unspecified => write!(f, "unspecified"),
+            IpRange::Ipv4Mapped => write!(f, "IPv4-mapped"),
+            IpRange::UniqueLocal => write!(f, "unique local"),
+            IpRange::Cidr(addr, len) => write!(f, "{}/{}", addr, len),
+        }
+    }
+}
+
+/// The reason a URI is rejected by a [`Policy`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Violation {
+    /// The scheme is not allowed.
+    Scheme,
+    /// The URI has a `userinfo`.
+    Userinfo,
+    /// The URI has no authority or its host is empty.
+    MissingHost,
+    /// The host is an IP address and IP hosts are not allowed.
+    IpHost,
+    /// The host is an IPv4 address in a form other than dotted decimal, such as `0x7f.1` or `2130706433`.
+    LegacyIpv4,
+    /// The host is an `IPvFuture` literal.
+    IpvFuture,
+    /// The `reg-name` cannot be mapped to a valid DNS name.
+    InvalidHost,
+    /// The port is empty or greater than 65535.
+    InvalidPort,
+    /// The port, or the default port of the scheme, is not allowed. `None` if the scheme has no known default.
+    Port(Option<u16>),
+    /// The address is in a blocked range.
+    BlockedAddress { addr: IpAddr, range: IpRange },
+}
+
+impl core::fmt::Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Violation::Scheme => write!(f, "scheme is not allowed"),
+            Violation::Userinfo => write!(f, "userinfo is not allowed"),
+            Violation::MissingHost => write!(f, "missing host"),
+            Violation::IpHost => write!(f, "IP address hosts are not allowed"),
+            Violation::LegacyIpv4 => write!(f, "non-canonical IPv4 address"),
+            Violation::IpvFuture => write!(f, "IPvFuture hosts are not allowed"),
+            Violation::InvalidHost => write!(f, "host is not a valid DNS name"),
+            Violation::InvalidPort => write!(f, "invalid port"),
+            Violation::Port(Some(port)) => write!(f, "port {} is not allowed", port),
+            Violation::Port(None) => write!(f, "unknown port"),
+            Violation::BlockedAddress { addr, range } => {
+                write!(f, "address {} is in the {} range", addr, range)
+            }
+        }
+    }
+}
+
+impl core::error::Error for Violation {}
+
+/// Rules for the URIs a server may send requests to.
+///
+/// ```
+/// use nom_uri::{Uri, policy::{IpRange, Policy, Violation}};
+///
+/// let policy = Policy::default();
+/// assert_eq!(policy.check(&Uri::parse("https://hooks.example.com/x").unwrap()), Ok(()));
+/// assert_eq!(
+///     policy.check(&Uri::parse("http://0x7f.1/").unwrap()),
+///     Err(Violation::LegacyIpv4)
+/// );
+///
+/// let policy = Policy {
+///     allow_ip_hosts: true,
+///     ..Policy::default()
+/// };
+/// assert_eq!(
+///     policy.check(&Uri::parse("http://[::ffff:169.254.169.254]/").unwrap()),
+///     Err(Violation::BlockedAddress {
+///         addr: "::ffff:169.254.169.254".parse().unwrap(),
+///         range: IpRange::Ipv4Mapped,
+///     })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Policy<'a> {
+    /// The allowed schemes, compared case-insensitively. Defaults to `http` and `https`.
+    pub schemes: &'a [&'a str],
+    /// The allowed ports, or `None` for any port. A URI without a port uses the default port of its scheme.
+    /// Defaults to 80 and 443.
+    pub ports: Option<&'a [u16]>,
+    /// The blocked address ranges. Defaults to [`IpRange::INTERNAL`].
+    pub blocked: &'a [IpRange],
+    /// Whether a `userinfo` is allowed. Defaults to `false`.
+    pub allow_userinfo: bool,
+    /// Whether an `IPv4address` or an IPv6 literal is allowed as the host. The address is still checked against
+    /// the blocked ranges. Defaults to `false`.
+    pub allow_ip_hosts: bool,
+}
+
+impl Default for Policy<'static> {
+    fn default() -> Self {
+        Self {
+            schemes: &["http", "https"],
+            ports: Some(&[80, 443]),
+            blocked: IpRange::INTERNAL,
+            allow_userinfo: false,
+            allow_ip_hosts: false,
+        }
+    }
+}
+
+impl Policy<'_> {
+    /// Checks `uri`, returning the first violation found.
+    pub fn check(&self, uri: &Uri<'_>) -> Result<(), Violation> {
+        if !self
+            .schemes
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(uri.scheme()))
+        {
+            return Err(Violation::Scheme);
+        }
+        if uri.userinfo().is_some() && !self.allow_userinfo {
+            return Err(Violation::Userinfo);
+        }
+        self.check_host(uri.host().ok_or(Violation::MissingHost)?)?;
+        self.check_port(uri)
+    }
+
+    fn check_host(&self, host: Host<'_>) -> Result<(), Violation> {
+        let addr = match host {
+            Host::RegName("") => return Err(Violation::MissingHost),
+            Host::RegName(name) => return check_reg_name(name),
+            Host::IpvFuture(_) => return Err(Violation::IpvFuture),
+            Host::Ipv4(addr) => IpAddr::V4(addr),
+            Host::Ipv6(addr) => IpAddr::V6(addr),
+        };
+        if !self.allow_ip_hosts {
+            return Err(Violation::IpHost);
+        }
+        self.check_addr(addr)
+    }
+
+    fn check_port(&self, uri: &Uri<'_>) -> Result<(), Violation> {
+        let port = match uri.port() {
+            Some(_) => Some(uri.port_u16().ok_or(Violation::InvalidPort)?),
+            None => default_port(uri.scheme()),
+        };
+        match (self.ports, port) {
+            (None, _) => Ok(()),
+            (Some(ports), Some(port)) if ports.contains(&port) => Ok(()),
+            (Some(_), port) => Err(Violation::Port(port)),
+        }
+    }
+
+    /// Checks an address, such as one that a `reg-name` host resolved to, against the blocked ranges.
+    pub fn check_addr(&self, addr: IpAddr) -> Result<(), Violation> {
+        let mapped = match addr {
+            IpAddr::V6(a) => a.to_ipv4_mapped().map(IpAddr::V4),
+            IpAddr::V4(_) => None,
+        };
+        for a in [Some(addr), mapped].into_iter().flatten() {
+            if let Some(range) = self.blocked.iter().find(|r| r.contains(a)) {
+                return Err(Violation::BlockedAddress {
+                    addr,
+                    range: *range,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_reg_name(name: &str) -> Result<(), Violation> {
+    if Host::RegName(name).interpret_ipv4().is_some() {
+        return Err(Violation::LegacyIpv4);
+    }
+    #[cfg(feature = "alloc")]
+    {
+        let ascii = Host::RegName(name)
+            .to_ascii()
+            .map_err(|_| Violation::InvalidHost)?;
+        if Host::RegName(&ascii).interpret_ipv4().is_some() {
+            return Err(Violation::LegacyIpv4);
+        }
+    }
+    // a percent-encoded name cannot be mapped without IDNA
+    #[cfg(not(feature = "alloc"))]
+    if name.contains('%') || DnsName::new(name).is_err() {
+        return Err(Violation::InvalidHost);
+    }
+    Ok(())
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+    [
+        ("http", 80),
+        ("https", 443),
+        ("ws", 80),
+        ("wss", 443),
+        ("ftp", 21),
+    ]
+    .into_iter()
+    .find(|(s, _)| s.eq_ignore_ascii_case(scheme))
+    .map(|(_, port)| port)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let policy = Policy::default();
+        let f = |s| policy.check(&Uri::parse(s).unwrap());
+        assert_eq!(f("https://example.com/hook"), Ok(()));
+        assert_eq!(f("HTTP://example.com:80/"), Ok(()));
+        assert_eq!(f("ftp://example.com/"), Err(Violation::Scheme));
+        assert_eq!(f("http://u:p@example.com/"), Err(Violation::Userinfo));
+        assert_eq!(f("http://@example.com/"), Err(Violation::Userinfo));
+        assert_eq!(f("http:///path"), Err(Violation::MissingHost));
+        assert_eq!(f("http:path"), Err(Violation::MissingHost));
+        assert_eq!(f("http://127.0.0.1/"), Err(Violation::IpHost));
+        assert_eq!(f("http://[::1]/"), Err(Violation::IpHost));
+        assert_eq!(f("http://2130706433/"), Err(Violation::LegacyIpv4));
+        assert_eq!(f("http://0177.0.0.1/"), Err(Violation::LegacyIpv4));
+        assert_eq!(f("http://%31%32%37.1/"), Err(Violation::LegacyIpv4));
+        assert_eq!(f("http://a_b.example/"), Err(Violation::InvalidHost));
+        assert_eq!(f("http://a..b/"), Err(Violation::InvalidHost));
+        assert_eq!(f("http://[v1.x]/"), Err(Violation::IpvFuture));
+        assert_eq!(f("http://example.com:/"), Err(Violation::InvalidPort));
+        assert_eq!(f("http://example.com:99999/"), Err(Violation::InvalidPort));
+        assert_eq!(
+            f("http://example.com:8080/"),
+            Err(Violation::Port(Some(8080)))
+        );
+        assert_eq!(f("https://example.com:0443/"), Ok(()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_check_idna() {
+        let policy = Policy::default();
+        let f = |s| policy.check(&Uri::parse(s).unwrap());
+        // fullwidth digits map to ASCII digits
+        assert_eq!(
+            f("http://%EF%BC%91%EF%BC%92%EF%BC%97.0.0.1/"),
+            Err(Violation::LegacyIpv4)
+        );
+        assert_eq!(
+            policy.check_host(Host::RegName("\u{FF11}\u{FF12}\u{FF17}.0.0.1")),
+            Err(Violation::LegacyIpv4)
+        );
+        assert_eq!(
+            policy.check_host(Host::RegName("0x7f\u{3002}1")),
+            Err(Violation::LegacyIpv4)
+        );
+        assert_eq!(f("http://b%C3%BCcher.example/"), Ok(()));
+        assert_eq!(f("http://%FF.example/"), Err(Violation::InvalidHost));
+        assert_eq!(f("http://a%20b.example/"), Err(Violation::InvalidHost));
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_check_percent_encoded() {
+        let policy = Policy::default();
+        let f = |s| policy.check(&Uri::parse(s).unwrap());
+        assert_eq!(
+            f("http://%EF%BC%91%EF%BC%92%EF%BC%97.0.0.1/"),
+            Err(Violation::InvalidHost)
+        );
+        assert_eq!(
+            f("http://b%C3%BCcher.example/"),
+            Err(Violation::InvalidHost)
+        );
+    }
+
+    #[test]
+    fn test_check_addr() {
+        let policy = Policy {
+            allow_ip_hosts: true,
+            ports: None,
+            ..Policy::default()
+        };
+        let f = |s| policy.check(&Uri::parse(s).unwrap());
+        let blocked = |addr: &str, range| {
+            Err(Violation::BlockedAddress {
+                addr: addr.parse().unwrap(),
+                range,
+            })
+        };
+        assert_eq!(f("http://93.184.216.34/"), Ok(()));
+        assert_eq!(f("http://[2001:db8::1]:8080/"), Ok(()));
+        assert_eq!(
+            f("http://127.0.0.1/"),
+            blocked("127.0.0.1", IpRange::Loopback)
+        );
+        assert_eq!(f("http://[::1]/"), blocked("::1", IpRange::Loopback));
+        assert_eq!(f("http://10.1.2.3/"), blocked("10.1.2.3", IpRange::Private));
+        assert_eq!(
+            f("http://172.31.0.1/"),
+            blocked("172.31.0.1", IpRange::Private)
+        );
+        assert_eq!(
+            f("http://192.168.1.1/"),
+            blocked("192.168.1.1", IpRange::Private)
+        );
+        assert_eq!(
+            f("http://169.254.169.254/"),
+            blocked("169.254.169.254", IpRange::LinkLocal)
+        );
+        assert_eq!(
+            f("http://[fe80::1]/"),
+            blocked("fe80::1", IpRange::LinkLocal)
+        );
+        assert_eq!(
+            f("http://0.0.0.0/"),
+            blocked("0.0.0.0", IpRange::Unspecified)
+        );
+        assert_eq!(f("http://[::]/"), blocked("::", IpRange::Unspecified));
+        assert_eq!(
+            f("http://[fd00::1]/"),
+            blocked("fd00::1", IpRange::UniqueLocal)
+        );
+        assert_eq!(
+            f("http://[::ffff:127.0.0.1]/"),
+            blocked("::ffff:127.0.0.1", IpRange::Ipv4Mapped)
+        );
+        assert_eq!(f("http://0x7f.1/"), Err(Violation::LegacyIpv4));
+
+        // without the IPv4-mapped range the embedded address is still checked
+        let policy = Policy {
+            blocked: &[
+                IpRange::Loopback,
+                IpRange::Cidr(IpAddr::V4(Ipv4Addr::new(100, 64, 0, 0)), 10),
+            ],
+            ..policy
+        };
+        let f = |s| policy.check(&Uri::parse(s).unwrap());
+        assert_eq!(
+            f("http://[::ffff:127.0.0.1]/"),
+            blocked("::ffff:127.0.0.1", IpRange::Loopback)
+        );
+        assert_eq!(
+            f("http://100.127.255.255/"),
+            blocked(
+                "100.127.255.255",
+                IpRange::Cidr(IpAddr::V4(Ipv4Addr::new(100, 64, 0, 0)), 10)
+            )
+        );
+        assert_eq!(f("http://100.128.0.0/"), Ok(()));
+        assert_eq!(f("http://10.0.0.1/"), Ok(()));
+        assert_eq!(
+            policy.check_addr(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            blocked("::1", IpRange::Loopback)
+        );
+    }
+}
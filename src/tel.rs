@@ -0,0 +1,523 @@
+//! The `tel` URI scheme
+//!
+//! ```text
+//! telephone-uri        = "tel:" telephone-subscriber
+//! telephone-subscriber = global-number / local-number
+//! global-number        = global-number-digits *par
+//! local-number         = local-number-digits *par context *par
+//! par                  = parameter / extension / isdn-subaddress
+//! isdn-subaddress      = ";isub=" 1*uric
+//! extension            = ";ext=" 1*phonedigit
+//! context              = ";phone-context=" descriptor
+//! descriptor           = domainname / global-number-digits
+//! global-number-digits = "+" *phonedigit DIGIT *phonedigit
+//! local-number-digits  = *phonedigit-hex (HEXDIG / "*" / "#")*phonedigit-hex
+//! parameter            = ";" pname ["=" pvalue ]
+//! pname                = 1*( alphanum / "-" )
+//! pvalue               = 1*paramchar
+//! paramchar            = param-unreserved / unreserved / pct-encoded
+//! param-unreserved     = "[" / "]" / "/" / ":" / "&" / "+" / "$"
+//! unreserved           = alphanum / mark
+//! mark                 = "-" / "_" / "." / "!" / "~" / "*" / "'" / "(" / ")"
+//! phonedigit           = DIGIT / [ visual-separator ]
+//! phonedigit-hex       = HEXDIG / "*" / "#" / [ visual-separator ]
+//! visual-separator     = "-" / "." / "(" / ")"
+//! ```
+//!
+//! `#` is percent-encoded as `%23` in a URI. `unreserved` is the RFC 2396 rule, which includes the marks that
+//! RFC 3986 moved to `sub-delims`. A parameter name must not repeat.
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc3966#section-3>
+
+use crate::{DnsName, ParseError, Uri};
+
+/// An error returned when a URI is not a `tel` URI.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TelError {
+    /// The input is not a `URI`.
+    Syntax(ParseError),
+    /// The scheme is not `tel`.
+    Scheme,
+    /// The URI has an authority, a query or a fragment.
+    Component,
+    /// The `global-number-digits` or the `local-number-digits` are invalid.
+    Number,
+    /// The parameter at the byte offset is invalid or repeated.
+    Parameter(usize),
+    /// A local number has no `phone-context`, or a global number has one.
+    PhoneContext,
+}
+
+impl core::fmt::Display for TelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TelError::Syntax(e) => write!(f, "{}", e),
+            TelError::Scheme => f.write_str("scheme is not tel"),
+            TelError::Component => f.write_str("tel URI with an authority, a query or a fragment"),
+            TelError::Number => f.write_str("invalid telephone number"),
+            TelError::Parameter(offset) => write!(f, "invalid parameter at offset {}", offset),
+            TelError::PhoneContext => {
+                f.write_str("phone-context is required for local numbers only")
+            }
+        }
+    }
+}
+
+impl core::error::Error for TelError {}
+
+/// A parsed `tel` URI.
+///
+/// The number and the parameters are returned as written, not percent-decoded.
+///
+/// ```
+/// use nom_uri::tel::Tel;
+///
+/// let tel = Tel::parse("tel:+1-816-555-1212;ext=1234").unwrap();
+/// assert!(tel.is_global());
+/// assert_eq!(tel.number(), "+1-816-555-1212");
+/// assert!(tel.digits().eq("18165551212".chars()));
+/// assert_eq!(tel.extension(), Some("1234"));
+///
+/// let tel = Tel::parse("tel:7042;phone-context=example.com").unwrap();
+/// assert!(!tel.is_global());
+/// assert_eq!(tel.phone_context(), Some("example.com"));
+/// assert!(tel.e164().is_none());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Tel<'a> {
+    uri: Uri<'a>,
+    number: &'a str,
+    parameters: &'a str,
+    extension: Option<&'a str>,
+    isdn_subaddress: Option<&'a str>,
+    phone_context: Option<&'a str>,
+}
+
+impl<'a> Tel<'a> {
+    /// Parses `s` as a `tel` URI.
+    pub fn parse(s: &'a str) -> Result<Self, TelError> {
+        Self::try_from(Uri::parse(s).map_err(TelError::Syntax)?)
+    }
+
+    pub fn as_uri(&self) -> Uri<'a> {
+        self.uri
+    }
+
+    /// Returns whether the number is a `global-number`, which begins with `+`.
+    pub fn is_global(&self) -> bool {
+        self.number.starts_with('+')
+    }
+
+    /// Returns the `global-number-digits` or the `local-number-digits` as written.
+    pub fn number(&self) -> &'a str {
+        self.number
+    }
+
+    /// Returns the digits of the number without `+` and the visual separators. `%23` is returned as `#`.
+    pub fn digits(&self) -> Digits<'a> {
+        Digits::new(self.number)
+    }
+
+    /// Returns the digits of a global number, which is an E.164 number: the country code followed by the
+    /// national number.
+    ///
+    /// ```
+    /// use nom_uri::tel::Tel;
+    ///
+    /// let tel = Tel::parse("tel:+44-20-7946-0000").unwrap();
+    /// assert!(tel.e164().unwrap().eq("442079460000".chars()));
+    /// ```
+    pub fn e164(&self) -> Option<Digits<'a>> {
+        self.is_global().then(|| self.digits())
+    }
+
+    /// Returns the value of `;ext=`.
+    pub fn extension(&self) -> Option<&'a str> {
+        self.extension
+    }
+
+    /// Returns the value of `;isub=`.
+    pub fn isdn_subaddress(&self) -> Option<&'a str> {
+        self.isdn_subaddress
+    }
+
+    /// Returns the value of `;phone-context=`, which is present exactly for local numbers.
+    pub fn phone_context(&self) -> Option<&'a str> {
+        self.phone_context
+    }
+
+    /// Returns all the parameters in order, including `ext`, `isub` and `phone-context`.
+    pub fn parameters(&self) -> Parameters<'a> {
+        Parameters {
+            rest: self.parameters,
+        }
+    }
+
+    /// Returns the value of the parameter named `name`, ignoring ASCII case. A parameter without a value
+    /// returns `Some("")`.
+    pub fn parameter(&self, name: &str) -> Option<&'a str> {
+        self.parameters()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.unwrap_or(""))
+    }
+
+    /// Compares by the rules of RFC 3966 section 4: the numbers without the visual separators, the
+    /// `phone-context` as a domain name or as digits, and the other parameters regardless of their order, all
+    /// ignoring case.
+    ///
+    /// ```
+    /// use nom_uri::tel::Tel;
+    ///
+    /// let a = Tel::parse("tel:+1-816-555-1212;ext=12;foo=bar").unwrap();
+    /// let b = Tel::parse("TEL:+1(816)5551212;FOO=BAR;ext=1-2").unwrap();
+    /// assert!(a.equivalent(&b));
+    /// let c = Tel::parse("tel:+1-816-555-1212").unwrap();
+    /// assert!(!a.equivalent(&c));
+    /// ```
+    ///
+    /// <https://datatracker.ietf.org/doc/html/rfc3966#section-4>
+    pub fn equivalent(&self, other: &Tel<'_>) -> bool {
+        self.is_global() == other.is_global()
+            && self
+                .digits()
+                .map(|c| c.to_ascii_lowercase())
+                .eq(other.digits().map(|c| c.to_ascii_lowercase()))
+            && self.parameters().count() == other.parameters().count()
+            && self.parameters().all(|(name, value)| {
+                other
+                    .parameters()
+                    .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                    .is_some_and(|(_, v)| parameter_eq(name, value, v))
+            })
+    }
+}
+
+impl<'a> TryFrom<Uri<'a>> for Tel<'a> {
+    type Error = TelError;
+
+    fn try_from(uri: Uri<'a>) -> Result<Self, Self::Error> {
+        if !uri.scheme().eq_ignore_ascii_case("tel") {
+            return Err(TelError::Scheme);
+        }
+        if uri.authority().is_some() || uri.query().is_some() || uri.fragment().is_some() {
+            return Err(TelError::Component);
+        }
+        let path = uri.path();
+        let (number, parameters) = path.split_at(path.find(';').unwrap_or(path.len()));
+        let valid = match number.strip_prefix('+') {
+            Some(digits) => is_phonedigits(digits),
+            None => {
+                let mut rest = number;
+                let mut valid = false;
+                while let Some(c) = rest.chars().next() {
+                    if let Some(r) = rest.strip_prefix("%23") {
+                        rest = r;
+                        valid = true;
+                        continue;
+                    }
+                    match c {
+                        '0'..='9' | 'A'..='F' | 'a'..='f' | '*' => valid = true,
+                        '-' | '.' | '(' | ')' => {}
+                        _ => return Err(TelError::Number),
+                    }
+                    rest = &rest[1..];
+                }
+                valid
+            }
+        };
+        if !valid {
+            return Err(TelError::Number);
+        }
+
+        let mut tel = Self {
+            uri,
+            number,
+            parameters,
+            extension: None,
+            isdn_subaddress: None,
+            phone_context: None,
+        };
+        let mut offset = uri.scheme().len() + 1 + number.len();
+        for (i, (name, value)) in tel.parameters().enumerate() {
+            let error = TelError::Parameter(offset);
+            offset += 1 + name.len() + value.map_or(0, |v| 1 + v.len());
+            if name.is_empty()
+                || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+                || value == Some("")
+                || tel
+                    .parameters()
+                    .take(i)
+                    .any(|(n, _)| n.eq_ignore_ascii_case(name))
+            {
+                return Err(error);
+            }
+            let field = if name.eq_ignore_ascii_case("ext") {
+                &mut tel.extension
+            } else if name.eq_ignore_ascii_case("isub") {
+                &mut tel.isdn_subaddress
+            } else if name.eq_ignore_ascii_case("phone-context") {
+                &mut tel.phone_context
+            } else {
+                let is_paramchar =
+                    |b: u8| b.is_ascii_alphanumeric() || b"-_.!~*'()[]/:&+$%".contains(&b);
+                if !value.is_none_or(|v| v.bytes().all(is_paramchar)) {
+                    return Err(error);
+                }
+                continue;
+            };
+            let Some(value) = value else {
+                return Err(error);
+            };
+            let valid = if name.eq_ignore_ascii_case("ext") {
+                is_phonedigits(value)
+            } else if name.eq_ignore_ascii_case("phone-context") {
+                match value.strip_prefix('+') {
+                    Some(digits) => is_phonedigits(digits),
+                    None => is_domainname(value),
+                }
+            } else {
+                true
+            };
+            if !valid {
+                return Err(error);
+            }
+            *field = Some(value);
+        }
+        if tel.is_global() == tel.phone_context.is_some() {
+            return Err(TelError::PhoneContext);
+        }
+        Ok(tel)
+    }
+}
+
+impl core::fmt::Display for Tel<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.uri)
+    }
+}
+
+/// `1*phonedigit` with at least one `DIGIT`.
+fn is_phonedigits(s: &str) -> bool {
+    s.bytes()
+        .all(|b| b.is_ascii_digit() || b"-.()".contains(&b))
+        && s.bytes().any(|b| b.is_ascii_digit())
+}
+
+/// `domainname = *( domainlabel "." ) toplabel [ "." ]`, where `toplabel` begins with `ALPHA`.
+fn is_domainname(s: &str) -> bool {
+    DnsName::new(s).is_ok_and(|name| {
+        name.labels()
+            .next_back()
+            .is_some_and(|label| label.starts_with(|c: char| c.is_ascii_alphabetic()))
+    })
+}
+
+fn parameter_eq(name: &str, a: Option<&str>, b: Option<&str>) -> bool {
+    let (Some(a), Some(b)) = (a, b) else {
+        return a.is_none() && b.is_none();
+    };
+    if name.eq_ignore_ascii_case("ext")
+        || (name.eq_ignore_ascii_case("phone-context") && a.starts_with('+') && b.starts_with('+'))
+    {
+        Digits::new(a).eq(Digits::new(b))
+    } else if name.eq_ignore_ascii_case("phone-context") {
+        let a = a.strip_suffix('.').unwrap_or(a);
+        let b = b.strip_suffix('.').unwrap_or(b);
+        a.eq_ignore_ascii_case(b)
+    } else {
+        Digits::decoded(a)
+            .map(|c| c.to_ascii_lowercase())
+            .eq(Digits::decoded(b).map(|c| c.to_ascii_lowercase()))
+    }
+}
+
+/// An iterator over the digits of a telephone number. See [`Tel::digits`].
+#[derive(Clone, Debug)]
+pub struct Digits<'a> {
+    rest: &'a str,
+    skip_separators: bool,
+}
+
+impl<'a> Digits<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            rest: s,
+            skip_separators: true,
+        }
+    }
+
+    /// Returns the percent-decoded bytes of `s` as `char`s.
+    fn decoded(s: &'a str) -> Self {
+        Self {
+            rest: s,
+            skip_separators: false,
+        }
+    }
+}
+
+impl Iterator for Digits<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let b = *self.rest.as_bytes().first()?;
+            let c = match b {
+                // a URI only has `%` in `pct-encoded` triplets
+                b'%' => {
+                    let b = u8::from_str_radix(&self.rest[1..3], 16).ok()?;
+                    self.rest = &self.rest[3..];
+                    char::from(b)
+                }
+                _ => {
+                    self.rest = &self.rest[1..];
+                    char::from(b)
+                }
+            };
+            if !(self.skip_separators && matches!(c, '+' | '-' | '.' | '(' | ')')) {
+                return Some(c);
+            }
+        }
+    }
+}
+
+/// An iterator over the `;` parameters of a [`Tel`], with their values if any.
+#[derive(Clone, Debug)]
+pub struct Parameters<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Parameters<'a> {
+    type Item = (&'a str, Option<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.strip_prefix(';')?;
+        let (parameter, rest) = rest.split_at(rest.find(';').unwrap_or(rest.len()));
+        self.rest = rest;
+        Some(match parameter.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (parameter, None),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let f = |s| {
+            Tel::parse(s).map(|t| {
+                (
+                    t.number(),
+                    t.extension(),
+                    t.isdn_subaddress(),
+                    t.phone_context(),
+                )
+            })
+        };
+        assert_eq!(
+            f("tel:+1-201-555-0123"),
+            Ok(("+1-201-555-0123", None, None, None))
+        );
+        assert_eq!(
+            f("tel:7042;phone-context=example.com"),
+            Ok(("7042", None, None, Some("example.com")))
+        );
+        assert_eq!(
+            f("tel:863-1234;phone-context=+1-914-555"),
+            Ok(("863-1234", None, None, Some("+1-914-555")))
+        );
+        assert_eq!(
+            f("tel:+1-212-555-0101;isub=1411;ext=22;x-a=b"),
+            Ok(("+1-212-555-0101", Some("22"), Some("1411"), None))
+        );
+        assert_eq!(
+            f("tel:*21%23;PHONE-CONTEXT=ex.com."),
+            Ok(("*21%23", None, None, Some("ex.com.")))
+        );
+        assert_eq!(f("tel:+1;flag"), Ok(("+1", None, None, None)));
+        assert_eq!(f("tel:+1;a=b!"), Ok(("+1", None, None, None)));
+        assert_eq!(f("tel:+1;a=(1)"), Ok(("+1", None, None, None)));
+        assert_eq!(f("tel:+1;a=*"), Ok(("+1", None, None, None)));
+        assert_eq!(f("tel:+1;a='x'"), Ok(("+1", None, None, None)));
+
+        assert_eq!(f("tel://+1"), Err(TelError::Component));
+        assert_eq!(f("tel:+1?a"), Err(TelError::Component));
+        assert_eq!(f("tel:+1#a"), Err(TelError::Component));
+        assert_eq!(f("sip:+1"), Err(TelError::Scheme));
+        assert_eq!(f("tel:"), Err(TelError::Number));
+        assert_eq!(f("tel:+"), Err(TelError::Number));
+        assert_eq!(f("tel:+-"), Err(TelError::Number));
+        assert_eq!(f("tel:+1a"), Err(TelError::Number));
+        assert_eq!(f("tel:(-)"), Err(TelError::Number));
+        assert_eq!(f("tel:12g;phone-context=a.b"), Err(TelError::Number));
+        assert_eq!(f("tel:1234"), Err(TelError::PhoneContext));
+        assert_eq!(f("tel:+1;phone-context=a.b"), Err(TelError::PhoneContext));
+        assert_eq!(f("tel:+1;ext=a"), Err(TelError::Parameter(6)));
+        assert_eq!(f("tel:+1;ext"), Err(TelError::Parameter(6)));
+        assert_eq!(f("tel:+1;ext=1;ext=2"), Err(TelError::Parameter(12)));
+        assert_eq!(f("tel:+1;a=b;="), Err(TelError::Parameter(10)));
+        assert_eq!(f("tel:+1;a="), Err(TelError::Parameter(6)));
+        assert_eq!(f("tel:+1;a_b"), Err(TelError::Parameter(6)));
+        assert_eq!(f("tel:+1;a=b,c"), Err(TelError::Parameter(6)));
+        assert_eq!(f("tel:+1;a=1;A=2"), Err(TelError::Parameter(10)));
+        assert_eq!(f("tel:+1;a;b;a"), Err(TelError::Parameter(10)));
+        assert_eq!(f("tel:1;phone-context=a.1"), Err(TelError::Parameter(5)));
+        assert_eq!(f("tel:1;phone-context=a..b"), Err(TelError::Parameter(5)));
+    }
+
+    #[test]
+    fn test_digits() {
+        let f = |s| Tel::parse(s).unwrap().digits().collect::<Vec<_>>();
+        assert_eq!(
+            f("tel:+1-(201).555-0123"),
+            "12015550123".chars().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            f("tel:*2A%23;phone-context=a.b"),
+            "*2A#".chars().collect::<Vec<_>>()
+        );
+        let tel = Tel::parse("tel:+1;x-a=%41;y").unwrap();
+        assert_eq!(
+            tel.parameters().collect::<Vec<_>>(),
+            [("x-a", Some("%41")), ("y", None)]
+        );
+        assert_eq!(tel.parameter("X-A"), Some("%41"));
+        assert_eq!(tel.parameter("y"), Some(""));
+        assert_eq!(tel.parameter("z"), None);
+    }
+
+    #[test]
+    fn test_equivalent() {
+        let f = |a, b| Tel::parse(a).unwrap().equivalent(&Tel::parse(b).unwrap());
+        assert!(f("tel:+1-201-555-0123", "tel:+1.201.555.0123"));
+        assert!(f("tel:+1-201-555-0123", "TEL:+12015550123"));
+        assert!(!f("tel:+1-201-555-0123", "tel:+1-201-555-0124"));
+        assert!(f(
+            "tel:7042;phone-context=Example.COM",
+            "tel:7042;phone-context=example.com."
+        ));
+        assert!(!f(
+            "tel:7042;phone-context=example.com",
+            "tel:7042;phone-context=example.org"
+        ));
+        assert!(f(
+            "tel:7042;phone-context=+1-2",
+            "tel:7042;phone-context=+12"
+        ));
+        assert!(!f("tel:+17042", "tel:17042;phone-context=a.b"));
+        assert!(f(
+            "tel:a*%23;phone-context=x.y",
+            "tel:A-*%23;phone-context=x.y"
+        ));
+        assert!(f("tel:+1;a=%41b;c", "tel:+1;c;A=aB"));
+        assert!(!f("tel:+1;a=b", "tel:+1;a"));
+        assert!(!f("tel:+1;a=b", "tel:+1"));
+        assert!(!f("tel:+1;a=b", "tel:+1;b=b"));
+        assert!(f("tel:+1;isub=Ab%2f", "tel:+1;isub=aB%2F"));
+        assert!(f("tel:+1;a=1;b=2", "tel:+1;b=2;a=1"));
+        assert!(!f("tel:+1;a=1;b=2", "tel:+1;a=1;c=2"));
+        assert!(!f("tel:+1;a=1;c=2", "tel:+1;a=1;b=2"));
+    }
+}
@@ -0,0 +1,212 @@
+use alloc::{borrow::ToOwned, format, string::String};
+
+use crate::{
+    Host, IdnaError, ParseError, ParseOptions, Redacted, Uri,
+    typed_uri::{Components, parse_components},
+};
+
+/// A parsed URI owning its string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UriBuf {
+    source: String,
+    components: Components,
+}
+
+impl UriBuf {
+    /// Parses `s` as a whole `URI`.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with_options(s, &ParseOptions::default())
+    }
+
+    /// Parses `s` as a whole `URI`, failing as soon as a limit of `options` is exceeded.
+    pub fn parse_with_options(s: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        Ok(Self {
+            components: parse_components(s, options)?,
+            source: s.to_owned(),
+        })
+    }
+
+    pub fn as_uri(&self) -> Uri<'_> {
+        Uri::from_parts(&self.source, self.components)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// See [`Uri::redacted`].
+    pub fn redacted(&self) -> Redacted<'_> {
+        self.as_uri().redacted()
+    }
+
+    pub fn into_string(self) -> String {
+        self.source
+    }
+
+    /// Returns a copy with the `reg-name` host converted to its ASCII (A-label) form.
+    ///
+    /// A URI without a host or with an empty `reg-name`, such as `file:///x`, is returned unchanged.
+    /// See [`Host::to_ascii`](crate::Host::to_ascii).
+    pub fn to_ascii_host(&self) -> Result<UriBuf, IdnaError> {
+        let Some(host) = self.converted_host() else {
+            return Ok(self.clone());
+        };
+        let s = self.replace_host(&host.to_ascii()?);
+        // an A-label is a valid reg-name
+        Ok(UriBuf::parse(&s).expect("URI with an ASCII host"))
+    }
+
+    /// Returns the URI with the `reg-name` host converted to its Unicode form, for display.
+    ///
+    /// The result is not a URI when the host contains non-ASCII characters.
+    /// See [`Host::to_unicode`](crate::Host::to_unicode).
+    pub fn to_unicode_host(&self) -> Result<String, IdnaError> {
+        let Some(host) = self.converted_host() else {
+            return Ok(self.source.clone());
+        };
+        Ok(self.replace_host(&host.to_unicode()?))
+    }
+
+    /// The host to convert, if any. An empty `reg-name` has no labels.
+    fn converted_host(&self) -> Option<Host<'_>> {
+        self.as_uri()
+            .host()
+            .filter(|host| *host != Host::RegName(""))
+    }
+
+    fn replace_host(&self, host: &str) -> String {
+        let (start, end) = self.components.authority.expect("URI with a host").host;
+        format!("{}{}{}", &self.source[..start], host, &self.source[end..])
+    }
+}
+
+impl From<Uri<'_>> for UriBuf {
+    fn from(uri: Uri<'_>) -> Self {
+        Self {
+            source: uri.as_str().to_owned(),
+            components: *uri.components(),
+        }
+    }
+}
+
+impl core::str::FromStr for UriBuf {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl core::fmt::Display for UriBuf {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for UriBuf {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UriBuf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = UriBuf;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a URI string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                UriBuf::parse(s).map_err(|e| E::custom(format_args!("invalid URI: {}", e)))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let uri = UriBuf::parse("http://example.com/path?q#f").unwrap();
+        assert_eq!(uri.as_str(), "http://example.com/path?q#f");
+        assert_eq!(uri.as_uri().host_str(), Some("example.com"));
+        assert_eq!(uri.as_uri().path(), "/path");
+        assert_eq!(
+            "http://example.com/"
+                .parse::<UriBuf>()
+                .map(|u| u.to_string()),
+            Ok("http://example.com/".to_owned())
+        );
+        assert_eq!(UriBuf::parse("http//example.com"), Err(ParseError::new(4)));
+        assert_eq!(
+            UriBuf::from(Uri::parse("a:b").unwrap()),
+            UriBuf::parse("a:b").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_ascii_host() {
+        let uri = UriBuf::parse("http://b%C3%BCcher.example/").unwrap();
+        let ascii = uri.to_ascii_host().unwrap();
+        assert_eq!(ascii.as_str(), "http://xn--bcher-kva.example/");
+        assert_eq!(ascii.as_uri().host_str(), Some("xn--bcher-kva.example"));
+
+        let uri = UriBuf::parse("http://user@EXAMPLE.com:80/p").unwrap();
+        assert_eq!(
+            uri.to_ascii_host().map(|u| u.into_string()),
+            Ok("http://user@example.com:80/p".to_owned())
+        );
+
+        let uri = UriBuf::parse("mailto:a@example.com").unwrap();
+        assert_eq!(uri.to_ascii_host(), Ok(uri.clone()));
+
+        let uri = UriBuf::parse("file:///x").unwrap();
+        assert_eq!(uri.to_ascii_host(), Ok(uri.clone()));
+
+        let uri = UriBuf::parse("http://a_b.example/").unwrap();
+        assert_eq!(
+            uri.to_ascii_host(),
+            Err(IdnaError::DisallowedCharacter { offset: 1, c: '_' })
+        );
+    }
+
+    #[test]
+    fn test_to_unicode_host() {
+        let uri = UriBuf::parse("http://xn--bcher-kva.example/").unwrap();
+        assert_eq!(
+            uri.to_unicode_host().as_deref(),
+            Ok("http://bücher.example/")
+        );
+
+        let uri = UriBuf::parse("file:///x").unwrap();
+        assert_eq!(uri.to_unicode_host().as_deref(), Ok("file:///x"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let uri = serde_json::from_str::<UriBuf>(r#""http:\/\/example.com\/v1""#).unwrap();
+        assert_eq!(uri, UriBuf::parse("http://example.com/v1").unwrap());
+        assert_eq!(
+            serde_json::to_string(&uri).unwrap(),
+            r#""http://example.com/v1""#
+        );
+
+        let e = serde_json::from_str::<UriBuf>(r#""1http:""#).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid URI: invalid syntax at offset 0 at line 1 column 8"
+        );
+        assert!(serde_json::from_str::<UriBuf>("1").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! Serde helpers for a `String` that holds a `URI-reference`
+//!
+//! [`Uri`](crate::Uri) and [`UriBuf`](crate::UriBuf) only deserialize an absolute `URI`. A field that may also
+//! hold a relative reference, such as a redirect target, can be validated with
+//! `#[serde(with = "nom_uri::uri_reference")]`:
+//!
+//! ```
+//! let mut de = serde_json::Deserializer::from_str(r#""../callback?x=1""#);
+//! assert_eq!(nom_uri::uri_reference::deserialize(&mut de).unwrap(), "../callback?x=1");
+//!
+//! let mut de = serde_json::Deserializer::from_str(r#""/a b""#);
+//! assert!(nom_uri::uri_reference::deserialize(&mut de).is_err());
+//! ```
+//!
+//! ```text
+//! URI-reference = URI / relative-ref
+//! ```
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc3986#section-4.1>
+
+use alloc::string::String;
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{parse_error::parse_all, parser};
+
+pub fn serialize<S: Serializer>(s: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(s)
+}
+
+/// Deserializes a string and validates it as a whole `URI-reference`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    parse_all(parser::uri_reference, &s)
+        .map_err(|e| serde::de::Error::custom(format_args!("invalid URI reference: {}", e)))?;
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let f = |json| deserialize(&mut serde_json::Deserializer::from_str(json));
+        assert_eq!(f(r#""/callback""#).unwrap(), "/callback");
+        assert_eq!(f(r#""../x""#).unwrap(), "../x");
+        assert_eq!(f(r#""""#).unwrap(), "");
+        assert_eq!(f(r#""?q#f""#).unwrap(), "?q#f");
+        assert_eq!(
+            f(r#""https:\/\/example.com\/cb""#).unwrap(),
+            "https://example.com/cb"
+        );
+
+        assert_eq!(
+            f(r#""/a b""#).unwrap_err().to_string(),
+            "invalid URI reference: invalid syntax at offset 2"
+        );
+        assert!(f(r#""a:b:[""#).is_err());
+        assert!(f("1").is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut json = vec![];
+        serialize("../x", &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(json, br#""../x""#);
+    }
+}
@@ -0,0 +1,487 @@
+//! Uniform Resource Names
+//!
+//! ```text
+//! namestring    = assigned-name
+//!                 [ rq-components ]
+//!                 [ "#" f-component ]
+//! assigned-name = "urn" ":" NID ":" NSS
+//! NID           = (alphanum) 0*30(ldh) (alphanum)
+//! ldh           = alphanum / "-"
+//! NSS           = pchar *(pchar / "/")
+//! rq-components = [ "?+" r-component ]
+//!                 [ "?=" q-component ]
+//! r-component   = pchar *( pchar / "/" / "?" )
+//! q-component   = pchar *( pchar / "/" / "?" )
+//! f-component   = fragment
+//! ```
+//!
+//! <https://datatracker.ietf.org/doc/html/rfc8141#section-2>
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{ParseError, Uri};
+
+/// An error returned when a URI is not a URN.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UrnError {
+    /// The input is not a `URI`.
+    Syntax(ParseError),
+    /// The scheme is not `urn`.
+    Scheme,
+    /// The `NID` is invalid.
+    Nid,
+    /// The `NSS` is empty or begins with `/`, or the URI has an authority.
+    Nss,
+    /// The query is not `rq-components`.
+    RqComponents,
+    /// The `NSS` is rejected by the [`Namespace`] of the `NID`.
+    Namespace,
+}
+
+impl core::fmt::Display for UrnError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UrnError::Syntax(e) => write!(f, "{}", e),
+            UrnError::Scheme => f.write_str("scheme is not urn"),
+            UrnError::Nid => f.write_str("invalid namespace identifier"),
+            UrnError::Nss => f.write_str("invalid namespace specific string"),
+            UrnError::RqComponents => f.write_str("invalid r-component or q-component"),
+            UrnError::Namespace => f.write_str("rejected by the namespace"),
+        }
+    }
+}
+
+impl core::error::Error for UrnError {}
+
+/// A parsed URN.
+///
+/// ```
+/// use nom_uri::urn::Urn;
+///
+/// let urn = Urn::parse("urn:example:a%2fb?+res?=q=1#frag").unwrap();
+/// assert_eq!(urn.nid(), "example");
+/// assert_eq!(urn.nss(), "a%2fb");
+/// assert_eq!(urn.r_component(), Some("res"));
+/// assert_eq!(urn.q_component(), Some("q=1"));
+/// assert_eq!(urn.f_component(), Some("frag"));
+/// assert!(urn.equivalent(&Urn::parse("URN:EXAMPLE:a%2Fb").unwrap()));
+/// assert!(!urn.equivalent(&Urn::parse("urn:example:A%2Fb").unwrap()));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Urn<'a> {
+    uri: Uri<'a>,
+    nid: &'a str,
+    nss: &'a str,
+    r_component: Option<&'a str>,
+    q_component: Option<&'a str>,
+}
+
+impl<'a> Urn<'a> {
+    /// Parses `s` as a `namestring`.
+    pub fn parse(s: &'a str) -> Result<Self, UrnError> {
+        Self::try_from(Uri::parse(s).map_err(UrnError::Syntax)?)
+    }
+
+    pub fn as_uri(&self) -> Uri<'a> {
+        self.uri
+    }
+
+    /// Returns the namespace identifier, as written.
+    pub fn nid(&self) -> &'a str {
+        self.nid
+    }
+
+    /// Returns the namespace specific string, not percent-decoded.
+    pub fn nss(&self) -> &'a str {
+        self.nss
+    }
+
+    /// Returns the `r-component`, passed to the resolver, without the leading `?+`.
+    pub fn r_component(&self) -> Option<&'a str> {
+        self.r_component
+    }
+
+    /// Returns the `q-component`, passed to the named resource, without the leading `?=`.
+    pub fn q_component(&self) -> Option<&'a str> {
+        self.q_component
+    }
+
+    pub fn f_component(&self) -> Option<&'a str> {
+        self.uri.fragment()
+    }
+
+    /// Whether the URNs are equivalent: their `NID`s are equal ignoring case, and their `NSS`s are equal ignoring
+    /// the case of the hex digits of `pct-encoded` triplets. The r-, q- and f-components are not compared.
+    ///
+    /// <https://datatracker.ietf.org/doc/html/rfc8141#section-3>
+    pub fn equivalent(&self, other: &Urn<'_>) -> bool {
+        self.nid.eq_ignore_ascii_case(other.nid)
+            && self.nss.len() == other.nss.len()
+            && normalized_nss(self.nss).eq(normalized_nss(other.nss))
+    }
+
+    /// Returns the `assigned-name` in the normal form of [`equivalent`](Self::equivalent): `urn`, the `NID` in
+    /// lowercase, and the `NSS` with uppercase hex digits.
+    #[cfg(feature = "alloc")]
+    pub fn normalized(&self) -> String {
+        let mut s = String::with_capacity(5 + self.nid.len() + self.nss.len());
+        s.push_str("urn:");
+        s.extend(self.nid.chars().map(|c| c.to_ascii_lowercase()));
+        s.push(':');
+        s.extend(normalized_nss(self.nss).map(char::from));
+        s
+    }
+
+    /// Checks the `NSS` with the [`Namespace`] in `namespaces` whose `NID` matches. A URN of another namespace
+    /// passes.
+    ///
+    /// ```
+    /// use nom_uri::urn::{self, Urn, UrnError};
+    ///
+    /// let urn = Urn::parse("urn:isbn:0-395-36341-1").unwrap();
+    /// assert_eq!(urn.validate(urn::NAMESPACES), Ok(()));
+    /// let urn = Urn::parse("urn:isbn:0-395-36341-2").unwrap();
+    /// assert_eq!(urn.validate(urn::NAMESPACES), Err(UrnError::Namespace));
+    /// ```
+    pub fn validate(&self, namespaces: &[&dyn Namespace]) -> Result<(), UrnError> {
+        match namespaces
+            .iter()
+            .find(|ns| ns.nid().eq_ignore_ascii_case(self.nid))
+        {
+            Some(ns) if !ns.is_valid(self.nss) => Err(UrnError::Namespace),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Uri<'a>> for Urn<'a> {
+    type Error = UrnError;
+
+    fn try_from(uri: Uri<'a>) -> Result<Self, Self::Error> {
+        if !uri.scheme().eq_ignore_ascii_case("urn") {
+            return Err(UrnError::Scheme);
+        }
+        if uri.authority().is_some() {
+            return Err(UrnError::Nss);
+        }
+        let (nid, nss) = uri.path().split_once(':').ok_or(UrnError::Nid)?;
+        if !is_nid(nid) {
+            return Err(UrnError::Nid);
+        }
+        if nss.is_empty() || nss.starts_with('/') {
+            return Err(UrnError::Nss);
+        }
+        let (r_component, q_component) = match uri.query() {
+            None => (None, None),
+            Some(query) => {
+                let (r, q) = match query.strip_prefix('+') {
+                    Some(rq) => match rq.split_once("?=") {
+                        Some((r, q)) => (Some(r), Some(q)),
+                        None => (Some(rq), None),
+                    },
+                    None => (
+                        None,
+                        Some(query.strip_prefix('=').ok_or(UrnError::RqComponents)?),
+                    ),
+                };
+                // both begin with a `pchar`
+                if [r, q]
+                    .into_iter()
+                    .flatten()
+                    .any(|c| c.is_empty() || c.starts_with(['/', '?']))
+                {
+                    return Err(UrnError::RqComponents);
+                }
+                (r, q)
+            }
+        };
+        Ok(Self {
+            uri,
+            nid,
+            nss,
+            r_component,
+            q_component,
+        })
+    }
+}
+
+impl core::fmt::Display for Urn<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.uri)
+    }
+}
+
+fn is_nid(s: &str) -> bool {
+    let b = s.as_bytes();
+    (2..=32).contains(&b.len())
+        && b[0].is_ascii_alphanumeric()
+        && b[b.len() - 1].is_ascii_alphanumeric()
+        && b.iter().all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+}
+
+/// Returns the bytes of `nss` with the two bytes after each `%` in uppercase.
+fn normalized_nss(nss: &str) -> impl Iterator<Item = u8> + '_ {
+    let mut hex = 0;
+    nss.bytes().map(move |b| {
+        if hex > 0 {
+            hex -= 1;
+            b.to_ascii_uppercase()
+        } else {
+            if b == b'%' {
+                hex = 2;
+            }
+            b
+        }
+    })
+}
+
+/// The rules of a URN namespace, checked by [`Urn::validate`].
+pub trait Namespace {
+    /// The `NID`, compared ignoring case.
+    fn nid(&self) -> &str;
+
+    /// Whether `nss`, not percent-decoded, is valid in this namespace.
+    fn is_valid(&self, nss: &str) -> bool;
+}
+
+/// The namespaces of this module.
+pub const NAMESPACES: &[&dyn Namespace] = &[&Isbn, &Uuid, &Ietf];
+
+/// `urn:isbn`: an ISBN-10 or ISBN-13 with optional hyphens and a valid check digit.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc8254>
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Isbn;
+
+impl Namespace for Isbn {
+    fn nid(&self) -> &str {
+        "isbn"
+    }
+
+    fn is_valid(&self, nss: &str) -> bool {
+        if nss.starts_with('-') || nss.ends_with('-') || nss.contains("--") {
+            return false;
+        }
+        let mut digits = [0u32; 13];
+        let mut len = 0;
+        for (i, b) in nss.bytes().filter(|b| *b != b'-').enumerate() {
+            let digit = match b {
+                b'0'..=b'9' => u32::from(b - b'0'),
+                // the check digit of an ISBN-10
+                b'X' | b'x' if i == 9 => 10,
+                _ => return false,
+            };
+            match digits.get_mut(i) {
+                Some(d) => *d = digit,
+                None => return false,
+            }
+            len = i + 1;
+        }
+        let d = &digits[..len];
+        match len {
+            10 => {
+                d.iter()
+                    .zip((1..=10).rev())
+                    .map(|(d, w)| d * w)
+                    .sum::<u32>()
+                    % 11
+                    == 0
+            }
+            13 => {
+                (d.starts_with(&[9, 7, 8]) || d.starts_with(&[9, 7, 9]))
+                    && !d.contains(&10)
+                    && d.iter()
+                        .zip([1, 3].into_iter().cycle())
+                        .map(|(d, w)| d * w)
+                        .sum::<u32>()
+                        % 10
+                        == 0
+            }
+            _ => false,
+        }
+    }
+}
+
+/// `urn:uuid`: a UUID in its hex-and-dash form.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc9562#section-4>
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Uuid;
+
+impl Namespace for Uuid {
+    fn nid(&self) -> &str {
+        "uuid"
+    }
+
+    fn is_valid(&self, nss: &str) -> bool {
+        nss.len() == 36
+            && nss.bytes().enumerate().all(|(i, b)| match i {
+                8 | 13 | 18 | 23 => b == b'-',
+                _ => b.is_ascii_hexdigit(),
+            })
+    }
+}
+
+/// `urn:ietf`: `rfc:`, `fyi:`, `std:` and `bcp:` followed by a number, and `id:`, `mtg:` and `params:` followed by
+/// a name.
+///
+/// <https://datatracker.ietf.org/doc/html/rfc2648#section-2>
+/// <https://datatracker.ietf.org/doc/html/rfc3553#section-4>
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Ietf;
+
+impl Namespace for Ietf {
+    fn nid(&self) -> &str {
+        "ietf"
+    }
+
+    fn is_valid(&self, nss: &str) -> bool {
+        let Some((kind, rest)) = nss.split_once(':') else {
+            return false;
+        };
+        let kind = |k: &str| kind.eq_ignore_ascii_case(k);
+        if kind("rfc") || kind("fyi") || kind("std") || kind("bcp") {
+            !rest.is_empty() && !rest.starts_with('0') && rest.bytes().all(|b| b.is_ascii_digit())
+        } else if kind("id") || kind("mtg") || kind("params") {
+            !rest.is_empty()
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let f = |s| {
+            Urn::parse(s).map(|u| {
+                (
+                    u.nid(),
+                    u.nss(),
+                    u.r_component(),
+                    u.q_component(),
+                    u.f_component(),
+                )
+            })
+        };
+        assert_eq!(
+            f("urn:oasis:names:specification:docbook:dtd:xml:4.1.2"),
+            Ok((
+                "oasis",
+                "names:specification:docbook:dtd:xml:4.1.2",
+                None,
+                None,
+                None
+            ))
+        );
+        assert_eq!(
+            f("URN:ex-1:a/b?+r?x/?=q?=y#f"),
+            Ok(("ex-1", "a/b", Some("r?x/"), Some("q?=y"), Some("f")))
+        );
+        assert_eq!(f("urn:ex:a?=q"), Ok(("ex", "a", None, Some("q"), None)));
+        assert_eq!(f("urn:ex:a?+r"), Ok(("ex", "a", Some("r"), None, None)));
+        assert_eq!(f("urn:ex:a#"), Ok(("ex", "a", None, None, Some(""))));
+
+        assert_eq!(f("http:ex:a"), Err(UrnError::Scheme));
+        assert_eq!(f("urn:ex"), Err(UrnError::Nid));
+        assert_eq!(f("urn:e:a"), Err(UrnError::Nid));
+        assert_eq!(f("urn:-ex:a"), Err(UrnError::Nid));
+        assert_eq!(f("urn:ex-:a"), Err(UrnError::Nid));
+        assert_eq!(f("urn:e.x:a"), Err(UrnError::Nid));
+        assert_eq!(
+            f("urn:a23456789012345678901234567890123:a"),
+            Err(UrnError::Nid)
+        );
+        assert!(f("urn:a2345678901234567890123456789012:a").is_ok());
+        assert_eq!(f("urn:ex:"), Err(UrnError::Nss));
+        assert_eq!(f("urn:ex:/a"), Err(UrnError::Nss));
+        assert_eq!(f("urn://ex/a:b"), Err(UrnError::Nss));
+        assert_eq!(f("urn:ex:a?q"), Err(UrnError::RqComponents));
+        assert_eq!(f("urn:ex:a?"), Err(UrnError::RqComponents));
+        assert_eq!(f("urn:ex:a?+"), Err(UrnError::RqComponents));
+        assert_eq!(f("urn:ex:a?+r?="), Err(UrnError::RqComponents));
+        assert_eq!(f("urn:ex:a?=/q"), Err(UrnError::RqComponents));
+        assert_eq!(f("urn:ex:a b"), Err(UrnError::Syntax(ParseError::new(8))));
+    }
+
+    #[test]
+    fn test_equivalent() {
+        let f = |a, b| Urn::parse(a).unwrap().equivalent(&Urn::parse(b).unwrap());
+        // examples of RFC 8141 section 3.2
+        assert!(f("urn:example:a123,z456", "URN:example:a123,z456"));
+        assert!(f("urn:example:a123,z456", "urn:EXAMPLE:a123,z456"));
+        assert!(f("urn:example:a123,z456", "urn:example:a123,z456?+abc"));
+        assert!(f("urn:example:a123,z456", "urn:example:a123,z456?=xyz"));
+        assert!(f("urn:example:a123,z456", "urn:example:a123,z456#789"));
+        assert!(!f("urn:example:a123,z456", "urn:example:a123,z456/foo"));
+        assert!(!f("urn:example:a123,z456", "urn:example:a123,z456/bar"));
+        assert!(!f("urn:example:a123,z456", "urn:example:A123,z456"));
+        assert!(!f("urn:example:a123,z456", "urn:example:a123,Z456"));
+        assert!(f("urn:example:a123%2Cz456", "URN:EXAMPLE:a123%2cz456"));
+        assert!(!f("urn:example:a123%2Cz456", "urn:example:a123,z456"));
+        assert!(!f("urn:example:%2c", "urn:example:%2C%2C"));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_normalized() {
+        let urn = Urn::parse("URN:Example:a%2cb%e2?+r#f").unwrap();
+        assert_eq!(urn.normalized(), "urn:example:a%2Cb%E2");
+    }
+
+    #[test]
+    fn test_validate() {
+        let f = |s| Urn::parse(s).unwrap().validate(NAMESPACES);
+        assert_eq!(f("urn:isbn:0451450523"), Ok(()));
+        assert_eq!(f("urn:ISBN:0-8044-2957-X"), Ok(()));
+        assert_eq!(f("urn:isbn:978-0-306-40615-7"), Ok(()));
+        assert_eq!(f("urn:isbn:9790-2600-0043-8"), Ok(()));
+        assert_eq!(f("urn:isbn:0451450524"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:isbn:978-0-306-40615-8"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:isbn:977-0-306-40615-8"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:isbn:X451450523"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:isbn:045145052"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:isbn:-0451450523"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:isbn:97803064061570"), Err(UrnError::Namespace));
+
+        assert_eq!(f("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bf6"), Ok(()));
+        assert_eq!(f("urn:UUID:F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6"), Ok(()));
+        assert_eq!(
+            f("urn:uuid:f81d4fae7dec11d0a76500a0c91e6bf6"),
+            Err(UrnError::Namespace)
+        );
+        assert_eq!(
+            f("urn:uuid:f81d4fae-7dec-11d0-a765-00a0c91e6bfg"),
+            Err(UrnError::Namespace)
+        );
+
+        assert_eq!(f("urn:ietf:rfc:2648"), Ok(()));
+        assert_eq!(f("urn:ietf:std:50"), Ok(()));
+        assert_eq!(f("urn:ietf:id:ietf-urn-ietf-06"), Ok(()));
+        assert_eq!(f("urn:ietf:params:xml:ns:netconf:base:1.0"), Ok(()));
+        assert_eq!(f("urn:ietf:rfc:02648"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:ietf:rfc:x"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:ietf:foo:1"), Err(UrnError::Namespace));
+        assert_eq!(f("urn:ietf:rfc"), Err(UrnError::Namespace));
+
+        assert_eq!(f("urn:example:anything"), Ok(()));
+
+        // a namespace of the caller
+        struct Example;
+        impl Namespace for Example {
+            fn nid(&self) -> &str {
+                "example"
+            }
+            fn is_valid(&self, nss: &str) -> bool {
+                nss.starts_with("a")
+            }
+        }
+        let urn = Urn::parse("urn:Example:b").unwrap();
+        assert_eq!(urn.validate(&[&Example]), Err(UrnError::Namespace));
+        assert_eq!(urn.validate(&[&Isbn]), Ok(()));
+    }
+}
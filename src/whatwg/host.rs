@@ -0,0 +1,355 @@
+//! Host parsing and serialization
+//!
+//! <https://url.spec.whatwg.org/#hosts-(domains-and-ip-addresses)>
+
+use alloc::{string::String, vec::Vec};
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    IdnaError,
+    host::{parse_ipv4, parse_ipv4_number},
+    idna,
+    percent_encoding::decode,
+};
+
+use super::{is_c0_control_encoded, percent_encode};
+
+/// An error returned when a host cannot be parsed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HostError {
+    /// An IPv6 address does not end with `]`.
+    UnclosedBracket,
+    /// The text in brackets is not an IPv6 address.
+    Ipv6,
+    /// The domain ends in a number but is not an IPv4 address.
+    Ipv4,
+    /// The host contains a forbidden host or domain code point.
+    ForbiddenCodePoint(char),
+    /// The domain cannot be converted to ASCII.
+    Idna(IdnaError),
+    /// The domain is empty after the conversion to ASCII.
+    EmptyDomain,
+}
+
+impl core::fmt::Display for HostError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HostError::UnclosedBracket => write!(f, "IPv6 address is not closed by ']'"),
+            HostError::Ipv6 => write!(f, "invalid IPv6 address"),
+            HostError::Ipv4 => write!(f, "invalid IPv4 address"),
+            HostError::ForbiddenCodePoint(c) => write!(f, "forbidden code point {:?}", c),
+            HostError::Idna(e) => write!(f, "{}", e),
+            HostError::EmptyDomain => write!(f, "empty domain"),
+        }
+    }
+}
+
+impl core::error::Error for HostError {}
+
+/// <https://url.spec.whatwg.org/#concept-host>
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum Host {
+    Domain(String),
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    Opaque(String),
+    Empty,
+}
+
+impl core::fmt::Display for Host {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Host::Domain(s) | Host::Opaque(s) => f.write_str(s),
+            Host::Ipv4(addr) => write!(f, "{}", addr),
+            Host::Ipv6(addr) => {
+                f.write_str("[")?;
+                write_ipv6(f, addr)?;
+                f.write_str("]")
+            }
+            Host::Empty => Ok(()),
+        }
+    }
+}
+
+/// <https://url.spec.whatwg.org/#concept-ipv6-serializer>
+fn write_ipv6(f: &mut core::fmt::Formatter<'_>, addr: &Ipv6Addr) -> core::fmt::Result {
+    let pieces = addr.segments();
+    let mut compress = None;
+    let mut longest = 1;
+    let mut i = 0;
+    while i < 8 {
+        let len = pieces[i..].iter().take_while(|p| **p == 0).count();
+        if len > longest {
+            compress = Some(i);
+            longest = len;
+        }
+        i += len.max(1);
+    }
+    let mut ignore0 = false;
+    for (i, piece) in pieces.iter().enumerate() {
+        if ignore0 && *piece == 0 {
+            continue;
+        }
+        ignore0 = false;
+        if compress == Some(i) {
+            f.write_str(if i == 0 { "::" } else { ":" })?;
+            ignore0 = true;
+            continue;
+        }
+        write!(f, "{:x}", piece)?;
+        if i != 7 {
+            f.write_str(":")?;
+        }
+    }
+    Ok(())
+}
+
+/// <https://url.spec.whatwg.org/#forbidden-host-code-point>
+fn is_forbidden_host(c: char) -> bool {
+    matches!(
+        c,
+        '\0' | '\t'
+            | '\n'
+            | '\r'
+            | ' '
+            | '#'
+            | '/'
+            | ':'
+            | '<'
+            | '>'
+            | '?'
+            | '@'
+            | '['
+            | '\\'
+            | ']'
+            | '^'
+            | '|'
+    )
+}
+
+/// <https://url.spec.whatwg.org/#forbidden-domain-code-point>
+fn is_forbidden_domain(c: char) -> bool {
+    is_forbidden_host(c) || c.is_ascii_control() || c == '%'
+}
+
+/// Parses `input` as the host of a special URL, or of another URL when `opaque` is set.
+///
+/// <https://url.spec.whatwg.org/#concept-host-parser>
+pub(super) fn parse(input: &str, opaque: bool) -> Result<Host, HostError> {
+    if let Some(rest) = input.strip_prefix('[') {
+        let address = rest.strip_suffix(']').ok_or(HostError::UnclosedBracket)?;
+        return parse_ipv6(address).map(Host::Ipv6);
+    }
+    if opaque {
+        if let Some(c) = input.chars().find(|c| is_forbidden_host(*c)) {
+            return Err(HostError::ForbiddenCodePoint(c));
+        }
+        let mut host = String::with_capacity(input.len());
+        for c in input.chars() {
+            percent_encode(c, is_c0_control_encoded, &mut host);
+        }
+        return Ok(Host::Opaque(host));
+    }
+
+    let domain = String::from_utf8_lossy(&decode(input)).into_owned();
+    let ascii = domain_to_ascii(&domain)?;
+    if let Some(c) = ascii.chars().find(|c| is_forbidden_domain(*c)) {
+        return Err(HostError::ForbiddenCodePoint(c));
+    }
+    if ends_in_a_number(&ascii) {
+        return parse_ipv4(ascii.bytes())
+            .map(Host::Ipv4)
+            .ok_or(HostError::Ipv4);
+    }
+    Ok(Host::Domain(ascii))
+}
+
+/// <https://url.spec.whatwg.org/#concept-domain-to-ascii>
+fn domain_to_ascii(domain: &str) -> Result<String, HostError> {
+    let needs_processing = !domain.is_ascii()
+        || domain
+            .split('.')
+            .any(|l| l.get(..4).is_some_and(|p| p.eq_ignore_ascii_case("xn--")));
+    let ascii = if needs_processing {
+        idna::to_ascii_for_url(domain).map_err(HostError::Idna)?
+    } else {
+        domain.to_ascii_lowercase()
+    };
+    if ascii.is_empty() {
+        return Err(HostError::EmptyDomain);
+    }
+    Ok(ascii)
+}
+
+/// <https://url.spec.whatwg.org/#ends-in-a-number-checker>
+fn ends_in_a_number(domain: &str) -> bool {
+    let mut parts = domain.split('.').collect::<Vec<_>>();
+    if parts.last() == Some(&"") {
+        if parts.len() == 1 {
+            return false;
+        }
+        parts.pop();
+    }
+    let last = parts.last().expect("split yields an item");
+    (!last.is_empty() && last.bytes().all(|b| b.is_ascii_digit()))
+        || parse_ipv4_number(last.as_bytes()).is_some()
+}
+
+/// <https://url.spec.whatwg.org/#concept-ipv6-parser>
+fn parse_ipv6(input: &str) -> Result<Ipv6Addr, HostError> {
+    let input = input.chars().collect::<Vec<_>>();
+    let c = |p: usize| input.get(p).copied();
+    let mut address = [0u16; 8];
+    let mut piece_index = 0;
+    let mut compress = None;
+    let mut p = 0;
+    if c(p) == Some(':') {
+        if c(p + 1) != Some(':') {
+            return Err(HostError::Ipv6);
+        }
+        p += 2;
+        piece_index += 1;
+        compress = Some(piece_index);
+    }
+    while c(p).is_some() {
+        if piece_index == 8 {
+            return Err(HostError::Ipv6);
+        }
+        if c(p) == Some(':') {
+            if compress.is_some() {
+                return Err(HostError::Ipv6);
+            }
+            p += 1;
+            piece_index += 1;
+            compress = Some(piece_index);
+            continue;
+        }
+        let mut value = 0u16;
+        let mut length = 0;
+        while length < 4
+            && let Some(digit) = c(p).and_then(|c| c.to_digit(16))
+        {
+            value = value * 0x10 + digit as u16;
+            p += 1;
+            length += 1;
+        }
+        match c(p) {
+            Some('.') => {
+                if length == 0 {
+                    return Err(HostError::Ipv6);
+                }
+                p -= length;
+                if piece_index > 6 {
+                    return Err(HostError::Ipv6);
+                }
+                let mut numbers_seen = 0;
+                while c(p).is_some() {
+                    let mut ipv4_piece: Option<u16> = None;
+                    if numbers_seen > 0 {
+                        if c(p) == Some('.') && numbers_seen < 4 {
+                            p += 1;
+                        } else {
+                            return Err(HostError::Ipv6);
+                        }
+                    }
+                    if !c(p).is_some_and(|c| c.is_ascii_digit()) {
+                        return Err(HostError::Ipv6);
+                    }
+                    while let Some(number) = c(p).and_then(|c| c.to_digit(10)) {
+                        ipv4_piece = match ipv4_piece {
+                            None => Some(number as u16),
+                            Some(0) => return Err(HostError::Ipv6),
+                            Some(n) => Some(n * 10 + number as u16),
+                        };
+                        if ipv4_piece > Some(255) {
+                            return Err(HostError::Ipv6);
+                        }
+                        p += 1;
+                    }
+                    address[piece_index] =
+                        address[piece_index] * 0x100 + ipv4_piece.expect("a digit");
+                    numbers_seen += 1;
+                    if numbers_seen == 2 || numbers_seen == 4 {
+                        piece_index += 1;
+                    }
+                }
+                if numbers_seen != 4 {
+                    return Err(HostError::Ipv6);
+                }
+                break;
+            }
+            Some(':') => {
+                p += 1;
+                if c(p).is_none() {
+                    return Err(HostError::Ipv6);
+                }
+            }
+            Some(_) => return Err(HostError::Ipv6),
+            None => {}
+        }
+        address[piece_index] = value;
+        piece_index += 1;
+    }
+    match compress {
+        Some(compress) => {
+            let mut swaps = piece_index - compress;
+            piece_index = 7;
+            while piece_index != 0 && swaps > 0 {
+                address.swap(piece_index, compress + swaps - 1);
+                piece_index -= 1;
+                swaps -= 1;
+            }
+        }
+        None if piece_index != 8 => return Err(HostError::Ipv6),
+        None => {}
+    }
+    Ok(Ipv6Addr::from(address))
+}
+
+impl Host {
+    /// Returns `true` for the host of `file://localhost/`, which is replaced by the empty host.
+    pub(super) fn is_localhost(&self) -> bool {
+        matches!(self, Host::Domain(d) if d == "localhost")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let f = |s, opaque| parse(s, opaque).map(|h| h.to_string());
+        assert_eq!(f("EXAMPLE.com", false).as_deref(), Ok("example.com"));
+        assert_eq!(
+            f("b%C3%BCcher.de", false).as_deref(),
+            Ok("xn--bcher-kva.de")
+        );
+        assert_eq!(f("0x7f.1", false).as_deref(), Ok("127.0.0.1"));
+        assert_eq!(f("0300.0250.0.01", false).as_deref(), Ok("192.168.0.1"));
+        assert_eq!(f("4294967295", false).as_deref(), Ok("255.255.255.255"));
+        assert_eq!(f("4294967296", false), Err(HostError::Ipv4));
+        assert_eq!(f("0x100.1", false), Err(HostError::Ipv4));
+        assert_eq!(f("1..2", false), Err(HostError::Ipv4));
+        assert_eq!(f("1.2.3.4.", false).as_deref(), Ok("1.2.3.4"));
+        // the last label is empty, so the host does not end in a number
+        assert_eq!(f("1.2.3.4..", false).as_deref(), Ok("1.2.3.4.."));
+        assert_eq!(f("1.2.3.09", false), Err(HostError::Ipv4));
+        assert_eq!(f("foo.09", false), Err(HostError::Ipv4));
+        assert_eq!(f("foo.0x", false), Err(HostError::Ipv4));
+        assert_eq!(f("foo.1a", false).as_deref(), Ok("foo.1a"));
+        assert_eq!(f("[0:0::1]", false).as_deref(), Ok("[::1]"));
+        assert_eq!(f("[1:0:0:2::3:0]", false).as_deref(), Ok("[1::2:0:0:3:0]"));
+        assert_eq!(
+            f("[::ffff:1.2.3.4]", false).as_deref(),
+            Ok("[::ffff:102:304]")
+        );
+        assert_eq!(f("[::1", false), Err(HostError::UnclosedBracket));
+        assert_eq!(f("[1:2]", false), Err(HostError::Ipv6));
+        assert_eq!(f("a b", false), Err(HostError::ForbiddenCodePoint(' ')));
+        assert_eq!(f("a%25b", false), Err(HostError::ForbiddenCodePoint('%')));
+        assert_eq!(f("%", true).as_deref(), Ok("%"));
+        assert_eq!(f("Ü", true).as_deref(), Ok("%C3%9C"));
+        assert_eq!(f("a^b", true), Err(HostError::ForbiddenCodePoint('^')));
+    }
+}